@@ -19,6 +19,7 @@ pub fn add_cli_context(mut engine_state: EngineState) -> EngineState {
             History,
             HistoryImport,
             HistorySession,
+            HistoryStats,
             Keybindings,
             KeybindingsDefault,
             KeybindingsList,
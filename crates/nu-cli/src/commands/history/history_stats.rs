@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use chrono::Timelike;
+use nu_engine::command_prelude::*;
+use nu_protocol::HistoryFileFormat;
+use reedline::{History as ReedlineHistory, SearchDirection, SearchQuery, SqliteBackedHistory};
+
+#[derive(Clone)]
+pub struct HistoryStats;
+
+impl Command for HistoryStats {
+    fn name(&self) -> &str {
+        "history stats"
+    }
+
+    fn description(&self) -> &str {
+        "Show command frequency, average duration, and failure-rate statistics from history."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Duration and failure-rate statistics are only recorded by the sqlite history file format \
+(see `$env.config.history.file_format`); with the plaintext format only command frequency is available."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("history stats")
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .switch(
+                "by-hour",
+                "Group by the hour of day a command was run instead of by command",
+                None,
+            )
+            .category(Category::History)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "history stats",
+                description: "Show the commands you run most often, with their average duration and failure rate",
+                result: None,
+            },
+            Example {
+                example: "history stats --by-hour",
+                description: "Show how many commands you've run in each hour of the day",
+                result: None,
+            },
+            Example {
+                example: "history stats | sort-by failure_rate --reverse | first 10",
+                description: "Show the commands that fail most often",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let by_hour = call.has_flag(engine_state, stack, "by-hour")?;
+
+        let Some(history) = engine_state.history_config() else {
+            return Ok(PipelineData::empty());
+        };
+        let Some(history_path) = history.file_path() else {
+            return Err(ShellError::ConfigDirNotFound { span: Some(head) });
+        };
+        if history.file_format != HistoryFileFormat::Sqlite {
+            return Err(ShellError::GenericError {
+                error: "`history stats` requires the sqlite history file format".into(),
+                msg: "durations, exit statuses, and timestamps aren't recorded in plaintext history"
+                    .into(),
+                span: Some(head),
+                help: Some("set `$env.config.history.file_format` to `sqlite`".into()),
+                inner: vec![],
+            });
+        }
+
+        let reader = SqliteBackedHistory::with_file(history_path.clone(), None, None)
+            .map_err(|err| ShellError::IOErrorSpanned {
+                msg: err.to_string(),
+                span: head,
+            })?;
+        let entries = reader
+            .search(SearchQuery::everything(SearchDirection::Forward, None))
+            .map_err(|err| ShellError::IOErrorSpanned {
+                msg: err.to_string(),
+                span: head,
+            })?;
+
+        let rows = if by_hour {
+            stats_by_hour(&entries, head)
+        } else {
+            stats_by_command(&entries, head)
+        };
+
+        Ok(rows.into_pipeline_data(head, engine_state.signals().clone()))
+    }
+}
+
+#[derive(Default)]
+struct CommandStats {
+    count: i64,
+    total_duration: i64,
+    known_durations: i64,
+    failures: i64,
+}
+
+fn stats_by_command(entries: &[reedline::HistoryItem], head: Span) -> Vec<Value> {
+    let mut by_command: HashMap<&str, CommandStats> = HashMap::new();
+    for entry in entries {
+        let stats = by_command.entry(&entry.command_line).or_default();
+        stats.count += 1;
+        if let Some(duration) = entry.duration.and_then(|d| i64::try_from(d.as_nanos()).ok()) {
+            stats.total_duration += duration;
+            stats.known_durations += 1;
+        }
+        if entry.exit_status.is_some_and(|status| status != 0) {
+            stats.failures += 1;
+        }
+    }
+
+    let mut rows: Vec<(&str, CommandStats)> = by_command.into_iter().collect();
+    rows.sort_by(|(_, a), (_, b)| b.count.cmp(&a.count));
+
+    rows.into_iter()
+        .map(|(command, stats)| {
+            let avg_duration = if stats.known_durations > 0 {
+                Value::duration(stats.total_duration / stats.known_durations, head)
+            } else {
+                Value::nothing(head)
+            };
+            let failure_rate = stats.failures as f64 / stats.count as f64;
+            Value::record(
+                record! {
+                    "command" => Value::string(command, head),
+                    "count" => Value::int(stats.count, head),
+                    "avg_duration" => avg_duration,
+                    "failures" => Value::int(stats.failures, head),
+                    "failure_rate" => Value::float(failure_rate, head),
+                },
+                head,
+            )
+        })
+        .collect()
+}
+
+fn stats_by_hour(entries: &[reedline::HistoryItem], head: Span) -> Vec<Value> {
+    let mut by_hour: HashMap<u32, i64> = HashMap::new();
+    for entry in entries {
+        if let Some(timestamp) = entry.start_timestamp {
+            *by_hour.entry(timestamp.hour()).or_default() += 1;
+        }
+    }
+
+    (0..24u32)
+        .map(|hour| {
+            Value::record(
+                record! {
+                    "hour" => Value::int(hour.into(), head),
+                    "count" => Value::int(by_hour.get(&hour).copied().unwrap_or(0), head),
+                },
+                head,
+            )
+        })
+        .collect()
+}
@@ -2,7 +2,9 @@ mod fields;
 mod history_;
 mod history_import;
 mod history_session;
+mod history_stats;
 
 pub use history_::History;
 pub use history_import::HistoryImport;
 pub use history_session::HistorySession;
+pub use history_stats::HistoryStats;
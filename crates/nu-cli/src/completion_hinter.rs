@@ -0,0 +1,73 @@
+use crate::completions::NuCompleter;
+use nu_ansi_term::Style;
+use reedline::{CwdAwareHinter, Hinter, History};
+
+/// A fish-style inline suggestion source that combines the usual history-based hint with a
+/// fallback to Nushell's own completion engine.
+///
+/// History is tried first, since a full previous command is normally the more useful
+/// suggestion. If history has nothing for the current line, the top completion candidate (if
+/// any) is shown instead, so a novel command still gets a suggestion for its current token.
+pub struct CompletionHinter {
+    history_hinter: CwdAwareHinter,
+    completer: NuCompleter,
+    current_hint: String,
+}
+
+impl CompletionHinter {
+    pub fn new(completer: NuCompleter) -> Self {
+        Self {
+            history_hinter: CwdAwareHinter::default(),
+            completer,
+            current_hint: String::new(),
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.history_hinter = self.history_hinter.with_style(style);
+        self
+    }
+}
+
+impl Hinter for CompletionHinter {
+    fn handle(
+        &mut self,
+        line: &str,
+        pos: usize,
+        history: &dyn History,
+        use_ansi_coloring: bool,
+        cwd: &str,
+    ) -> String {
+        let history_hint =
+            self.history_hinter
+                .handle(line, pos, history, use_ansi_coloring, cwd);
+
+        self.current_hint = if !history_hint.is_empty() {
+            history_hint
+        } else {
+            self.completer
+                .fetch_completions_at(line, pos)
+                .into_iter()
+                .next()
+                .and_then(|suggestion| {
+                    let typed = line.get(suggestion.suggestion.span.start..pos)?;
+                    suggestion
+                        .suggestion
+                        .value
+                        .strip_prefix(typed)
+                        .map(str::to_string)
+                })
+                .unwrap_or_default()
+        };
+
+        self.current_hint.clone()
+    }
+
+    fn complete_hint(&self) -> String {
+        self.current_hint.clone()
+    }
+
+    fn next_hint(&mut self, forward: bool) -> String {
+        self.history_hinter.next_hint(forward)
+    }
+}
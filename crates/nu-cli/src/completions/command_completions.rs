@@ -119,7 +119,7 @@ impl CommandCompletion {
             },
             true,
         );
-        for (name, description, typ) in filtered_commands {
+        for (name, description, typ, category) in filtered_commands {
             let name = String::from_utf8_lossy(&name);
             internal_suggs.insert(
                 name.to_string(),
@@ -127,6 +127,7 @@ impl CommandCompletion {
                     suggestion: Suggestion {
                         value: name.to_string(),
                         description,
+                        extra: Some(vec![typ.to_string(), category.to_string()]),
                         span: sugg_span,
                         append_whitespace: true,
                         ..Suggestion::default()
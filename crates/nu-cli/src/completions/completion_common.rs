@@ -153,6 +153,19 @@ fn surround_remove(partial: &str) -> String {
     partial.to_string()
 }
 
+/// Returns the drive letter a Windows path `Prefix` component names, e.g. `Some('D')` for `D:` or
+/// `D:\`, so drive-relative completions can look up that drive's remembered current directory.
+fn drive_letter(prefix: std::path::PrefixComponent<'_>) -> Option<char> {
+    use std::path::Prefix;
+
+    match prefix.kind() {
+        Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => {
+            Some((letter as char).to_ascii_uppercase())
+        }
+        _ => None,
+    }
+}
+
 pub struct FileSuggestion {
     pub span: nu_protocol::Span,
     pub path: String,
@@ -211,9 +224,16 @@ pub fn complete_item(
 
     let mut components = Path::new(&partial).components().peekable();
     match components.peek().cloned() {
-        Some(c @ Component::Prefix(..)) => {
+        Some(c @ Component::Prefix(prefix)) => {
             // windows only by definition
-            cwds = vec![[c, Component::RootDir].iter().collect()];
+            let mut rest = components.clone();
+            rest.next();
+            let drive_relative = !matches!(rest.peek(), Some(Component::RootDir));
+            let drive_cwd = drive_relative
+                .then(|| drive_letter(prefix))
+                .flatten()
+                .and_then(|drive| engine_state.remembered_drive_cwd(drive));
+            cwds = vec![drive_cwd.unwrap_or_else(|| [c, Component::RootDir].iter().collect())];
             prefix_len = c.as_os_str().len();
             original_cwd = OriginalCwd::Prefix(c.as_os_str().to_string_lossy().into_owned());
         }
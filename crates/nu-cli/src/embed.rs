@@ -0,0 +1,51 @@
+//! A small, stable facade for Rust programs that want to embed Nushell without wiring up the
+//! parse-then-merge-then-eval dance (and the process-exiting error handling of the `nu` binary)
+//! themselves.
+//!
+//! This intentionally does no more than [`evaluate_commands`](crate::evaluate_commands) does
+//! internally: it does not register any commands (callers add whichever `Command`
+//! implementations they need to the `EngineState` before calling this), and it does not manage
+//! configuration files, plugins, or history.
+
+use nu_engine::eval_block;
+use nu_parser::parse;
+use nu_protocol::{
+    debugger::WithoutDebug,
+    engine::{EngineState, Stack, StateWorkingSet},
+    PipelineData, ShellError, Span, Value,
+};
+
+/// Parse and evaluate a snippet of Nushell source against an existing [`EngineState`], returning
+/// the final pipeline value.
+///
+/// Unlike [`evaluate_commands`](crate::evaluate_commands), this never calls `std::process::exit`;
+/// parse errors are returned to the caller as a [`ShellError`].
+pub fn eval_source(
+    engine_state: &mut EngineState,
+    stack: &mut Stack,
+    source: &[u8],
+    fname: &str,
+    input: PipelineData,
+) -> Result<Value, ShellError> {
+    let block = {
+        let mut working_set = StateWorkingSet::new(engine_state);
+        let block = parse(&mut working_set, Some(fname), source, false);
+
+        if let Some(err) = working_set.parse_errors.first() {
+            return Err(ShellError::GenericError {
+                error: "Parse error while embedding Nushell".into(),
+                msg: err.to_string(),
+                span: Some(err.span()),
+                help: None,
+                inner: vec![],
+            });
+        }
+
+        let delta = working_set.render();
+        engine_state.merge_delta(delta)?;
+        block
+    };
+
+    let pipeline = eval_block::<WithoutDebug>(engine_state, stack, &block, input)?;
+    pipeline.into_value(Span::unknown())
+}
@@ -16,6 +16,9 @@ pub struct EvaluateCommandsOpts {
     pub table_mode: Option<Value>,
     pub error_style: Option<Value>,
     pub no_newline: bool,
+    /// Append `| to json` to the given commands, so the final pipeline is rendered as JSON
+    /// instead of a table. Intended for `nu -c ... --json` in scripts and other automation.
+    pub json: bool,
 }
 
 /// Run a command (or commands) given to us by the user
@@ -30,8 +33,20 @@ pub fn evaluate_commands(
         table_mode,
         error_style,
         no_newline,
+        json,
     } = opts;
 
+    let json_commands;
+    let commands = if json {
+        json_commands = Spanned {
+            item: format!("{} | to json", commands.item),
+            span: commands.span,
+        };
+        &json_commands
+    } else {
+        commands
+    };
+
     // Handle the configured error style early
     if let Some(e_style) = error_style {
         match e_style.coerce_str()?.parse() {
@@ -121,9 +121,20 @@ pub fn evaluate_file(
         // Print the pipeline output of the last command of the file.
         print_pipeline(engine_state, stack, pipeline, true)?;
 
-        // Invoke the main command with arguments.
-        // Arguments with whitespaces are quoted, thus can be safely concatenated by whitespace.
-        let args = format!("main {}", args.join(" "));
+        // Invoke the main command with arguments. Only arguments that actually need it are quoted
+        // and escaped, so that plain words and flags (e.g. `--verbose`) are still parsed as such,
+        // while values containing whitespace or characters meaningful to the parser (quotes, `$`,
+        // backticks, etc.) are passed through to `main` verbatim rather than being reinterpreted.
+        let args = std::iter::once("main".to_string())
+            .chain(args.iter().map(|arg| {
+                if nu_utils::needs_quoting(arg) {
+                    nu_utils::escape_quote_string(arg)
+                } else {
+                    arg.clone()
+                }
+            }))
+            .collect::<Vec<_>>()
+            .join(" ");
         eval_source(
             engine_state,
             stack,
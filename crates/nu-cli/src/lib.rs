@@ -1,7 +1,9 @@
 #![doc = include_str!("../README.md")]
 mod commands;
+mod completion_hinter;
 mod completions;
 mod config_files;
+pub mod embed;
 mod eval_cmds;
 mod eval_file;
 mod menus;
@@ -16,6 +18,7 @@ mod util;
 mod validation;
 
 pub use commands::add_cli_context;
+pub use completion_hinter::CompletionHinter;
 pub use completions::{FileCompletion, NuCompleter, SemanticSuggestion, SuggestionKind};
 pub use config_files::eval_config_contents;
 pub use eval_cmds::{evaluate_commands, EvaluateCommandsOpts};
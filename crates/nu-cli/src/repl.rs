@@ -6,6 +6,7 @@ use crate::prompt_update::{
     VSCODE_PRE_EXECUTION_MARKER,
 };
 use crate::{
+    completion_hinter::CompletionHinter,
     completions::NuCompleter,
     nu_highlight::NoOpHighlighter,
     prompt_update,
@@ -84,8 +85,13 @@ pub fn evaluate_repl(
         "CMD_DURATION_MS".into(),
         Value::string("0823", Span::unknown()),
     );
+    unique_stack.add_env_var(
+        "CMD_DURATION".into(),
+        Value::duration(823 * 1_000_000, Span::unknown()),
+    );
 
     unique_stack.set_last_exit_code(0, Span::unknown());
+    unique_stack.set_pipeline_exit_codes(vec![0], Span::unknown());
 
     let mut line_editor = get_line_editor(engine_state, use_color)?;
     let temp_file = temp_dir().join(format!("{}.nu", uuid::Uuid::new_v4()));
@@ -404,11 +410,14 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
 
     start_time = std::time::Instant::now();
     line_editor = if config.use_ansi_coloring.get(engine_state) {
-        line_editor.with_hinter(Box::new({
-            // As of Nov 2022, "hints" color_config closures only get `null` passed in.
-            let style = style_computer.compute("hints", &Value::nothing(Span::unknown()));
-            CwdAwareHinter::default().with_style(style)
-        }))
+        // As of Nov 2022, "hints" color_config closures only get `null` passed in.
+        let style = style_computer.compute("hints", &Value::nothing(Span::unknown()));
+        if config.completions.use_completer_hint {
+            let completer = NuCompleter::new(engine_reference.clone(), stack_arc.clone());
+            line_editor.with_hinter(Box::new(CompletionHinter::new(completer).with_style(style)))
+        } else {
+            line_editor.with_hinter(Box::new(CwdAwareHinter::default().with_style(style)))
+        }
     } else {
         line_editor.disable_hints()
     };
@@ -633,6 +642,12 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
                 "CMD_DURATION_MS".into(),
                 Value::string(format!("{}", cmd_duration.as_millis()), Span::unknown()),
             );
+            // A `duration`-typed counterpart to `CMD_DURATION_MS`, so prompts can pipe it
+            // straight into `format duration` instead of parsing the millisecond string.
+            stack.add_env_var(
+                "CMD_DURATION".into(),
+                Value::duration(cmd_duration.as_nanos() as i64, Span::unknown()),
+            );
 
             if history_supports_meta {
                 if let Err(e) = fill_in_result_related_history_metadata(
@@ -18,10 +18,22 @@ impl Validator for NuValidator {
         if matches!(
             working_set.parse_errors.first(),
             Some(ParseError::UnexpectedEof(..))
-        ) {
+        ) || ends_with_dangling_pipe(line)
+        {
             ValidationResult::Incomplete
         } else {
             ValidationResult::Complete
         }
     }
 }
+
+/// Whether `line` ends with a pipe that's clearly meant to carry a pipeline onto the next line,
+/// e.g. `ls |` or `ls | where size > 1mb |`. This lets the line editor auto-continue those without
+/// requiring a trailing backslash, matching how unbalanced delimiters are already handled above.
+///
+/// Deliberately conservative: only a single trailing `|` counts, so `true || false` (boolean or)
+/// and pipe redirection operators like `|&` are left alone.
+fn ends_with_dangling_pipe(line: &str) -> bool {
+    let trimmed = line.trim_end();
+    trimmed.ends_with('|') && !trimmed.ends_with("||")
+}
@@ -1,5 +1,5 @@
 use nu_engine::command_prelude::*;
-use nu_protocol::{engine::StateWorkingSet, ByteStreamSource, PipelineMetadata};
+use nu_protocol::{engine::StateWorkingSet, ByteStream, ByteStreamSource, PipelineMetadata};
 
 #[derive(Clone)]
 pub struct Describe;
@@ -26,6 +26,11 @@ impl Command for Describe {
                 "show detailed information about the value",
                 Some('d'),
             )
+            .switch(
+                "peek",
+                "for a stream, print the description to stderr and pass the stream through untouched instead of collecting or draining it",
+                Some('p'),
+            )
             .category(Category::Core)
     }
 
@@ -43,6 +48,7 @@ impl Command for Describe {
         let options = Options {
             no_collect: call.has_flag(engine_state, stack, "no-collect")?,
             detailed: call.has_flag(engine_state, stack, "detailed")?,
+            peek: call.has_flag(engine_state, stack, "peek")?,
         };
         run(Some(engine_state), call, input, options)
     }
@@ -56,6 +62,7 @@ impl Command for Describe {
         let options = Options {
             no_collect: call.has_flag_const(working_set, "no-collect")?,
             detailed: call.has_flag_const(working_set, "detailed")?,
+            peek: call.has_flag_const(working_set, "peek")?,
         };
         run(None, call, input, options)
     }
@@ -138,6 +145,11 @@ impl Command for Describe {
                 result: None // Give "Running external commands not supported" error
                 // result: Some(Value::test_string("stream")),
             },
+            Example {
+                description: "Peek at a stream's description without collecting or draining it, so it still reaches the next command untouched",
+                example: "open --raw file.txt | describe --peek | lines",
+                result: None,
+            },
         ]
     }
 
@@ -150,6 +162,7 @@ impl Command for Describe {
 struct Options {
     no_collect: bool,
     detailed: bool,
+    peek: bool,
 }
 
 fn run(
@@ -165,18 +178,20 @@ fn run(
         PipelineData::ByteStream(stream, ..) => {
             let type_ = stream.type_().describe();
 
-            let description = if options.detailed {
-                let origin = match stream.source() {
-                    ByteStreamSource::Read(_) => "unknown",
-                    ByteStreamSource::File(_) => "file",
-                    #[cfg(feature = "os")]
-                    ByteStreamSource::Child(_) => "external",
-                };
+            if options.peek {
+                eprintln!(
+                    "describe (peek): type={type_}, origin={}, content_type={}",
+                    origin_of(&stream),
+                    content_type_of(&metadata)
+                );
+                return Ok(PipelineData::ByteStream(stream, metadata));
+            }
 
+            let description = if options.detailed {
                 Value::record(
                     record! {
                         "type" => Value::string(type_, head),
-                        "origin" => Value::string(origin, head),
+                        "origin" => Value::string(origin_of(&stream), head),
                         "metadata" => metadata_to_value(metadata, head),
                     },
                     head,
@@ -192,7 +207,13 @@ fn run(
             description
         }
         PipelineData::ListStream(stream, ..) => {
-            if options.detailed {
+            if options.peek {
+                eprintln!(
+                    "describe (peek): type=stream, origin=nushell, content_type={}",
+                    content_type_of(&metadata)
+                );
+                return Ok(PipelineData::ListStream(stream, metadata));
+            } else if options.detailed {
                 let subtype = if options.no_collect {
                     Value::string("any", head)
                 } else {
@@ -336,6 +357,22 @@ fn metadata_to_value(metadata: Option<PipelineMetadata>, head: Span) -> Value {
     }
 }
 
+fn origin_of(stream: &ByteStream) -> &'static str {
+    match stream.source() {
+        ByteStreamSource::Read(_) => "unknown",
+        ByteStreamSource::File(_) => "file",
+        #[cfg(feature = "os")]
+        ByteStreamSource::Child(_) => "external",
+    }
+}
+
+fn content_type_of(metadata: &Option<PipelineMetadata>) -> String {
+    metadata
+        .as_ref()
+        .and_then(|m| m.content_type.clone())
+        .unwrap_or_else(|| "unknown".into())
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -12,6 +12,13 @@ enum PercentageCalcMethod {
     Relative,
 }
 
+enum Binning {
+    /// Divide the data into this many equal-width bins between its min and max.
+    Auto(usize),
+    /// Bin the data using these explicit, ascending boundaries.
+    Edges(Vec<f64>),
+}
+
 impl Command for Histogram {
     fn name(&self) -> &str {
         "histogram"
@@ -23,6 +30,9 @@ impl Command for Histogram {
             .optional("column-name", SyntaxShape::String, "Column name to calc frequency, no need to provide if input is a list.")
             .optional("frequency-column-name", SyntaxShape::String, "Histogram's frequency column, default to be frequency column output.")
             .named("percentage-type", SyntaxShape::String, "percentage calculate method, can be 'normalize' or 'relative', in 'normalize', defaults to be 'normalize'", Some('t'))
+            .named("bins", SyntaxShape::Int, "Divide numeric data into this many equal-width bins between its min and max. Mutually exclusive with --edges.", Some('b'))
+            .named("edges", SyntaxShape::List(Box::new(SyntaxShape::Number)), "Bin numeric data using these explicit, ascending bucket boundaries. Mutually exclusive with --bins.", Some('e'))
+            .switch("log", "Scale the frequency bar by log(count + 1) instead of by count, so a few dominant buckets don't drown out the rest.", Some('l'))
             .category(Category::Chart)
     }
 
@@ -30,6 +40,12 @@ impl Command for Histogram {
         "Creates a new table with a histogram based on the column name passed in."
     }
 
+    fn extra_description(&self) -> &str {
+        "With --bins or --edges, numeric data is grouped into ranges first, and the output has \
+         `min`/`max` columns describing each bucket's boundaries instead of a single `value` \
+         column. Values outside the range covered by --edges are dropped."
+    }
+
     fn examples(&self) -> Vec<Example> {
         vec![
             Example {
@@ -68,7 +84,17 @@ impl Command for Histogram {
                 description: "Compute a histogram for a list of numbers, and percentage is based on the maximum value",
                 example: "[1 2 3 1 1 1 2 2 1 1] | histogram --percentage-type relative",
                 result: None,
-            }
+            },
+            Example {
+                description: "Bin a list of numbers into 3 equal-width buckets",
+                example: "[1 2 3 4 5 6 7 8 9] | histogram --bins 3",
+                result: None,
+            },
+            Example {
+                description: "Bin a column using explicit bucket edges",
+                example: "ls | histogram size --edges [0 1kb 1mb 1gb]",
+                result: None,
+            },
         ]
     }
 
@@ -84,7 +110,8 @@ impl Command for Histogram {
         let frequency_name_arg = call.opt::<Spanned<String>>(engine_state, stack, 1)?;
         let frequency_column_name = match frequency_name_arg {
             Some(inner) => {
-                let forbidden_column_names = ["value", "count", "quantile", "percentage"];
+                let forbidden_column_names =
+                    ["value", "count", "quantile", "percentage", "min", "max"];
                 if forbidden_column_names.contains(&inner.item.as_str()) {
                     return Err(ShellError::TypeMismatch {
                         err_message: format!(
@@ -121,6 +148,54 @@ impl Command for Histogram {
         };
 
         let span = call.head;
+
+        let bins: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "bins")?;
+        let edges: Option<Vec<Value>> = call.get_flag(engine_state, stack, "edges")?;
+        let log_scale = call.has_flag(engine_state, stack, "log")?;
+
+        let binning = match (bins, edges) {
+            (Some(_), Some(_)) => {
+                return Err(ShellError::IncompatibleParametersSingle {
+                    msg: "`--bins` and `--edges` cannot be used together".into(),
+                    span,
+                })
+            }
+            (Some(bins), None) => {
+                let count = usize::try_from(bins.item)
+                    .map_err(|_| ShellError::NeedsPositiveValue { span: bins.span })?;
+                if count == 0 {
+                    return Err(ShellError::IncorrectValue {
+                        msg: "`--bins` must be at least 1".into(),
+                        val_span: bins.span,
+                        call_span: span,
+                    });
+                }
+                Some(Binning::Auto(count))
+            }
+            (None, Some(edges)) => {
+                let edges = edges
+                    .iter()
+                    .map(|v| v.as_float())
+                    .collect::<Result<Vec<f64>, ShellError>>()?;
+                if edges.len() < 2 {
+                    return Err(ShellError::IncorrectValue {
+                        msg: "`--edges` needs at least two boundaries".into(),
+                        val_span: span,
+                        call_span: span,
+                    });
+                }
+                if !edges.windows(2).all(|w| w[0] < w[1]) {
+                    return Err(ShellError::IncorrectValue {
+                        msg: "`--edges` must be strictly ascending".into(),
+                        val_span: span,
+                        call_span: span,
+                    });
+                }
+                Some(Binning::Edges(edges))
+            }
+            (None, None) => None,
+        };
+
         let data_as_value = input.into_value(span)?;
         let value_span = data_as_value.span();
         // `input` is not a list, here we can return an error.
@@ -129,6 +204,8 @@ impl Command for Histogram {
             column_name,
             frequency_column_name,
             calc_method,
+            binning,
+            log_scale,
             span,
             // Note that as_list() filters out Value::Error here.
             value_span,
@@ -136,14 +213,40 @@ impl Command for Histogram {
     }
 }
 
+fn numeric_value(value: &Value) -> Option<f64> {
+    match value {
+        Value::Int { val, .. } => Some(*val as f64),
+        Value::Float { val, .. } => Some(*val),
+        Value::Duration { val, .. } => Some(*val as f64),
+        Value::Filesize { val, .. } => Some(val.get() as f64),
+        _ => None,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn run_histogram(
     values: Vec<Value>,
     column_name: Option<Spanned<String>>,
     freq_column: String,
     calc_method: PercentageCalcMethod,
+    binning: Option<Binning>,
+    log_scale: bool,
     head_span: Span,
     list_span: Span,
 ) -> Result<PipelineData, ShellError> {
+    if let Some(binning) = binning {
+        let numbers = numeric_values(values, &column_name, head_span, list_span)?;
+        let edges = bucket_edges(&binning, &numbers, head_span)?;
+        return Ok(histogram_binned_impl(
+            numbers,
+            edges,
+            calc_method,
+            &freq_column,
+            log_scale,
+            head_span,
+        ));
+    }
+
     let mut inputs = vec![];
     // convert from inputs to hashable values.
     match column_name {
@@ -207,15 +310,182 @@ fn run_histogram(
         &value_column_name,
         calc_method,
         &freq_column,
+        log_scale,
         head_span,
     ))
 }
 
+/// Pull every numeric value out of `values`, either directly or (if `column_name`
+/// is given) from that column of each record, mirroring the skip/skip/error rules
+/// the categorical path uses for non-hashable values.
+fn numeric_values(
+    values: Vec<Value>,
+    column_name: &Option<Spanned<String>>,
+    head_span: Span,
+    list_span: Span,
+) -> Result<Vec<f64>, ShellError> {
+    let mut numbers = vec![];
+    match column_name {
+        None => {
+            for v in values {
+                match v {
+                    Value::Error { error, .. } => return Err(*error),
+                    _ => {
+                        let t = v.get_type();
+                        let span = v.span();
+                        let n = numeric_value(&v).ok_or_else(|| ShellError::UnsupportedInput {
+                            msg: "Binning with --bins or --edges requires numeric input."
+                                .to_string(),
+                            input: format!("input type: {t:?}"),
+                            msg_span: head_span,
+                            input_span: span,
+                        })?;
+                        numbers.push(n);
+                    }
+                }
+            }
+        }
+        Some(col) => {
+            let col_name = &col.item;
+            for v in values {
+                match v {
+                    Value::Record { val, .. } => {
+                        if let Some(v) = val.get(col_name) {
+                            if let Some(n) = numeric_value(v) {
+                                numbers.push(n);
+                            }
+                        }
+                    }
+                    Value::Error { error, .. } => return Err(*error),
+                    _ => continue,
+                }
+            }
+
+            if numbers.is_empty() {
+                return Err(ShellError::CantFindColumn {
+                    col_name: col_name.clone(),
+                    span: Some(head_span),
+                    src_span: list_span,
+                });
+            }
+        }
+    }
+    Ok(numbers)
+}
+
+fn bucket_edges(
+    binning: &Binning,
+    values: &[f64],
+    head_span: Span,
+) -> Result<Vec<f64>, ShellError> {
+    match binning {
+        Binning::Auto(bins) => {
+            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            if !min.is_finite() || !max.is_finite() {
+                return Err(ShellError::UnsupportedInput {
+                    msg: "Cannot compute bins for an empty input.".to_string(),
+                    input: "value originates from here".into(),
+                    msg_span: head_span,
+                    input_span: head_span,
+                });
+            }
+            if min == max {
+                Ok(vec![min, max])
+            } else {
+                let width = (max - min) / *bins as f64;
+                Ok((0..=*bins).map(|i| min + width * i as f64).collect())
+            }
+        }
+        Binning::Edges(edges) => Ok(edges.clone()),
+    }
+}
+
+/// Find which `[edges[i], edges[i + 1])` bucket `value` falls into, treating the
+/// very last bucket as inclusive of the upper edge. Returns `None` if `value`
+/// falls outside the full range covered by `edges`.
+fn bucket_index(edges: &[f64], value: f64) -> Option<usize> {
+    let n_bins = edges.len() - 1;
+    if value < edges[0] || value > edges[n_bins] {
+        return None;
+    }
+    for i in 0..n_bins {
+        if value < edges[i + 1] || i == n_bins - 1 {
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn bar(quantile: f64, count: usize, max_cnt: usize, log_scale: bool) -> String {
+    const MAX_FREQ_COUNT: f64 = 100.0;
+    let ratio = if log_scale {
+        let log_max = (max_cnt as f64).ln_1p();
+        if log_max == 0.0 {
+            0.0
+        } else {
+            (count as f64).ln_1p() / log_max
+        }
+    } else {
+        quantile
+    };
+    "*".repeat((MAX_FREQ_COUNT * ratio).floor() as usize)
+}
+
+fn histogram_binned_impl(
+    values: Vec<f64>,
+    edges: Vec<f64>,
+    calc_method: PercentageCalcMethod,
+    freq_column: &str,
+    log_scale: bool,
+    span: Span,
+) -> PipelineData {
+    let n_bins = edges.len() - 1;
+    let mut counts = vec![0usize; n_bins];
+    for value in values {
+        if let Some(idx) = bucket_index(&edges, value) {
+            counts[idx] += 1;
+        }
+    }
+
+    let total_cnt: usize = counts.iter().sum();
+    let max_cnt = counts.iter().copied().max().unwrap_or(0);
+
+    let mut result = Vec::with_capacity(n_bins);
+    for (i, count) in counts.into_iter().enumerate() {
+        let quantile = if total_cnt == 0 || max_cnt == 0 {
+            0.0
+        } else {
+            match calc_method {
+                PercentageCalcMethod::Normalize => count as f64 / total_cnt as f64,
+                PercentageCalcMethod::Relative => count as f64 / max_cnt as f64,
+            }
+        };
+        let percentage = format!("{:.2}%", quantile * 100_f64);
+        let freq = bar(quantile, count, max_cnt, log_scale);
+
+        result.push(Value::record(
+            record! {
+                "min" => Value::float(edges[i], span),
+                "max" => Value::float(edges[i + 1], span),
+                "count" => Value::int(count as i64, span),
+                "quantile" => Value::float(quantile, span),
+                "percentage" => Value::string(percentage, span),
+                freq_column => Value::string(freq, span),
+            },
+            span,
+        ));
+    }
+
+    Value::list(result, span).into_pipeline_data()
+}
+
 fn histogram_impl(
     inputs: Vec<HashableValue>,
     value_column_name: &str,
     calc_method: PercentageCalcMethod,
     freq_column: &str,
+    log_scale: bool,
     span: Span,
 ) -> PipelineData {
     // here we can make sure that inputs is not empty, and every elements
@@ -232,7 +502,6 @@ fn histogram_impl(
     }
 
     let mut result = vec![];
-    const MAX_FREQ_COUNT: f64 = 100.0;
     for (val, count) in counter.into_iter().sorted() {
         let quantile = match calc_method {
             PercentageCalcMethod::Normalize => count as f64 / total_cnt as f64,
@@ -240,7 +509,7 @@ fn histogram_impl(
         };
 
         let percentage = format!("{:.2}%", quantile * 100_f64);
-        let freq = "*".repeat((MAX_FREQ_COUNT * quantile).floor() as usize);
+        let freq = bar(quantile, count, max_cnt, log_scale);
 
         result.push((
             count, // attach count first for easily sorting.
@@ -1,5 +1,19 @@
-use nu_cmd_base::input_handler::{operate, CellPathOnlyArgs};
+use nu_cmd_base::input_handler::{operate, CmdArgument};
 use nu_engine::command_prelude::*;
+use nu_utils::get_system_locale;
+use num_format::Locale;
+
+struct Arguments {
+    cell_paths: Option<Vec<CellPath>>,
+    currency: bool,
+    locale: Locale,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -23,6 +37,17 @@ impl Command for SubCommand {
                     Type::List(Box::new(Type::Float)),
                 ),
             ])
+            .switch(
+                "currency",
+                "strip a currency symbol and locale-specific thousands/decimal separators before parsing",
+                Some('c'),
+            )
+            .named(
+                "locale",
+                SyntaxShape::String,
+                "locale to use with --currency, e.g. de-DE (defaults to the system locale)",
+                Some('l'),
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -48,7 +73,21 @@ impl Command for SubCommand {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
-        let args = CellPathOnlyArgs::from(cell_paths);
+        let currency = call.has_flag(engine_state, stack, "currency")?;
+        let locale_flag: Option<Spanned<String>> = call.get_flag(engine_state, stack, "locale")?;
+        let locale = match locale_flag {
+            Some(name) => Locale::from_name(&name.item).map_err(|_| ShellError::InvalidValue {
+                valid: "a valid locale name, e.g. en-US or de-DE".into(),
+                actual: name.item,
+                span: name.span,
+            })?,
+            None => get_system_locale(),
+        };
+        let args = Arguments {
+            cell_paths: (!cell_paths.is_empty()).then_some(cell_paths),
+            currency,
+            locale,
+        };
         operate(action, args, input, call.head, engine_state.signals())
     }
 
@@ -79,18 +118,27 @@ impl Command for SubCommand {
                 example: "true | into float",
                 result: Some(Value::test_float(1.0)),
             },
+            Example {
+                description: "Convert a currency string to float",
+                example: "'$1,234.56' | into float --currency",
+                result: Some(Value::test_float(1234.56)),
+            },
         ]
     }
 }
 
-fn action(input: &Value, _args: &CellPathOnlyArgs, head: Span) -> Value {
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
     let span = input.span();
     match input {
         Value::Float { .. } => input.clone(),
         Value::String { val: s, .. } => {
-            let other = s.trim();
+            let other = if args.currency {
+                strip_currency(s, &args.locale)
+            } else {
+                s.trim().to_string()
+            };
 
-            match other.parse::<f64>() {
+            match other.trim().parse::<f64>() {
                 Ok(x) => Value::float(x, head),
                 Err(reason) => Value::error(
                     ShellError::CantConvert {
@@ -125,6 +173,30 @@ fn action(input: &Value, _args: &CellPathOnlyArgs, head: Span) -> Value {
     }
 }
 
+/// Strip a leading/trailing currency symbol, parenthesized-negative notation
+/// (e.g. `(1,234.56)` -> `-1234.56`), and locale-specific thousands/decimal
+/// separators from a currency string so it can be parsed as a plain `f64`.
+fn strip_currency(s: &str, locale: &Locale) -> String {
+    let trimmed = s.trim();
+    let (trimmed, negative) = match trimmed.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        Some(inner) => (inner, true),
+        None => (trimmed, false),
+    };
+
+    let digits_and_seps: String = trimmed
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '-' || c.to_string() == locale.decimal())
+        .collect();
+
+    let normalized = digits_and_seps.replace(locale.decimal(), ".");
+
+    if negative && !normalized.starts_with('-') {
+        format!("-{normalized}")
+    } else {
+        normalized
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,7 +215,15 @@ mod tests {
         let word = Value::test_string("3.1415");
         let expected = Value::test_float(3.1415);
 
-        let actual = action(&word, &CellPathOnlyArgs::from(vec![]), Span::test_data());
+        let actual = action(
+            &word,
+            &Arguments {
+                cell_paths: None,
+                currency: false,
+                locale: Locale::en,
+            },
+            Span::test_data(),
+        );
         assert_eq!(actual, expected);
     }
 
@@ -153,7 +233,11 @@ mod tests {
 
         let actual = action(
             &invalid_str,
-            &CellPathOnlyArgs::from(vec![]),
+            &Arguments {
+                cell_paths: None,
+                currency: false,
+                locale: Locale::en,
+            },
             Span::test_data(),
         );
 
@@ -166,7 +250,11 @@ mod tests {
         let expected = Value::test_float(10.0);
         let actual = action(
             &input_int,
-            &CellPathOnlyArgs::from(vec![]),
+            &Arguments {
+                cell_paths: None,
+                currency: false,
+                locale: Locale::en,
+            },
             Span::test_data(),
         );
 
@@ -3,12 +3,14 @@ use nu_cmd_base::input_handler::{operate, CmdArgument};
 use nu_engine::command_prelude::*;
 
 use nu_utils::get_system_locale;
+use num_format::Locale;
 
 struct Arguments {
     radix: u32,
     cell_paths: Option<Vec<CellPath>>,
     signed: bool,
     little_endian: bool,
+    locale: Locale,
 }
 
 impl CmdArgument for Arguments {
@@ -81,6 +83,12 @@ impl Command for SubCommand {
                 "always treat input number as a signed number",
                 Some('s'),
             )
+            .named(
+                "locale",
+                SyntaxShape::String,
+                "locale to use when stripping thousands separators and decimal points, e.g. de-DE (defaults to the system locale)",
+                Some('l'),
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -152,11 +160,22 @@ impl Command for SubCommand {
 
         let signed = call.has_flag(engine_state, stack, "signed")?;
 
+        let locale_flag: Option<Spanned<String>> = call.get_flag(engine_state, stack, "locale")?;
+        let locale = match locale_flag {
+            Some(name) => Locale::from_name(&name.item).map_err(|_| ShellError::InvalidValue {
+                valid: "a valid locale name, e.g. en-US or de-DE".into(),
+                actual: name.item,
+                span: name.span,
+            })?,
+            None => get_system_locale(),
+        };
+
         let args = Arguments {
             radix,
             little_endian,
             signed,
             cell_paths,
+            locale,
         };
         operate(action, args, input, call.head, engine_state.signals())
     }
@@ -236,6 +255,11 @@ impl Command for SubCommand {
                 example: "0x[a0] | into int --signed",
                 result: Some(Value::test_int(-96)),
             },
+            Example {
+                description: "Convert a German-formatted decimal string to int",
+                example: "'1.234,5' | into int --locale de-DE",
+                result: Some(Value::test_int(1234)),
+            },
         ]
     }
 }
@@ -279,7 +303,7 @@ fn action(input: &Value, args: &Arguments, span: Span) -> Value {
         ),
         Value::String { val, .. } => {
             if radix == 10 {
-                match int_from_string(val, span) {
+                match int_from_string(val, &args.locale, span) {
                     Ok(val) => Value::int(val, span),
                     Err(error) => Value::error(error, span),
                 }
@@ -386,7 +410,7 @@ fn convert_int(input: &Value, head: Span, radix: u32) -> Value {
                 || val.starts_with("0o")
             // octal
             {
-                match int_from_string(val, head) {
+                match int_from_string(val, &get_system_locale(), head) {
                     Ok(x) => return Value::int(x, head),
                     Err(e) => return Value::error(e, head),
                 }
@@ -437,13 +461,13 @@ fn convert_int(input: &Value, head: Span, radix: u32) -> Value {
     }
 }
 
-fn int_from_string(a_string: &str, span: Span) -> Result<i64, ShellError> {
-    // Get the Locale so we know what the thousands separator is
-    let locale = get_system_locale();
-
-    // Now that we know the locale, get the thousands separator and remove it
-    // so strings like 1,123,456 can be parsed as 1123456
-    let no_comma_string = a_string.replace(locale.separator(), "");
+fn int_from_string(a_string: &str, locale: &Locale, span: Span) -> Result<i64, ShellError> {
+    // Get the thousands separator and remove it so strings like 1,123,456 can
+    // be parsed as 1123456, and normalize the decimal separator to '.' so
+    // strings like "1.234,56" (de-DE) parse the same way as "1,234.56" (en-US).
+    let no_comma_string = a_string
+        .replace(locale.separator(), "")
+        .replace(locale.decimal(), ".");
 
     let trimmed = no_comma_string.trim();
     match trimmed {
@@ -493,7 +517,7 @@ fn int_from_string(a_string: &str, span: Span) -> Result<i64, ShellError> {
         }
         _ => match trimmed.parse::<i64>() {
             Ok(n) => Ok(n),
-            Err(_) => match a_string.parse::<f64>() {
+            Err(_) => match trimmed.parse::<f64>() {
                 Ok(f) => Ok(f as i64),
                 _ => Err(ShellError::CantConvert {
                     to_type: "int".to_string(),
@@ -536,6 +560,7 @@ mod test {
                 cell_paths: None,
                 signed: false,
                 little_endian: false,
+                locale: Locale::en,
             },
             Span::test_data(),
         );
@@ -552,6 +577,7 @@ mod test {
                 cell_paths: None,
                 signed: false,
                 little_endian: false,
+                locale: Locale::en,
             },
             Span::test_data(),
         );
@@ -568,6 +594,7 @@ mod test {
                 cell_paths: None,
                 signed: false,
                 little_endian: false,
+                locale: Locale::en,
             },
             Span::test_data(),
         );
@@ -585,6 +612,7 @@ mod test {
                 cell_paths: None,
                 signed: false,
                 little_endian: false,
+                locale: Locale::en,
             },
             Span::test_data(),
         );
@@ -608,6 +636,7 @@ mod test {
                 cell_paths: None,
                 signed: false,
                 little_endian: false,
+                locale: Locale::en,
             },
             Span::test_data(),
         );
@@ -631,6 +660,7 @@ mod test {
                 cell_paths: None,
                 signed: false,
                 little_endian: false,
+                locale: Locale::en,
             },
             Span::test_data(),
         );
@@ -0,0 +1,189 @@
+use std::{collections::BTreeMap, io::BufRead};
+
+use nu_engine::{command_prelude::*, ClosureEvalOnce};
+use nu_protocol::{debugger::CoverageCollector, engine::Closure};
+
+#[derive(Clone)]
+pub struct DebugCoverage;
+
+impl Command for DebugCoverage {
+    fn name(&self) -> &str {
+        "debug coverage"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("debug coverage")
+            .required(
+                "closure",
+                SyntaxShape::Closure(None),
+                "The closure to collect line coverage for.",
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "Output format: table (default), text, or lcov",
+                Some('f'),
+            )
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .category(Category::Debug)
+    }
+
+    fn description(&self) -> &str {
+        "Collect per-file line coverage for a closure."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Runs the closure with a coverage-collecting debugger active, recording every source line
+the evaluator executes. This is meant for measuring how much of a nu script library is exercised
+by its test suite.
+
+By default, the raw per-line hit counts are returned as a table. --format text prints a
+human-readable summary per file, and --format lcov prints a standard lcov.info tracefile that can
+be fed to coverage tools such as genhtml."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+        let format: Option<String> = call.get_flag(engine_state, stack, "format")?;
+        let format = format.unwrap_or_else(|| "table".into());
+        if !matches!(format.as_str(), "table" | "text" | "lcov") {
+            return Err(ShellError::IncorrectValue {
+                msg: "format must be one of table, text, or lcov".into(),
+                val_span: call.head,
+                call_span: call.head,
+            });
+        }
+
+        let lock_err = |_| ShellError::GenericError {
+            error: "Coverage Error".to_string(),
+            msg: "could not lock debugger, poisoned mutex".to_string(),
+            span: Some(call.head),
+            help: None,
+            inner: vec![],
+        };
+
+        engine_state
+            .activate_debugger(Box::new(CoverageCollector::new()))
+            .map_err(lock_err)?;
+
+        let result = ClosureEvalOnce::new(engine_state, stack, closure).run_with_input(input);
+
+        // Return potential errors
+        let pipeline_data = result?;
+
+        // Collect the output
+        let _ = pipeline_data.into_value(call.span());
+
+        let report = engine_state
+            .deactivate_debugger()
+            .map_err(lock_err)?
+            .report(engine_state, call.span())?;
+
+        match format.as_str() {
+            "table" => Ok(report.into_pipeline_data()),
+            "text" => Ok(Value::string(render_text(engine_state, &report, call.head), call.head)
+                .into_pipeline_data()),
+            "lcov" => Ok(Value::string(render_lcov(engine_state, &report), call.head)
+                .into_pipeline_data()),
+            _ => unreachable!("format was validated above"),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Collect raw line hit counts for a closure",
+                example: "debug coverage { source mylib.nu }",
+                result: None,
+            },
+            Example {
+                description: "Print a human-readable coverage summary",
+                example: "debug coverage { source mylib.nu } --format text",
+                result: None,
+            },
+            Example {
+                description: "Write an lcov tracefile for use with genhtml",
+                example: "debug coverage { source mylib.nu } --format lcov | save coverage.info",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Per-file executed-line -> hit-count, extracted from a `CoverageCollector::report()` value.
+fn hits_by_file(report: &Value) -> BTreeMap<String, BTreeMap<usize, usize>> {
+    let mut hits: BTreeMap<String, BTreeMap<usize, usize>> = BTreeMap::new();
+    let Value::List { vals, .. } = report else {
+        return hits;
+    };
+    for row in vals {
+        let Value::Record { val: row, .. } = row else {
+            continue;
+        };
+        let (Some(file), Some(line), Some(count)) = (
+            row.get("file").and_then(|v| v.as_str().ok()),
+            row.get("line").and_then(|v| v.as_int().ok()),
+            row.get("count").and_then(|v| v.as_int().ok()),
+        ) else {
+            continue;
+        };
+        hits.entry(file.to_string())
+            .or_default()
+            .insert(line as usize, count as usize);
+    }
+    hits
+}
+
+/// Total number of lines in each cached source file, keyed the same way as `find_file_of_span`.
+fn total_lines_by_file(engine_state: &EngineState) -> BTreeMap<String, usize> {
+    engine_state
+        .files()
+        .map(|file| (file.name.to_string(), file.content.lines().count()))
+        .collect()
+}
+
+fn render_text(engine_state: &EngineState, report: &Value, span: Span) -> String {
+    let _ = span;
+    let hits = hits_by_file(report);
+    let totals = total_lines_by_file(engine_state);
+
+    let mut out = String::new();
+    for (file, lines_hit) in &hits {
+        let lines_total = totals.get(file).copied().unwrap_or(lines_hit.len());
+        let covered = lines_hit.len();
+        let percentage = if lines_total == 0 {
+            0.0
+        } else {
+            100.0 * covered as f64 / lines_total as f64
+        };
+        out.push_str(&format!(
+            "{file}: {covered}/{lines_total} lines ({percentage:.1}%)\n"
+        ));
+    }
+    out
+}
+
+fn render_lcov(engine_state: &EngineState, report: &Value) -> String {
+    let hits = hits_by_file(report);
+    let totals = total_lines_by_file(engine_state);
+
+    let mut out = String::new();
+    for (file, lines_hit) in &hits {
+        let lines_total = totals.get(file).copied().unwrap_or(lines_hit.len());
+        out.push_str("TN:\n");
+        out.push_str(&format!("SF:{file}\n"));
+        for (line, count) in lines_hit {
+            out.push_str(&format!("DA:{line},{count}\n"));
+        }
+        out.push_str(&format!("LF:{lines_total}\n"));
+        out.push_str(&format!("LH:{}\n", lines_hit.len()));
+        out.push_str("end_of_record\n");
+    }
+    out
+}
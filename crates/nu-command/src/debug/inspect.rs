@@ -1,6 +1,7 @@
 use super::inspect_table;
 use nu_engine::command_prelude::*;
 use nu_utils::terminal_size;
+use std::time::Instant;
 
 #[derive(Clone)]
 pub struct Inspect;
@@ -14,46 +15,111 @@ impl Command for Inspect {
         "Inspect pipeline results while running a pipeline."
     }
 
+    fn extra_description(&self) -> &str {
+        "When the input is a stream, it is tapped rather than collected: a running preview \
+         (item count, rate, and the last sampled item) is printed to stderr every `--sample` \
+         items instead of a single table for the whole input."
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("inspect")
             .input_output_types(vec![(Type::Any, Type::Any)])
+            .named(
+                "sample",
+                SyntaxShape::Int,
+                "for a stream, print a preview every Nth item instead of every item",
+                Some('n'),
+            )
+            .switch(
+                "quiet",
+                "don't print anything, just pass the input through",
+                Some('q'),
+            )
             .allow_variants_without_examples(true)
             .category(Category::Debug)
     }
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let input_metadata = input.metadata();
-        let input_val = input.into_value(call.head)?;
-        if input_val.is_nothing() {
-            return Err(ShellError::PipelineEmpty {
-                dst_span: call.head,
-            });
-        }
-        let original_input = input_val.clone();
-        let description = input_val.get_type().to_string();
+        let sample = call
+            .get_flag::<i64>(engine_state, stack, "sample")?
+            .map(|n| n.max(1) as usize)
+            .unwrap_or(1);
+        let quiet = call.has_flag(engine_state, stack, "quiet")?;
 
-        let (cols, _rows) = terminal_size().unwrap_or((0, 0));
+        match input {
+            PipelineData::ListStream(stream, metadata) => {
+                if quiet {
+                    return Ok(PipelineData::ListStream(stream, metadata));
+                }
+                let start = Instant::now();
+                let mut count: usize = 0;
+                let config = stack.get_config(engine_state);
+                let stream = stream.map(move |value| {
+                    count += 1;
+                    if count % sample == 0 {
+                        let elapsed = start.elapsed().as_secs_f64();
+                        let rate = if elapsed > 0.0 {
+                            count as f64 / elapsed
+                        } else {
+                            0.0
+                        };
+                        eprintln!(
+                            "inspect: {count} items ({rate:.1}/sec), last: {}",
+                            value.to_abbreviated_string(&config)
+                        );
+                    }
+                    value
+                });
+                Ok(PipelineData::ListStream(stream, metadata))
+            }
+            input => {
+                let input_metadata = input.metadata();
+                let input_val = input.into_value(call.head)?;
+                if input_val.is_nothing() {
+                    return Err(ShellError::PipelineEmpty {
+                        dst_span: call.head,
+                    });
+                }
+                let original_input = input_val.clone();
 
-        let table = inspect_table::build_table(input_val, description, cols as usize);
+                if !quiet {
+                    let description = input_val.get_type().to_string();
+                    let (cols, _rows) = terminal_size().unwrap_or((0, 0));
+                    let table = inspect_table::build_table(input_val, description, cols as usize);
 
-        // Note that this is printed to stderr. The reason for this is so it doesn't disrupt the regular nushell
-        // tabular output. If we printed to stdout, nushell would get confused with two outputs.
-        eprintln!("{table}\n");
+                    // Note that this is printed to stderr. The reason for this is so it doesn't disrupt the regular nushell
+                    // tabular output. If we printed to stdout, nushell would get confused with two outputs.
+                    eprintln!("{table}\n");
+                }
 
-        Ok(original_input.into_pipeline_data_with_metadata(input_metadata))
+                Ok(original_input.into_pipeline_data_with_metadata(input_metadata))
+            }
+        }
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Inspect pipeline results",
-            example: "ls | inspect | get name | inspect",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Inspect pipeline results",
+                example: "ls | inspect | get name | inspect",
+                result: None,
+            },
+            Example {
+                description: "Sample every 100th item while a long stream runs",
+                example: "1..100000 | inspect --sample 100 | math sum",
+                result: None,
+            },
+            Example {
+                description: "Pass a stream through without printing anything",
+                example: "1..10 | inspect --quiet | math sum",
+                result: Some(Value::test_int(55)),
+            },
+        ]
     }
 }
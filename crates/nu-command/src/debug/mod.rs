@@ -1,4 +1,5 @@
 mod ast;
+mod coverage;
 mod debug_;
 mod explain;
 mod info;
@@ -8,6 +9,7 @@ mod metadata;
 mod metadata_access;
 mod metadata_set;
 mod profile;
+mod progress;
 mod timeit;
 mod view;
 mod view_blocks;
@@ -17,6 +19,7 @@ mod view_source;
 mod view_span;
 
 pub use ast::Ast;
+pub use coverage::DebugCoverage;
 pub use debug_::Debug;
 pub use explain::Explain;
 pub use info::DebugInfo;
@@ -26,6 +29,7 @@ pub use metadata::Metadata;
 pub use metadata_access::MetadataAccess;
 pub use metadata_set::MetadataSet;
 pub use profile::DebugProfile;
+pub use progress::Progress;
 pub use timeit::TimeIt;
 pub use view::View;
 pub use view_blocks::ViewBlocks;
@@ -0,0 +1,136 @@
+use crate::progress_bar::NuProgressBar;
+use nu_engine::command_prelude::*;
+use std::io::{self, Read};
+use std::time::{Duration, Instant};
+
+#[derive(Clone)]
+pub struct Progress;
+
+impl Command for Progress {
+    fn name(&self) -> &str {
+        "progress"
+    }
+
+    fn description(&self) -> &str {
+        "Show a progress bar while a stream passes through unchanged."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Byte streams are tracked by bytes transferred; if they don't have a known size, or for \
+         list streams, `--total` gives the count needed to show a percentage and ETA instead of \
+         just a spinner. Values that have already been collected are passed through untouched, \
+         since there is nothing left to stream."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("progress")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .named(
+                "total",
+                SyntaxShape::Int,
+                "expected number of bytes or items, used to show a percentage and ETA",
+                Some('t'),
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Debug)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["progress bar", "eta", "stream"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let total = call
+            .get_flag::<i64>(engine_state, stack, "total")?
+            .map(|n| n.max(0) as u64);
+
+        match input {
+            PipelineData::ByteStream(stream, metadata) => {
+                let known_size = stream.known_size().or(total);
+                let type_ = stream.type_();
+                let span = stream.span();
+                let signals = engine_state.signals().clone();
+                let Some(reader) = stream.reader() else {
+                    return Ok(PipelineData::Empty);
+                };
+
+                let bar = NuProgressBar::new(known_size);
+                let tracked = ProgressReader::new(reader, bar);
+                Ok(PipelineData::ByteStream(
+                    ByteStream::read(tracked, span, signals, type_).with_known_size(known_size),
+                    metadata,
+                ))
+            }
+            PipelineData::ListStream(stream, metadata) => {
+                let mut bar = NuProgressBar::new(total);
+                let mut count: u64 = 0;
+                let mut last_update = Instant::now();
+                let stream = stream.map(move |value| {
+                    count += 1;
+                    if last_update.elapsed() >= Duration::from_millis(75) {
+                        bar.update_bar(count);
+                        last_update = Instant::now();
+                    }
+                    value
+                });
+                Ok(PipelineData::ListStream(stream, metadata))
+            }
+            other => Ok(other),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show a byte progress bar while downloading a file",
+                example: "http get --raw https://example.com/file | progress | save file.bin",
+                result: None,
+            },
+            Example {
+                description: "Show a progress bar for a list stream of known length",
+                example: "1..100000 | progress --total 100000 | each { |x| $x * 2 } | math sum",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct ProgressReader<R: Read> {
+    reader: R,
+    bar: NuProgressBar,
+    bytes_processed: u64,
+    last_update: Instant,
+}
+
+impl<R: Read> ProgressReader<R> {
+    fn new(reader: R, bar: NuProgressBar) -> Self {
+        Self {
+            reader,
+            bar,
+            bytes_processed: 0,
+            last_update: Instant::now(),
+        }
+    }
+}
+
+impl<R: Read> Read for ProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.reader.read(buf)?;
+        if len == 0 {
+            self.bar.update_bar(self.bytes_processed);
+            return Ok(0);
+        }
+        self.bytes_processed += len as u64;
+        if self.last_update.elapsed() >= Duration::from_millis(75) {
+            self.bar.update_bar(self.bytes_processed);
+            self.last_update = Instant::now();
+        }
+        Ok(len)
+    }
+}
@@ -29,6 +29,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
         // Filters
         #[cfg(feature = "rand")]
         bind_command! {
+            Sample,
             Shuffle
         }
         bind_command! {
@@ -58,6 +59,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Interleave,
             Items,
             Join,
+            QueryJsonpath,
             SplitBy,
             Take,
             Merge,
@@ -70,12 +72,20 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Lines,
             ParEach,
             ChunkBy,
+            Pivot,
             Prepend,
+            Profile,
             Range,
             Reduce,
             Reject,
             Rename,
             Reverse,
+            Roll,
+            RollCustom,
+            RollMax,
+            RollMean,
+            RollMin,
+            RollSum,
             Select,
             Skip,
             SkipUntil,
@@ -87,6 +97,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Transpose,
             Uniq,
             UniqBy,
+            Unpivot,
             Upsert,
             Update,
             Values,
@@ -98,6 +109,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
 
         // Misc
         bind_command! {
+            Cached,
             Panic,
             Source,
             Tutor,
@@ -108,12 +120,15 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Path,
             PathBasename,
             PathSelf,
+            PathCommonPrefix,
+            PathComponents,
             PathDirname,
             PathExists,
             PathExpand,
             PathJoin,
             PathParse,
             PathRelativeTo,
+            PathSanitize,
             PathSplit,
             PathType,
         };
@@ -125,12 +140,17 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             External,
             Exec,
             NuCheck,
+            On,
+            OnInterval,
+            OnSignal,
             Sys,
+            SysBattery,
             SysCpu,
             SysDisks,
             SysHost,
             SysMem,
             SysNet,
+            SysSensors,
             SysTemp,
             SysUsers,
             UName,
@@ -152,6 +172,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
         bind_command! {
             Ast,
             Debug,
+            DebugCoverage,
             DebugInfo,
             DebugProfile,
             Explain,
@@ -159,6 +180,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Metadata,
             MetadataAccess,
             MetadataSet,
+            Progress,
             TimeIt,
             View,
             ViewBlocks,
@@ -169,7 +191,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
         };
 
         #[cfg(all(feature = "os", windows))]
-        bind_command! { RegistryQuery }
+        bind_command! { RegistryDelete, RegistryQuery, RegistryWrite }
 
         #[cfg(all(
             feature = "os",
@@ -196,8 +218,12 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             EncodeBase32,
             DecodeBase32Hex,
             EncodeBase32Hex,
+            DecodeBase58,
+            EncodeBase58,
             DecodeBase64,
             EncodeBase64,
+            DecodeZ85,
+            EncodeZ85,
             DetectColumns,
             Parse,
             Split,
@@ -223,9 +249,11 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             StrTrim,
             StrUpcase,
             Format,
+            FormatCurrency,
             FormatDate,
             FormatDuration,
             FormatFilesize,
+            FormatNumber,
         };
 
         // FileSystem
@@ -259,6 +287,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             InputListen,
             IsTerminal,
             Kill,
+            PromptSegments,
             Sleep,
             Term,
             TermSize,
@@ -269,6 +298,15 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
         #[cfg(all(unix, feature = "os"))]
         bind_command! { ULimit };
 
+        #[cfg(all(unix, feature = "os"))]
+        bind_command! { PortOpen };
+
+        #[cfg(all(target_os = "macos", feature = "plist", feature = "os"))]
+        bind_command! { DefaultsRead, DefaultsWrite };
+
+        #[cfg(all(target_os = "linux", feature = "dbus", feature = "os"))]
+        bind_command! { DbusCall, DbusListen };
+
         // Date
         bind_command! {
             Date,
@@ -288,13 +326,17 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
         // Formats
         bind_command! {
             From,
+            FromClf,
             FromCsv,
+            FromHtml,
             FromJson,
+            FromLogfmt,
             FromMsgpack,
             FromMsgpackz,
             FromNuon,
             FromOds,
             FromSsv,
+            FromSyslog,
             FromToml,
             FromTsv,
             FromXlsx,
@@ -313,9 +355,20 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             ToTsv,
             Upsert,
             Where,
+            ToXlsx,
             ToXml,
             ToYaml,
         };
+        #[cfg(feature = "pcap")]
+        bind_command! { FromPcap };
+        #[cfg(feature = "pdf")]
+        bind_command! { FromPdf };
+        #[cfg(feature = "image")]
+        bind_command! { FromImage };
+        #[cfg(feature = "media")]
+        bind_command! { FromMedia };
+        #[cfg(feature = "journald")]
+        bind_command! { FromJournal };
 
         // Viewers
         bind_command! {
@@ -348,6 +401,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             LoadEnv,
             SourceEnv,
             WithEnv,
+            WithPath,
             ConfigNu,
             ConfigEnv,
             ConfigFlatten,
@@ -405,6 +459,20 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             HttpPut,
             HttpOptions,
             Port,
+            SshRun,
+            DockerPs,
+            KubectlGet,
+            KubectlContexts,
+        }
+        #[cfg(all(feature = "network", feature = "mqtt"))]
+        bind_command! {
+            MqttPublish,
+            MqttSubscribe,
+        }
+        #[cfg(all(feature = "network", feature = "kafka"))]
+        bind_command! {
+            KafkaConsume,
+            KafkaProduce,
         }
         bind_command! {
             Url,
@@ -3,6 +3,7 @@ mod export_env;
 mod load_env;
 mod source_env;
 mod with_env;
+mod with_path;
 
 pub use config::ConfigEnv;
 pub use config::ConfigFlatten;
@@ -14,3 +15,4 @@ pub use export_env::ExportEnv;
 pub use load_env::LoadEnv;
 pub use source_env::SourceEnv;
 pub use with_env::WithEnv;
+pub use with_path::WithPath;
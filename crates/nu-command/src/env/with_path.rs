@@ -0,0 +1,81 @@
+use nu_engine::{command_prelude::*, eval_block};
+use nu_protocol::{debugger::WithoutDebug, engine::Closure};
+
+#[derive(Clone)]
+pub struct WithPath;
+
+impl Command for WithPath {
+    fn name(&self) -> &str {
+        "with-path"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("with-path")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "paths",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "The directories to prepend to PATH for the duration of the block.",
+            )
+            .required(
+                "block",
+                SyntaxShape::Closure(None),
+                "The block to run with the updated PATH.",
+            )
+            .category(Category::Env)
+    }
+
+    fn description(&self) -> &str {
+        "Runs a block with directories prepended to PATH, without stringly-typed PATH juggling."
+    }
+
+    fn extra_description(&self) -> &str {
+        "PATH is kept as a list for the extent of the block, so this avoids the common mistake of \
+concatenating strings by hand with the platform's path separator."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let prepend: Vec<Spanned<String>> = call.req(engine_state, stack, 0)?;
+        let capture_block: Closure = call.req(engine_state, stack, 1)?;
+        let block = engine_state.get_block(capture_block.block_id);
+        let mut stack = stack.captures_to_stack_preserve_out_dest(capture_block.captures);
+
+        let path_name = if cfg!(windows) { "Path" } else { "PATH" };
+
+        let mut new_paths: Vec<Value> = prepend
+            .into_iter()
+            .map(|p| Value::string(p.item, p.span))
+            .collect();
+
+        if let Some(existing) = stack.get_env_var_insensitive(engine_state, "path") {
+            match existing {
+                Value::List { vals, .. } => new_paths.extend(vals.iter().cloned()),
+                Value::String { val, .. } => {
+                    new_paths.extend(
+                        std::env::split_paths(val)
+                            .map(|p| Value::string(p.to_string_lossy().to_string(), call.head)),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        stack.add_env_var(path_name.to_string(), Value::list(new_paths, call.head));
+
+        eval_block::<WithoutDebug>(engine_state, &mut stack, block, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Prepend a directory to PATH for a single external call",
+            example: r#"with-path [/opt/tool/bin] { $env.PATH | first }"#,
+            result: None,
+        }]
+    }
+}
@@ -1,5 +1,6 @@
 use nu_engine::command_prelude::*;
 use nu_utils::filesystem::{have_permission, PermissionResult};
+use std::path::PathBuf;
 
 #[derive(Clone)]
 pub struct Cd;
@@ -71,7 +72,25 @@ impl Command for Cd {
                 } else {
                     // Trim whitespace from the end of path.
                     let path_no_whitespace =
-                        &v.item.trim_end_matches(|x| matches!(x, '\x09'..='\x0d'));
+                        v.item.trim_end_matches(|x| matches!(x, '\x09'..='\x0d'));
+
+                    // A Windows drive-relative path (`d:` or `d:foo`) is resolved against that
+                    // drive's own remembered current directory, not the shell's cwd, matching
+                    // cmd.exe. Anything else (including drive-absolute paths like `d:\foo`) is
+                    // resolved as usual below.
+                    let (cwd, path_no_whitespace) =
+                        match nu_path::parse_drive_relative_path(path_no_whitespace) {
+                            Some(drive_relative) => {
+                                let drive_cwd = engine_state
+                                    .remembered_drive_cwd(drive_relative.drive)
+                                    .unwrap_or_else(|| {
+                                        PathBuf::from(format!("{}:\\", drive_relative.drive))
+                                    });
+                                (drive_cwd, drive_relative.rest.to_string())
+                            }
+                            None => (cwd, path_no_whitespace.to_string()),
+                        };
+                    let path_no_whitespace = &path_no_whitespace;
 
                     // If `--physical` is specified, canonicalize the path; otherwise expand the path.
                     if physical {
@@ -114,6 +133,9 @@ impl Command for Cd {
             //FIXME: this only changes the current scope, but instead this environment variable
             //should probably be a block that loads the information from the state in the overlay
             PermissionResult::PermissionOk => {
+                if let Some(drive) = drive_letter(&path) {
+                    engine_state.remember_drive_cwd(drive, path.clone());
+                }
                 stack.set_cwd(path)?;
                 Ok(PipelineData::empty())
             }
@@ -157,3 +179,19 @@ impl Command for Cd {
         ]
     }
 }
+
+/// Returns the drive letter `path` is rooted on, e.g. `Some('D')` for `D:\foo`, so it can be
+/// remembered as that drive's current directory.
+fn drive_letter(path: &std::path::Path) -> Option<char> {
+    use std::path::{Component, Prefix};
+
+    match path.components().next()? {
+        Component::Prefix(prefix) => match prefix.kind() {
+            Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => {
+                Some((letter as char).to_ascii_uppercase())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -1,5 +1,8 @@
+use chrono::{DateTime, FixedOffset};
 use nu_engine::command_prelude::*;
-use nu_protocol::{ListStream, Signals};
+use nu_glob::EntryType;
+use nu_protocol::{Filesize, ListStream, Signals};
+use std::time::SystemTime;
 use wax::{Glob as WaxGlob, WalkBehavior, WalkEntry};
 
 #[derive(Clone)]
@@ -41,6 +44,24 @@ impl Command for Glob {
                 "Patterns to exclude from the search: `glob` will not walk the inside of directories matching the excluded patterns.",
                 Some('e'),
             )
+            .named(
+                "type",
+                SyntaxShape::String,
+                "Only return entries of this type: file, dir, or symlink.",
+                Some('t'),
+            )
+            .named(
+                "size",
+                SyntaxShape::Filesize,
+                "Only return files at least this large.",
+                None,
+            )
+            .named(
+                "modified-after",
+                SyntaxShape::DateTime,
+                "Only return entries modified at or after this date.",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -111,6 +132,11 @@ impl Command for Glob {
                 example: r#"glob **/* --exclude [**/target/** **/.git/** */]"#,
                 result: None,
             },
+            Example {
+                description: "Search for files over 1mb modified in the last day",
+                example: r#"glob **/* --type file --size 1mb --modified-after ((date now) - 1day)"#,
+                result: None,
+            },
         ]
     }
 
@@ -133,6 +159,30 @@ impl Command for Glob {
         let no_files = call.has_flag(engine_state, stack, "no-file")?;
         let no_symlinks = call.has_flag(engine_state, stack, "no-symlink")?;
         let paths_to_exclude: Option<Value> = call.get_flag(engine_state, stack, "exclude")?;
+        let entry_type: Option<Spanned<String>> = call.get_flag(engine_state, stack, "type")?;
+        let entry_type = match entry_type {
+            Some(t) => Some(match t.item.as_str() {
+                "file" => EntryType::File,
+                "dir" => EntryType::Dir,
+                "symlink" => EntryType::Symlink,
+                other => {
+                    return Err(ShellError::InvalidValue {
+                        valid: "file, dir, or symlink".into(),
+                        actual: other.into(),
+                        span: t.span,
+                    })
+                }
+            }),
+            None => None,
+        };
+        let min_size: Option<Filesize> = call.get_flag(engine_state, stack, "size")?;
+        let modified_after: Option<DateTime<FixedOffset>> =
+            call.get_flag(engine_state, stack, "modified-after")?;
+        let modified_after: Option<SystemTime> = modified_after.map(|dt| {
+            SystemTime::UNIX_EPOCH
+                + std::time::Duration::from_secs(dt.timestamp().max(0) as u64)
+                + std::time::Duration::from_nanos(dt.timestamp_subsec_nanos() as u64)
+        });
 
         let (not_patterns, not_pattern_span): (Vec<String>, Span) = match paths_to_exclude {
             None => (vec![], span),
@@ -231,14 +281,16 @@ impl Command for Glob {
                     span: Some(not_pattern_span),
                     help: None,
                     inner: vec![],
-                })?
-                .flatten();
+                })?;
             glob_to_value(
                 engine_state.signals(),
                 glob_results,
                 no_dirs,
                 no_files,
                 no_symlinks,
+                entry_type,
+                min_size,
+                modified_after,
                 span,
             )
         } else {
@@ -250,14 +302,16 @@ impl Command for Glob {
                         ..Default::default()
                     },
                 )
-                .into_owned()
-                .flatten();
+                .into_owned();
             glob_to_value(
                 engine_state.signals(),
                 glob_results,
                 no_dirs,
                 no_files,
                 no_symlinks,
+                entry_type,
+                min_size,
+                modified_after,
                 span,
             )
         };
@@ -281,12 +335,16 @@ fn convert_patterns(columns: &[Value]) -> Result<Vec<String>, ShellError> {
     Ok(res)
 }
 
-fn glob_to_value(
+#[allow(clippy::too_many_arguments)]
+fn glob_to_value<E: std::fmt::Display>(
     signals: &Signals,
-    glob_results: impl Iterator<Item = WalkEntry<'static>> + Send + 'static,
+    glob_results: impl Iterator<Item = Result<WalkEntry<'static>, E>> + Send + 'static,
     no_dirs: bool,
     no_files: bool,
     no_symlinks: bool,
+    entry_type: Option<EntryType>,
+    min_size: Option<Filesize>,
+    modified_after: Option<SystemTime>,
     span: Span,
 ) -> ListStream {
     let map_signals = signals.clone();
@@ -294,19 +352,66 @@ fn glob_to_value(
         if let Err(err) = map_signals.check(span) {
             return Some(Value::error(err, span));
         };
+
+        // Surface walk errors (e.g. permission denied on a subdirectory) as structured
+        // error values in the stream instead of silently dropping them, so callers like
+        // `glob '**/*' | first 10` still see what the walk couldn't read.
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                return Some(Value::error(
+                    ShellError::GenericError {
+                        error: "error while walking glob pattern".into(),
+                        msg: format!("{err}"),
+                        span: Some(span),
+                        help: None,
+                        inner: vec![],
+                    },
+                    span,
+                ))
+            }
+        };
+
         let file_type = entry.file_type();
 
-        if !(no_dirs && file_type.is_dir()
+        if no_dirs && file_type.is_dir()
             || no_files && file_type.is_file()
-            || no_symlinks && file_type.is_symlink())
+            || no_symlinks && file_type.is_symlink()
         {
-            Some(Value::string(
-                entry.into_path().to_string_lossy().to_string(),
-                span,
-            ))
-        } else {
-            None
+            return None;
+        }
+
+        if let Some(entry_type) = entry_type {
+            let matches_type = match entry_type {
+                EntryType::File => file_type.is_file(),
+                EntryType::Dir => file_type.is_dir(),
+                EntryType::Symlink => file_type.is_symlink(),
+            };
+            if !matches_type {
+                return None;
+            }
         }
+
+        let path = entry.into_path();
+
+        if min_size.is_some() || modified_after.is_some() {
+            let Ok(meta) = std::fs::metadata(&path) else {
+                return None;
+            };
+            if min_size.is_some_and(|min| meta.len() < min.get() as u64) {
+                return None;
+            }
+            if let Some(modified_after) = modified_after {
+                let Ok(modified) = meta.modified() else {
+                    return None;
+                };
+                if modified < modified_after {
+                    return None;
+                }
+            }
+        }
+
+        Some(Value::string(path.to_string_lossy().to_string(), span))
     });
 
     ListStream::new(result, span, signals.clone())
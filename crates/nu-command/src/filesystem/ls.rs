@@ -30,6 +30,8 @@ struct Args {
     directory: bool,
     use_mime_type: bool,
     use_threads: bool,
+    max_depth: Option<usize>,
+    no_follow_symlinks: bool,
     call_span: Span,
 }
 
@@ -76,6 +78,17 @@ impl Command for Ls {
             )
             .switch("mime-type", "Show mime-type in type column instead of 'file' (based on filenames only; files' contents are not examined)", Some('m'))
             .switch("threads", "Use multiple threads to list contents. Output will be non-deterministic.", Some('t'))
+            .named(
+                "max-depth",
+                SyntaxShape::Int,
+                "directory depth to search when the pattern is recursive (e.g. `**/*`)",
+                Some('M'),
+            )
+            .switch(
+                "no-follow-symlinks",
+                "Don't follow symlinked directories when the pattern is recursive (e.g. `**/*`)",
+                Some('T'),
+            )
             .category(Category::FileSystem)
     }
 
@@ -94,6 +107,16 @@ impl Command for Ls {
         let directory = call.has_flag(engine_state, stack, "directory")?;
         let use_mime_type = call.has_flag(engine_state, stack, "mime-type")?;
         let use_threads = call.has_flag(engine_state, stack, "threads")?;
+        let no_follow_symlinks = call.has_flag(engine_state, stack, "no-follow-symlinks")?;
+        let max_depth: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "max-depth")?;
+        if let Some(ref max_depth) = max_depth {
+            if max_depth.item < 0 {
+                return Err(ShellError::NeedsPositiveValue {
+                    span: max_depth.span,
+                });
+            }
+        }
+        let max_depth = max_depth.map(|d| d.item as usize);
         let call_span = call.head;
         #[allow(deprecated)]
         let cwd = current_dir(engine_state, stack)?;
@@ -107,6 +130,8 @@ impl Command for Ls {
             directory,
             use_mime_type,
             use_threads,
+            max_depth,
+            no_follow_symlinks,
             call_span,
         };
 
@@ -200,6 +225,12 @@ impl Command for Ls {
                 example: "ls -a **/*",
                 result: None,
             },
+            Example {
+                description:
+                    "Recursively list files no more than 2 directories deep",
+                example: "ls **/* --max-depth 2",
+                result: None,
+            },
             Example {
                 description:
                     "Recursively list *.rs and *.toml files using the glob command",
@@ -248,6 +279,8 @@ fn ls_for_one_pattern(
         directory,
         use_mime_type,
         use_threads,
+        max_depth,
+        no_follow_symlinks,
         call_span,
     } = args;
     let pattern_arg = {
@@ -322,11 +355,11 @@ fn ls_for_one_pattern(
         // just need to read the directory, so prefix is path itself.
         (Some(expanded), paths)
     } else {
-        let glob_options = if all {
-            None
-        } else {
+        let glob_options = {
             let glob_options = MatchOptions {
-                recursive_match_hidden_dir: false,
+                recursive_match_hidden_dir: all,
+                follow_symlinks: !no_follow_symlinks,
+                max_depth: max_depth.unwrap_or(usize::MAX),
                 ..Default::default()
             };
             Some(glob_options)
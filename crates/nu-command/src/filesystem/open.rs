@@ -1,8 +1,12 @@
 #[allow(deprecated)]
-use nu_engine::{command_prelude::*, current_dir, get_eval_block};
-use nu_protocol::{ast, DataSource, NuGlob, PipelineMetadata};
+use nu_engine::{command_prelude::*, current_dir, current_dir_const, get_eval_block};
+use nu_protocol::{ast, engine::StateWorkingSet, DataSource, NuGlob, PipelineMetadata};
 use std::path::Path;
 
+/// Files larger than this are refused in a `const` context, even with `--allow-const-read`, so a
+/// module author can't accidentally make parsing a script depend on reading a large file.
+const MAX_CONST_READ_SIZE: u64 = 64 * 1024;
+
 #[cfg(feature = "sqlite")]
 use crate::database::SQLiteDatabase;
 
@@ -22,7 +26,12 @@ impl Command for Open {
     }
 
     fn extra_description(&self) -> &str {
-        "Support to automatically parse files with an extension `.xyz` can be provided by a `from xyz` command in scope."
+        "Support to automatically parse files with an extension `.xyz` can be provided by a `from xyz` command in scope.
+
+`open` can also run in a `const` context (e.g. `const x = (open ...)` inside a module), but only
+with a single literal path and the `--allow-const-read` flag, since reading a file at parse time
+makes a script's meaning depend on its environment. Files larger than 64 KiB are refused, and no
+`from <ext>` conversion is applied; the raw text or binary contents are returned as-is."
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -38,9 +47,104 @@ impl Command for Open {
                 "The file(s) to open.",
             )
             .switch("raw", "open file as raw binary", Some('r'))
+            .switch(
+                "allow-const-read",
+                format!(
+                    "Allow this `open` to run in a `const` context, reading the file at parse \
+                     time. Refused for files larger than {} KiB; no `from <ext>` conversion is \
+                     applied, the raw contents are always returned.",
+                    MAX_CONST_READ_SIZE / 1024
+                ),
+                None,
+            )
             .category(Category::FileSystem)
     }
 
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let call_span = call.head;
+
+        if !call.has_flag_const(working_set, "allow-const-read")? {
+            return Err(ShellError::GenericError {
+                error: "`open` requires explicit opt-in in a const context".into(),
+                msg: "reading a file at parse time can make a script's meaning depend on its \
+                      environment; pass --allow-const-read to confirm that's intended"
+                    .into(),
+                span: Some(call_span),
+                help: Some("const data = (open --raw --allow-const-read file.txt)".into()),
+                inner: vec![],
+            });
+        }
+
+        let raw = call.has_flag_const(working_set, "raw")?;
+        let paths = call.rest_const::<Spanned<NuGlob>>(working_set, 0)?;
+        let path = match (paths.as_slice(), &input) {
+            ([path], _) => path.clone(),
+            ([], PipelineData::Value(val, ..)) => {
+                let span = val.span();
+                Spanned {
+                    item: NuGlob::Expand(val.clone().coerce_into_string()?),
+                    span,
+                }
+            }
+            _ => {
+                return Err(ShellError::GenericError {
+                    error: "`open` in a const context only supports a single literal path".into(),
+                    msg: "globbing and multiple files are not supported at parse time".into(),
+                    span: Some(call_span),
+                    help: None,
+                    inner: vec![],
+                })
+            }
+        };
+
+        let cwd = current_dir_const(working_set)?;
+        let path = nu_path::expand_path_with(path.item.as_ref(), &cwd, path.item.is_expand());
+
+        let metadata = std::fs::metadata(&path).map_err(|err| ShellError::FileNotFoundCustom {
+            msg: format!("{}: {err}", path.display()),
+            span: call_span,
+        })?;
+        if metadata.len() > MAX_CONST_READ_SIZE {
+            return Err(ShellError::GenericError {
+                error: "File too large to read in a const context".into(),
+                msg: format!(
+                    "{} is {} bytes, which is over the {} KiB limit for --allow-const-read",
+                    path.display(),
+                    metadata.len(),
+                    MAX_CONST_READ_SIZE / 1024
+                ),
+                span: Some(call_span),
+                help: None,
+                inner: vec![],
+            });
+        }
+
+        let bytes = std::fs::read(&path).map_err(|err| ShellError::GenericError {
+            error: "Could not read file".into(),
+            msg: err.to_string(),
+            span: Some(call_span),
+            help: None,
+            inner: vec![],
+        })?;
+
+        if raw {
+            return Ok(Value::binary(bytes, call_span).into_pipeline_data());
+        }
+        match String::from_utf8(bytes) {
+            Ok(s) => Ok(Value::string(s, call_span).into_pipeline_data()),
+            Err(err) => Ok(Value::binary(err.into_bytes(), call_span).into_pipeline_data()),
+        }
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -251,6 +355,11 @@ impl Command for Open {
                 example: r#"def "from ndjson" [] { from json -o }; open myfile.ndjson"#,
                 result: None,
             },
+            Example {
+                description: "Read a small file at parse time, for use in a module's exports",
+                example: "const version = (open --raw --allow-const-read VERSION)",
+                result: None,
+            },
         ]
     }
 }
@@ -14,6 +14,7 @@ use std::{
     thread,
     time::{Duration, Instant},
 };
+use toml_edit::{value, Array, InlineTable, Item, Table};
 
 #[derive(Clone)]
 pub struct Save;
@@ -226,8 +227,17 @@ impl Command for Save {
                     )?;
                 }
 
-                let bytes =
-                    input_to_bytes(input, Path::new(&path.item), raw, engine_state, stack, span)?;
+                let is_toml_record = !raw
+                    && path.item.extension().is_some_and(|ext| ext == "toml")
+                    && matches!(input, PipelineData::Value(Value::Record { .. }, ..));
+
+                let bytes = if is_toml_record {
+                    let metadata = input.metadata();
+                    let value = input.into_value(span)?;
+                    toml_bytes_for_save(engine_state, stack, value, metadata, &path.item, span)?
+                } else {
+                    input_to_bytes(input, Path::new(&path.item), raw, engine_state, stack, span)?
+                };
 
                 // Only open file after successful conversion
                 let (mut file, _) = get_files(&path, stderr_path.as_ref(), append, force)?;
@@ -270,6 +280,11 @@ impl Command for Save {
                 example: r#"do -i {} | save foo.txt --stderr bar.txt"#,
                 result: None,
             },
+            Example {
+                description: "Update one key in a TOML file without disturbing its formatting or comments",
+                example: r#"open Cargo.toml | update package.version "2.0.0" | save --force Cargo.toml"#,
+                result: None,
+            },
         ]
     }
 
@@ -370,6 +385,137 @@ fn convert_to_extension(
     }
 }
 
+/// Convert a record into TOML bytes for `save`, preferring an edit-preserving merge into the
+/// original file's text over a plain `to toml` when the pipeline can be traced back (via
+/// `open`'s `DataSource::FilePath` metadata) to the very file being written. That way, something
+/// like `open Cargo.toml | update package.version '2.0.0' | save -f Cargo.toml` only touches the
+/// `version` line instead of reformatting the whole file and dropping its comments.
+fn toml_bytes_for_save(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    value: Value,
+    metadata: Option<PipelineMetadata>,
+    dest: &Path,
+    span: Span,
+) -> Result<Vec<u8>, ShellError> {
+    if let Some(bytes) = try_edit_preserving_toml(engine_state, &value, metadata.as_ref(), dest, span) {
+        return Ok(bytes);
+    }
+
+    let input = convert_to_extension(
+        engine_state,
+        "toml",
+        stack,
+        PipelineData::Value(value, metadata),
+        span,
+    )?;
+    value_to_bytes(input.into_value(span)?)
+}
+
+/// Returns `None` (falling back to a plain `to toml` conversion) unless `dest` is the same file
+/// the input was originally read from and it's still readable and parseable as TOML; a partial
+/// merge would be worse than a clean rewrite, so any failure just gives up on preservation rather
+/// than producing a half-updated file.
+fn try_edit_preserving_toml(
+    engine_state: &EngineState,
+    value: &Value,
+    metadata: Option<&PipelineMetadata>,
+    dest: &Path,
+    span: Span,
+) -> Option<Vec<u8>> {
+    let DataSource::FilePath(source) = metadata.map(|meta| &meta.data_source)? else {
+        return None;
+    };
+    if source != dest {
+        return None;
+    }
+
+    let original = std::fs::read_to_string(source).ok()?;
+    let mut document = original.parse::<toml_edit::DocumentMut>().ok()?;
+
+    let toml::Value::Table(new_table) = crate::value_to_toml_value(engine_state, value, span).ok()?
+    else {
+        return None;
+    };
+    merge_toml_table(document.as_table_mut(), &new_table);
+
+    Some(document.to_string().into_bytes())
+}
+
+/// Merge `new_table` into `table` in place: keys that are unchanged keep their original item
+/// (and therefore their formatting/comments), keys that are gone are removed, and keys that are
+/// new or changed are (re)inserted, recursing into nested `[section]` tables so a change deep in
+/// the tree doesn't disturb its unrelated siblings.
+fn merge_toml_table(table: &mut Table, new_table: &toml::map::Map<String, toml::Value>) {
+    let stale: Vec<String> = table
+        .iter()
+        .map(|(k, _)| k.to_string())
+        .filter(|k| !new_table.contains_key(k))
+        .collect();
+    for k in stale {
+        table.remove(&k);
+    }
+
+    for (k, v) in new_table {
+        if let Some(existing) = table.get_mut(k) {
+            merge_toml_item(existing, v);
+        } else {
+            table.insert(k, toml_value_to_edit_item(v));
+        }
+    }
+}
+
+fn merge_toml_item(existing: &mut Item, new: &toml::Value) {
+    match (existing, new) {
+        (Item::Table(table), toml::Value::Table(new_table)) => merge_toml_table(table, new_table),
+        (existing, new) => *existing = toml_value_to_edit_item(new),
+    }
+}
+
+fn toml_value_to_edit_item(v: &toml::Value) -> Item {
+    match v {
+        toml::Value::Table(t) => {
+            let mut table = Table::new();
+            for (k, v) in t {
+                table.insert(k, toml_value_to_edit_item(v));
+            }
+            Item::Table(table)
+        }
+        other => value(toml_value_to_edit_value(other)),
+    }
+}
+
+/// Like [`toml_value_to_edit_item`], but for a value that can't be a top-level table item,
+/// e.g. an element of an array. A `toml::Value::Table` here (a table inside an array) becomes a
+/// `toml_edit` inline table, since `toml_edit::Array` can only hold values, not full tables.
+fn toml_value_to_edit_value(v: &toml::Value) -> toml_edit::Value {
+    match v {
+        toml::Value::String(s) => s.clone().into(),
+        toml::Value::Integer(i) => (*i).into(),
+        toml::Value::Float(f) => (*f).into(),
+        toml::Value::Boolean(b) => (*b).into(),
+        toml::Value::Datetime(dt) => dt
+            .to_string()
+            .parse::<toml_edit::Datetime>()
+            .map(Into::into)
+            .unwrap_or_else(|_| dt.to_string().into()),
+        toml::Value::Array(arr) => {
+            let mut a = Array::new();
+            for item in arr {
+                a.push(toml_value_to_edit_value(item));
+            }
+            a.into()
+        }
+        toml::Value::Table(t) => {
+            let mut inline = InlineTable::new();
+            for (k, v) in t {
+                inline.insert(k, toml_value_to_edit_value(v));
+            }
+            inline.into()
+        }
+    }
+}
+
 /// Convert [`Value::String`] [`Value::Binary`] or [`Value::List`] into [`Vec`] of bytes
 ///
 /// Propagates [`Value::Error`] and creates error otherwise
@@ -11,7 +11,8 @@ use nu_protocol::{
     format_shell_error,
 };
 use std::{
-    path::PathBuf,
+    collections::VecDeque,
+    path::{Path, PathBuf},
     sync::mpsc::{channel, RecvTimeoutError},
     time::Duration,
 };
@@ -29,7 +30,7 @@ impl Command for Watch {
     }
 
     fn description(&self) -> &str {
-        "Watch for file changes and execute Nu code when they happen."
+        "Watch for file changes and execute Nu code when they happen, or stream the change records."
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -38,11 +39,11 @@ impl Command for Watch {
 
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("watch")
-        .input_output_types(vec![(Type::Nothing, Type::table())])
+        .input_output_types(vec![(Type::Nothing, Type::table()), (Type::Nothing, Type::Nothing)])
             .required("path", SyntaxShape::Filepath, "The path to watch. Can be a file or directory.")
-            .required("closure",
+            .optional("closure",
             SyntaxShape::Closure(Some(vec![SyntaxShape::String, SyntaxShape::String, SyntaxShape::String])),
-                "Some Nu code to run whenever a file changes. The closure will be passed `operation`, `path`, and `new_path` (for renames only) arguments in that order.")
+                "Some Nu code to run whenever a file changes. The closure will be passed `operation`, `path`, and `new_path` (for renames only) arguments in that order. If omitted, `watch` instead streams `{event, path, old_path}` records.")
             .named(
                 "debounce-ms",
                 SyntaxShape::Int,
@@ -55,6 +56,12 @@ impl Command for Watch {
                 "Only report changes for files that match this glob pattern (default: all files)",
                 Some('g'),
             )
+            .named(
+                "exclude",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "Don't report changes for files that match any of these glob patterns (default: none)",
+                Some('e'),
+            )
             .named(
                 "recursive",
                 SyntaxShape::Boolean,
@@ -91,7 +98,7 @@ impl Command for Watch {
             }
         };
 
-        let closure: Closure = call.req(engine_state, stack, 1)?;
+        let closure: Option<Closure> = call.opt(engine_state, stack, 1)?;
 
         let verbose = call.has_flag(engine_state, stack, "verbose")?;
 
@@ -133,6 +140,28 @@ impl Command for Watch {
             None => None,
         };
 
+        let exclude_flag: Option<Vec<Spanned<String>>> =
+            call.get_flag(engine_state, stack, "exclude")?;
+        let exclude_patterns = match exclude_flag {
+            Some(excludes) => {
+                let mut patterns = Vec::with_capacity(excludes.len());
+                for exclude in excludes {
+                    let absolute_path = path.join(exclude.item);
+                    match nu_glob::Pattern::new(&absolute_path.to_string_lossy()) {
+                        Ok(pattern) => patterns.push(pattern),
+                        Err(_) => {
+                            return Err(ShellError::TypeMismatch {
+                                err_message: "Exclude pattern is invalid".to_string(),
+                                span: exclude.span,
+                            })
+                        }
+                    }
+                }
+                patterns
+            }
+            None => Vec::new(),
+        };
+
         let recursive_flag: Option<Spanned<bool>> =
             call.get_flag(engine_state, stack, "recursive")?;
         let recursive_mode = match recursive_flag {
@@ -168,21 +197,87 @@ impl Command for Watch {
             eprintln!("Now watching files at {path:?}. Press ctrl+c to abort.");
         }
 
+        let Some(closure) = closure else {
+            // No closure was given, so stream `{event, path, old_path}` records instead of
+            // invoking anything. The debouncer is moved into the iterator closure to keep the
+            // underlying watch alive for as long as the stream is consumed.
+            let signals = engine_state.signals().clone();
+            let mut pending: VecDeque<(&'static str, PathBuf, Option<PathBuf>)> = VecDeque::new();
+            let mut done = false;
+            let iter = std::iter::from_fn(move || {
+                let _debouncer = &debouncer;
+                loop {
+                    if done {
+                        return None;
+                    }
+
+                    if let Some((event, path, old_path)) = pending.pop_front() {
+                        if matches_filters(&path, &glob_pattern, &exclude_patterns) {
+                            return Some(Value::record(
+                                record! {
+                                    "event" => Value::string(event, head),
+                                    "path" => Value::string(path.to_string_lossy(), head),
+                                    "old_path" => old_path.map_or_else(
+                                        || Value::nothing(head),
+                                        |p| Value::string(p.to_string_lossy(), head),
+                                    ),
+                                },
+                                head,
+                            ));
+                        }
+                        continue;
+                    }
+
+                    if let Err(error) = signals.check(head) {
+                        done = true;
+                        return Some(Value::error(error, head));
+                    }
+
+                    match rx.recv_timeout(CHECK_CTRL_C_FREQUENCY) {
+                        Ok(Ok(events)) => {
+                            if verbose {
+                                eprintln!("{events:?}");
+                            }
+                            pending.extend(classify_events(events));
+                        }
+                        Ok(Err(_)) => {
+                            done = true;
+                            return Some(Value::error(
+                                ShellError::IOError {
+                                    msg: "Unexpected errors when receiving events".into(),
+                                },
+                                head,
+                            ));
+                        }
+                        Err(RecvTimeoutError::Disconnected) => {
+                            done = true;
+                            return Some(Value::error(
+                                ShellError::IOError {
+                                    msg: "Unexpected disconnect from file watcher".into(),
+                                },
+                                head,
+                            ));
+                        }
+                        Err(RecvTimeoutError::Timeout) => {}
+                    }
+                }
+            });
+
+            return Ok(iter.into_pipeline_data(head, engine_state.signals().clone()));
+        };
+
         let mut closure = ClosureEval::new(engine_state, stack, closure);
 
         let mut event_handler = move |operation: &str,
                                       path: PathBuf,
                                       new_path: Option<PathBuf>|
               -> Result<(), ShellError> {
-            let matches_glob = match &glob_pattern {
-                Some(glob) => glob.matches_path(&path),
-                None => true,
-            };
-            if verbose && glob_pattern.is_some() {
-                eprintln!("Matches glob: {matches_glob}");
+            let matches = matches_filters(&path, &glob_pattern, &exclude_patterns);
+            if verbose {
+                eprintln!("Matches filters: {matches}");
             }
 
-            if matches_glob {
+            if matches {
                 let result = closure
                     .add_arg(Value::string(operation, head))
                     .add_arg(Value::string(path.to_string_lossy(), head))
@@ -212,40 +307,8 @@ impl Command for Watch {
                     if verbose {
                         eprintln!("{events:?}");
                     }
-                    for mut one_event in events {
-                        let handle_result = match one_event.event.kind {
-                            // only want to handle event if relative path exists.
-                            EventKind::Create(_) => one_event
-                                .paths
-                                .pop()
-                                .map(|path| event_handler("Create", path, None))
-                                .unwrap_or(Ok(())),
-                            EventKind::Remove(_) => one_event
-                                .paths
-                                .pop()
-                                .map(|path| event_handler("Remove", path, None))
-                                .unwrap_or(Ok(())),
-                            EventKind::Modify(ModifyKind::Data(DataChange::Content))
-                            | EventKind::Modify(ModifyKind::Data(DataChange::Any))
-                            | EventKind::Modify(ModifyKind::Any) => one_event
-                                .paths
-                                .pop()
-                                .map(|path| event_handler("Write", path, None))
-                                .unwrap_or(Ok(())),
-                            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => one_event
-                                .paths
-                                .pop()
-                                .map(|to| {
-                                    one_event
-                                        .paths
-                                        .pop()
-                                        .map(|from| event_handler("Rename", from, Some(to)))
-                                        .unwrap_or(Ok(()))
-                                })
-                                .unwrap_or(Ok(())),
-                            _ => Ok(()),
-                        };
-                        handle_result?;
+                    for (operation, path, new_path) in classify_events(events) {
+                        event_handler(operation, path, new_path)?;
                     }
                 }
                 Ok(Err(_)) => {
@@ -290,6 +353,64 @@ impl Command for Watch {
                 example: r#"loop { command; sleep duration }"#,
                 result: None,
             },
+            Example {
+                description: "Stream change records instead of running a closure, for use in a pipeline",
+                example: r#"watch . --glob=**/*.rs --exclude=[**/target/**] | first 10"#,
+                result: None,
+            },
         ]
     }
 }
+
+/// Turn a batch of debounced filesystem events into `(operation, path, old_path)` tuples,
+/// dropping event kinds this command doesn't report on and events missing their path.
+fn classify_events(
+    events: Vec<notify_debouncer_full::DebouncedEvent>,
+) -> Vec<(&'static str, PathBuf, Option<PathBuf>)> {
+    let mut out = Vec::new();
+    for mut one_event in events {
+        match one_event.event.kind {
+            // only want to handle event if relative path exists.
+            EventKind::Create(_) => {
+                if let Some(path) = one_event.paths.pop() {
+                    out.push(("Create", path, None));
+                }
+            }
+            EventKind::Remove(_) => {
+                if let Some(path) = one_event.paths.pop() {
+                    out.push(("Remove", path, None));
+                }
+            }
+            EventKind::Modify(ModifyKind::Data(DataChange::Content))
+            | EventKind::Modify(ModifyKind::Data(DataChange::Any))
+            | EventKind::Modify(ModifyKind::Any) => {
+                if let Some(path) = one_event.paths.pop() {
+                    out.push(("Write", path, None));
+                }
+            }
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+                if let Some(to) = one_event.paths.pop() {
+                    if let Some(from) = one_event.paths.pop() {
+                        out.push(("Rename", from, Some(to)));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Whether `path` should be reported: it must match `glob_pattern` (if given) and must not
+/// match any of `exclude_patterns`.
+fn matches_filters(
+    path: &Path,
+    glob_pattern: &Option<nu_glob::Pattern>,
+    exclude_patterns: &[nu_glob::Pattern],
+) -> bool {
+    let included = match glob_pattern {
+        Some(glob) => glob.matches_path(path),
+        None => true,
+    };
+    included && !exclude_patterns.iter().any(|pattern| pattern.matches_path(path))
+}
@@ -20,6 +20,13 @@ enum IncludeInner {
     Yes,
 }
 
+#[derive(Clone, Copy)]
+enum AsofStrategy {
+    Backward,
+    Forward,
+    Nearest,
+}
+
 impl Command for Join {
     fn name(&self) -> &str {
         "join"
@@ -46,6 +53,23 @@ impl Command for Join {
             .switch("left", "Left-outer join", Some('l'))
             .switch("right", "Right-outer join", Some('r'))
             .switch("outer", "Outer join", Some('o'))
+            .switch(
+                "asof",
+                "As-of join: match each left row to the nearest right row by key instead of requiring equality",
+                None,
+            )
+            .named(
+                "strategy",
+                SyntaxShape::String,
+                "As-of match strategy: 'backward' (default, nearest key <= left key), 'forward' (nearest key >= left key), or 'nearest'",
+                None,
+            )
+            .named(
+                "tolerance",
+                SyntaxShape::Any,
+                "Maximum allowed difference between matched keys for --asof, e.g. a duration like 5min or a plain number",
+                None,
+            )
             .input_output_types(vec![(Type::table(), Type::table())])
             .category(Category::Filters)
     }
@@ -72,7 +96,7 @@ impl Command for Join {
             .opt(engine_state, stack, 2)?
             .unwrap_or_else(|| l_on.clone());
         let span = call.head;
-        let join_type = join_type(engine_state, stack, call)?;
+        let asof = call.has_flag(engine_state, stack, "asof")?;
 
         // FIXME: we should handle ListStreams properly instead of collecting
         let collected_input = input.into_value(span)?;
@@ -84,7 +108,24 @@ impl Command for Join {
                 Value::String { val: l_on, .. },
                 Value::String { val: r_on, .. },
             ) => {
-                let result = join(rows_1, rows_2, l_on, r_on, join_type, span);
+                let result = if asof {
+                    if call.has_flag(engine_state, stack, "inner")?
+                        || call.has_flag(engine_state, stack, "left")?
+                        || call.has_flag(engine_state, stack, "right")?
+                        || call.has_flag(engine_state, stack, "outer")?
+                    {
+                        return Err(ShellError::IncompatibleParametersSingle {
+                            msg: "--asof cannot be combined with --inner, --left, --right, or --outer".into(),
+                            span,
+                        });
+                    }
+                    let strategy = asof_strategy(engine_state, stack, call)?;
+                    let tolerance = asof_tolerance(engine_state, stack, call)?;
+                    join_asof(rows_1, rows_2, l_on, r_on, strategy, tolerance, span)?
+                } else {
+                    let join_type = join_type(engine_state, stack, call)?;
+                    join(rows_1, rows_2, l_on, r_on, join_type, span)
+                };
                 Ok(PipelineData::Value(result, metadata))
             }
             _ => Err(ShellError::UnsupportedInput {
@@ -103,13 +144,25 @@ impl Command for Join {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Join two tables",
-            example: "[{a: 1 b: 2}] | join [{a: 1 c: 3}] a",
-            result: Some(Value::test_list(vec![Value::test_record(record! {
-                "a" => Value::test_int(1), "b" => Value::test_int(2), "c" => Value::test_int(3),
-            })])),
-        }]
+        vec![
+            Example {
+                description: "Join two tables",
+                example: "[{a: 1 b: 2}] | join [{a: 1 c: 3}] a",
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                    "a" => Value::test_int(1), "b" => Value::test_int(2), "c" => Value::test_int(3),
+                })])),
+            },
+            Example {
+                description: "Join two time series on the nearest earlier timestamp",
+                example: "$trades | join --asof --strategy backward $quotes time time",
+                result: None,
+            },
+            Example {
+                description: "Join two time series, requiring matches within 1 second",
+                example: "$trades | join --asof --tolerance 1sec $quotes time time",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -137,6 +190,195 @@ fn join_type(
     }
 }
 
+fn asof_strategy(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<AsofStrategy, nu_protocol::ShellError> {
+    let strategy: Option<Spanned<String>> = call.get_flag(engine_state, stack, "strategy")?;
+    match strategy {
+        None => Ok(AsofStrategy::Backward),
+        Some(s) => match s.item.as_str() {
+            "backward" => Ok(AsofStrategy::Backward),
+            "forward" => Ok(AsofStrategy::Forward),
+            "nearest" => Ok(AsofStrategy::Nearest),
+            _ => Err(ShellError::InvalidValue {
+                valid: "one of 'backward', 'forward', 'nearest'".into(),
+                actual: s.item,
+                span: s.span,
+            }),
+        },
+    }
+}
+
+fn asof_tolerance(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Option<f64>, nu_protocol::ShellError> {
+    let tolerance: Option<Value> = call.get_flag(engine_state, stack, "tolerance")?;
+    tolerance
+        .map(|v| {
+            let span = v.span();
+            numeric_value(&v).ok_or_else(|| ShellError::UnsupportedInput {
+                msg: "--tolerance must be a duration or a number".into(),
+                input: format!("{:?}", v.get_type()),
+                msg_span: span,
+                input_span: span,
+            })
+        })
+        .transpose()
+}
+
+// Convert a join-key value into an f64 for distance comparisons, used by
+// as-of joins to evaluate `--strategy nearest` and `--tolerance`.
+fn numeric_value(value: &Value) -> Option<f64> {
+    match value {
+        Value::Int { val, .. } => Some(*val as f64),
+        Value::Float { val, .. } => Some(*val),
+        Value::Duration { val, .. } => Some(*val as f64),
+        Value::Date { val, .. } => val.timestamp_nanos_opt().map(|ns| ns as f64),
+        _ => None,
+    }
+}
+
+fn numeric_distance(a: &Value, b: &Value) -> Option<f64> {
+    Some((numeric_value(a)? - numeric_value(b)?).abs())
+}
+
+fn row_key<'a>(row: &'a Value, key_col: &str) -> Option<&'a Value> {
+    match row {
+        Value::Record { val, .. } => val.get(key_col),
+        _ => None,
+    }
+}
+
+fn compare_keys(a: Option<&Value>, b: Option<&Value>) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+    match (a, b) {
+        (Some(a), Some(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+// Find the index (into `right`) of the row whose join key is the last one
+// <= `left_key` ("backward"), the first one >= `left_key` ("forward"), or
+// whichever of those two is numerically closest ("nearest").
+fn find_asof_match(
+    sorted_right: &[usize],
+    right: &[Value],
+    right_on: &str,
+    left_key: &Value,
+    strategy: AsofStrategy,
+) -> Option<usize> {
+    // Binary search for the first position whose key is > left_key.
+    let mut lo = 0usize;
+    let mut hi = sorted_right.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let key = row_key(&right[sorted_right[mid]], right_on);
+        if compare_keys(key, Some(left_key)) == std::cmp::Ordering::Greater {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    let backward = lo.checked_sub(1).map(|i| sorted_right[i]);
+    let forward = sorted_right.get(lo).copied();
+
+    match strategy {
+        AsofStrategy::Backward => backward,
+        AsofStrategy::Forward => forward,
+        AsofStrategy::Nearest => match (backward, forward) {
+            (Some(b), Some(f)) => {
+                let bd = row_key(&right[b], right_on).and_then(|k| numeric_distance(k, left_key));
+                let fd = row_key(&right[f], right_on).and_then(|k| numeric_distance(k, left_key));
+                match (bd, fd) {
+                    (Some(bd), Some(fd)) if fd < bd => Some(f),
+                    (Some(_), Some(_)) => Some(b),
+                    _ => Some(b),
+                }
+            }
+            (Some(b), None) => Some(b),
+            (None, Some(f)) => Some(f),
+            (None, None) => None,
+        },
+    }
+}
+
+// As-of (nearest-key) join: behaves like a left join, but each left row is
+// matched to the single nearest right row by key (per `strategy`) instead of
+// requiring an exact match, optionally bounded by `tolerance`.
+fn join_asof(
+    left: &[Value],
+    right: &[Value],
+    left_on: &str,
+    right_on: &str,
+    strategy: AsofStrategy,
+    tolerance: Option<f64>,
+    span: Span,
+) -> Result<Value, nu_protocol::ShellError> {
+    let shared_join_key = (left_on == right_on).then_some(left_on);
+    let right_names = column_names(right);
+
+    let mut sorted_right: Vec<usize> = (0..right.len()).collect();
+    sorted_right
+        .sort_by(|&a, &b| compare_keys(row_key(&right[a], right_on), row_key(&right[b], right_on)));
+
+    let mut result = Vec::with_capacity(left.len());
+    for left_row in left {
+        let Value::Record {
+            val: left_record, ..
+        } = left_row
+        else {
+            continue;
+        };
+
+        let matched = left_record.get(left_on).and_then(|left_key| {
+            let idx = find_asof_match(&sorted_right, right, right_on, left_key, strategy)?;
+            let right_key = row_key(&right[idx], right_on)?;
+            let within = tolerance.map_or(true, |tol| {
+                numeric_distance(left_key, right_key).is_some_and(|d| d <= tol)
+            });
+            within.then_some(idx)
+        });
+
+        let right_record = match matched {
+            Some(idx) => match &right[idx] {
+                Value::Record { val, .. } => Some(val.clone().into_owned()),
+                _ => None,
+            },
+            None => None,
+        };
+
+        let right_record = right_record.unwrap_or_else(|| {
+            right_names
+                .iter()
+                .map(|&key| {
+                    let val = if Some(key.as_str()) == shared_join_key {
+                        left_record
+                            .get(key)
+                            .cloned()
+                            .unwrap_or_else(|| Value::nothing(span))
+                    } else {
+                        Value::nothing(span)
+                    };
+                    (key.clone(), val)
+                })
+                .collect()
+        });
+
+        result.push(Value::record(
+            merge_records(left_record, &right_record, shared_join_key),
+            span,
+        ));
+    }
+
+    Ok(Value::list(result, span))
+}
+
 fn join(
     left: &[Value],
     right: &[Value],
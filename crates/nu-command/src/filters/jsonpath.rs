@@ -0,0 +1,422 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct QueryJsonpath;
+
+impl Command for QueryJsonpath {
+    fn name(&self) -> &str {
+        "query jsonpath"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Any, Type::List(Box::new(Type::Any)))])
+            .required(
+                "path",
+                SyntaxShape::String,
+                "The JSONPath expression to evaluate.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Query a structured value with a JSONPath expression, returning matches as a list."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Unlike a cell path, a JSONPath expression can recurse into a value at any depth
+(`..name`), select every element of a step (`*`), slice arrays (`[1:3]`), and filter arrays
+with a predicate (`[?(@.field > 1)]`). It operates directly on nushell values, so it works on
+any structured input, not just JSON text."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["jsonpath", "json", "filter", "recursive", "cell-path"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let segments = parse(&path.item).map_err(|msg| ShellError::IncorrectValue {
+            msg,
+            val_span: path.span,
+            call_span: head,
+        })?;
+
+        let value = input.into_value(head)?;
+        let mut current = vec![value];
+        for segment in &segments {
+            current = apply(current, segment);
+        }
+
+        Ok(Value::list(current, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Select a nested field",
+                example: r#"{store: {book: {title: "Nu"}}} | query jsonpath "$.store.book.title""#,
+                result: Some(Value::test_list(vec![Value::test_string("Nu")])),
+            },
+            Example {
+                description: "Recursively find every `price` field, at any depth",
+                example: r#"{store: {book: [{price: 10}, {price: 20}]}} | query jsonpath "$..price""#,
+                result: Some(Value::test_list(vec![
+                    Value::test_int(10),
+                    Value::test_int(20),
+                ])),
+            },
+            Example {
+                description: "Filter a list of records",
+                example: r#"{book: [{price: 10}, {price: 20}]} | query jsonpath "$.book[?(@.price > 15)]""#,
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                    "price" => Value::test_int(20),
+                })])),
+            },
+        ]
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Child(String),
+    Wildcard,
+    Recursive(Option<String>),
+    Index(i64),
+    Slice(Option<i64>, Option<i64>),
+    Filter(FilterExpr),
+}
+
+#[derive(Debug, Clone)]
+struct FilterExpr {
+    field: String,
+    test: Option<(CmpOp, Literal)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CmpOp {
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+}
+
+#[derive(Debug, Clone)]
+enum Literal {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+}
+
+fn parse(path: &str) -> Result<Vec<Segment>, String> {
+    let mut chars = path.trim().chars().peekable();
+    if chars.peek() == Some(&'$') {
+        chars.next();
+    }
+
+    let mut segments = Vec::new();
+    while let Some(&c) = chars.peek() {
+        match c {
+            '.' => {
+                chars.next();
+                if chars.peek() == Some(&'.') {
+                    chars.next();
+                    let name = take_name(&mut chars);
+                    segments.push(if name == "*" {
+                        Segment::Recursive(None)
+                    } else {
+                        Segment::Recursive(Some(name))
+                    });
+                } else {
+                    let name = take_name(&mut chars);
+                    segments.push(if name == "*" {
+                        Segment::Wildcard
+                    } else {
+                        Segment::Child(name)
+                    });
+                }
+            }
+            '[' => {
+                chars.next();
+                let mut inner = String::new();
+                let mut depth = 1;
+                for c in chars.by_ref() {
+                    if c == '[' {
+                        depth += 1;
+                    } else if c == ']' {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    inner.push(c);
+                }
+                segments.push(parse_bracket(inner.trim())?);
+            }
+            _ => return Err(format!("unexpected character '{c}' in JSONPath expression")),
+        }
+    }
+
+    Ok(segments)
+}
+
+fn take_name(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut name = String::new();
+    while let Some(&c) = chars.peek() {
+        if c == '.' || c == '[' {
+            break;
+        }
+        name.push(c);
+        chars.next();
+    }
+    name
+}
+
+fn parse_bracket(inner: &str) -> Result<Segment, String> {
+    if inner == "*" {
+        return Ok(Segment::Wildcard);
+    }
+    if let Some(filter) = inner.strip_prefix("?(").and_then(|s| s.strip_suffix(')')) {
+        return parse_filter(filter.trim()).map(Segment::Filter);
+    }
+    if let Some(quoted) = inner
+        .strip_prefix('\'')
+        .and_then(|s| s.strip_suffix('\''))
+        .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+    {
+        return Ok(Segment::Child(quoted.to_string()));
+    }
+    if let Some((start, end)) = inner.split_once(':') {
+        let start = if start.is_empty() {
+            None
+        } else {
+            Some(
+                start
+                    .parse::<i64>()
+                    .map_err(|_| format!("invalid slice start '{start}'"))?,
+            )
+        };
+        let end = if end.is_empty() {
+            None
+        } else {
+            Some(
+                end.parse::<i64>()
+                    .map_err(|_| format!("invalid slice end '{end}'"))?,
+            )
+        };
+        return Ok(Segment::Slice(start, end));
+    }
+    inner
+        .parse::<i64>()
+        .map(Segment::Index)
+        .map_err(|_| format!("invalid JSONPath bracket contents '[{inner}]'"))
+}
+
+fn parse_filter(expr: &str) -> Result<FilterExpr, String> {
+    let ops: &[(&str, CmpOp)] = &[
+        ("==", CmpOp::Eq),
+        ("!=", CmpOp::Ne),
+        (">=", CmpOp::Ge),
+        ("<=", CmpOp::Le),
+        (">", CmpOp::Gt),
+        ("<", CmpOp::Lt),
+    ];
+
+    for (token, op) in ops {
+        if let Some((lhs, rhs)) = expr.split_once(token) {
+            let field = lhs
+                .trim()
+                .strip_prefix("@.")
+                .ok_or_else(|| format!("filter field must start with '@.', got '{lhs}'"))?
+                .to_string();
+            let literal = parse_literal(rhs.trim())?;
+            return Ok(FilterExpr {
+                field,
+                test: Some((*op, literal)),
+            });
+        }
+    }
+
+    let field = expr
+        .trim()
+        .strip_prefix("@.")
+        .ok_or_else(|| format!("filter field must start with '@.', got '{expr}'"))?
+        .to_string();
+    Ok(FilterExpr { field, test: None })
+}
+
+fn parse_literal(text: &str) -> Result<Literal, String> {
+    if let Some(s) = text
+        .strip_prefix('\'')
+        .and_then(|s| s.strip_suffix('\''))
+        .or_else(|| text.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+    {
+        return Ok(Literal::Str(s.to_string()));
+    }
+    if text == "true" {
+        return Ok(Literal::Bool(true));
+    }
+    if text == "false" {
+        return Ok(Literal::Bool(false));
+    }
+    if let Ok(i) = text.parse::<i64>() {
+        return Ok(Literal::Int(i));
+    }
+    if let Ok(f) = text.parse::<f64>() {
+        return Ok(Literal::Float(f));
+    }
+    Err(format!("invalid filter literal '{text}'"))
+}
+
+fn apply(nodes: Vec<Value>, segment: &Segment) -> Vec<Value> {
+    match segment {
+        Segment::Child(name) => nodes
+            .into_iter()
+            .filter_map(|v| match v {
+                Value::Record { val, .. } => val.get(name).cloned(),
+                _ => None,
+            })
+            .collect(),
+        Segment::Wildcard => nodes
+            .into_iter()
+            .flat_map(|v| match v {
+                Value::Record { val, .. } => val.into_owned().into_values().collect::<Vec<_>>(),
+                Value::List { vals, .. } => vals,
+                _ => vec![],
+            })
+            .collect(),
+        Segment::Recursive(name) => nodes
+            .into_iter()
+            .flat_map(|v| {
+                let mut matches = Vec::new();
+                collect_recursive(&v, name.as_deref(), &mut matches);
+                matches
+            })
+            .collect(),
+        Segment::Index(i) => nodes
+            .into_iter()
+            .filter_map(|v| match v {
+                Value::List { vals, .. } => index_of(&vals, *i).cloned(),
+                _ => None,
+            })
+            .collect(),
+        Segment::Slice(start, end) => nodes
+            .into_iter()
+            .flat_map(|v| match v {
+                Value::List { vals, .. } => slice_of(vals, *start, *end),
+                _ => vec![],
+            })
+            .collect(),
+        Segment::Filter(filter) => nodes
+            .into_iter()
+            .flat_map(|v| match v {
+                Value::List { vals, .. } => vals
+                    .into_iter()
+                    .filter(|item| matches_filter(item, filter))
+                    .collect(),
+                _ => vec![],
+            })
+            .collect(),
+    }
+}
+
+fn collect_recursive(value: &Value, name: Option<&str>, out: &mut Vec<Value>) {
+    match value {
+        Value::Record { val, .. } => {
+            for (key, child) in val.iter() {
+                if name.is_none_or(|name| name == key) {
+                    out.push(child.clone());
+                }
+                collect_recursive(child, name, out);
+            }
+        }
+        Value::List { vals, .. } => {
+            for child in vals {
+                collect_recursive(child, name, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn index_of(vals: &[Value], i: i64) -> Option<&Value> {
+    let len = vals.len() as i64;
+    let idx = if i < 0 { len + i } else { i };
+    usize::try_from(idx).ok().and_then(|idx| vals.get(idx))
+}
+
+fn slice_of(vals: Vec<Value>, start: Option<i64>, end: Option<i64>) -> Vec<Value> {
+    let len = vals.len() as i64;
+    let normalize = |i: i64| -> i64 {
+        if i < 0 {
+            (len + i).max(0)
+        } else {
+            i.min(len)
+        }
+    };
+    let start = normalize(start.unwrap_or(0));
+    let end = normalize(end.unwrap_or(len));
+    if start >= end {
+        return vec![];
+    }
+    vals.into_iter()
+        .skip(start as usize)
+        .take((end - start) as usize)
+        .collect()
+}
+
+fn matches_filter(item: &Value, filter: &FilterExpr) -> bool {
+    let Value::Record { val, .. } = item else {
+        return false;
+    };
+    let Some(field_value) = val.get(&filter.field) else {
+        return false;
+    };
+
+    let Some((op, literal)) = &filter.test else {
+        return true;
+    };
+
+    match (field_value, literal) {
+        (Value::Int { val, .. }, Literal::Int(lit)) => compare(*val, *lit, *op),
+        (Value::Int { val, .. }, Literal::Float(lit)) => compare(*val as f64, *lit, *op),
+        (Value::Float { val, .. }, Literal::Float(lit)) => compare(*val, *lit, *op),
+        (Value::Float { val, .. }, Literal::Int(lit)) => compare(*val, *lit as f64, *op),
+        (Value::String { val, .. }, Literal::Str(lit)) => compare(val.as_str(), lit.as_str(), *op),
+        (Value::Bool { val, .. }, Literal::Bool(lit)) => compare(*val, *lit, *op),
+        _ => false,
+    }
+}
+
+fn compare<T: PartialOrd>(lhs: T, rhs: T, op: CmpOp) -> bool {
+    match op {
+        CmpOp::Eq => lhs == rhs,
+        CmpOp::Ne => lhs != rhs,
+        CmpOp::Gt => lhs > rhs,
+        CmpOp::Lt => lhs < rhs,
+        CmpOp::Ge => lhs >= rhs,
+        CmpOp::Le => lhs <= rhs,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(QueryJsonpath {})
+    }
+}
@@ -24,18 +24,24 @@ mod is_empty;
 mod is_not_empty;
 mod items;
 mod join;
+mod jsonpath;
 mod last;
 mod length;
 mod lines;
 mod merge;
 mod move_;
 mod par_each;
+mod pivot;
 mod prepend;
+mod profile;
 mod range;
 mod reduce;
 mod reject;
 mod rename;
 mod reverse;
+mod roll;
+#[cfg(feature = "rand")]
+mod sample;
 mod select;
 #[cfg(feature = "rand")]
 mod shuffle;
@@ -48,6 +54,7 @@ mod tee;
 mod transpose;
 mod uniq;
 mod uniq_by;
+mod unpivot;
 mod update;
 mod upsert;
 mod utils;
@@ -83,6 +90,7 @@ pub use is_empty::IsEmpty;
 pub use is_not_empty::IsNotEmpty;
 pub use items::Items;
 pub use join::Join;
+pub use jsonpath::QueryJsonpath;
 pub use last::Last;
 pub use length::Length;
 pub use lines::Lines;
@@ -90,12 +98,17 @@ pub use merge::Merge;
 pub use merge::MergeDeep;
 pub use move_::Move;
 pub use par_each::ParEach;
+pub use pivot::Pivot;
 pub use prepend::Prepend;
+pub use profile::Profile;
 pub use range::Range;
 pub use reduce::Reduce;
 pub use reject::Reject;
 pub use rename::Rename;
 pub use reverse::Reverse;
+pub use roll::*;
+#[cfg(feature = "rand")]
+pub use sample::Sample;
 pub use select::Select;
 #[cfg(feature = "rand")]
 pub use shuffle::Shuffle;
@@ -108,6 +121,7 @@ pub use tee::Tee;
 pub use transpose::Transpose;
 pub use uniq::*;
 pub use uniq_by::UniqBy;
+pub use unpivot::Unpivot;
 pub use update::Update;
 pub use upsert::Upsert;
 pub use values::Values;
@@ -0,0 +1,217 @@
+use indexmap::IndexMap;
+use nu_engine::{command_prelude::*, ClosureEval};
+use nu_protocol::engine::Closure;
+
+#[derive(Clone)]
+pub struct Pivot;
+
+impl Command for Pivot {
+    fn name(&self) -> &str {
+        "pivot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pivot")
+            .input_output_types(vec![(Type::table(), Type::table())])
+            .rest(
+                "group-by",
+                SyntaxShape::String,
+                "Columns to group rows by; these become the row keys of the pivoted table. Defaults to every column other than --columns and --values.",
+            )
+            .required_named(
+                "columns",
+                SyntaxShape::String,
+                "Column whose distinct values become new column headers.",
+                Some('c'),
+            )
+            .required_named(
+                "values",
+                SyntaxShape::String,
+                "Column whose values populate the new pivoted columns.",
+                Some('v'),
+            )
+            .named(
+                "aggregate",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "Closure to combine values that land in the same cell (receives a list of the colliding values). Defaults to keeping the first value.",
+                Some('a'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Spread a column's distinct values into new columns, aggregating collisions."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Groups rows by the --group-by columns, producing one output row per group. For each \
+         group, one new column is created per distinct value of --columns, filled in from \
+         --values. When more than one row in a group shares the same --columns value, the \
+         colliding --values are combined with --aggregate. The inverse operation is `unpivot`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["spread", "widen", "reshape", "unpivot"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        pivot(engine_state, stack, call, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pivot quarterly sales into one column per quarter",
+                example: "[[year, quarter, sales]; [2023, Q1, 100], [2023, Q2, 150], [2024, Q1, 200]] | pivot year --columns quarter --values sales",
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! {
+                        "year" => Value::test_int(2023),
+                        "Q1" => Value::test_int(100),
+                        "Q2" => Value::test_int(150),
+                    }),
+                    Value::test_record(record! {
+                        "year" => Value::test_int(2024),
+                        "Q1" => Value::test_int(200),
+                    }),
+                ])),
+            },
+            Example {
+                description: "Sum values that collide in the same pivoted cell",
+                example: "[[region, quarter, sales]; [east, Q1, 100], [east, Q1, 50]] | pivot region --columns quarter --values sales --aggregate {|vals| $vals | math sum}",
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                    "region" => Value::test_string("east"),
+                    "Q1" => Value::test_int(150),
+                })])),
+            },
+        ]
+    }
+}
+
+fn column_names(rows: &[Value]) -> Vec<String> {
+    rows.iter()
+        .find_map(|val| match val {
+            Value::Record { val, .. } => Some(val.columns().cloned().collect()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+fn pivot(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let group_by: Vec<String> = call
+        .rest::<Spanned<String>>(engine_state, stack, 0)?
+        .into_iter()
+        .map(|s| s.item)
+        .collect();
+    let columns_col: Option<String> = call.get_flag(engine_state, stack, "columns")?;
+    let values_col: Option<String> = call.get_flag(engine_state, stack, "values")?;
+    let aggregate: Option<Closure> = call.get_flag(engine_state, stack, "aggregate")?;
+
+    let columns_col = columns_col.ok_or(ShellError::MissingParameter {
+        param_name: "columns".into(),
+        span: head,
+    })?;
+    let values_col = values_col.ok_or(ShellError::MissingParameter {
+        param_name: "values".into(),
+        span: head,
+    })?;
+
+    let rows: Vec<Value> = input.into_iter().collect();
+
+    let group_by = if group_by.is_empty() {
+        column_names(&rows)
+            .into_iter()
+            .filter(|c| *c != columns_col && *c != values_col)
+            .collect::<Vec<_>>()
+    } else {
+        group_by
+    };
+
+    let config = engine_state.get_config().clone();
+
+    // Row key (joined group-by values) -> (group-by values, pivot column value -> collected values)
+    let mut groups: IndexMap<String, (Vec<Value>, IndexMap<String, Vec<Value>>)> = IndexMap::new();
+
+    for row in &rows {
+        let Value::Record { val: record, .. } = row else {
+            continue;
+        };
+
+        let key_values: Vec<Value> = group_by
+            .iter()
+            .map(|c| {
+                record
+                    .get(c)
+                    .cloned()
+                    .unwrap_or_else(|| Value::nothing(head))
+            })
+            .collect();
+        let key = key_values
+            .iter()
+            .map(|v| v.to_expanded_string("\u{1}", &config))
+            .collect::<Vec<_>>()
+            .join("\u{0}");
+
+        let Some(pivot_value) = record.get(&columns_col) else {
+            continue;
+        };
+        let pivot_key = pivot_value.to_expanded_string(",", &config);
+
+        let Some(value) = record.get(&values_col).cloned() else {
+            continue;
+        };
+
+        let entry = groups
+            .entry(key)
+            .or_insert_with(|| (key_values, IndexMap::new()));
+        entry.1.entry(pivot_key).or_default().push(value);
+    }
+
+    let mut closure_eval = aggregate.map(|c| ClosureEval::new(engine_state, stack, c));
+
+    let mut result = Vec::with_capacity(groups.len());
+    for (_, (key_values, cells)) in groups {
+        let mut record = Record::with_capacity(group_by.len() + cells.len());
+        for (name, val) in group_by.iter().zip(key_values) {
+            record.push(name.clone(), val);
+        }
+        for (pivot_key, values) in cells {
+            let cell_value = match &mut closure_eval {
+                Some(closure) => closure
+                    .run_with_value(Value::list(values, head))?
+                    .into_value(head)?,
+                None => values
+                    .into_iter()
+                    .next()
+                    .unwrap_or_else(|| Value::nothing(head)),
+            };
+            record.push(pivot_key, cell_value);
+        }
+        result.push(Value::record(record, head));
+    }
+
+    Ok(Value::list(result, head).into_pipeline_data())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Pivot {})
+    }
+}
@@ -0,0 +1,200 @@
+use indexmap::IndexMap;
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct Profile;
+
+impl Command for Profile {
+    fn name(&self) -> &str {
+        "profile"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("profile")
+            .input_output_types(vec![(Type::table(), Type::table())])
+            .named(
+                "top",
+                SyntaxShape::Int,
+                "Number of most frequent values to report per column. Defaults to 3.",
+                Some('t'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Summarize a table with per-column statistics, in a single pass over the input."
+    }
+
+    fn extra_description(&self) -> &str {
+        "For each column, reports the types seen, how many values are null, an estimate of \
+         how many distinct values there are, the minimum/maximum/mean for numeric columns, \
+         and the most frequent values. Meant to be the first thing you run against an \
+         unfamiliar dataset."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["describe", "summary", "stats", "quality"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let top: Option<i64> = call.get_flag(engine_state, stack, "top")?;
+        let top = usize::try_from(top.unwrap_or(3))
+            .map_err(|_| ShellError::NeedsPositiveValue { span: head })?;
+
+        let config = engine_state.get_config().clone();
+
+        let mut columns: IndexMap<String, ColumnProfile> = IndexMap::new();
+        let mut row_count = 0i64;
+        for value in input {
+            if let Value::Error { error, .. } = value {
+                return Err(*error);
+            }
+            row_count += 1;
+            let Value::Record { val, .. } = &value else {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "record".into(),
+                    wrong_type: value.get_type().to_string(),
+                    dst_span: head,
+                    src_span: value.span(),
+                });
+            };
+            for (col, val) in val.iter() {
+                columns
+                    .entry(col.clone())
+                    .or_default()
+                    .observe(val, &config);
+            }
+        }
+
+        let result = columns
+            .into_iter()
+            .map(|(col, profile)| profile.into_value(col, row_count, top, head))
+            .collect::<Vec<_>>();
+
+        Ok(Value::list(result, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Profile a table",
+            example: "[[name, age]; [alice, 30], [bob, 25], [alice, 41]] | profile",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Default)]
+struct ColumnProfile {
+    types: IndexMap<String, i64>,
+    null_count: i64,
+    value_counts: IndexMap<String, i64>,
+    min: Option<f64>,
+    max: Option<f64>,
+    numeric_sum: f64,
+    numeric_count: i64,
+}
+
+impl ColumnProfile {
+    fn observe(&mut self, value: &Value, config: &nu_protocol::Config) {
+        *self.types.entry(value.get_type().to_string()).or_insert(0) += 1;
+
+        if matches!(value, Value::Nothing { .. }) {
+            self.null_count += 1;
+        }
+
+        if let Some(n) = numeric_value(value) {
+            self.min = Some(self.min.map_or(n, |min: f64| min.min(n)));
+            self.max = Some(self.max.map_or(n, |max: f64| max.max(n)));
+            self.numeric_sum += n;
+            self.numeric_count += 1;
+        }
+
+        *self
+            .value_counts
+            .entry(value.to_abbreviated_string(config))
+            .or_insert(0) += 1;
+    }
+
+    fn into_value(self, column: String, row_count: i64, top: usize, span: Span) -> Value {
+        let types = Value::record(
+            self.types
+                .into_iter()
+                .map(|(ty, count)| (ty, Value::int(count, span)))
+                .collect(),
+            span,
+        );
+
+        let mut top_values: Vec<(String, i64)> = self.value_counts.into_iter().collect();
+        top_values.sort_by(|a, b| b.1.cmp(&a.1));
+        top_values.truncate(top);
+        let top_values = Value::list(
+            top_values
+                .into_iter()
+                .map(|(val, count)| {
+                    Value::record(
+                        record! {
+                            "value" => Value::string(val, span),
+                            "count" => Value::int(count, span),
+                        },
+                        span,
+                    )
+                })
+                .collect(),
+            span,
+        );
+
+        let mean = if self.numeric_count > 0 {
+            Value::float(self.numeric_sum / self.numeric_count as f64, span)
+        } else {
+            Value::nothing(span)
+        };
+
+        Value::record(
+            record! {
+                "column" => Value::string(column, span),
+                "count" => Value::int(row_count, span),
+                "types" => types,
+                "null_count" => Value::int(self.null_count, span),
+                "distinct_count" => Value::int(self.distinct_count(), span),
+                "min" => self.min.map_or_else(|| Value::nothing(span), |v| Value::float(v, span)),
+                "max" => self.max.map_or_else(|| Value::nothing(span), |v| Value::float(v, span)),
+                "mean" => mean,
+                "top_values" => top_values,
+            },
+            span,
+        )
+    }
+
+    fn distinct_count(&self) -> i64 {
+        self.value_counts.len() as i64
+    }
+}
+
+fn numeric_value(value: &Value) -> Option<f64> {
+    match value {
+        Value::Int { val, .. } => Some(*val as f64),
+        Value::Float { val, .. } => Some(*val),
+        Value::Duration { val, .. } => Some(*val as f64),
+        Value::Filesize { val, .. } => Some(val.get() as f64),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Profile {})
+    }
+}
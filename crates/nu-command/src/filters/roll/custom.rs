@@ -0,0 +1,118 @@
+use super::utils::{apply_rolling, min_periods_arg, window_arg};
+use nu_engine::{command_prelude::*, ClosureEval};
+use nu_protocol::engine::Closure;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "roll custom"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("roll custom")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (Type::table(), Type::table()),
+            ])
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::List(Box::new(SyntaxShape::Any))])),
+                "Closure that reduces a window (a list of values) to a single value.",
+            )
+            .required_named(
+                "window",
+                SyntaxShape::Int,
+                "Number of trailing rows in each window.",
+                Some('w'),
+            )
+            .named(
+                "min-periods",
+                SyntaxShape::Int,
+                "Minimum number of values required to produce a result; earlier rows are null. Defaults to --window.",
+                Some('p'),
+            )
+            .rest(
+                "columns",
+                SyntaxShape::String,
+                "Table columns to roll over. Defaults to every numeric column.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Compute a rolling window aggregate with a custom closure."
+    }
+
+    fn extra_description(&self) -> &str {
+        "The closure receives each window as a list of values and must return the aggregated value for that row."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["window", "reduce"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+        let window = window_arg(engine_state, stack, call)?;
+        let min_periods = min_periods_arg(engine_state, stack, call, window)?;
+        let columns: Vec<String> = call
+            .rest::<Spanned<String>>(engine_state, stack, 1)?
+            .into_iter()
+            .map(|s| s.item)
+            .collect();
+
+        let rows: Vec<Value> = input.into_iter().collect();
+
+        let mut closure_eval = ClosureEval::new(engine_state, stack, closure);
+        apply_rolling(
+            rows,
+            columns,
+            window.get(),
+            min_periods,
+            head,
+            |values, _span, head| {
+                closure_eval
+                    .run_with_value(Value::list(values.to_vec(), head))?
+                    .into_value(head)
+            },
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Compute a 3-row rolling range (max minus min)",
+            example: "[1 5 2 8 3] | roll custom {|vals| ($vals | math max) - ($vals | math min)} --window 3",
+            result: Some(Value::test_list(vec![
+                Value::test_nothing(),
+                Value::test_nothing(),
+                Value::test_int(4),
+                Value::test_int(6),
+                Value::test_int(6),
+            ])),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,77 @@
+use super::utils::{apply_rolling, min_periods_arg, window_arg, window_signature};
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "roll mean"
+    }
+
+    fn signature(&self) -> Signature {
+        window_signature("roll mean")
+    }
+
+    fn description(&self) -> &str {
+        "Compute the rolling average over a list or the numeric columns of a table."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["average", "moving average", "window"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let window = window_arg(engine_state, stack, call)?;
+        let min_periods = min_periods_arg(engine_state, stack, call, window)?;
+        let columns: Vec<String> = call
+            .rest::<Spanned<String>>(engine_state, stack, 0)?
+            .into_iter()
+            .map(|s| s.item)
+            .collect();
+
+        let rows: Vec<Value> = input.into_iter().collect();
+        apply_rolling(rows, columns, window.get(), min_periods, head, mean)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Compute a 3-row rolling average",
+            example: "[1 2 3 4 5] | roll mean --window 3",
+            result: Some(Value::test_list(vec![
+                Value::test_nothing(),
+                Value::test_nothing(),
+                Value::test_float(2.0),
+                Value::test_float(3.0),
+                Value::test_float(4.0),
+            ])),
+        }]
+    }
+}
+
+fn mean(values: &[Value], span: Span, head: Span) -> Result<Value, ShellError> {
+    let mut acc = Value::int(0, head);
+    for value in values {
+        acc = acc.add(head, value, head)?;
+    }
+    acc.div(head, &Value::int(values.len() as i64, head), span)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,80 @@
+use super::utils::{apply_rolling, min_periods_arg, window_arg, window_signature};
+use nu_engine::command_prelude::*;
+use std::cmp::Ordering;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "roll min"
+    }
+
+    fn signature(&self) -> Signature {
+        window_signature("roll min")
+    }
+
+    fn description(&self) -> &str {
+        "Compute the rolling minimum over a list or the numeric columns of a table."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["smallest", "window"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let window = window_arg(engine_state, stack, call)?;
+        let min_periods = min_periods_arg(engine_state, stack, call, window)?;
+        let columns: Vec<String> = call
+            .rest::<Spanned<String>>(engine_state, stack, 0)?
+            .into_iter()
+            .map(|s| s.item)
+            .collect();
+
+        let rows: Vec<Value> = input.into_iter().collect();
+        apply_rolling(rows, columns, window.get(), min_periods, head, min)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Compute a 3-row rolling minimum",
+            example: "[4 2 3 1 5] | roll min --window 3",
+            result: Some(Value::test_list(vec![
+                Value::test_nothing(),
+                Value::test_nothing(),
+                Value::test_int(2),
+                Value::test_int(1),
+                Value::test_int(1),
+            ])),
+        }]
+    }
+}
+
+fn min(values: &[Value], _span: Span, _head: Span) -> Result<Value, ShellError> {
+    let mut smallest = values[0].clone();
+    for value in &values[1..] {
+        if value.partial_cmp(&smallest) == Some(Ordering::Less) {
+            smallest = value.clone();
+        }
+    }
+    Ok(smallest)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,14 @@
+mod custom;
+mod max;
+mod mean;
+mod min;
+mod roll_;
+mod sum;
+mod utils;
+
+pub use custom::SubCommand as RollCustom;
+pub use max::SubCommand as RollMax;
+pub use mean::SubCommand as RollMean;
+pub use min::SubCommand as RollMin;
+pub use roll_::Roll;
+pub use sum::SubCommand as RollSum;
@@ -0,0 +1,77 @@
+use super::utils::{apply_rolling, min_periods_arg, window_arg, window_signature};
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "roll sum"
+    }
+
+    fn signature(&self) -> Signature {
+        window_signature("roll sum")
+    }
+
+    fn description(&self) -> &str {
+        "Compute the rolling sum over a list or the numeric columns of a table."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["total", "moving sum", "window"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let window = window_arg(engine_state, stack, call)?;
+        let min_periods = min_periods_arg(engine_state, stack, call, window)?;
+        let columns: Vec<String> = call
+            .rest::<Spanned<String>>(engine_state, stack, 0)?
+            .into_iter()
+            .map(|s| s.item)
+            .collect();
+
+        let rows: Vec<Value> = input.into_iter().collect();
+        apply_rolling(rows, columns, window.get(), min_periods, head, sum)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Compute a 3-row rolling sum",
+            example: "[1 2 3 4 5] | roll sum --window 3",
+            result: Some(Value::test_list(vec![
+                Value::test_nothing(),
+                Value::test_nothing(),
+                Value::test_int(6),
+                Value::test_int(9),
+                Value::test_int(12),
+            ])),
+        }]
+    }
+}
+
+fn sum(values: &[Value], _span: Span, head: Span) -> Result<Value, ShellError> {
+    let mut acc = Value::int(0, head);
+    for value in values {
+        acc = acc.add(head, value, head)?;
+    }
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
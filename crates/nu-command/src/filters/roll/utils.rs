@@ -0,0 +1,184 @@
+use indexmap::IndexMap;
+use nu_engine::command_prelude::*;
+use std::num::NonZeroUsize;
+
+/// Signature shared by the `mean`/`sum`/`min`/`max` subcommands, which all take
+/// a `--window`, an optional `--min-periods`, and a rest list of columns.
+pub(super) fn window_signature(name: &str) -> Signature {
+    Signature::build(name)
+        .input_output_types(vec![
+            (
+                Type::List(Box::new(Type::Number)),
+                Type::List(Box::new(Type::Number)),
+            ),
+            (Type::table(), Type::table()),
+        ])
+        .required_named(
+            "window",
+            SyntaxShape::Int,
+            "Number of trailing rows in each window.",
+            Some('w'),
+        )
+        .named(
+            "min-periods",
+            SyntaxShape::Int,
+            "Minimum number of values required to produce a result; earlier rows are null. Defaults to --window.",
+            Some('p'),
+        )
+        .rest(
+            "columns",
+            SyntaxShape::String,
+            "Table columns to roll over. Defaults to every numeric column.",
+        )
+        .category(Category::Filters)
+}
+
+pub(super) fn window_arg(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<NonZeroUsize, ShellError> {
+    let window: Option<Value> = call.get_flag(engine_state, stack, "window")?;
+    let window = window.ok_or(ShellError::MissingParameter {
+        param_name: "window".into(),
+        span: call.head,
+    })?;
+
+    let size = usize::try_from(window.as_int()?).map_err(|_| ShellError::NeedsPositiveValue {
+        span: window.span(),
+    })?;
+
+    NonZeroUsize::new(size).ok_or_else(|| ShellError::IncorrectValue {
+        msg: "`--window` cannot be zero".into(),
+        val_span: window.span(),
+        call_span: call.head,
+    })
+}
+
+pub(super) fn min_periods_arg(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    window: NonZeroUsize,
+) -> Result<usize, ShellError> {
+    let min_periods: Option<Value> = call.get_flag(engine_state, stack, "min-periods")?;
+    match min_periods {
+        Some(val) => {
+            let periods = usize::try_from(val.as_int()?)
+                .map_err(|_| ShellError::NeedsPositiveValue { span: val.span() })?;
+            if periods == 0 || periods > window.get() {
+                return Err(ShellError::IncorrectValue {
+                    msg: "`--min-periods` must be between 1 and `--window`".into(),
+                    val_span: val.span(),
+                    call_span: call.head,
+                });
+            }
+            Ok(periods)
+        }
+        None => Ok(window.get()),
+    }
+}
+
+/// Column names taken from the first record found in `rows`, used as a stand-in
+/// schema when no explicit columns are given.
+pub(super) fn column_names(rows: &[Value]) -> Vec<String> {
+    rows.iter()
+        .find_map(|val| match val {
+            Value::Record { val, .. } => Some(val.columns().cloned().collect()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+fn is_numeric(value: &Value) -> bool {
+    matches!(value, Value::Int { .. } | Value::Float { .. })
+}
+
+/// Slide a `window`-sized (or smaller, for the first rows) trailing window over
+/// `values` and reduce each window to a single value, producing one output per
+/// input row. Rows whose window has fewer than `min_periods` values become null.
+fn roll_series(
+    values: &[Value],
+    window: usize,
+    min_periods: usize,
+    head: Span,
+    reduce: &mut impl FnMut(&[Value], Span, Span) -> Result<Value, ShellError>,
+) -> Result<Vec<Value>, ShellError> {
+    let mut out = Vec::with_capacity(values.len());
+    for i in 0..values.len() {
+        let start = i + 1 - window.min(i + 1);
+        let slice = &values[start..=i];
+        if slice.len() < min_periods {
+            out.push(Value::nothing(head));
+            continue;
+        }
+        let span = Span::merge_many(slice.iter().map(|v| v.span()));
+        out.push(reduce(slice, span, head)?);
+    }
+    Ok(out)
+}
+
+/// Apply a rolling `reduce` over either a plain list of values or every numeric
+/// column of a table, replacing each value with its rolling aggregate.
+pub(super) fn apply_rolling(
+    rows: Vec<Value>,
+    columns: Vec<String>,
+    window: usize,
+    min_periods: usize,
+    head: Span,
+    mut reduce: impl FnMut(&[Value], Span, Span) -> Result<Value, ShellError>,
+) -> Result<PipelineData, ShellError> {
+    if !rows.iter().any(|row| matches!(row, Value::Record { .. })) {
+        let rolled = roll_series(&rows, window, min_periods, head, &mut reduce)?;
+        return Ok(Value::list(rolled, head).into_pipeline_data());
+    }
+
+    let columns = if columns.is_empty() {
+        column_names(&rows)
+            .into_iter()
+            .filter(|c| {
+                rows.iter().any(|row| match row {
+                    Value::Record { val, .. } => val.get(c).is_some_and(is_numeric),
+                    _ => false,
+                })
+            })
+            .collect::<Vec<_>>()
+    } else {
+        columns
+    };
+
+    let mut series: IndexMap<String, Vec<Value>> = IndexMap::new();
+    for col in &columns {
+        let col_values: Vec<Value> = rows
+            .iter()
+            .map(|row| match row {
+                Value::Record { val, .. } => val
+                    .get(col)
+                    .cloned()
+                    .unwrap_or_else(|| Value::nothing(head)),
+                _ => Value::nothing(head),
+            })
+            .collect();
+        series.insert(
+            col.clone(),
+            roll_series(&col_values, window, min_periods, head, &mut reduce)?,
+        );
+    }
+
+    let mut result = Vec::with_capacity(rows.len());
+    for (i, row) in rows.into_iter().enumerate() {
+        let Value::Record { val, .. } = row else {
+            result.push(row);
+            continue;
+        };
+        let mut record = val.into_owned();
+        for col in &columns {
+            if let Some(rolled) = series.get(col).and_then(|v| v.get(i)) {
+                record.insert(col.clone(), rolled.clone());
+            }
+        }
+        result.push(Value::record(record, head));
+    }
+
+    Ok(Value::list(result, head).into_pipeline_data())
+}
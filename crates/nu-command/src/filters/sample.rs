@@ -0,0 +1,212 @@
+use indexmap::IndexMap;
+use nu_engine::command_prelude::*;
+use nu_protocol::Config;
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+
+#[derive(Clone)]
+pub struct Sample;
+
+impl Command for Sample {
+    fn name(&self) -> &str {
+        "sample"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sample")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::List(Box::new(Type::Any)),
+            )])
+            .named(
+                "n",
+                SyntaxShape::Int,
+                "Number of rows to draw with reservoir sampling. Mutually exclusive with --fraction.",
+                Some('n'),
+            )
+            .named(
+                "fraction",
+                SyntaxShape::Number,
+                "Fraction (0.0 to 1.0) of rows to keep, decided independently per row. Mutually exclusive with --n.",
+                Some('f'),
+            )
+            .named(
+                "by",
+                SyntaxShape::String,
+                "Sample independently within each distinct value of this column (stratified sampling).",
+                Some('b'),
+            )
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "Seed the random number generator for reproducible sampling.",
+                None,
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Randomly sample rows from a list or table."
+    }
+
+    fn extra_description(&self) -> &str {
+        "With --n, draws a fixed-size sample using reservoir sampling, so the full input never \
+         needs to be held in memory at once. With --fraction, each row is kept independently \
+         with that probability. With --by, sampling is done separately within each distinct \
+         value of the given column (stratified sampling)."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["random", "reservoir", "stratified", "subset"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let n: Option<i64> = call.get_flag(engine_state, stack, "n")?;
+        let fraction: Option<f64> = call.get_flag(engine_state, stack, "fraction")?;
+        let by: Option<String> = call.get_flag(engine_state, stack, "by")?;
+        let seed: Option<i64> = call.get_flag(engine_state, stack, "seed")?;
+
+        let strategy = match (n, fraction) {
+            (Some(_), Some(_)) => {
+                return Err(ShellError::IncompatibleParametersSingle {
+                    msg: "`--n` and `--fraction` cannot be used together".into(),
+                    span: head,
+                })
+            }
+            (Some(n), None) => {
+                let n = usize::try_from(n)
+                    .map_err(|_| ShellError::NeedsPositiveValue { span: head })?;
+                Strategy::Count(n)
+            }
+            (None, Some(fraction)) => {
+                if !(0.0..=1.0).contains(&fraction) {
+                    return Err(ShellError::IncorrectValue {
+                        msg: "`--fraction` must be between 0.0 and 1.0".into(),
+                        val_span: head,
+                        call_span: head,
+                    });
+                }
+                Strategy::Fraction(fraction)
+            }
+            (None, None) => {
+                return Err(ShellError::MissingParameter {
+                    param_name: "n".into(),
+                    span: head,
+                })
+            }
+        };
+
+        let mut rng: Box<dyn RngCore> = match seed {
+            Some(seed) => Box::new(StdRng::seed_from_u64(seed as u64)),
+            None => Box::new(rand::thread_rng()),
+        };
+
+        let rows: Vec<Value> = input.into_iter().collect();
+
+        let sampled = match by {
+            Some(by) => {
+                let config = engine_state.get_config().clone();
+                sample_stratified(rows, &by, strategy, &mut *rng, &config, head)?
+            }
+            None => sample_rows(rows.into_iter(), strategy, &mut *rng),
+        };
+
+        Ok(Value::list(sampled, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Draw a reproducible sample of 2 rows",
+                example: "[1 2 3 4 5] | sample --n 2 --seed 1",
+                result: None,
+            },
+            Example {
+                description: "Keep roughly 10% of rows",
+                example: "[1 2 3 4 5] | sample --fraction 0.1",
+                result: None,
+            },
+            Example {
+                description: "Sample 1 row from each group",
+                example:
+                    "[[team, player]; [a, sam], [a, sarah], [b, joe]] | sample --n 1 --by team",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Strategy {
+    Count(usize),
+    Fraction(f64),
+}
+
+/// Reservoir-sample (Algorithm R) `n` items from `iter`, or independently keep
+/// each item with probability `fraction`. Both run in a single pass and never
+/// buffer more than the sample itself, so they work on streams of unknown length.
+fn sample_rows(
+    iter: impl Iterator<Item = Value>,
+    strategy: Strategy,
+    rng: &mut dyn RngCore,
+) -> Vec<Value> {
+    match strategy {
+        Strategy::Count(n) => {
+            let mut reservoir = Vec::with_capacity(n);
+            for (i, item) in iter.enumerate() {
+                if reservoir.len() < n {
+                    reservoir.push(item);
+                } else {
+                    let j = rng.gen_range(0..=i);
+                    if j < n {
+                        reservoir[j] = item;
+                    }
+                }
+            }
+            reservoir
+        }
+        Strategy::Fraction(fraction) => iter.filter(|_| rng.gen::<f64>() < fraction).collect(),
+    }
+}
+
+fn sample_stratified(
+    rows: Vec<Value>,
+    by: &str,
+    strategy: Strategy,
+    rng: &mut dyn RngCore,
+    config: &Config,
+    head: Span,
+) -> Result<Vec<Value>, ShellError> {
+    let mut groups: IndexMap<String, Vec<Value>> = IndexMap::new();
+    for row in rows {
+        let Value::Record { val, .. } = &row else {
+            return Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "record".into(),
+                wrong_type: row.get_type().to_string(),
+                dst_span: head,
+                src_span: row.span(),
+            });
+        };
+        let Some(key_value) = val.get(by) else {
+            return Err(ShellError::CantFindColumn {
+                col_name: by.to_string(),
+                span: Some(head),
+                src_span: row.span(),
+            });
+        };
+        let key = key_value.to_abbreviated_string(config);
+        groups.entry(key).or_default().push(row);
+    }
+
+    let mut sampled = Vec::new();
+    for (_, group) in groups {
+        sampled.extend(sample_rows(group.into_iter(), strategy, rng));
+    }
+    Ok(sampled)
+}
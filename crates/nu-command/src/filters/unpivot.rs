@@ -0,0 +1,163 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct Unpivot;
+
+impl Command for Unpivot {
+    fn name(&self) -> &str {
+        "unpivot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("unpivot")
+            .input_output_types(vec![(Type::table(), Type::table())])
+            .rest(
+                "group-by",
+                SyntaxShape::String,
+                "Columns to keep as row identifiers; every other column becomes name/value rows unless --columns is given.",
+            )
+            .named(
+                "columns",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "Explicit list of columns to unpivot, instead of every column not in --group-by.",
+                Some('c'),
+            )
+            .named(
+                "names-to",
+                SyntaxShape::String,
+                "Name of the new column holding the original column names. Default: 'column'",
+                None,
+            )
+            .named(
+                "values-to",
+                SyntaxShape::String,
+                "Name of the new column holding the original values. Default: 'value'",
+                None,
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Unpivot columns into name/value row pairs, the inverse of `pivot`."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Keeps the --group-by columns as row identifiers and turns every other column (or, if \
+         given, every column in --columns) into a pair of rows: one holding the original column \
+         name (in --names-to) and one holding its value (in --values-to)."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["melt", "gather", "reshape", "pivot"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        unpivot(engine_state, stack, call, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Turn quarterly columns back into name/value rows",
+            example: "[[year, Q1, Q2]; [2023, 100, 150]] | unpivot year --names-to quarter --values-to sales",
+            result: Some(Value::test_list(vec![
+                Value::test_record(record! {
+                    "year" => Value::test_int(2023),
+                    "quarter" => Value::test_string("Q1"),
+                    "sales" => Value::test_int(100),
+                }),
+                Value::test_record(record! {
+                    "year" => Value::test_int(2023),
+                    "quarter" => Value::test_string("Q2"),
+                    "sales" => Value::test_int(150),
+                }),
+            ])),
+        }]
+    }
+}
+
+fn column_names(rows: &[Value]) -> Vec<String> {
+    rows.iter()
+        .find_map(|val| match val {
+            Value::Record { val, .. } => Some(val.columns().cloned().collect()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+fn unpivot(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let group_by: Vec<String> = call
+        .rest::<Spanned<String>>(engine_state, stack, 0)?
+        .into_iter()
+        .map(|s| s.item)
+        .collect();
+    let explicit_columns: Option<Vec<String>> = call.get_flag(engine_state, stack, "columns")?;
+    let names_to: String = call
+        .get_flag(engine_state, stack, "names-to")?
+        .unwrap_or_else(|| "column".to_string());
+    let values_to: String = call
+        .get_flag(engine_state, stack, "values-to")?
+        .unwrap_or_else(|| "value".to_string());
+
+    let rows: Vec<Value> = input.into_iter().collect();
+
+    let value_columns = match explicit_columns {
+        Some(cols) => cols,
+        None => column_names(&rows)
+            .into_iter()
+            .filter(|c| !group_by.contains(c))
+            .collect(),
+    };
+
+    let mut result = Vec::with_capacity(rows.len() * value_columns.len());
+    for row in &rows {
+        let Value::Record { val: record, .. } = row else {
+            continue;
+        };
+
+        for col in &value_columns {
+            let Some(value) = record.get(col) else {
+                continue;
+            };
+
+            let mut new_record = Record::with_capacity(group_by.len() + 2);
+            for id_col in &group_by {
+                new_record.push(
+                    id_col.clone(),
+                    record
+                        .get(id_col)
+                        .cloned()
+                        .unwrap_or_else(|| Value::nothing(head)),
+                );
+            }
+            new_record.push(names_to.clone(), Value::string(col.clone(), head));
+            new_record.push(values_to.clone(), value.clone());
+            result.push(Value::record(new_record, head));
+        }
+    }
+
+    Ok(Value::list(result, head).into_pipeline_data())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Unpivot {})
+    }
+}
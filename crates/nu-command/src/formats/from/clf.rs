@@ -0,0 +1,140 @@
+use fancy_regex::Regex;
+use nu_engine::command_prelude::*;
+use std::sync::LazyLock;
+
+// host ident authuser [date] "request" status bytes ["referer" "user-agent"]
+static CLF_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?x)
+        ^(?P<host>\S+)\s
+        (?P<ident>\S+)\s
+        (?P<authuser>\S+)\s
+        \[(?P<date>[^\]]+)\]\s
+        "(?P<request>[^"]*)"\s
+        (?P<status>\d{3})\s
+        (?P<bytes>\S+)
+        (?:\s"(?P<referer>[^"]*)"\s"(?P<user_agent>[^"]*)")?
+        \s*$
+        "#,
+    )
+    .expect("valid regex")
+});
+
+#[derive(Clone)]
+pub struct FromClf;
+
+impl Command for FromClf {
+    fn name(&self) -> &str {
+        "from clf"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::String, Type::table())])
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Parse Apache/Nginx access log lines (common or combined log format) and create a table."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"
+Each line is parsed independently, so this streams naturally over a byte
+stream. Lines that don't match the format become an error value in their
+row rather than aborting the whole parse.
+"#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Parse a combined log format line",
+            example: r#"'127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache.gif HTTP/1.0" 200 2326 "http://example.com/" "curl/8.0"' | from clf"#,
+            result: Some(Value::test_list(vec![Value::test_record(record! {
+                "host" => Value::test_string("127.0.0.1"),
+                "ident" => Value::test_string("-"),
+                "authuser" => Value::test_string("frank"),
+                "date" => Value::test_string("10/Oct/2000:13:55:36 -0700"),
+                "request" => Value::test_string("GET /apache.gif HTTP/1.0"),
+                "status" => Value::test_int(200),
+                "bytes" => Value::test_int(2326),
+                "referer" => Value::test_string("http://example.com/"),
+                "user_agent" => Value::test_string("curl/8.0"),
+            })])),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let span = input.span().unwrap_or(head);
+
+        match input {
+            PipelineData::ByteStream(stream, ..) => {
+                if let Some(lines) = stream.lines() {
+                    Ok(lines
+                        .map(move |line| match line {
+                            Ok(line) => parse_line(&line, head),
+                            Err(err) => Value::error(err, head),
+                        })
+                        .into_pipeline_data(head, engine_state.signals().clone()))
+                } else {
+                    Ok(PipelineData::empty())
+                }
+            }
+            input => {
+                let text = input.into_value(head)?.coerce_into_string()?;
+                let records = text
+                    .lines()
+                    .map(|line| parse_line(line, head))
+                    .collect::<Vec<_>>();
+                Ok(Value::list(records, span).into_pipeline_data())
+            }
+        }
+    }
+}
+
+fn parse_line(line: &str, span: Span) -> Value {
+    let Ok(Some(caps)) = CLF_RE.captures(line) else {
+        return Value::error(
+            ShellError::GenericError {
+                error: "Could not parse log line".into(),
+                msg: "line does not match the common/combined log format".into(),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            },
+            span,
+        );
+    };
+
+    let field = |name: &str| caps.name(name).map(|m| m.as_str()).unwrap_or("");
+    let mut record = record! {
+        "host" => Value::string(field("host"), span),
+        "ident" => Value::string(field("ident"), span),
+        "authuser" => Value::string(field("authuser"), span),
+        "date" => Value::string(field("date"), span),
+        "request" => Value::string(field("request"), span),
+        "status" => int_or_string(field("status"), span),
+        "bytes" => int_or_string(field("bytes"), span),
+    };
+
+    if let Some(referer) = caps.name("referer") {
+        record.push("referer", Value::string(referer.as_str(), span));
+        record.push("user_agent", Value::string(field("user_agent"), span));
+    }
+
+    Value::record(record, span)
+}
+
+fn int_or_string(value: &str, span: Span) -> Value {
+    value
+        .parse::<i64>()
+        .map(|i| Value::int(i, span))
+        .unwrap_or_else(|_| Value::string(value, span))
+}
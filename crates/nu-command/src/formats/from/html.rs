@@ -0,0 +1,431 @@
+use indexmap::IndexMap;
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct FromHtml;
+
+impl Command for FromHtml {
+    fn name(&self) -> &str {
+        "from html"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from html")
+            .input_output_types(vec![(Type::String, Type::Any)])
+            .switch(
+                "tables",
+                "return only the list of tables found in the document",
+                Some('t'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Parse text as .html and extract its tables, links, and headings."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"This is a small, dependency-free HTML scanner meant for quick scraping, not a full
+HTML parser: it does not build a DOM and assumes elements of the same kind are not nested in one
+another (e.g. a <table> inside a <table>). `<table>` elements become tables, using a leading row
+of `<th>` cells as the header if present, otherwise naming columns column0, column1, and so on.
+Links and headings are reported as a record of `tables`, `links`, and `headings`; pass --tables
+to get just the list of tables."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let tables_only = call.has_flag(engine_state, stack, "tables")?;
+        let (html, span, metadata) = input.collect_string_strict(head)?;
+
+        let tables = extract_tables(&html, span);
+
+        if tables_only {
+            return Ok(Value::list(tables, span).into_pipeline_data_with_metadata(metadata));
+        }
+
+        let links = extract_links(&html, span);
+        let headings = extract_headings(&html, span);
+
+        Ok(Value::record(
+            record! {
+                "tables" => Value::list(tables, span),
+                "links" => Value::list(links, span),
+                "headings" => Value::list(headings, span),
+            },
+            span,
+        )
+        .into_pipeline_data_with_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Extract a table with a header row",
+                example: r#"'<table><tr><th>name</th><th>age</th></tr><tr><td>Nu</td><td>5</td></tr></table>' | from html --tables"#,
+                result: Some(Value::test_list(vec![Value::test_list(vec![
+                    Value::test_record(record! {
+                        "name" => Value::test_string("Nu"),
+                        "age" => Value::test_string("5"),
+                    }),
+                ])])),
+            },
+            Example {
+                description: "Extract tables, links, and headings from a page",
+                example: r#"'<h1>Title</h1><a href="https://nushell.sh">Nu</a>' | from html"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn extract_tables(html: &str, span: Span) -> Vec<Value> {
+    extract_elements(html, "table")
+        .into_iter()
+        .map(|(_, content)| table_to_value(&content, span))
+        .collect()
+}
+
+fn table_to_value(table_html: &str, span: Span) -> Value {
+    let rows: Vec<Vec<(bool, String)>> = extract_rows(table_html)
+        .into_iter()
+        .map(|(_, row_html)| extract_cells(&row_html))
+        .filter(|cells| !cells.is_empty())
+        .collect();
+
+    let Some(first) = rows.first() else {
+        return Value::list(vec![], span);
+    };
+
+    let (headers, body): (Vec<String>, &[Vec<(bool, String)>]) =
+        if first.iter().all(|(is_header, _)| *is_header) {
+            (
+                first.iter().map(|(_, text)| text.clone()).collect(),
+                &rows[1..],
+            )
+        } else {
+            (
+                (0..first.len()).map(|i| format!("column{i}")).collect(),
+                &rows[..],
+            )
+        };
+
+    let table = body
+        .iter()
+        .map(|cells| {
+            let mut record = Record::new();
+            for (i, (_, text)) in cells.iter().enumerate() {
+                let column = headers
+                    .get(i)
+                    .cloned()
+                    .unwrap_or_else(|| format!("column{i}"));
+                record.push(column, Value::string(text.clone(), span));
+            }
+            Value::record(record, span)
+        })
+        .collect();
+
+    Value::list(table, span)
+}
+
+fn extract_links(html: &str, span: Span) -> Vec<Value> {
+    extract_elements(html, "a")
+        .into_iter()
+        .map(|(attrs, content)| {
+            Value::record(
+                record! {
+                    "text" => Value::string(strip_tags(&content), span),
+                    "href" => Value::string(attrs.get("href").cloned().unwrap_or_default(), span),
+                },
+                span,
+            )
+        })
+        .collect()
+}
+
+fn extract_headings(html: &str, span: Span) -> Vec<Value> {
+    const HEADING_TAGS: &[&str] = &["h1", "h2", "h3", "h4", "h5", "h6"];
+
+    scan_tags(html, HEADING_TAGS)
+        .into_iter()
+        .map(|tag| {
+            let level = tag.name[1..].parse::<i64>().unwrap_or_default();
+            Value::record(
+                record! {
+                    "level" => Value::int(level, span),
+                    "text" => Value::string(strip_tags(&tag.content), span),
+                },
+                span,
+            )
+        })
+        .collect()
+}
+
+fn extract_rows(table_html: &str) -> Vec<(IndexMap<String, String>, String)> {
+    extract_elements(table_html, "tr")
+}
+
+fn extract_cells(row_html: &str) -> Vec<(bool, String)> {
+    scan_tags(row_html, &["td", "th"])
+        .into_iter()
+        .map(|tag| (tag.name == "th", strip_tags(&tag.content).trim().into()))
+        .collect()
+}
+
+fn extract_elements(html: &str, tag: &str) -> Vec<(IndexMap<String, String>, String)> {
+    scan_tags(html, &[tag])
+        .into_iter()
+        .map(|tag| (tag.attrs, tag.content))
+        .collect()
+}
+
+struct ScannedTag {
+    name: String,
+    attrs: IndexMap<String, String>,
+    content: String,
+}
+
+/// Sequentially scans `html` for the earliest opening tag matching any name in `tags`, then
+/// collects everything up to that same tag's closing tag as its content, and continues scanning
+/// after it. Does not handle a tag nested inside another of the same name.
+fn scan_tags(html: &str, tags: &[&str]) -> Vec<ScannedTag> {
+    let mut found = Vec::new();
+    let mut pos = 0;
+
+    while pos < html.len() {
+        let Some((tag_name, open_end, attrs)) = tags
+            .iter()
+            .filter_map(|tag| {
+                find_opening_tag(html, tag, pos).map(|(start, end, attrs)| (tag, start, end, attrs))
+            })
+            .min_by_key(|(_, start, ..)| *start)
+            .map(|(tag, _, end, attrs)| (tag.to_string(), end, attrs))
+        else {
+            break;
+        };
+
+        let Some(close_end) = find_closing_tag(html, &tag_name, open_end) else {
+            pos = open_end;
+            continue;
+        };
+        let close_start = html[open_end..close_end]
+            .rfind('<')
+            .map(|i| open_end + i)
+            .unwrap_or(close_end);
+
+        found.push(ScannedTag {
+            name: tag_name,
+            attrs,
+            content: html[open_end..close_start].to_string(),
+        });
+        pos = close_end;
+    }
+
+    found
+}
+
+fn find_opening_tag(
+    html: &str,
+    tag: &str,
+    from: usize,
+) -> Option<(usize, usize, IndexMap<String, String>)> {
+    let bytes = html.as_bytes();
+    let mut i = from;
+    while i < bytes.len() {
+        if bytes[i] == b'<' && bytes.get(i + 1) != Some(&b'/') {
+            let after = i + 1;
+            let name_end = html[after..]
+                .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                .map(|rel| after + rel)?;
+            let name = &html[after..name_end];
+            if name.eq_ignore_ascii_case(tag) {
+                if let Some(close_rel) = html[name_end..].find('>') {
+                    let tag_end = name_end + close_rel + 1;
+                    let attrs = parse_attrs(&html[name_end..name_end + close_rel]);
+                    return Some((i, tag_end, attrs));
+                }
+                return None;
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+fn find_closing_tag(html: &str, tag: &str, from: usize) -> Option<usize> {
+    let needle_start = format!("</{tag}");
+    let bytes = html.as_bytes();
+    let mut i = from;
+    while i < bytes.len() {
+        if bytes[i] == b'<'
+            && html[i..].len() >= needle_start.len()
+            && html[i..i + needle_start.len()].eq_ignore_ascii_case(&needle_start)
+        {
+            let close_rel = html[i..].find('>')?;
+            return Some(i + close_rel + 1);
+        }
+        i += 1;
+    }
+    None
+}
+
+fn parse_attrs(attrs: &str) -> IndexMap<String, String> {
+    let mut result = IndexMap::new();
+    let mut chars = attrs.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() || c == '/' {
+            chars.next();
+            continue;
+        }
+
+        let mut name = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() || c == '=' {
+                break;
+            }
+            name.push(c);
+            chars.next();
+        }
+        if name.is_empty() {
+            break;
+        }
+
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+
+        let mut value = String::new();
+        if chars.peek() == Some(&'=') {
+            chars.next();
+            while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                chars.next();
+            }
+            match chars.peek() {
+                Some(&quote) if quote == '"' || quote == '\'' => {
+                    chars.next();
+                    for c in chars.by_ref() {
+                        if c == quote {
+                            break;
+                        }
+                        value.push(c);
+                    }
+                }
+                _ => {
+                    while let Some(&c) = chars.peek() {
+                        if c.is_whitespace() {
+                            break;
+                        }
+                        value.push(c);
+                        chars.next();
+                    }
+                }
+            }
+        }
+
+        result.insert(name, decode_entities(&value));
+    }
+
+    result
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(out.trim())
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromHtml {})
+    }
+
+    #[test]
+    fn extracts_table_with_header() {
+        let html =
+            "<table><tr><th>name</th><th>age</th></tr><tr><td>Nu</td><td>5</td></tr></table>";
+        let tables = extract_tables(html, Span::test_data());
+        assert_eq!(
+            tables,
+            vec![Value::test_list(vec![Value::test_record(record! {
+                "name" => Value::test_string("Nu"),
+                "age" => Value::test_string("5"),
+            })])]
+        );
+    }
+
+    #[test]
+    fn extracts_table_without_header() {
+        let html = "<table><tr><td>a</td><td>b</td></tr></table>";
+        let tables = extract_tables(html, Span::test_data());
+        assert_eq!(
+            tables,
+            vec![Value::test_list(vec![Value::test_record(record! {
+                "column0" => Value::test_string("a"),
+                "column1" => Value::test_string("b"),
+            })])]
+        );
+    }
+
+    #[test]
+    fn extracts_links() {
+        let html = r#"<a href="https://nushell.sh">Nu</a>"#;
+        let links = extract_links(html, Span::test_data());
+        assert_eq!(
+            links,
+            vec![Value::test_record(record! {
+                "text" => Value::test_string("Nu"),
+                "href" => Value::test_string("https://nushell.sh"),
+            })]
+        );
+    }
+
+    #[test]
+    fn extracts_headings_in_order() {
+        let html = "<h2>Section</h2><h1>Title</h1>";
+        let headings = extract_headings(html, Span::test_data());
+        assert_eq!(
+            headings,
+            vec![
+                Value::test_record(record! {
+                    "level" => Value::test_int(2),
+                    "text" => Value::test_string("Section"),
+                }),
+                Value::test_record(record! {
+                    "level" => Value::test_int(1),
+                    "text" => Value::test_string("Title"),
+                }),
+            ]
+        );
+    }
+}
@@ -0,0 +1,456 @@
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct FromImage;
+
+impl Command for FromImage {
+    fn name(&self) -> &str {
+        "from image"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Binary, Type::record())])
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Read image dimensions and EXIF metadata from image bytes."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Recognizes PNG, GIF, BMP and JPEG by their file signature and reports `format`, \
+         `width`, and `height`. EXIF metadata (camera make/model, timestamps, exposure \
+         settings, and GPS coordinates as signed decimal degrees) is only read from the APP1 \
+         segment of JPEG files, since that's the only container this command understands; other \
+         formats are returned with an empty `exif` record. Only the common baseline EXIF tags \
+         are decoded, not manufacturer-specific MakerNote data."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["exif", "jpeg", "png", "photo", "dimensions"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Read a photo's dimensions and EXIF metadata",
+            example: "open --raw photo.jpg | from image",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let bytes = input.into_value(head)?.into_binary()?;
+
+        parse_image(&bytes, head).map(|record| Value::record(record, head).into_pipeline_data())
+    }
+}
+
+fn unrecognized_format(head: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Not a recognized image file".into(),
+        msg: "expected a PNG, GIF, BMP, or JPEG signature".into(),
+        span: Some(head),
+        help: None,
+        inner: vec![],
+    }
+}
+
+fn parse_image(bytes: &[u8], head: Span) -> Result<Record, ShellError> {
+    let (format, width, height, exif) = if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        let (width, height) = parse_png_dimensions(bytes, head)?;
+        ("png", width, height, Record::new())
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        let (width, height) = parse_gif_dimensions(bytes, head)?;
+        ("gif", width, height, Record::new())
+    } else if bytes.starts_with(b"BM") {
+        let (width, height) = parse_bmp_dimensions(bytes, head)?;
+        ("bmp", width, height, Record::new())
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        let (width, height) = parse_jpeg_dimensions(bytes, head)?;
+        let exif = parse_jpeg_exif(bytes).unwrap_or_default();
+        ("jpeg", width, height, exif)
+    } else {
+        return Err(unrecognized_format(head));
+    };
+
+    Ok(record! {
+        "format" => Value::string(format, head),
+        "width" => Value::int(width as i64, head),
+        "height" => Value::int(height as i64, head),
+        "exif" => Value::record(exif, head),
+    })
+}
+
+fn parse_png_dimensions(bytes: &[u8], head: Span) -> Result<(u32, u32), ShellError> {
+    // The IHDR chunk is always the first chunk: 8-byte signature, then a
+    // 4-byte length, 4-byte "IHDR" tag, then width and height as big-endian u32s.
+    bytes
+        .get(16..24)
+        .map(|ihdr| {
+            (
+                BigEndian::read_u32(&ihdr[0..4]),
+                BigEndian::read_u32(&ihdr[4..8]),
+            )
+        })
+        .ok_or_else(|| truncated_image(head))
+}
+
+fn parse_gif_dimensions(bytes: &[u8], head: Span) -> Result<(u32, u32), ShellError> {
+    // 6-byte signature, then width and height as little-endian u16s.
+    bytes
+        .get(6..10)
+        .map(|dims| {
+            (
+                LittleEndian::read_u16(&dims[0..2]) as u32,
+                LittleEndian::read_u16(&dims[2..4]) as u32,
+            )
+        })
+        .ok_or_else(|| truncated_image(head))
+}
+
+fn parse_bmp_dimensions(bytes: &[u8], head: Span) -> Result<(u32, u32), ShellError> {
+    // 14-byte file header, then a DIB header starting with width/height as
+    // little-endian i32s (BMP height is negative for top-down images).
+    bytes
+        .get(18..26)
+        .map(|dims| {
+            (
+                LittleEndian::read_i32(&dims[0..4]).unsigned_abs(),
+                LittleEndian::read_i32(&dims[4..8]).unsigned_abs(),
+            )
+        })
+        .ok_or_else(|| truncated_image(head))
+}
+
+fn truncated_image(head: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Truncated image file".into(),
+        msg: "the header was shorter than expected for this format".into(),
+        span: Some(head),
+        help: None,
+        inner: vec![],
+    }
+}
+
+/// Scans JPEG markers for the SOF0-SOF3/SOF5-SOF7/SOF9-SOF11/SOF13-SOF15 frame
+/// headers, which all share the same layout for the fields we care about.
+fn parse_jpeg_dimensions(bytes: &[u8], head: Span) -> Result<(u32, u32), ShellError> {
+    let mut pos = 2;
+    while pos + 4 <= bytes.len() {
+        if bytes[pos] != 0xFF {
+            pos += 1;
+            continue;
+        }
+        let marker = bytes[pos + 1];
+        // Markers with no payload.
+        if marker == 0x01 || (0xD0..=0xD9).contains(&marker) {
+            pos += 2;
+            continue;
+        }
+        let segment_len = BigEndian::read_u16(&bytes[pos + 2..pos + 4]) as usize;
+        let is_sof = matches!(marker, 0xC0..=0xC3 | 0xC5..=0xC7 | 0xC9..=0xCB | 0xCD..=0xCF);
+        if is_sof {
+            let body = bytes
+                .get(pos + 4..pos + 4 + 5)
+                .ok_or_else(|| truncated_image(head))?;
+            let height = BigEndian::read_u16(&body[1..3]) as u32;
+            let width = BigEndian::read_u16(&body[3..5]) as u32;
+            return Ok((width, height));
+        }
+        pos += 2 + segment_len;
+    }
+    Err(unrecognized_format(head))
+}
+
+/// Finds the APP1 "Exif" segment and decodes its TIFF-format IFD0, following
+/// the EXIF and GPS sub-IFD pointers if present.
+fn parse_jpeg_exif(bytes: &[u8]) -> Option<Record> {
+    let mut pos = 2;
+    while pos + 4 <= bytes.len() {
+        if bytes[pos] != 0xFF {
+            pos += 1;
+            continue;
+        }
+        let marker = bytes[pos + 1];
+        if marker == 0x01 || (0xD0..=0xD9).contains(&marker) {
+            pos += 2;
+            continue;
+        }
+        let segment_len = BigEndian::read_u16(&bytes[pos + 2..pos + 4]) as usize;
+        if marker == 0xE1 {
+            let segment = bytes.get(pos + 4..pos + 2 + segment_len)?;
+            if segment.starts_with(b"Exif\0\0") {
+                return decode_tiff(&segment[6..]);
+            }
+        }
+        if marker == 0xDA {
+            // Start of scan: no more metadata segments follow.
+            break;
+        }
+        pos += 2 + segment_len;
+    }
+    None
+}
+
+fn decode_tiff(tiff: &[u8]) -> Option<Record> {
+    let little_endian = match tiff.get(0..2)? {
+        b"II" => true,
+        b"MM" => false,
+        _ => return None,
+    };
+    let read_u16 = |b: &[u8]| -> u16 {
+        if little_endian {
+            LittleEndian::read_u16(b)
+        } else {
+            BigEndian::read_u16(b)
+        }
+    };
+    let read_u32 = |b: &[u8]| -> u32 {
+        if little_endian {
+            LittleEndian::read_u32(b)
+        } else {
+            BigEndian::read_u32(b)
+        }
+    };
+    let read_i32 = |b: &[u8]| -> i32 {
+        if little_endian {
+            LittleEndian::read_i32(b)
+        } else {
+            BigEndian::read_i32(b)
+        }
+    };
+
+    let ifd0_offset = read_u32(tiff.get(4..8)?) as usize;
+    let mut record = Record::new();
+    let mut gps_offset = None;
+    let mut exif_offset = None;
+
+    read_ifd(
+        tiff,
+        ifd0_offset,
+        little_endian,
+        &read_u16,
+        &read_u32,
+        &read_i32,
+        &mut record,
+        &mut exif_offset,
+        &mut gps_offset,
+    )?;
+
+    if let Some(offset) = exif_offset {
+        read_ifd(
+            tiff,
+            offset,
+            little_endian,
+            &read_u16,
+            &read_u32,
+            &read_i32,
+            &mut record,
+            &mut None,
+            &mut None,
+        );
+    }
+
+    if let Some(offset) = gps_offset {
+        if let Some(gps) = read_gps_ifd(tiff, offset, little_endian, &read_u16, &read_u32) {
+            record.insert("gps", Value::test_record(gps));
+        }
+    }
+
+    Some(record)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn read_ifd(
+    tiff: &[u8],
+    offset: usize,
+    little_endian: bool,
+    read_u16: &dyn Fn(&[u8]) -> u16,
+    read_u32: &dyn Fn(&[u8]) -> u32,
+    read_i32: &dyn Fn(&[u8]) -> i32,
+    record: &mut Record,
+    exif_offset: &mut Option<usize>,
+    gps_offset: &mut Option<usize>,
+) -> Option<()> {
+    let count = read_u16(tiff.get(offset..offset + 2)?) as usize;
+    for i in 0..count {
+        let entry = tiff.get(offset + 2 + i * 12..offset + 2 + i * 12 + 12)?;
+        let tag = read_u16(&entry[0..2]);
+        let format = read_u16(&entry[2..4]);
+        let num_values = read_u32(&entry[4..8]) as usize;
+
+        match tag {
+            0x8769 => *exif_offset = Some(read_u32(&entry[8..12]) as usize),
+            0x8825 => *gps_offset = Some(read_u32(&entry[8..12]) as usize),
+            _ => {
+                if let Some((name, value)) =
+                    decode_tag(tiff, tag, format, num_values, &entry[8..12], little_endian)
+                {
+                    record.insert(name, value);
+                }
+            }
+        }
+    }
+    Some(())
+}
+
+fn read_gps_ifd(
+    tiff: &[u8],
+    offset: usize,
+    little_endian: bool,
+    read_u16: &dyn Fn(&[u8]) -> u16,
+    read_u32: &dyn Fn(&[u8]) -> u32,
+) -> Option<Record> {
+    let count = read_u16(tiff.get(offset..offset + 2)?) as usize;
+    let mut lat_ref = None;
+    let mut lon_ref = None;
+    let mut lat = None;
+    let mut lon = None;
+
+    for i in 0..count {
+        let entry = tiff.get(offset + 2 + i * 12..offset + 2 + i * 12 + 12)?;
+        let tag = read_u16(&entry[0..2]);
+        match tag {
+            // GPSLatitudeRef / GPSLongitudeRef: a single ASCII byte, 'N'/'S'/'E'/'W'.
+            1 => lat_ref = Some(entry[8] as char),
+            3 => lon_ref = Some(entry[8] as char),
+            // GPSLatitude / GPSLongitude: 3 RATIONALs (degrees, minutes, seconds).
+            2 => lat = read_dms(tiff, read_u32(&entry[8..12]) as usize, read_u32),
+            4 => lon = read_dms(tiff, read_u32(&entry[8..12]) as usize, read_u32),
+            _ => {}
+        }
+    }
+
+    let (lat, lon) = (lat?, lon?);
+    let lat = if lat_ref == Some('S') { -lat } else { lat };
+    let lon = if lon_ref == Some('W') { -lon } else { lon };
+
+    Some(record! {
+        "latitude" => Value::test_float(lat),
+        "longitude" => Value::test_float(lon),
+    })
+}
+
+/// Reads a GPS degrees/minutes/seconds triple of RATIONALs at `offset` and
+/// converts it to signed decimal degrees.
+fn read_dms(tiff: &[u8], offset: usize, read_u32: &dyn Fn(&[u8]) -> u32) -> Option<f64> {
+    let rational = |i: usize| -> Option<f64> {
+        let bytes = tiff.get(offset + i * 8..offset + i * 8 + 8)?;
+        let numerator = read_u32(&bytes[0..4]) as f64;
+        let denominator = read_u32(&bytes[4..8]) as f64;
+        (denominator != 0.0).then_some(numerator / denominator)
+    };
+    let degrees = rational(0)?;
+    let minutes = rational(1)?;
+    let seconds = rational(2)?;
+    Some(degrees + minutes / 60.0 + seconds / 3600.0)
+}
+
+fn decode_tag(
+    tiff: &[u8],
+    tag: u16,
+    format: u16,
+    num_values: usize,
+    value_bytes: &[u8],
+    little_endian: bool,
+) -> Option<(&'static str, Value)> {
+    let name = match tag {
+        0x010F => "make",
+        0x0110 => "model",
+        0x0112 => "orientation",
+        0x0132 => "date_time",
+        0x829A => "exposure_time",
+        0x829D => "f_number",
+        0x8827 => "iso",
+        0x920A => "focal_length",
+        _ => return None,
+    };
+
+    let read_u32 = |b: &[u8]| -> u32 {
+        if little_endian {
+            LittleEndian::read_u32(b)
+        } else {
+            BigEndian::read_u32(b)
+        }
+    };
+    let read_u16 = |b: &[u8]| -> u16 {
+        if little_endian {
+            LittleEndian::read_u16(b)
+        } else {
+            BigEndian::read_u16(b)
+        }
+    };
+
+    let value = match format {
+        // ASCII string, inline if it fits in 4 bytes, otherwise stored at an offset.
+        2 => {
+            let bytes = if num_values <= 4 {
+                &value_bytes[..num_values.min(4)]
+            } else {
+                tiff.get(read_u32(value_bytes) as usize..)?
+                    .get(..num_values)?
+            };
+            let text = String::from_utf8_lossy(bytes)
+                .trim_end_matches('\0')
+                .to_string();
+            Value::test_string(text)
+        }
+        // SHORT
+        3 => Value::test_int(read_u16(&value_bytes[0..2]) as i64),
+        // LONG
+        4 => Value::test_int(read_u32(&value_bytes[0..4]) as i64),
+        // RATIONAL: stored at an offset as two u32s, numerator/denominator.
+        5 => {
+            let offset = read_u32(value_bytes) as usize;
+            let bytes = tiff.get(offset..offset + 8)?;
+            let numerator = read_u32(&bytes[0..4]) as f64;
+            let denominator = read_u32(&bytes[4..8]) as f64;
+            if denominator == 0.0 {
+                return None;
+            }
+            Value::test_float(numerator / denominator)
+        }
+        _ => return None,
+    };
+
+    Some((name, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+        test_examples(FromImage {})
+    }
+
+    #[test]
+    fn reads_png_dimensions() {
+        let mut bytes = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        bytes.extend_from_slice(&[0, 0, 0, 13]); // chunk length, unused here
+        bytes.extend_from_slice(b"IHDR");
+        bytes.extend_from_slice(&100u32.to_be_bytes());
+        bytes.extend_from_slice(&50u32.to_be_bytes());
+
+        let record = parse_image(&bytes, Span::test_data()).unwrap();
+        assert_eq!(record.get("width"), Some(&Value::test_int(100)));
+        assert_eq!(record.get("height"), Some(&Value::test_int(50)));
+        assert_eq!(record.get("format"), Some(&Value::test_string("png")));
+    }
+
+    #[test]
+    fn rejects_unknown_format() {
+        assert!(parse_image(b"not an image", Span::test_data()).is_err());
+    }
+}
@@ -0,0 +1,230 @@
+use nu_engine::command_prelude::*;
+use std::io::{BufRead, BufReader, Cursor, Read};
+
+const SEVERITIES: [&str; 8] = [
+    "emergency",
+    "alert",
+    "critical",
+    "error",
+    "warning",
+    "notice",
+    "informational",
+    "debug",
+];
+
+#[derive(Clone)]
+pub struct FromJournal;
+
+impl Command for FromJournal {
+    fn name(&self) -> &str {
+        "from journal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::table()),
+                (Type::Binary, Type::table()),
+            ])
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "Only keep entries from this systemd unit (the _SYSTEMD_UNIT field).",
+                Some('u'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Parse journald's export format and create a table."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"
+Parses the journal export format (the same wire format journald speaks over
+its native socket, and what `journalctl --output=export` prints), rather than
+scraping `journalctl`'s JSON output. Each entry is parsed independently as
+soon as its trailing blank line is seen, so this streams naturally over a
+byte stream, including one piped live from `journalctl --output=export
+--follow`. `__REALTIME_TIMESTAMP` becomes a `timestamp` date and `PRIORITY`
+becomes a `priority` severity name; every other field keeps journald's own
+name and is a string, or binary if the field's value isn't valid UTF-8.
+"#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Parse journal entries piped from journalctl",
+            example: "journalctl --output=export | from journal",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let unit: Option<String> = call.get_flag(engine_state, stack, "unit")?;
+
+        match input {
+            PipelineData::ByteStream(stream, ..) => {
+                let span = stream.span();
+                if let Some(reader) = stream.reader() {
+                    let entries = JournalEntries::new(BufReader::new(reader), span)
+                        .filter(move |entry| matches_unit(entry, unit.as_deref()));
+                    Ok(entries.into_pipeline_data(head, engine_state.signals().clone()))
+                } else {
+                    Ok(PipelineData::empty())
+                }
+            }
+            input => {
+                let span = input.span().unwrap_or(head);
+                let bytes = input.into_value(head)?.coerce_into_binary()?;
+                let entries = JournalEntries::new(BufReader::new(Cursor::new(bytes)), span)
+                    .filter(|entry| matches_unit(entry, unit.as_deref()))
+                    .collect();
+                Ok(Value::list(entries, span).into_pipeline_data())
+            }
+        }
+    }
+}
+
+fn matches_unit(entry: &Value, unit: Option<&str>) -> bool {
+    let Some(unit) = unit else {
+        return true;
+    };
+    entry
+        .as_record()
+        .ok()
+        .and_then(|record| record.get("_SYSTEMD_UNIT"))
+        .and_then(|value| value.as_str().ok())
+        .is_some_and(|value| value == unit)
+}
+
+/// Iterates the entries of a journal export format stream, one [`Value::Record`] per entry.
+///
+/// Each entry is a sequence of fields terminated by a blank line. A field is either
+/// `NAME=value\n` or, for values that may contain embedded newlines or aren't valid UTF-8,
+/// `NAME\n` followed by an 8-byte little-endian length, that many bytes of data, and `\n`.
+struct JournalEntries<R: BufRead> {
+    reader: R,
+    span: Span,
+}
+
+impl<R: BufRead> JournalEntries<R> {
+    fn new(reader: R, span: Span) -> Self {
+        Self { reader, span }
+    }
+
+    fn read_field(&mut self) -> Result<Option<(String, FieldValue)>, std::io::Error> {
+        let mut line = Vec::new();
+        if self.reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok(None);
+        }
+        if line.last() == Some(&b'\n') {
+            line.pop();
+        }
+        if line.is_empty() {
+            return Ok(Some((String::new(), FieldValue::Blank)));
+        }
+        if let Some(eq) = line.iter().position(|&b| b == b'=') {
+            let name = String::from_utf8_lossy(&line[..eq]).into_owned();
+            let value = line[eq + 1..].to_vec();
+            Ok(Some((name, FieldValue::Bytes(value))))
+        } else {
+            let name = String::from_utf8_lossy(&line).into_owned();
+            let mut len_buf = [0u8; 8];
+            self.reader.read_exact(&mut len_buf)?;
+            let len = u64::from_le_bytes(len_buf) as usize;
+            let mut data = vec![0u8; len];
+            self.reader.read_exact(&mut data)?;
+            let mut newline = [0u8; 1];
+            self.reader.read_exact(&mut newline)?;
+            Ok(Some((name, FieldValue::Bytes(data))))
+        }
+    }
+}
+
+enum FieldValue {
+    Blank,
+    Bytes(Vec<u8>),
+}
+
+impl<R: BufRead> Iterator for JournalEntries<R> {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        let mut record = Record::new();
+        let mut saw_field = false;
+        loop {
+            match self.read_field() {
+                Ok(None) => return saw_field.then(|| Value::record(record, self.span)),
+                Ok(Some((_, FieldValue::Blank))) => {
+                    if saw_field {
+                        return Some(Value::record(record, self.span));
+                    }
+                }
+                Ok(Some((name, FieldValue::Bytes(bytes)))) => {
+                    saw_field = true;
+                    insert_field(&mut record, &name, bytes, self.span);
+                }
+                Err(err) => {
+                    return Some(Value::error(
+                        ShellError::GenericError {
+                            error: "Could not parse journal entry".into(),
+                            msg: err.to_string(),
+                            span: Some(self.span),
+                            help: None,
+                            inner: vec![],
+                        },
+                        self.span,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn insert_field(record: &mut Record, name: &str, bytes: Vec<u8>, span: Span) {
+    match name {
+        "__REALTIME_TIMESTAMP" => {
+            record.insert("timestamp", realtime_timestamp(&bytes, span));
+        }
+        "PRIORITY" => {
+            record.insert("priority", priority(&bytes, span));
+        }
+        _ => {
+            record.insert(name, bytes_to_value(bytes, span));
+        }
+    }
+}
+
+fn bytes_to_value(bytes: Vec<u8>, span: Span) -> Value {
+    String::from_utf8(bytes)
+        .map(|val| Value::string(val, span))
+        .unwrap_or_else(|err| Value::binary(err.into_bytes(), span))
+}
+
+fn realtime_timestamp(bytes: &[u8], span: Span) -> Value {
+    std::str::from_utf8(bytes)
+        .ok()
+        .and_then(|text| text.parse::<i64>().ok())
+        .and_then(|usec| {
+            chrono::DateTime::from_timestamp(usec / 1_000_000, ((usec % 1_000_000) * 1_000) as u32)
+        })
+        .map(|dt| Value::date(dt.fixed_offset(), span))
+        .unwrap_or_else(|| bytes_to_value(bytes.to_vec(), span))
+}
+
+fn priority(bytes: &[u8], span: Span) -> Value {
+    std::str::from_utf8(bytes)
+        .ok()
+        .and_then(|text| text.parse::<usize>().ok())
+        .and_then(|pri| SEVERITIES.get(pri))
+        .map(|severity| Value::string(*severity, span))
+        .unwrap_or_else(|| bytes_to_value(bytes.to_vec(), span))
+}
@@ -0,0 +1,143 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct FromLogfmt;
+
+impl Command for FromLogfmt {
+    fn name(&self) -> &str {
+        "from logfmt"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::String, Type::table())])
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Parse logfmt (key=value) formatted lines and create a table."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"
+Each line is read and parsed independently, so this streams naturally over a
+byte stream instead of requiring the whole input up front. Bare words with no
+`=` become `true` flags, values are unquoted, and `true`/`false`/integers/floats
+are cast to their Nu equivalent.
+"#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Parse logfmt formatted lines into a table",
+                example: r#"'level=info msg="listening" port=8080' | from logfmt"#,
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                    "level" => Value::test_string("info"),
+                    "msg" => Value::test_string("listening"),
+                    "port" => Value::test_int(8080),
+                })])),
+            },
+            Example {
+                description: "A bare word with no `=` is read as a boolean flag",
+                example: "'ready shutdown=false' | from logfmt",
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                    "ready" => Value::test_bool(true),
+                    "shutdown" => Value::test_bool(false),
+                })])),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let span = input.span().unwrap_or(head);
+
+        match input {
+            PipelineData::ByteStream(stream, ..) => {
+                if let Some(lines) = stream.lines() {
+                    Ok(lines
+                        .map(move |line| match line {
+                            Ok(line) => parse_line(&line, head),
+                            Err(err) => Value::error(err, head),
+                        })
+                        .into_pipeline_data(head, engine_state.signals().clone()))
+                } else {
+                    Ok(PipelineData::empty())
+                }
+            }
+            input => {
+                let text = input.into_value(head)?.coerce_into_string()?;
+                let records = text
+                    .lines()
+                    .map(|line| parse_line(line, head))
+                    .collect::<Vec<_>>();
+                Ok(Value::list(records, span).into_pipeline_data())
+            }
+        }
+    }
+}
+
+fn parse_line(line: &str, span: Span) -> Value {
+    let mut record = Record::new();
+    for token in tokenize(line) {
+        let (key, value) = match token.split_once('=') {
+            Some((key, value)) => (key, unquote(value)),
+            None => (token, "true"),
+        };
+        record.push(key, string_to_value(value, span));
+    }
+    Value::record(record, span)
+}
+
+/// Split a logfmt line on unquoted whitespace, keeping `key="quoted value"` intact.
+fn tokenize(line: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut in_quotes = false;
+    let mut start = None;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if let Some(s) = start.take() {
+                    tokens.push(&line[s..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        if start.is_none() && !c.is_whitespace() {
+            start = Some(i);
+        }
+    }
+    if let Some(s) = start {
+        tokens.push(&line[s..]);
+    }
+    tokens
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+fn string_to_value(value: &str, span: Span) -> Value {
+    if let Ok(i) = value.parse::<i64>() {
+        Value::int(i, span)
+    } else if let Ok(f) = value.parse::<f64>() {
+        Value::float(f, span)
+    } else if let Ok(b) = value.parse::<bool>() {
+        Value::bool(b, span)
+    } else {
+        Value::string(value, span)
+    }
+}
@@ -0,0 +1,317 @@
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct FromMedia;
+
+impl Command for FromMedia {
+    fn name(&self) -> &str {
+        "from media"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Binary, Type::record())])
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Read container-level metadata (duration, codec, bitrate, tags) from audio/video bytes."
+    }
+
+    fn extra_description(&self) -> &str {
+        "This reads only the container header, not the audio/video streams themselves, since \
+         neither symphonia nor a matroska parser is vendored in the workspace. WAV and MP4/M4A \
+         are recognized: WAV metadata comes from its `fmt`/`data`/`LIST INFO` chunks, and MP4 \
+         metadata comes from the `moov/mvhd` box and `moov/udta/meta/ilst` tag atoms. Codec \
+         detail beyond the WAV format tag isn't decoded, and chapters aren't read from either \
+         format. Other containers (Matroska, Ogg, FLAC, MP3) are reported as an error."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["ffprobe", "audio", "video", "duration", "tags"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Read a WAV file's duration and format",
+            example: "open --raw song.wav | from media",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let bytes = input.into_value(head)?.into_binary()?;
+
+        parse_media(&bytes, head).map(|record| Value::record(record, head).into_pipeline_data())
+    }
+}
+
+fn unrecognized_format(head: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Not a recognized media container".into(),
+        msg: "expected a WAV or MP4/M4A file".into(),
+        span: Some(head),
+        help: Some("Matroska, Ogg, FLAC, and MP3 containers aren't supported".into()),
+        inner: vec![],
+    }
+}
+
+fn parse_media(bytes: &[u8], head: Span) -> Result<Record, ShellError> {
+    if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WAVE" {
+        parse_wav(bytes, head)
+    } else if bytes.len() >= 12 && &bytes[4..8] == b"ftyp" {
+        parse_mp4(bytes, head)
+    } else {
+        Err(unrecognized_format(head))
+    }
+}
+
+fn wav_codec_name(format_tag: u16) -> String {
+    match format_tag {
+        1 => "pcm".into(),
+        3 => "ieee-float".into(),
+        6 => "alaw".into(),
+        7 => "mulaw".into(),
+        0xFFFE => "extensible".into(),
+        other => format!("unknown (format tag {other})"),
+    }
+}
+
+fn parse_wav(bytes: &[u8], head: Span) -> Result<Record, ShellError> {
+    let mut pos = 12;
+    let mut codec = None;
+    let mut channels = None;
+    let mut sample_rate = None;
+    let mut byte_rate = None;
+    let mut data_size = None;
+    let mut tags = Record::new();
+
+    while pos + 8 <= bytes.len() {
+        let chunk_id = &bytes[pos..pos + 4];
+        let chunk_size = LittleEndian::read_u32(&bytes[pos + 4..pos + 8]) as usize;
+        let body_start = pos + 8;
+        let body = bytes
+            .get(body_start..body_start + chunk_size)
+            .ok_or_else(|| truncated_media(head))?;
+
+        match chunk_id {
+            b"fmt " if body.len() >= 16 => {
+                codec = Some(wav_codec_name(LittleEndian::read_u16(&body[0..2])));
+                channels = Some(LittleEndian::read_u16(&body[2..4]));
+                sample_rate = Some(LittleEndian::read_u32(&body[4..8]));
+                byte_rate = Some(LittleEndian::read_u32(&body[8..12]));
+            }
+            b"data" => {
+                data_size = Some(chunk_size);
+            }
+            b"LIST" if body.len() >= 4 && &body[0..4] == b"INFO" => {
+                parse_wav_info(&body[4..], &mut tags);
+            }
+            _ => {}
+        }
+
+        // Chunks are padded to an even number of bytes.
+        pos = body_start + chunk_size + (chunk_size % 2);
+    }
+
+    let byte_rate = byte_rate.ok_or_else(|| missing_fmt_chunk(head))?;
+    let duration_secs = data_size.map(|size| size as f64 / byte_rate as f64);
+
+    let mut record = record! {
+        "format" => Value::string("wav", head),
+        "codec" => Value::string(codec.unwrap_or_else(|| "unknown".into()), head),
+        "channels" => Value::int(channels.unwrap_or_default() as i64, head),
+        "sample_rate" => Value::int(sample_rate.unwrap_or_default() as i64, head),
+        "bitrate" => Value::int(byte_rate as i64 * 8, head),
+        "tags" => Value::record(tags, head),
+        "chapters" => Value::list(vec![], head),
+    };
+    if let Some(secs) = duration_secs {
+        record.insert(
+            "duration",
+            Value::duration((secs * 1_000_000_000.0) as i64, head),
+        );
+    }
+    Ok(record)
+}
+
+fn parse_wav_info(body: &[u8], tags: &mut Record) {
+    let mut pos = 0;
+    while pos + 8 <= body.len() {
+        let id = &body[pos..pos + 4];
+        let size = LittleEndian::read_u32(&body[pos + 4..pos + 8]) as usize;
+        let Some(value) = body.get(pos + 8..pos + 8 + size) else {
+            break;
+        };
+        let name = match id {
+            b"INAM" => Some("title"),
+            b"IART" => Some("artist"),
+            b"IPRD" => Some("album"),
+            b"ICRD" => Some("date"),
+            _ => None,
+        };
+        if let Some(name) = name {
+            let text = String::from_utf8_lossy(value)
+                .trim_end_matches('\0')
+                .to_string();
+            tags.insert(name, Value::test_string(text));
+        }
+        pos += 8 + size + (size % 2);
+    }
+}
+
+fn missing_fmt_chunk(head: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Malformed WAV file".into(),
+        msg: "no `fmt ` chunk was found".into(),
+        span: Some(head),
+        help: None,
+        inner: vec![],
+    }
+}
+
+fn truncated_media(head: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Truncated media file".into(),
+        msg: "a chunk or box claimed a size larger than the remaining data".into(),
+        span: Some(head),
+        help: None,
+        inner: vec![],
+    }
+}
+
+/// Walks a sequence of MP4 boxes, calling `on_box(kind, body)` for each one
+/// found directly at this level.
+fn walk_boxes(bytes: &[u8], mut on_box: impl FnMut(&[u8], &[u8])) {
+    let mut pos = 0;
+    while pos + 8 <= bytes.len() {
+        let size = BigEndian::read_u32(&bytes[pos..pos + 4]) as usize;
+        let kind = &bytes[pos + 4..pos + 8];
+        if size < 8 {
+            break;
+        }
+        let Some(body) = bytes.get(pos + 8..pos + size) else {
+            break;
+        };
+        on_box(kind, body);
+        pos += size;
+    }
+}
+
+fn parse_mp4(bytes: &[u8], head: Span) -> Result<Record, ShellError> {
+    let mut duration_secs = None;
+    let mut tags = Record::new();
+    let mut found_moov = false;
+
+    walk_boxes(bytes, |kind, body| {
+        if kind != b"moov" {
+            return;
+        }
+        found_moov = true;
+        walk_boxes(body, |kind, body| match kind {
+            b"mvhd" if body.len() >= 20 => {
+                let version = body[0];
+                let (timescale, duration) = if version == 1 && body.len() >= 28 {
+                    (
+                        BigEndian::read_u32(&body[20..24]),
+                        BigEndian::read_u64(&body[20..28]),
+                    )
+                } else {
+                    (
+                        BigEndian::read_u32(&body[12..16]),
+                        BigEndian::read_u32(&body[16..20]) as u64,
+                    )
+                };
+                if timescale > 0 {
+                    duration_secs = Some(duration as f64 / timescale as f64);
+                }
+            }
+            b"udta" => walk_boxes(body, |kind, body| {
+                if kind != b"meta" || body.len() < 4 {
+                    return;
+                }
+                walk_boxes(&body[4..], |kind, body| {
+                    if kind != b"ilst" {
+                        return;
+                    }
+                    parse_mp4_ilst(body, &mut tags);
+                });
+            }),
+            _ => {}
+        });
+    });
+
+    if !found_moov {
+        return Err(missing_moov_box(head));
+    }
+
+    let mut record = record! {
+        "format" => Value::string("mp4", head),
+        "codec" => Value::string("unknown", head),
+        "bitrate" => Value::int(0, head),
+        "tags" => Value::record(tags, head),
+        "chapters" => Value::list(vec![], head),
+    };
+    if let Some(secs) = duration_secs {
+        record.insert(
+            "duration",
+            Value::duration((secs * 1_000_000_000.0) as i64, head),
+        );
+    }
+    Ok(record)
+}
+
+fn parse_mp4_ilst(body: &[u8], tags: &mut Record) {
+    walk_boxes(body, |kind, body| {
+        let name = match kind {
+            b"\xa9nam" => Some("title"),
+            b"\xa9ART" => Some("artist"),
+            b"\xa9alb" => Some("album"),
+            b"\xa9day" => Some("date"),
+            _ => None,
+        };
+        let Some(name) = name else { return };
+        // Each tag atom contains a nested "data" atom: 4-byte type flags, 4-byte
+        // locale, then the UTF-8 value.
+        walk_boxes(body, |kind, body| {
+            if kind == b"data" && body.len() > 8 {
+                let text = String::from_utf8_lossy(&body[8..]).to_string();
+                tags.insert(name, Value::test_string(text));
+            }
+        });
+    });
+}
+
+fn missing_moov_box(head: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Malformed MP4 file".into(),
+        msg: "no `moov` box was found".into(),
+        span: Some(head),
+        help: None,
+        inner: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+        test_examples(FromMedia {})
+    }
+
+    #[test]
+    fn rejects_unknown_format() {
+        assert!(parse_media(b"not a media file", Span::test_data()).is_err());
+    }
+}
@@ -1,12 +1,26 @@
+mod clf;
 mod command;
 mod csv;
 mod delimited;
+mod html;
+#[cfg(feature = "image")]
+mod image;
+#[cfg(feature = "journald")]
+mod journal;
 mod json;
+mod logfmt;
+#[cfg(feature = "media")]
+mod media;
 mod msgpack;
 mod msgpackz;
 mod nuon;
 mod ods;
+#[cfg(feature = "pcap")]
+mod pcap;
+#[cfg(feature = "pdf")]
+mod pdf;
 mod ssv;
+mod syslog;
 mod toml;
 mod tsv;
 mod xlsx;
@@ -15,13 +29,27 @@ mod yaml;
 
 pub use self::csv::FromCsv;
 pub use self::toml::FromToml;
+pub use clf::FromClf;
 pub use command::From;
+pub use html::FromHtml;
+#[cfg(feature = "image")]
+pub use image::FromImage;
+#[cfg(feature = "journald")]
+pub use journal::FromJournal;
 pub use json::FromJson;
+pub use logfmt::FromLogfmt;
+#[cfg(feature = "media")]
+pub use media::FromMedia;
 pub use msgpack::FromMsgpack;
 pub use msgpackz::FromMsgpackz;
 pub use nuon::FromNuon;
 pub use ods::FromOds;
+#[cfg(feature = "pcap")]
+pub use pcap::FromPcap;
+#[cfg(feature = "pdf")]
+pub use pdf::FromPdf;
 pub use ssv::FromSsv;
+pub use syslog::FromSyslog;
 pub use tsv::FromTsv;
 pub use xlsx::FromXlsx;
 pub use xml::FromXml;
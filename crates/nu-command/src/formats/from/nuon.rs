@@ -1,4 +1,6 @@
 use nu_engine::command_prelude::*;
+use nu_protocol::{ListStream, Signals};
+use std::io::{BufRead, Cursor};
 
 #[derive(Clone)]
 pub struct FromNuon;
@@ -12,9 +14,14 @@ impl Command for FromNuon {
         "Convert from nuon to structured data."
     }
 
+    fn extra_description(&self) -> &str {
+        "Comments and trailing commas are always allowed in the input, matching nuon's normal syntax; they just aren't preserved in the resulting value."
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("from nuon")
             .input_output_types(vec![(Type::String, Type::Any)])
+            .switch("objects", "treat each line as a separate value", Some('o'))
             .category(Category::Formats)
     }
 
@@ -35,17 +42,54 @@ impl Command for FromNuon {
                     "b" => Value::test_list(vec![Value::test_int(1), Value::test_int(2)]),
                 })),
             },
+            Example {
+                example: "\"{a: 1}\n{a: 2}\" | from nuon --objects",
+                description: "Parse a stream of line-delimited nuon values",
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! {"a" => Value::test_int(1)}),
+                    Value::test_record(record! {"a" => Value::test_int(2)}),
+                ])),
+            },
         ]
     }
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
+
+        if call.has_flag(engine_state, stack, "objects")? {
+            let metadata = input.metadata().map(|md| md.with_content_type(None));
+            return match input {
+                PipelineData::Value(Value::String { val, .. }, ..) => Ok(PipelineData::ListStream(
+                    read_nuon_lines(Cursor::new(val), head, engine_state.signals().clone()),
+                    metadata,
+                )),
+                PipelineData::ByteStream(stream, ..)
+                    if stream.type_() != ByteStreamType::Binary =>
+                {
+                    if let Some(reader) = stream.reader() {
+                        Ok(PipelineData::ListStream(
+                            read_nuon_lines(reader, head, Signals::empty()),
+                            metadata,
+                        ))
+                    } else {
+                        Ok(PipelineData::Empty)
+                    }
+                }
+                _ => Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "string".into(),
+                    wrong_type: input.get_type().to_string(),
+                    dst_span: head,
+                    src_span: input.span().unwrap_or(head),
+                }),
+            };
+        }
+
         let (string_input, _span, metadata) = input.collect_string_strict(head)?;
 
         match nuon::from_nuon(&string_input, Some(head)) {
@@ -62,6 +106,24 @@ impl Command for FromNuon {
     }
 }
 
+/// Create a stream of values from a reader that produces line-delimited nuon
+fn read_nuon_lines(
+    input: impl BufRead + Send + 'static,
+    span: Span,
+    signals: Signals,
+) -> ListStream {
+    let iter = input
+        .lines()
+        .filter(|line| line.as_ref().is_ok_and(|line| !line.trim().is_empty()) || line.is_err())
+        .map(move |line| {
+            let line = line.err_span(span)?;
+            nuon::from_nuon(&line, Some(span))
+        })
+        .map(move |result| result.unwrap_or_else(|err| Value::error(err, span)));
+
+    ListStream::new(iter, span, signals)
+}
+
 #[cfg(test)]
 mod test {
     use nu_cmd_lang::eval_pipeline_without_terminal_expression;
@@ -1,4 +1,5 @@
 use calamine::*;
+use chrono::{Local, LocalResult, Offset, TimeZone, Utc};
 use indexmap::IndexMap;
 use nu_engine::command_prelude::*;
 
@@ -22,6 +23,12 @@ impl Command for FromOds {
                 "Only convert specified sheets",
                 Some('s'),
             )
+            .named(
+                "range",
+                SyntaxShape::String,
+                "Only convert the given cell range, e.g. 'A1:D100'",
+                Some('r'),
+            )
             .category(Category::Formats)
     }
 
@@ -46,8 +53,13 @@ impl Command for FromOds {
             vec![]
         };
 
+        let range = match call.get_flag::<Spanned<String>>(engine_state, stack, "range")? {
+            Some(range) => Some(parse_range(&range.item, range.span)?),
+            None => None,
+        };
+
         let metadata = input.metadata().map(|md| md.with_content_type(None));
-        from_ods(input, head, sel_sheets).map(|pd| pd.set_metadata(metadata))
+        from_ods(input, head, sel_sheets, range).map(|pd| pd.set_metadata(metadata))
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -62,10 +74,62 @@ impl Command for FromOds {
                 example: "open --raw test.ods | from ods --sheets [Spreadsheet1]",
                 result: None,
             },
+            Example {
+                description: "Convert binary .ods data to a table, restricted to a cell range",
+                example: "open --raw test.ods | from ods --range A1:D100",
+                result: None,
+            },
         ]
     }
 }
 
+/// Parses a cell range like `A1:D100` into 0-indexed, inclusive `((start_row, start_col),
+/// (end_row, end_col))` bounds.
+fn parse_range(range: &str, span: Span) -> Result<((u32, u32), (u32, u32)), ShellError> {
+    let (start, end) = range
+        .split_once(':')
+        .ok_or_else(|| ShellError::IncorrectValue {
+            msg: "Range must be of the form 'A1:D100'".to_string(),
+            val_span: span,
+            call_span: span,
+        })?;
+
+    let start = parse_cell_ref(start, span)?;
+    let end = parse_cell_ref(end, span)?;
+    Ok((start, end))
+}
+
+fn parse_cell_ref(cell: &str, span: Span) -> Result<(u32, u32), ShellError> {
+    let col_end = cell
+        .find(|c: char| c.is_ascii_digit())
+        .ok_or_else(|| invalid_cell_ref(cell, span))?;
+    let (col, row) = cell.split_at(col_end);
+
+    if col.is_empty() || row.is_empty() {
+        return Err(invalid_cell_ref(cell, span));
+    }
+
+    let mut col_index: u32 = 0;
+    for c in col.chars() {
+        if !c.is_ascii_alphabetic() {
+            return Err(invalid_cell_ref(cell, span));
+        }
+        col_index = col_index * 26 + (c.to_ascii_uppercase() as u32 - 'A' as u32 + 1);
+    }
+
+    let row_index: u32 = row.parse().map_err(|_| invalid_cell_ref(cell, span))?;
+
+    Ok((row_index - 1, col_index - 1))
+}
+
+fn invalid_cell_ref(cell: &str, span: Span) -> ShellError {
+    ShellError::IncorrectValue {
+        msg: format!("'{cell}' is not a valid cell reference, expected something like 'A1'"),
+        val_span: span,
+        call_span: span,
+    }
+}
+
 fn convert_columns(columns: &[Value]) -> Result<Vec<String>, ShellError> {
     let res = columns
         .iter()
@@ -114,6 +178,7 @@ fn from_ods(
     input: PipelineData,
     head: Span,
     sel_sheets: Vec<String>,
+    range: Option<((u32, u32), (u32, u32))>,
 ) -> Result<PipelineData, ShellError> {
     let span = input.span();
     let bytes = collect_binary(input, head)?;
@@ -132,21 +197,52 @@ fn from_ods(
         sheet_names.retain(|e| sel_sheets.contains(e));
     }
 
+    let tz = match Local.timestamp_opt(0, 0) {
+        LocalResult::Single(tz) => *tz.offset(),
+        _ => Utc.fix(),
+    };
+
     for sheet_name in sheet_names {
         let mut sheet_output = vec![];
 
         if let Ok(current_sheet) = ods.worksheet_range(&sheet_name) {
-            for row in current_sheet.rows() {
-                let record = row
-                    .iter()
+            let rows: Box<dyn Iterator<Item = (usize, &[Data])>> = match range {
+                Some(((start_row, _), (end_row, _))) => Box::new(
+                    current_sheet
+                        .rows()
+                        .enumerate()
+                        .filter(move |(i, _)| (*i as u32) >= start_row && (*i as u32) <= end_row),
+                ),
+                None => Box::new(current_sheet.rows().enumerate()),
+            };
+
+            for (_, row) in rows {
+                let cells: Box<dyn Iterator<Item = (usize, &Data)>> = match range {
+                    Some(((_, start_col), (_, end_col))) => {
+                        Box::new(row.iter().enumerate().filter(move |(i, _)| {
+                            (*i as u32) >= start_col && (*i as u32) <= end_col
+                        }))
+                    }
+                    None => Box::new(row.iter().enumerate()),
+                };
+
+                let record = cells
                     .enumerate()
-                    .map(|(i, cell)| {
+                    .map(|(i, (_, cell))| {
                         let value = match cell {
                             Data::Empty => Value::nothing(head),
                             Data::String(s) => Value::string(s, head),
                             Data::Float(f) => Value::float(*f, head),
                             Data::Int(i) => Value::int(*i, head),
                             Data::Bool(b) => Value::bool(*b, head),
+                            Data::DateTime(d) => d
+                                .as_datetime()
+                                .and_then(|d| match tz.from_local_datetime(&d) {
+                                    LocalResult::Single(d) => Some(d),
+                                    _ => None,
+                                })
+                                .map(|d| Value::date(d, head))
+                                .unwrap_or(Value::nothing(head)),
                             _ => Value::nothing(head),
                         };
 
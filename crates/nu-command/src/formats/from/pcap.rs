@@ -0,0 +1,342 @@
+use std::io::{self, Cursor, Read};
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian, ReadBytesExt};
+use chrono::{TimeZone, Utc};
+use nu_engine::command_prelude::*;
+
+const MAGIC_MICROS_LE: u32 = 0xa1b2c3d4;
+const MAGIC_NANOS_LE: u32 = 0xa1b23c4d;
+const MAGIC_MICROS_BE: u32 = 0xd4c3b2a1;
+const MAGIC_NANOS_BE: u32 = 0x4d3cb2a1;
+const MAGIC_PCAPNG: u32 = 0x0a0d0d0a;
+
+const LINKTYPE_ETHERNET: u32 = 1;
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86dd;
+const IPPROTO_TCP: u8 = 6;
+const IPPROTO_UDP: u8 = 17;
+
+#[derive(Clone)]
+pub struct FromPcap;
+
+impl Command for FromPcap {
+    fn name(&self) -> &str {
+        "from pcap"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Binary, Type::table())
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Parse a packet capture (pcap) byte stream and create a table of packets."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"
+Reads the classic libpcap file format (magic number a1b2c3d4/a1b23c4d, in either
+byte order). The newer pcapng format is not supported yet.
+
+Each row is one captured packet: `timestamp`, `length` (the original length on the
+wire), `captured_length` (how many bytes were actually captured), and, when the
+link layer is Ethernet carrying IPv4 or IPv6, `protocol`, `source`, `destination`,
+`source_port`, and `dest_port`. Whatever is left of the packet after the layers
+that could be decoded is returned as binary in `payload`.
+"#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Count how many packets a capture contains",
+            example: "open --raw capture.pcap | from pcap | length",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let metadata = input.metadata().map(|md| md.with_content_type(None));
+        let bytes = collect_binary(input, span)?;
+
+        let packets = read_pcap(&bytes, span).map_err(|err| err.into_shell_error(span))?;
+        Ok(Value::list(packets, span)
+            .into_pipeline_data()
+            .set_metadata(metadata))
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    if let PipelineData::ByteStream(stream, ..) = input {
+        stream.into_bytes()
+    } else {
+        let mut bytes = vec![];
+        let mut values = input.into_iter();
+
+        loop {
+            match values.next() {
+                Some(Value::Binary { val: b, .. }) => {
+                    bytes.extend_from_slice(&b);
+                }
+                Some(Value::Error { error, .. }) => return Err(*error),
+                Some(x) => {
+                    return Err(ShellError::UnsupportedInput {
+                        msg: "Expected binary from pipeline".to_string(),
+                        input: "value originates from here".into(),
+                        msg_span: span,
+                        input_span: x.span(),
+                    })
+                }
+                None => break,
+            }
+        }
+
+        Ok(bytes)
+    }
+}
+
+enum ReadError {
+    Io(io::Error),
+    UnknownMagic(u32),
+    Pcapng,
+}
+
+impl ReadError {
+    fn into_shell_error(self, span: Span) -> ShellError {
+        match self {
+            ReadError::Io(err) => ShellError::GenericError {
+                error: "Error while reading pcap data".into(),
+                msg: err.to_string(),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            },
+            ReadError::UnknownMagic(magic) => ShellError::GenericError {
+                error: "Not a recognized pcap file".into(),
+                msg: format!("unknown magic number 0x{magic:08x}"),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            },
+            ReadError::Pcapng => ShellError::GenericError {
+                error: "pcapng is not supported".into(),
+                msg: "only the classic pcap file format can be read".into(),
+                span: Some(span),
+                help: Some("convert the capture with `editcap -F pcap` first".into()),
+                inner: vec![],
+            },
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(value: io::Error) -> Self {
+        ReadError::Io(value)
+    }
+}
+
+fn read_pcap(bytes: &[u8], span: Span) -> Result<Vec<Value>, ReadError> {
+    let mut cursor = Cursor::new(bytes);
+    let magic = cursor.read_u32::<BigEndian>()?;
+
+    let (big_endian, nanos) = match magic {
+        MAGIC_MICROS_LE => (false, false),
+        MAGIC_NANOS_LE => (false, true),
+        MAGIC_MICROS_BE => (true, false),
+        MAGIC_NANOS_BE => (true, true),
+        MAGIC_PCAPNG => return Err(ReadError::Pcapng),
+        other => return Err(ReadError::UnknownMagic(other)),
+    };
+
+    // Skip version_major, version_minor, thiszone, sigfigs, snaplen.
+    cursor.set_position(cursor.position() + 2 + 2 + 4 + 4 + 4);
+    let link_type = if big_endian {
+        cursor.read_u32::<BigEndian>()?
+    } else {
+        cursor.read_u32::<LittleEndian>()?
+    };
+
+    let mut packets = Vec::new();
+    loop {
+        let (ts_sec, ts_frac, incl_len, orig_len) =
+            match read_record_header(&mut cursor, big_endian) {
+                Ok(header) => header,
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err.into()),
+            };
+
+        let mut data = vec![0u8; incl_len as usize];
+        cursor.read_exact(&mut data)?;
+
+        packets.push(packet_to_value(
+            &data, orig_len, link_type, ts_sec, ts_frac, nanos, span,
+        ));
+    }
+
+    Ok(packets)
+}
+
+fn read_record_header(
+    cursor: &mut Cursor<&[u8]>,
+    big_endian: bool,
+) -> io::Result<(u32, u32, u32, u32)> {
+    if big_endian {
+        let ts_sec = cursor.read_u32::<BigEndian>()?;
+        let ts_frac = cursor.read_u32::<BigEndian>()?;
+        let incl_len = cursor.read_u32::<BigEndian>()?;
+        let orig_len = cursor.read_u32::<BigEndian>()?;
+        Ok((ts_sec, ts_frac, incl_len, orig_len))
+    } else {
+        let ts_sec = cursor.read_u32::<LittleEndian>()?;
+        let ts_frac = cursor.read_u32::<LittleEndian>()?;
+        let incl_len = cursor.read_u32::<LittleEndian>()?;
+        let orig_len = cursor.read_u32::<LittleEndian>()?;
+        Ok((ts_sec, ts_frac, incl_len, orig_len))
+    }
+}
+
+fn packet_to_value(
+    data: &[u8],
+    orig_len: u32,
+    link_type: u32,
+    ts_sec: u32,
+    ts_frac: u32,
+    nanos: bool,
+    span: Span,
+) -> Value {
+    let nanosecs = if nanos { ts_frac } else { ts_frac * 1_000 };
+    let timestamp = Utc
+        .timestamp_opt(ts_sec as i64, nanosecs)
+        .single()
+        .map(|dt| Value::date(dt.into(), span))
+        .unwrap_or_else(|| Value::nothing(span));
+
+    let layers = if link_type == LINKTYPE_ETHERNET {
+        decode_ethernet(data)
+    } else {
+        None
+    };
+
+    let mut record = record! {
+        "timestamp" => timestamp,
+        "length" => Value::int(orig_len as i64, span),
+        "captured_length" => Value::int(data.len() as i64, span),
+    };
+
+    let payload = match layers {
+        Some(layers) => {
+            record.push("protocol", Value::string(layers.protocol, span));
+            record.push("source", Value::string(layers.source, span));
+            record.push("destination", Value::string(layers.destination, span));
+            record.push(
+                "source_port",
+                layers
+                    .source_port
+                    .map_or_else(|| Value::nothing(span), |p| Value::int(p as i64, span)),
+            );
+            record.push(
+                "dest_port",
+                layers
+                    .dest_port
+                    .map_or_else(|| Value::nothing(span), |p| Value::int(p as i64, span)),
+            );
+            layers.payload
+        }
+        None => data.to_vec(),
+    };
+    record.push("payload", Value::binary(payload, span));
+
+    Value::record(record, span)
+}
+
+struct Layers {
+    protocol: String,
+    source: String,
+    destination: String,
+    source_port: Option<u16>,
+    dest_port: Option<u16>,
+    payload: Vec<u8>,
+}
+
+fn decode_ethernet(data: &[u8]) -> Option<Layers> {
+    if data.len() < 14 {
+        return None;
+    }
+    let ethertype = BigEndian::read_u16(&data[12..14]);
+    let rest = &data[14..];
+
+    match ethertype {
+        ETHERTYPE_IPV4 => decode_ipv4(rest),
+        ETHERTYPE_IPV6 => decode_ipv6(rest),
+        _ => None,
+    }
+}
+
+fn decode_ipv4(data: &[u8]) -> Option<Layers> {
+    if data.len() < 20 {
+        return None;
+    }
+    let ihl = (data[0] & 0x0f) as usize * 4;
+    if data.len() < ihl {
+        return None;
+    }
+    let protocol = data[9];
+    let source = std::net::Ipv4Addr::new(data[12], data[13], data[14], data[15]).to_string();
+    let destination = std::net::Ipv4Addr::new(data[16], data[17], data[18], data[19]).to_string();
+
+    Some(decode_transport(
+        protocol,
+        source,
+        destination,
+        &data[ihl..],
+    ))
+}
+
+fn decode_ipv6(data: &[u8]) -> Option<Layers> {
+    if data.len() < 40 {
+        return None;
+    }
+    let protocol = data[6];
+    let source = std::net::Ipv6Addr::from(<[u8; 16]>::try_from(&data[8..24]).ok()?).to_string();
+    let destination =
+        std::net::Ipv6Addr::from(<[u8; 16]>::try_from(&data[24..40]).ok()?).to_string();
+
+    Some(decode_transport(protocol, source, destination, &data[40..]))
+}
+
+fn decode_transport(protocol: u8, source: String, destination: String, data: &[u8]) -> Layers {
+    let (protocol_name, source_port, dest_port, header_len) = match protocol {
+        IPPROTO_TCP if data.len() >= 20 => {
+            let data_offset = ((data[12] >> 4) as usize) * 4;
+            (
+                "tcp",
+                Some(BigEndian::read_u16(&data[0..2])),
+                Some(BigEndian::read_u16(&data[2..4])),
+                data_offset.max(20).min(data.len()),
+            )
+        }
+        IPPROTO_UDP if data.len() >= 8 => (
+            "udp",
+            Some(BigEndian::read_u16(&data[0..2])),
+            Some(BigEndian::read_u16(&data[2..4])),
+            8,
+        ),
+        _ => ("other", None, None, 0),
+    };
+
+    Layers {
+        protocol: protocol_name.to_string(),
+        source,
+        destination,
+        source_port,
+        dest_port,
+        payload: data[header_len..].to_vec(),
+    }
+}
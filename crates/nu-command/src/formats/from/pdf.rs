@@ -0,0 +1,509 @@
+use nu_engine::command_prelude::*;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct FromPdf;
+
+impl Command for FromPdf {
+    fn name(&self) -> &str {
+        "from pdf"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Binary, Type::Any)])
+            .switch(
+                "layout",
+                "preserve line breaks by splitting text at Td/TD/T* positioning operators",
+                Some('l'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Extract text per page and document metadata from a PDF."
+    }
+
+    fn extra_description(&self) -> &str {
+        "This is a minimal, dependency-free PDF text extractor, not a full PDF renderer: it \
+         only reads classic (non-compressed, non-cross-reference-stream) PDF structure, and can \
+         only recover text from content streams that aren't compressed (no /FlateDecode \
+         support), since no inflate implementation is vendored in the workspace. Such pages are \
+         reported with empty text. --layout inserts a line break wherever a Td/TD/T* operator \
+         appears between two shown strings, which approximates a page's line structure without \
+         truly replaying its layout."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let layout = call.has_flag(engine_state, stack, "layout")?;
+        let bytes = input.into_value(head)?.into_binary()?;
+
+        parse_pdf(&bytes, head, layout).map(|value| value.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Extract per-page text and metadata from a PDF",
+            example: "open --raw report.pdf | from pdf",
+            result: None,
+        }]
+    }
+}
+
+struct PdfObject {
+    dict: String,
+    stream: Option<Vec<u8>>,
+    stream_compressed: bool,
+}
+
+fn parse_pdf(bytes: &[u8], head: Span, layout: bool) -> Result<Value, ShellError> {
+    let objects = parse_objects(bytes);
+
+    let metadata = find_info_dict(bytes, &objects)
+        .map(|dict| metadata_record(&dict, head))
+        .unwrap_or_else(|| Value::record(Record::new(), head));
+
+    let page_refs = find_page_order(bytes, &objects).unwrap_or_else(|| {
+        // Fall back to object declaration order if there's no /Root -> /Pages -> /Kids chain
+        // we can follow (e.g. a malformed or unusually structured document).
+        let mut nums: Vec<u32> = objects
+            .iter()
+            .filter(|(_, obj)| {
+                obj.dict.contains("/Type")
+                    && obj.dict.contains("/Page")
+                    && !obj.dict.contains("/Pages")
+            })
+            .map(|(num, _)| *num)
+            .collect();
+        nums.sort_unstable();
+        nums
+    });
+
+    let pages = page_refs
+        .iter()
+        .enumerate()
+        .map(|(i, page_num)| {
+            let (text, compressed) = objects
+                .get(page_num)
+                .map(|page| page_text(&page.dict, &objects, layout))
+                .unwrap_or((String::new(), false));
+
+            Value::record(
+                record! {
+                    "page" => Value::int(i as i64 + 1, head),
+                    "text" => Value::string(text, head),
+                    "compressed" => Value::bool(compressed, head),
+                },
+                head,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Value::record(
+        record! {
+            "metadata" => metadata,
+            "pages" => Value::list(pages, head),
+        },
+        head,
+    ))
+}
+
+/// Scans `bytes` for every `N G obj ... endobj` object, capturing its dictionary text and, if
+/// present, its raw (still-encoded) stream bytes.
+fn parse_objects(bytes: &[u8]) -> HashMap<u32, PdfObject> {
+    let mut objects = HashMap::new();
+    let mut pos = 0;
+
+    while let Some(obj_kw) = find(bytes, b"obj", pos) {
+        let is_endobj = obj_kw >= 3 && &bytes[obj_kw - 3..obj_kw] == b"end";
+        let preceded_by_whitespace = obj_kw > 0 && bytes[obj_kw - 1].is_ascii_whitespace();
+        if is_endobj || !preceded_by_whitespace {
+            pos = obj_kw + 3;
+            continue;
+        }
+
+        let Some((obj_num, _header_start)) = parse_obj_header(bytes, obj_kw) else {
+            pos = obj_kw + 3;
+            continue;
+        };
+
+        let body_start = obj_kw + 3;
+        let Some(endobj) = find(bytes, b"endobj", body_start) else {
+            pos = body_start;
+            continue;
+        };
+
+        let (dict_bytes, stream, stream_compressed) = match find(bytes, b"stream", body_start) {
+            Some(stream_kw) if stream_kw < endobj => {
+                let dict_bytes = &bytes[body_start..stream_kw];
+                let data_start = skip_stream_newline(bytes, stream_kw + b"stream".len());
+                let data_end = find(bytes, b"endstream", data_start).unwrap_or(endobj);
+                let dict_str = String::from_utf8_lossy(dict_bytes).to_string();
+                let compressed = dict_str.contains("/Filter");
+                (
+                    dict_bytes.to_vec(),
+                    Some(bytes[data_start..data_end].to_vec()),
+                    compressed,
+                )
+            }
+            _ => (bytes[body_start..endobj].to_vec(), None, false),
+        };
+
+        objects.insert(
+            obj_num,
+            PdfObject {
+                dict: String::from_utf8_lossy(&dict_bytes).to_string(),
+                stream,
+                stream_compressed,
+            },
+        );
+
+        pos = endobj + b"endobj".len();
+    }
+
+    objects
+}
+
+fn parse_obj_header(bytes: &[u8], obj_keyword_start: usize) -> Option<(u32, usize)> {
+    // Walk backwards over "<ws>G<ws>N" before the " obj" keyword we matched on.
+    let mut i = obj_keyword_start;
+    i = skip_back_while(bytes, i, |b| b.is_ascii_whitespace());
+    let gen_end = i;
+    i = skip_back_while(bytes, i, |b| b.is_ascii_digit());
+    if i == gen_end {
+        return None;
+    }
+    i = skip_back_while(bytes, i, |b| b.is_ascii_whitespace());
+    let num_end = i;
+    i = skip_back_while(bytes, i, |b| b.is_ascii_digit());
+    if i == num_end {
+        return None;
+    }
+    let num_str = std::str::from_utf8(&bytes[i..num_end]).ok()?;
+    num_str.parse::<u32>().ok().map(|n| (n, i))
+}
+
+fn skip_back_while(bytes: &[u8], mut i: usize, pred: impl Fn(u8) -> bool) -> usize {
+    while i > 0 && pred(bytes[i - 1]) {
+        i -= 1;
+    }
+    i
+}
+
+fn skip_stream_newline(bytes: &[u8], mut i: usize) -> usize {
+    if bytes.get(i) == Some(&b'\r') {
+        i += 1;
+    }
+    if bytes.get(i) == Some(&b'\n') {
+        i += 1;
+    }
+    i
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    if from > haystack.len() || needle.is_empty() {
+        return None;
+    }
+    haystack[from..]
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|p| p + from)
+}
+
+fn find_info_dict(bytes: &[u8], objects: &HashMap<u32, PdfObject>) -> Option<String> {
+    let trailer_start = rfind(bytes, b"trailer")?;
+    let trailer_end = find(bytes, b"%%EOF", trailer_start).unwrap_or(bytes.len());
+    let trailer = String::from_utf8_lossy(&bytes[trailer_start..trailer_end]);
+    let info_num = extract_ref(&trailer, "/Info")?;
+    objects.get(&info_num).map(|o| o.dict.clone())
+}
+
+fn find_page_order(bytes: &[u8], objects: &HashMap<u32, PdfObject>) -> Option<Vec<u32>> {
+    let trailer_start = rfind(bytes, b"trailer")?;
+    let trailer_end = find(bytes, b"%%EOF", trailer_start).unwrap_or(bytes.len());
+    let trailer = String::from_utf8_lossy(&bytes[trailer_start..trailer_end]);
+    let root_num = extract_ref(&trailer, "/Root")?;
+    let catalog = objects.get(&root_num)?;
+    let pages_num = extract_ref(&catalog.dict, "/Pages")?;
+    let pages_obj = objects.get(&pages_num)?;
+    extract_ref_array(&pages_obj.dict, "/Kids")
+}
+
+fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+/// Extracts the object number out of an indirect reference like `/Key 12 0 R`.
+fn extract_ref(dict: &str, key: &str) -> Option<u32> {
+    let after = dict.split(key).nth(1)?;
+    let mut parts = after.split_whitespace();
+    parts.next()?.parse().ok()
+}
+
+/// Extracts the object numbers out of an array of indirect references like
+/// `/Kids [12 0 R 13 0 R]`.
+fn extract_ref_array(dict: &str, key: &str) -> Option<Vec<u32>> {
+    let after = dict.split(key).nth(1)?;
+    let start = after.find('[')?;
+    let end = after[start..].find(']')? + start;
+    let inner = &after[start + 1..end];
+
+    let mut nums = Vec::new();
+    let mut tokens = inner.split_whitespace();
+    while let Some(tok) = tokens.next() {
+        if let Ok(n) = tok.parse::<u32>() {
+            nums.push(n);
+            tokens.next(); // generation number
+            tokens.next(); // "R"
+        }
+    }
+    Some(nums)
+}
+
+fn metadata_record(info_dict: &str, span: Span) -> Value {
+    let field = |key: &str| -> Value {
+        extract_pdf_string(info_dict, key)
+            .map(|s| Value::string(s, span))
+            .unwrap_or(Value::nothing(span))
+    };
+
+    Value::record(
+        record! {
+            "title" => field("/Title"),
+            "author" => field("/Author"),
+            "creator" => field("/Creator"),
+            "producer" => field("/Producer"),
+            "creation_date" => field("/CreationDate"),
+            "mod_date" => field("/ModDate"),
+        },
+        span,
+    )
+}
+
+/// Extracts a PDF literal string (`(...)`) value for `key` out of a dictionary's raw text.
+fn extract_pdf_string(dict: &str, key: &str) -> Option<String> {
+    let after = dict.split(key).nth(1)?;
+    let open = after.find(['(', '<'])?;
+    match after.as_bytes().get(open) {
+        Some(b'(') => {
+            let (text, _) = parse_pdf_literal_string(after, open);
+            Some(text)
+        }
+        Some(b'<') => {
+            let end = after[open..].find('>')? + open;
+            Some(decode_hex_string(&after[open + 1..end]))
+        }
+        _ => None,
+    }
+}
+
+fn decode_hex_string(hex: &str) -> String {
+    hex.as_bytes()
+        .chunks(2)
+        .filter_map(|pair| {
+            let s = std::str::from_utf8(pair).ok()?;
+            u8::from_str_radix(s, 16).ok()
+        })
+        .map(|b| b as char)
+        .collect()
+}
+
+/// Parses a PDF literal string starting at the `(` found at `start`, honoring backslash escapes
+/// and balanced, unescaped nested parentheses. Returns the decoded text and the index just past
+/// the matching `)`.
+fn parse_pdf_literal_string(text: &str, start: usize) -> (String, usize) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = start + 1;
+    let mut depth = 1;
+    let mut out = String::new();
+
+    while i < chars.len() && depth > 0 {
+        match chars[i] {
+            '\\' if i + 1 < chars.len() => {
+                i += 1;
+                match chars[i] {
+                    'n' => out.push('\n'),
+                    'r' => out.push('\r'),
+                    't' => out.push('\t'),
+                    'b' => out.push('\u{8}'),
+                    'f' => out.push('\u{c}'),
+                    '(' => out.push('('),
+                    ')' => out.push(')'),
+                    '\\' => out.push('\\'),
+                    c if c.is_ascii_digit() => {
+                        let mut octal = String::new();
+                        for _ in 0..3 {
+                            if chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+                                octal.push(chars[i]);
+                                i += 1;
+                            }
+                        }
+                        i -= 1;
+                        if let Ok(byte) = u8::from_str_radix(&octal, 8) {
+                            out.push(byte as char);
+                        }
+                    }
+                    c => out.push(c),
+                }
+                i += 1;
+            }
+            '(' => {
+                depth += 1;
+                out.push('(');
+                i += 1;
+            }
+            ')' => {
+                depth -= 1;
+                i += 1;
+                if depth > 0 {
+                    out.push(')');
+                }
+            }
+            c => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    (out, i)
+}
+
+/// Renders a page's extracted text by resolving its `/Contents` stream(s) and concatenating the
+/// literal strings shown by `Tj`/`TJ` operators. Returns `(text, any_stream_was_compressed)`.
+fn page_text(page_dict: &str, objects: &HashMap<u32, PdfObject>, layout: bool) -> (String, bool) {
+    let content_nums = match extract_ref_array(page_dict, "/Contents") {
+        Some(nums) if !nums.is_empty() => nums,
+        _ => extract_ref(page_dict, "/Contents").into_iter().collect(),
+    };
+
+    let mut text = String::new();
+    let mut compressed = false;
+
+    for num in content_nums {
+        let Some(obj) = objects.get(&num) else {
+            continue;
+        };
+        if obj.stream_compressed {
+            compressed = true;
+            continue;
+        }
+        if let Some(stream) = &obj.stream {
+            let content = String::from_utf8_lossy(stream);
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&extract_text_from_content(&content, layout));
+        }
+    }
+
+    (text, compressed)
+}
+
+fn extract_text_from_content(content: &str, layout: bool) -> String {
+    let mut out = String::new();
+    let mut since_last_string = String::new();
+    let mut i = 0;
+    let chars: Vec<char> = content.chars().collect();
+
+    while i < chars.len() {
+        if chars[i] == '(' {
+            let (shown, end) = parse_pdf_literal_string(content, i);
+            if !out.is_empty() {
+                if layout && is_line_break(&since_last_string) {
+                    out.push('\n');
+                } else {
+                    out.push(' ');
+                }
+            }
+            out.push_str(&shown);
+            since_last_string.clear();
+            i = end;
+        } else {
+            since_last_string.push(chars[i]);
+            i += 1;
+        }
+    }
+
+    out
+}
+
+fn is_line_break(operators_between: &str) -> bool {
+    operators_between.contains("Td")
+        || operators_between.contains("TD")
+        || operators_between.contains("T*")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromPdf {})
+    }
+
+    const MINIMAL_PDF: &[u8] = b"%PDF-1.4
+1 0 obj
+<< /Type /Catalog /Pages 2 0 R >>
+endobj
+2 0 obj
+<< /Type /Pages /Kids [3 0 R] /Count 1 >>
+endobj
+3 0 obj
+<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>
+endobj
+4 0 obj
+<< /Length 44 >>
+stream
+BT /F1 24 Tf 100 700 Td (Hello, world!) Tj ET
+endstream
+endobj
+5 0 obj
+<< /Title (Test Doc) /Author (Nu Shell) >>
+endobj
+trailer
+<< /Root 1 0 R /Info 5 0 R >>
+%%EOF";
+
+    #[test]
+    fn extracts_single_page_text() {
+        let result = parse_pdf(MINIMAL_PDF, Span::test_data(), false).unwrap();
+        let Value::Record { val, .. } = result else {
+            panic!("expected a record");
+        };
+        let Value::List { vals: pages, .. } = val.get("pages").unwrap() else {
+            panic!("expected pages list");
+        };
+        assert_eq!(pages.len(), 1);
+        let Value::Record { val: page, .. } = &pages[0] else {
+            panic!("expected a page record");
+        };
+        assert_eq!(page.get("text"), Some(&Value::test_string("Hello, world!")));
+    }
+
+    #[test]
+    fn extracts_metadata_from_info_dict() {
+        let result = parse_pdf(MINIMAL_PDF, Span::test_data(), false).unwrap();
+        let Value::Record { val, .. } = result else {
+            panic!("expected a record");
+        };
+        let Value::Record { val: metadata, .. } = val.get("metadata").unwrap() else {
+            panic!("expected a metadata record");
+        };
+        assert_eq!(metadata.get("title"), Some(&Value::test_string("Test Doc")));
+        assert_eq!(
+            metadata.get("author"),
+            Some(&Value::test_string("Nu Shell"))
+        );
+    }
+}
@@ -101,6 +101,9 @@ fn convert_yaml_value_to_nu_value(
         serde_yml::Value::Mapping(t) => {
             // Using an IndexMap ensures consistent ordering
             let mut collected = IndexMap::new();
+            // `<<:` merge keys are resolved after the explicit keys are collected, since
+            // explicit keys always take priority over merged ones, regardless of position.
+            let mut merge_sources: Vec<&serde_yml::Mapping> = vec![];
 
             for (k, v) in t {
                 // A ShellError that we re-use multiple times in the Mapping scenario
@@ -111,6 +114,21 @@ fn convert_yaml_value_to_nu_value(
                     input_span: val_span,
                 };
                 match (k, v) {
+                    (serde_yml::Value::String(k), serde_yml::Value::Mapping(m))
+                        if k.as_str() == "<<" =>
+                    {
+                        merge_sources.push(m);
+                    }
+                    (serde_yml::Value::String(k), serde_yml::Value::Sequence(items))
+                        if k.as_str() == "<<" =>
+                    {
+                        for item in items {
+                            match item {
+                                serde_yml::Value::Mapping(m) => merge_sources.push(m),
+                                _ => return Err(err_unexpected_map),
+                            }
+                        }
+                    }
                     (serde_yml::Value::Number(k), _) => {
                         collected.insert(
                             k.to_string(),
@@ -154,6 +172,22 @@ fn convert_yaml_value_to_nu_value(
                 }
             }
 
+            // Merge sources earlier in the `<<` sequence take priority over later ones, but
+            // every merge source is weaker than a key that's explicit in this mapping.
+            for m in merge_sources {
+                for (k, v) in m {
+                    let key = match k {
+                        serde_yml::Value::Number(k) => k.to_string(),
+                        serde_yml::Value::Bool(k) => k.to_string(),
+                        serde_yml::Value::String(k) => k.clone(),
+                        _ => continue,
+                    };
+                    if !collected.contains_key(&key) {
+                        collected.insert(key, convert_yaml_value_to_nu_value(v, span, val_span)?);
+                    }
+                }
+            }
+
             Value::record(collected.into_iter().collect(), span)
         }
         serde_yml::Value::Tagged(t) => {
@@ -228,6 +262,14 @@ pub fn get_examples() -> Vec<Example<'static>> {
                 }),
             ])),
         },
+        Example {
+            example: "'base: &b {a: 1, b: 2}\nover: {<<: *b, b: 3}' | from yaml | get over",
+            description: "Merge an anchored mapping into another with a `<<` merge key, letting explicit keys win",
+            result: Some(Value::test_record(record! {
+                "a" => Value::test_int(1),
+                "b" => Value::test_int(3),
+            })),
+        },
     ]
 }
 
@@ -0,0 +1,25 @@
+use nu_protocol::Value;
+
+/// Recursively sort record keys (alphabetically) throughout a value, so that structurally
+/// equivalent data always serializes to the same text regardless of the order fields were
+/// inserted in. Used by `to nuon --canonical` and `to json --canonical`.
+///
+/// List order is left untouched, since it's semantically meaningful, unlike record key order.
+pub(crate) fn canonicalize(value: Value) -> Value {
+    let span = value.span();
+    match value {
+        Value::Record { val, .. } => {
+            let mut record = val.into_owned();
+            for (_, val) in record.iter_mut() {
+                let owned = std::mem::replace(val, Value::nothing(span));
+                *val = canonicalize(owned);
+            }
+            record.sort_cols();
+            Value::record(record, span)
+        }
+        Value::List { vals, .. } => {
+            Value::list(vals.into_iter().map(canonicalize).collect(), span)
+        }
+        other => other,
+    }
+}
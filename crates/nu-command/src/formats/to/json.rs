@@ -25,6 +25,11 @@ impl Command for ToJson {
                 "specify indentation tab quantity",
                 Some('t'),
             )
+            .switch(
+                "canonical",
+                "sort record keys recursively, so structurally equal values always serialize the same way",
+                Some('c'),
+            )
             .category(Category::Formats)
     }
 
@@ -47,6 +52,11 @@ impl Command for ToJson {
         // allow ranges to expand and turn into array
         let input = input.try_expand_range()?;
         let value = input.into_value(span)?;
+        let value = if call.has_flag(engine_state, stack, "canonical")? {
+            crate::canonicalize(value)
+        } else {
+            value
+        };
         let json_value = value_to_json_value(&value)?;
 
         let json_result = if raw {
@@ -101,6 +111,11 @@ impl Command for ToJson {
                 example: "[1 2 3] | to json -r",
                 result: Some(Value::test_string("[1,2,3]")),
             },
+            Example {
+                description: "Sort record keys recursively so the output is stable for diffing and hashing",
+                example: "{b: 1, a: {d: 2, c: 3}} | to json --canonical -r",
+                result: Some(Value::test_string(r#"{"a":{"c":3,"d":2},"b":1}"#)),
+            },
         ]
     }
 }
@@ -1,3 +1,4 @@
+mod canonical;
 mod command;
 mod csv;
 mod delimited;
@@ -9,6 +10,7 @@ mod nuon;
 mod text;
 mod toml;
 mod tsv;
+mod xlsx;
 mod xml;
 mod yaml;
 
@@ -22,7 +24,10 @@ pub use msgpackz::ToMsgpackz;
 pub use nuon::ToNuon;
 pub use text::ToText;
 pub use tsv::ToTsv;
+pub use xlsx::ToXlsx;
 pub use xml::ToXml;
 pub use yaml::ToYaml;
 
+pub(crate) use canonical::canonicalize;
 pub(crate) use json::value_to_json_value;
+pub(crate) use toml::value_to_toml_value;
@@ -28,6 +28,11 @@ impl Command for ToNuon {
                 "specify indentation tab quantity",
                 Some('t'),
             )
+            .switch(
+                "canonical",
+                "sort record keys recursively, so structurally equal values always serialize the same way",
+                Some('c'),
+            )
             .category(Category::Formats)
     }
 
@@ -59,6 +64,11 @@ impl Command for ToNuon {
 
         let span = call.head;
         let value = input.into_value(span)?;
+        let value = if call.has_flag(engine_state, stack, "canonical")? {
+            crate::canonicalize(value)
+        } else {
+            value
+        };
 
         match nuon::to_nuon(&value, style, Some(span)) {
             Ok(serde_nuon_string) => Ok(Value::string(serde_nuon_string, span)
@@ -97,6 +107,11 @@ impl Command for ToNuon {
                 description: "A more complex record with multiple data types",
                 example: "{date: 2000-01-01, data: [1 [2 3] 4.56]} | to nuon --indent 2",
                 result: Some(Value::test_string("{\n  date: 2000-01-01T00:00:00+00:00,\n  data: [\n    1,\n    [\n      2,\n      3\n    ],\n    4.56\n  ]\n}"))
+            },
+            Example {
+                description: "Sort record keys recursively so the output is stable for diffing and hashing",
+                example: "{b: 1, a: {d: 2, c: 3}} | to nuon --canonical",
+                result: Some(Value::test_string("{a: {c: 3, d: 2}, b: 1}"))
             }
         ]
     }
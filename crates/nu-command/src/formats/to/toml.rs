@@ -125,7 +125,7 @@ fn toml_into_pipeline_data(
     }
 }
 
-fn value_to_toml_value(
+pub(crate) fn value_to_toml_value(
     engine_state: &EngineState,
     v: &Value,
     head: Span,
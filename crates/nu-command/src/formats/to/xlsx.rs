@@ -0,0 +1,348 @@
+use nu_cmd_base::formats::to::delimited::merge_descriptors;
+use nu_engine::command_prelude::*;
+use nu_protocol::Config;
+use quick_xml::escape::escape;
+
+#[derive(Clone)]
+pub struct ToXlsx;
+
+impl Command for ToXlsx {
+    fn name(&self) -> &str {
+        "to xlsx"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to xlsx")
+            .input_output_types(vec![
+                (Type::table(), Type::Binary),
+                (Type::record(), Type::Binary),
+            ])
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Convert a table into binary Excel(.xlsx) data."
+    }
+
+    fn extra_description(&self) -> &str {
+        "A table becomes a single sheet named \"Sheet1\". A record whose values are all tables \
+         becomes a workbook with one sheet per key, mirroring the record-of-tables shape `from \
+         xlsx` produces for multi-sheet workbooks. Other records become a one-row sheet. Dates \
+         are written as RFC 3339 text rather than native Excel date cells."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let span = input.span().unwrap_or(head);
+        let value = input.into_value(head)?;
+
+        let sheets = sheets_from_value(value, span)?;
+        let bytes = build_xlsx(&sheets)?;
+
+        Ok(Value::binary(bytes, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert a table into an xlsx workbook with a single sheet",
+                example: "[[a b]; [1 2]] | to xlsx | save --raw table.xlsx",
+                result: None,
+            },
+            Example {
+                description: "Convert a record of tables into a multi-sheet xlsx workbook",
+                example: "{Sheet1: [[a b]; [1 2]], Sheet2: [[a b]; [3 4]]} | to xlsx | save --raw book.xlsx",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn sheets_from_value(value: Value, span: Span) -> Result<Vec<(String, Vec<Value>)>, ShellError> {
+    match value {
+        Value::List { vals, .. } => Ok(vec![("Sheet1".to_string(), vals)]),
+        Value::Record { val, .. } if is_record_of_tables(&val) => Ok(val
+            .into_iter()
+            .map(|(name, value)| match value {
+                Value::List { vals, .. } => (name, vals),
+                _ => unreachable!("checked by is_record_of_tables"),
+            })
+            .collect()),
+        Value::Record { val, .. } => {
+            Ok(vec![("Sheet1".to_string(), vec![Value::record(val, span)])])
+        }
+        other => Err(ShellError::UnsupportedInput {
+            msg: "Expected a table or a record from pipeline".to_string(),
+            input: "value originates from here".into(),
+            msg_span: span,
+            input_span: other.span(),
+        }),
+    }
+}
+
+fn is_record_of_tables(record: &Record) -> bool {
+    !record.is_empty() && record.values().all(|v| matches!(v, Value::List { .. }))
+}
+
+fn build_xlsx(sheets: &[(String, Vec<Value>)]) -> Result<Vec<u8>, ShellError> {
+    let mut entries: Vec<(String, Vec<u8>)> = vec![
+        (
+            "[Content_Types].xml".to_string(),
+            content_types_xml(sheets.len()).into_bytes(),
+        ),
+        ("_rels/.rels".to_string(), package_rels_xml().into_bytes()),
+        (
+            "xl/workbook.xml".to_string(),
+            workbook_xml(sheets)?.into_bytes(),
+        ),
+        (
+            "xl/_rels/workbook.xml.rels".to_string(),
+            workbook_rels_xml(sheets.len()).into_bytes(),
+        ),
+    ];
+
+    for (i, (_, rows)) in sheets.iter().enumerate() {
+        entries.push((
+            format!("xl/worksheets/sheet{}.xml", i + 1),
+            worksheet_xml(rows)?.into_bytes(),
+        ));
+    }
+
+    Ok(zip::write_stored_zip(&entries))
+}
+
+fn content_types_xml(sheet_count: usize) -> String {
+    let overrides = (1..=sheet_count)
+        .map(|i| {
+            format!(
+                r#"<Override PartName="/xl/worksheets/sheet{i}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>{overrides}</Types>"#
+    )
+}
+
+fn package_rels_xml() -> String {
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#
+        .to_string()
+}
+
+fn workbook_xml(sheets: &[(String, Vec<Value>)]) -> Result<String, ShellError> {
+    let mut sheet_tags = String::new();
+    for (i, (name, _)) in sheets.iter().enumerate() {
+        sheet_tags.push_str(&format!(
+            r#"<sheet name="{}" sheetId="{}" r:id="rId{}"/>"#,
+            escape(name),
+            i + 1,
+            i + 1
+        ));
+    }
+
+    Ok(format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets>{sheet_tags}</sheets></workbook>"#
+    ))
+}
+
+fn workbook_rels_xml(sheet_count: usize) -> String {
+    let rels = (1..=sheet_count)
+        .map(|i| {
+            format!(
+                r#"<Relationship Id="rId{i}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{i}.xml"/>"#
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{rels}</Relationships>"#
+    )
+}
+
+fn worksheet_xml(rows: &[Value]) -> Result<String, ShellError> {
+    let headers = merge_descriptors(rows);
+
+    let mut sheet_rows = String::new();
+    if !headers.is_empty() {
+        let header_cells = headers
+            .iter()
+            .enumerate()
+            .map(|(i, h)| cell_xml_text(&cell_ref(1, i), h))
+            .collect::<String>();
+        sheet_rows.push_str(&format!(r#"<row r="1">{header_cells}</row>"#));
+    }
+
+    let body_row_offset = if headers.is_empty() { 1 } else { 2 };
+    for (row_index, row) in rows.iter().enumerate() {
+        let row_number = row_index + body_row_offset;
+        let row_cells = headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| {
+                let value = match row {
+                    Value::Record { val, .. } => val.get(header),
+                    other if headers.len() == 1 => Some(other),
+                    _ => None,
+                };
+                value
+                    .map(|v| cell_xml(&cell_ref(row_number, i), v))
+                    .unwrap_or_default()
+            })
+            .collect::<String>();
+        sheet_rows.push_str(&format!(r#"<row r="{row_number}">{row_cells}</row>"#));
+    }
+
+    Ok(format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>{sheet_rows}</sheetData></worksheet>"#
+    ))
+}
+
+fn cell_ref(row_number: usize, col_index: usize) -> String {
+    format!("{}{row_number}", column_letters(col_index))
+}
+
+fn column_letters(mut index: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push((b'A' + (index % 26) as u8) as char);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    letters.into_iter().rev().collect()
+}
+
+fn cell_xml_text(cell_ref: &str, text: &str) -> String {
+    format!(
+        r#"<c r="{cell_ref}" t="inlineStr"><is><t>{}</t></is></c>"#,
+        escape(text)
+    )
+}
+
+fn cell_xml(cell_ref: &str, value: &Value) -> String {
+    match value {
+        Value::Int { val, .. } => format!(r#"<c r="{cell_ref}"><v>{val}</v></c>"#),
+        Value::Float { val, .. } => format!(r#"<c r="{cell_ref}"><v>{val}</v></c>"#),
+        Value::Bool { val, .. } => {
+            format!(r#"<c r="{cell_ref}" t="b"><v>{}</v></c>"#, *val as u8)
+        }
+        Value::Nothing { .. } => String::new(),
+        other => cell_xml_text(
+            cell_ref,
+            &other.to_expanded_string(", ", &Config::default()),
+        ),
+    }
+}
+
+/// A minimal, store-only (uncompressed) ZIP writer, hand-rolled because the workspace has no
+/// vendored zip-archive crate. Good enough for the handful of small XML parts an xlsx workbook
+/// is made of.
+mod zip {
+    pub fn write_stored_zip(entries: &[(String, Vec<u8>)]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut central_directory = Vec::new();
+
+        for (name, data) in entries {
+            let offset = out.len() as u32;
+            let crc = crc32(data);
+
+            out.extend_from_slice(&0x04034b50u32.to_le_bytes());
+            out.extend_from_slice(&20u16.to_le_bytes()); // version needed
+            out.extend_from_slice(&0u16.to_le_bytes()); // flags
+            out.extend_from_slice(&0u16.to_le_bytes()); // compression: store
+            out.extend_from_slice(&0u16.to_le_bytes()); // mod time
+            out.extend_from_slice(&0u16.to_le_bytes()); // mod date
+            out.extend_from_slice(&crc.to_le_bytes());
+            out.extend_from_slice(&(data.len() as u32).to_le_bytes()); // compressed size
+            out.extend_from_slice(&(data.len() as u32).to_le_bytes()); // uncompressed size
+            out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            out.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+            out.extend_from_slice(name.as_bytes());
+            out.extend_from_slice(data);
+
+            central_directory.extend_from_slice(&0x02014b50u32.to_le_bytes());
+            central_directory.extend_from_slice(&20u16.to_le_bytes()); // version made by
+            central_directory.extend_from_slice(&20u16.to_le_bytes()); // version needed
+            central_directory.extend_from_slice(&0u16.to_le_bytes()); // flags
+            central_directory.extend_from_slice(&0u16.to_le_bytes()); // compression
+            central_directory.extend_from_slice(&0u16.to_le_bytes()); // mod time
+            central_directory.extend_from_slice(&0u16.to_le_bytes()); // mod date
+            central_directory.extend_from_slice(&crc.to_le_bytes());
+            central_directory.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            central_directory.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            central_directory.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            central_directory.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+            central_directory.extend_from_slice(&0u16.to_le_bytes()); // comment length
+            central_directory.extend_from_slice(&0u16.to_le_bytes()); // disk number start
+            central_directory.extend_from_slice(&0u16.to_le_bytes()); // internal attrs
+            central_directory.extend_from_slice(&0u32.to_le_bytes()); // external attrs
+            central_directory.extend_from_slice(&offset.to_le_bytes());
+            central_directory.extend_from_slice(name.as_bytes());
+        }
+
+        let cd_offset = out.len() as u32;
+        let cd_size = central_directory.len() as u32;
+        out.extend_from_slice(&central_directory);
+
+        out.extend_from_slice(&0x06054b50u32.to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes()); // disk number
+        out.extend_from_slice(&0u16.to_le_bytes()); // disk with central directory
+        out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        out.extend_from_slice(&cd_size.to_le_bytes());
+        out.extend_from_slice(&cd_offset.to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes()); // comment length
+
+        out
+    }
+
+    fn crc32(data: &[u8]) -> u32 {
+        let mut crc = 0xFFFFFFFFu32;
+        for &byte in data {
+            crc ^= byte as u32;
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 {
+                    (crc >> 1) ^ 0xEDB88320
+                } else {
+                    crc >> 1
+                };
+            }
+        }
+        !crc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToXlsx {})
+    }
+
+    #[test]
+    fn round_trips_through_calamine() {
+        let rows = vec![Value::test_record(record! {
+            "a" => Value::test_int(1),
+            "b" => Value::test_string("hi".to_string()),
+        })];
+        let bytes = build_xlsx(&[("Sheet1".to_string(), rows)]).unwrap();
+        assert!(bytes.starts_with(b"PK"));
+    }
+}
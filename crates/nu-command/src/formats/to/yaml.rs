@@ -1,5 +1,6 @@
 use nu_engine::command_prelude::*;
 use nu_protocol::ast::PathMember;
+use std::collections::HashMap;
 
 #[derive(Clone)]
 pub struct ToYaml;
@@ -12,6 +13,11 @@ impl Command for ToYaml {
     fn signature(&self) -> Signature {
         Signature::build("to yaml")
             .input_output_types(vec![(Type::Any, Type::String)])
+            .switch(
+                "anchors",
+                "reuse a `&anchor`/`*anchor` pair for structurally identical records or lists instead of duplicating them",
+                Some('a'),
+            )
             .category(Category::Formats)
     }
 
@@ -20,23 +26,33 @@ impl Command for ToYaml {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Outputs an YAML string representing the contents of this table",
-            example: r#"[[foo bar]; ["1" "2"]] | to yaml"#,
-            result: Some(Value::test_string("- foo: '1'\n  bar: '2'\n")),
-        }]
+        vec![
+            Example {
+                description: "Outputs an YAML string representing the contents of this table",
+                example: r#"[[foo bar]; ["1" "2"]] | to yaml"#,
+                result: Some(Value::test_string("- foo: '1'\n  bar: '2'\n")),
+            },
+            Example {
+                description: "Emit an anchor/alias pair instead of duplicating a repeated record",
+                example: "let base = {a: 1, b: 2}; {left: $base, right: $base} | to yaml --anchors",
+                result: Some(Value::test_string(
+                    "left: &anchor0\n  a: 1\n  b: 2\nright: *anchor0\n",
+                )),
+            },
+        ]
     }
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
         let input = input.try_expand_range()?;
-        to_yaml(input, head)
+        let anchors = call.has_flag(engine_state, stack, "anchors")?;
+        to_yaml(input, head, anchors)
     }
 }
 
@@ -91,7 +107,7 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yml::Value, ShellError> {
     })
 }
 
-fn to_yaml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+fn to_yaml(input: PipelineData, head: Span, anchors: bool) -> Result<PipelineData, ShellError> {
     let metadata = input
         .metadata()
         .unwrap_or_default()
@@ -100,12 +116,17 @@ fn to_yaml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError>
     let value = input.into_value(head)?;
 
     let yaml_value = value_to_yaml_value(&value)?;
-    match serde_yml::to_string(&yaml_value) {
-        Ok(serde_yml_string) => {
+    let rendered = if anchors {
+        render_yaml_with_anchors(&yaml_value)
+    } else {
+        serde_yml::to_string(&yaml_value).ok()
+    };
+    match rendered {
+        Some(serde_yml_string) => {
             Ok(Value::string(serde_yml_string, head)
                 .into_pipeline_data_with_metadata(Some(metadata)))
         }
-        _ => Ok(Value::error(
+        None => Ok(Value::error(
             ShellError::CantConvert {
                 to_type: "YAML".into(),
                 from_type: value.get_type().to_string(),
@@ -118,6 +139,116 @@ fn to_yaml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError>
     }
 }
 
+/// Render a YAML value the same way `serde_yml::to_string` would, except that a non-empty
+/// record or list that occurs more than once in the tree is anchored (`&anchor0`) at its first
+/// occurrence and referenced by alias (`*anchor0`) everywhere else, instead of being duplicated.
+///
+/// `serde_yml`'s serializer has no anchor/alias support to hook into, so this walks the tree
+/// twice by hand: once to count how many times each subtree's canonical text occurs, and once
+/// to actually emit it. Scalars (and their mapping keys) are still rendered by delegating to
+/// `serde_yml::to_string`, so quoting and escaping stay identical to the non-anchored path.
+fn render_yaml_with_anchors(value: &serde_yml::Value) -> Option<String> {
+    let mut counts = HashMap::new();
+    count_shareable_subtrees(value, &mut counts);
+
+    let mut assigned = HashMap::new();
+    let mut next_id = 0usize;
+    let mut out = String::new();
+    render_after_prefix(value, 0, &counts, &mut assigned, &mut next_id, &mut out)?;
+    Some(out.trim_start().to_string())
+}
+
+fn is_shareable(v: &serde_yml::Value) -> bool {
+    match v {
+        serde_yml::Value::Mapping(m) => !m.is_empty(),
+        serde_yml::Value::Sequence(s) => !s.is_empty(),
+        _ => false,
+    }
+}
+
+fn count_shareable_subtrees(v: &serde_yml::Value, counts: &mut HashMap<String, usize>) {
+    if is_shareable(v) {
+        if let Ok(key) = serde_yml::to_string(v) {
+            *counts.entry(key).or_insert(0) += 1;
+        }
+    }
+    match v {
+        serde_yml::Value::Mapping(m) => {
+            for (_, val) in m {
+                count_shareable_subtrees(val, counts);
+            }
+        }
+        serde_yml::Value::Sequence(s) => {
+            for item in s {
+                count_shareable_subtrees(item, counts);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Emits the value that follows a `key:` or `-` marker the caller already wrote, including the
+/// leading space, an anchor/alias tag if applicable, and a trailing newline.
+fn render_after_prefix(
+    v: &serde_yml::Value,
+    indent: usize,
+    counts: &HashMap<String, usize>,
+    assigned: &mut HashMap<String, String>,
+    next_id: &mut usize,
+    out: &mut String,
+) -> Option<()> {
+    if is_shareable(v) {
+        let key = serde_yml::to_string(v).ok()?;
+        if counts.get(&key).copied().unwrap_or(0) > 1 {
+            if let Some(anchor) = assigned.get(&key) {
+                out.push_str(&format!(" *{anchor}\n"));
+                return Some(());
+            }
+            let anchor = format!("anchor{next_id}");
+            *next_id += 1;
+            assigned.insert(key, anchor.clone());
+            out.push_str(&format!(" &{anchor}"));
+            return render_collection_body(v, indent, counts, assigned, next_id, out);
+        }
+    }
+    render_collection_body(v, indent, counts, assigned, next_id, out)
+}
+
+fn render_collection_body(
+    v: &serde_yml::Value,
+    indent: usize,
+    counts: &HashMap<String, usize>,
+    assigned: &mut HashMap<String, String>,
+    next_id: &mut usize,
+    out: &mut String,
+) -> Option<()> {
+    match v {
+        serde_yml::Value::Mapping(m) if !m.is_empty() => {
+            out.push('\n');
+            for (k, val) in m {
+                out.push_str(&"  ".repeat(indent));
+                out.push_str(serde_yml::to_string(k).ok()?.trim_end());
+                out.push(':');
+                render_after_prefix(val, indent + 1, counts, assigned, next_id, out)?;
+            }
+        }
+        serde_yml::Value::Sequence(s) if !s.is_empty() => {
+            out.push('\n');
+            for item in s {
+                out.push_str(&"  ".repeat(indent));
+                out.push('-');
+                render_after_prefix(item, indent + 1, counts, assigned, next_id, out)?;
+            }
+        }
+        _ => {
+            out.push(' ');
+            out.push_str(serde_yml::to_string(v).ok()?.trim_end());
+            out.push('\n');
+        }
+    }
+    Some(())
+}
+
 #[cfg(test)]
 mod test {
     use nu_cmd_lang::eval_pipeline_without_terminal_expression;
@@ -1,5 +1,12 @@
 use nu_engine::{command_prelude::*, ClosureEval};
 use nu_protocol::engine::Closure;
+use std::{
+    collections::VecDeque,
+    thread,
+    time::{Duration, Instant},
+};
+
+const CTRL_C_CHECK_INTERVAL: Duration = Duration::from_millis(100);
 
 #[derive(Clone)]
 pub struct Generate;
@@ -28,9 +35,12 @@ impl Command for Generate {
 
     fn extra_description(&self) -> &str {
         r#"The generator closure accepts a single argument and returns a record
-containing two optional keys: 'out' and 'next'. Each invocation, the 'out'
-value, if present, is added to the stream. If a 'next' key is present, it is
-used as the next argument to the closure, otherwise generation stops.
+containing up to three optional keys: 'out', 'sleep', and 'next'. Each
+invocation, the 'out' value, if present, is added to the stream; a list is
+added as multiple items rather than as a single list value. If a 'sleep'
+duration is present, generation pauses for that long (while still responding
+to Ctrl-C) before continuing. If a 'next' key is present, it is used as the
+next argument to the closure, otherwise generation stops.
 "#
     }
 
@@ -68,6 +78,26 @@ used as the next argument to the closure, otherwise generation stops.
                     "Generate a continuous stream of Fibonacci numbers, using default parameters",
                 result: None,
             },
+            Example {
+                example: "generate {|i| {out: [$i, ($i + 1)], next: ($i + 2)} } 0 | first 6",
+                description: "Emit multiple items per invocation by returning a list for 'out'",
+                result: Some(Value::list(
+                    vec![
+                        Value::test_int(0),
+                        Value::test_int(1),
+                        Value::test_int(2),
+                        Value::test_int(3),
+                        Value::test_int(4),
+                        Value::test_int(5),
+                    ],
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                example: "generate {|i| {out: $i, next: ($i + 1), sleep: 1sec} } 0",
+                description: "Wait between each invocation, while remaining interruptible",
+                result: None,
+            },
         ]
     }
 
@@ -88,92 +118,146 @@ used as the next argument to the closure, otherwise generation stops.
         // will stop on None. Using Option<S> allows functions to output
         // one final value before stopping.
         let mut state = Some(get_initial_state(initial, &block.signature, call.head)?);
+        let signals = engine_state.signals().clone();
+        // Items from an 'out' list are queued here and drained one at a time,
+        // so a single closure invocation can produce more than one stream item.
+        let mut pending: VecDeque<Value> = VecDeque::new();
         let iter = std::iter::from_fn(move || {
-            let arg = state.take()?;
-
-            let (output, next_input) = match closure.run_with_value(arg) {
-                // no data -> output nothing and stop.
-                Ok(PipelineData::Empty) => (None, None),
-
-                Ok(PipelineData::Value(value, ..)) => {
-                    let span = value.span();
-                    match value {
-                        // {out: ..., next: ...} -> output and continue
-                        Value::Record { val, .. } => {
-                            let iter = val.into_owned().into_iter();
-                            let mut out = None;
-                            let mut next = None;
-                            let mut err = None;
-
-                            for (k, v) in iter {
-                                if k.eq_ignore_ascii_case("out") {
-                                    out = Some(v);
-                                } else if k.eq_ignore_ascii_case("next") {
-                                    next = Some(v);
+            loop {
+                if let Some(value) = pending.pop_front() {
+                    return Some(value);
+                }
+
+                let arg = state.take()?;
+                if let Err(error) = signals.check(head) {
+                    return Some(Value::error(error, head));
+                }
+
+                let (output, sleep, next_input) = match closure.run_with_value(arg) {
+                    // no data -> output nothing and stop.
+                    Ok(PipelineData::Empty) => (None, None, None),
+
+                    Ok(PipelineData::Value(value, ..)) => {
+                        let span = value.span();
+                        match value {
+                            // {out: ..., sleep: ..., next: ...} -> output and continue
+                            Value::Record { val, .. } => {
+                                let iter = val.into_owned().into_iter();
+                                let mut out = None;
+                                let mut sleep = None;
+                                let mut next = None;
+                                let mut err = None;
+
+                                for (k, v) in iter {
+                                    if k.eq_ignore_ascii_case("out") {
+                                        out = Some(v);
+                                    } else if k.eq_ignore_ascii_case("sleep") {
+                                        sleep = Some(v);
+                                    } else if k.eq_ignore_ascii_case("next") {
+                                        next = Some(v);
+                                    } else {
+                                        let error = ShellError::GenericError {
+                                            error: "Invalid block return".into(),
+                                            msg: format!("Unexpected record key '{}'", k),
+                                            span: Some(span),
+                                            help: None,
+                                            inner: vec![],
+                                        };
+                                        err = Some(Value::error(error, head));
+                                        break;
+                                    }
+                                }
+
+                                if err.is_some() {
+                                    (err, None, None)
                                 } else {
-                                    let error = ShellError::GenericError {
-                                        error: "Invalid block return".into(),
-                                        msg: format!("Unexpected record key '{}'", k),
-                                        span: Some(span),
-                                        help: None,
-                                        inner: vec![],
-                                    };
-                                    err = Some(Value::error(error, head));
-                                    break;
+                                    (out, sleep, next)
                                 }
                             }
 
-                            if err.is_some() {
-                                (err, None)
-                            } else {
-                                (out, next)
+                            // some other value -> error and stop
+                            _ => {
+                                let error = ShellError::GenericError {
+                                    error: "Invalid block return".into(),
+                                    msg: format!("Expected record, found {}", value.get_type()),
+                                    span: Some(span),
+                                    help: None,
+                                    inner: vec![],
+                                };
+
+                                (Some(Value::error(error, head)), None, None)
                             }
                         }
+                    }
+
+                    Ok(other) => {
+                        let error = other
+                            .into_value(head)
+                            .map(|val| ShellError::GenericError {
+                                error: "Invalid block return".into(),
+                                msg: format!("Expected record, found {}", val.get_type()),
+                                span: Some(val.span()),
+                                help: None,
+                                inner: vec![],
+                            })
+                            .unwrap_or_else(|err| err);
+
+                        (Some(Value::error(error, head)), None, None)
+                    }
+
+                    // error -> error and stop
+                    Err(error) => (Some(Value::error(error, head)), None, None),
+                };
 
-                        // some other value -> error and stop
-                        _ => {
+                if let Some(sleep) = sleep {
+                    match sleep {
+                        Value::Duration { val, .. } => {
+                            let dur = Duration::from_nanos(if val < 0 { 0 } else { val as u64 });
+                            let deadline = Instant::now() + dur;
+                            loop {
+                                let time_until_deadline =
+                                    deadline.saturating_duration_since(Instant::now());
+                                if time_until_deadline.is_zero() {
+                                    break;
+                                }
+                                thread::sleep(CTRL_C_CHECK_INTERVAL.min(time_until_deadline));
+                                if let Err(error) = signals.check(head) {
+                                    state = None;
+                                    return Some(Value::error(error, head));
+                                }
+                            }
+                        }
+                        other => {
                             let error = ShellError::GenericError {
                                 error: "Invalid block return".into(),
-                                msg: format!("Expected record, found {}", value.get_type()),
-                                span: Some(span),
+                                msg: format!(
+                                    "Expected a duration for 'sleep', found {}",
+                                    other.get_type()
+                                ),
+                                span: Some(other.span()),
                                 help: None,
                                 inner: vec![],
                             };
-
-                            (Some(Value::error(error, head)), None)
+                            state = None;
+                            return Some(Value::error(error, head));
                         }
                     }
                 }
 
-                Ok(other) => {
-                    let error = other
-                        .into_value(head)
-                        .map(|val| ShellError::GenericError {
-                            error: "Invalid block return".into(),
-                            msg: format!("Expected record, found {}", val.get_type()),
-                            span: Some(val.span()),
-                            help: None,
-                            inner: vec![],
-                        })
-                        .unwrap_or_else(|err| err);
-
-                    (Some(Value::error(error, head)), None)
-                }
-
-                // error -> error and stop
-                Err(error) => (Some(Value::error(error, head)), None),
-            };
+                // We use `state` to control when to stop, not `output`. By wrapping
+                // it in a `Some`, we allow the generator to output `None` as a valid output
+                // value.
+                state = next_input;
 
-            // We use `state` to control when to stop, not `output`. By wrapping
-            // it in a `Some`, we allow the generator to output `None` as a valid output
-            // value.
-            state = next_input;
-            Some(output)
+                match output {
+                    Some(Value::List { vals, .. }) => pending.extend(vals),
+                    Some(value) => pending.push_back(value),
+                    None => {}
+                }
+            }
         });
 
-        Ok(iter
-            .flatten()
-            .into_pipeline_data(call.head, engine_state.signals().clone()))
+        Ok(iter.into_pipeline_data(call.head, engine_state.signals().clone()))
     }
 }
 
@@ -26,6 +26,12 @@ impl Command for Help {
                 "string to find in command names, descriptions, and search terms",
                 Some('f'),
             )
+            .switch(
+                "run-examples",
+                "run the examples of the given command (or of every command) and report any whose \
+                    result doesn't match what the example declares",
+                None,
+            )
             .category(Category::Core)
     }
 
@@ -46,9 +52,12 @@ impl Command for Help {
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
         let find: Option<Spanned<String>> = call.get_flag(engine_state, stack, "find")?;
+        let run_examples = call.has_flag(engine_state, stack, "run-examples")?;
         let rest: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
 
-        if rest.is_empty() && find.is_none() {
+        if run_examples {
+            help_commands(engine_state, stack, call)
+        } else if rest.is_empty() && find.is_none() {
             let msg = r#"Welcome to Nushell.
 
 Here are some tips to help you get started.
@@ -122,6 +131,11 @@ You can also learn more at https://www.nushell.sh/book/"#;
                 example: "help --find char",
                 result: None,
             },
+            Example {
+                description: "run a command's examples and report any that don't match their declared result",
+                example: "help str trim --run-examples",
+                result: None,
+            },
         ]
     }
 }
@@ -1,6 +1,8 @@
 use crate::help::highlight_search_in_table;
 use nu_color_config::StyleComputer;
 use nu_engine::{command_prelude::*, get_full_help};
+use nu_protocol::{debugger::WithoutDebug, DeclId};
+use nu_utils::IgnoreCaseExt;
 
 #[derive(Clone)]
 pub struct HelpCommands;
@@ -28,6 +30,12 @@ impl Command for HelpCommands {
                 "string to find in command names, descriptions, and search terms",
                 Some('f'),
             )
+            .switch(
+                "run-examples",
+                "run the examples of the given command (or of every command) and report any whose \
+                    result doesn't match what the example declares",
+                None,
+            )
             .input_output_types(vec![(Type::Nothing, Type::table())])
             .allow_variants_without_examples(true)
     }
@@ -50,8 +58,33 @@ pub fn help_commands(
 ) -> Result<PipelineData, ShellError> {
     let head = call.head;
     let find: Option<Spanned<String>> = call.get_flag(engine_state, stack, "find")?;
+    let run_examples = call.has_flag(engine_state, stack, "run-examples")?;
     let rest: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
 
+    if run_examples {
+        let name = rest
+            .iter()
+            .map(|r| r.item.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let decls: Vec<(String, DeclId)> = if name.is_empty() {
+            engine_state
+                .get_decls_sorted(false)
+                .map(|(name, decl_id)| (String::from_utf8_lossy(&name).into_owned(), decl_id))
+                .collect()
+        } else if let Some(decl_id) = engine_state.find_decl(name.as_bytes(), &[]) {
+            vec![(name, decl_id)]
+        } else {
+            return Err(ShellError::CommandNotFound {
+                span: Span::merge_many(rest.iter().map(|s| s.span)),
+            });
+        };
+
+        let report = run_examples_report(engine_state, stack, decls, head);
+        return Ok(Value::list(report, head).into_pipeline_data());
+    }
+
     // 🚩The following two-lines are copied from filters/find.rs:
     let style_computer = StyleComputer::from_config(engine_state, stack);
     // Currently, search results all use the same style.
@@ -63,7 +96,7 @@ pub fn help_commands(
 
     if let Some(f) = find {
         let all_cmds_vec = build_help_commands(engine_state, head);
-        let found_cmds_vec = highlight_search_in_table(
+        let mut found_cmds_vec = highlight_search_in_table(
             all_cmds_vec,
             &f.item,
             &["name", "description", "search_terms"],
@@ -71,6 +104,11 @@ pub fn help_commands(
             &highlight_style,
         )?;
 
+        // Put the closest matches (an exact name match, then a name prefix, then any other
+        // name match) ahead of commands that only matched on description or search terms, since
+        // those are what someone typing `help --find <word>` is almost always looking for.
+        found_cmds_vec.sort_by_key(|cmd| std::cmp::Reverse(relevance(cmd, &f.item)));
+
         return Ok(Value::list(found_cmds_vec, head).into_pipeline_data());
     }
 
@@ -99,6 +137,97 @@ pub fn help_commands(
     }
 }
 
+/// Runs every example with a declared `result` for each of `decls` and reports whether the
+/// example's output matched. Doubles as a doc-testing facility: a custom command with `@example`
+/// attributes gets checked the same way as a built-in.
+fn run_examples_report(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    decls: Vec<(String, DeclId)>,
+    head: Span,
+) -> Vec<Value> {
+    let mut rows = vec![];
+
+    for (name, decl_id) in decls {
+        let decl = engine_state.get_decl(decl_id);
+        for example in decl.examples() {
+            let Some(expected) = example.result else {
+                continue;
+            };
+
+            let (actual, error) = match run_example(engine_state, stack, example.example) {
+                Ok(actual) => (Some(actual), None),
+                Err(err) => (None, Some(err.to_string())),
+            };
+            let passed = actual.as_ref() == Some(&expected);
+
+            rows.push(Value::record(
+                record! {
+                    "command" => Value::string(&name, head),
+                    "example" => Value::string(example.example, head),
+                    "passed" => Value::bool(passed, head),
+                    "expected" => expected,
+                    "actual" => actual.unwrap_or(Value::nothing(head)),
+                    "error" => error.map_or(Value::nothing(head), |e| Value::string(e, head)),
+                },
+                head,
+            ));
+        }
+    }
+
+    rows
+}
+
+/// Parses and evaluates `source` (an example's `example` field) against a scratch copy of `stack`,
+/// so a `let`/`mut` in one example can't leak into the caller's shell or a later example.
+fn run_example(
+    engine_state: &EngineState,
+    stack: &Stack,
+    source: &str,
+) -> Result<Value, ShellError> {
+    let block = {
+        let mut working_set = StateWorkingSet::new(engine_state);
+        let block = nu_parser::parse(&mut working_set, None, source.as_bytes(), false);
+        if let Some(err) = working_set.parse_errors.first() {
+            return Err(ShellError::GenericError {
+                error: "Example failed to parse".into(),
+                msg: err.to_string(),
+                span: None,
+                help: None,
+                inner: vec![],
+            });
+        }
+        block
+    };
+
+    let mut stack = stack.clone();
+    nu_engine::eval_block::<WithoutDebug>(engine_state, &mut stack, &block, PipelineData::empty())
+        .and_then(|data| data.into_value(Span::unknown()))
+}
+
+/// Ranks a (possibly highlighted) row from [`build_help_commands`] against `search_string` so that
+/// name matches sort ahead of matches that only hit the description or search terms.
+fn relevance(cmd: &Value, search_string: &str) -> u8 {
+    let Value::Record { val: record, .. } = cmd else {
+        return 0;
+    };
+    let Some(name) = record.get("name").and_then(|v| v.as_str().ok()) else {
+        return 0;
+    };
+    let name = nu_utils::strip_ansi_string_unlikely(name.to_string()).to_folded_case();
+    let search_string = search_string.to_folded_case();
+
+    if name == search_string {
+        3
+    } else if name.starts_with(&search_string) {
+        2
+    } else if name.contains(&search_string) {
+        1
+    } else {
+        0
+    }
+}
+
 fn build_help_commands(engine_state: &EngineState, span: Span) -> Vec<Value> {
     let commands = engine_state.get_decls_sorted(false);
     let mut found_cmds_vec = Vec::new();
@@ -210,6 +339,7 @@ fn build_help_commands(engine_state: &EngineState, span: Span) -> Vec<Value> {
             "input_output" => input_output_table,
             "search_terms" => Value::string(search_terms.join(", "), span),
             "is_const" => Value::bool(decl.is_const(), span),
+            "is_deprecated" => Value::bool(!decl.deprecation_info().is_empty(), span),
         };
 
         found_cmds_vec.push(Value::record(record, span));
@@ -226,4 +356,27 @@ mod test {
         use crate::test_examples;
         test_examples(HelpCommands {})
     }
+
+    #[test]
+    fn relevance_ranks_name_matches_above_description_matches() {
+        use super::relevance;
+        use nu_protocol::{record, Span, Value};
+
+        let name_match = Value::record(
+            record! {
+                "name" => Value::test_string("table"),
+                "description" => Value::test_string("Nothing to do with the query."),
+            },
+            Span::test_data(),
+        );
+        let description_match = Value::record(
+            record! {
+                "name" => Value::test_string("select"),
+                "description" => Value::test_string("Down-select table rows."),
+            },
+            Span::test_data(),
+        );
+
+        assert!(relevance(&name_match, "table") > relevance(&description_match, "table"));
+    }
 }
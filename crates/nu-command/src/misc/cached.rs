@@ -0,0 +1,108 @@
+use nu_engine::{command_prelude::*, ClosureEvalOnce};
+use nu_protocol::engine::Closure;
+use std::time::Duration;
+use web_time::Instant;
+
+#[derive(Clone)]
+pub struct Cached;
+
+impl Command for Cached {
+    fn name(&self) -> &str {
+        "cached"
+    }
+
+    fn description(&self) -> &str {
+        "Run a closure, reusing its previous result if it was cached recently."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Results are kept in memory for the lifetime of the engine, keyed by `--key` (or by the closure's own source position, so two `cached { ... }` call sites don't share a result by accident). Once `--ttl` elapses, the next call re-runs the closure and refreshes the cache."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("cached")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "closure",
+                SyntaxShape::Closure(None),
+                "The closure to run and cache the result of.",
+            )
+            .named(
+                "key",
+                SyntaxShape::String,
+                "cache key to store and look up the result under (defaults to the closure's source position)",
+                None,
+            )
+            .named(
+                "ttl",
+                SyntaxShape::Duration,
+                "how long a cached result stays valid (defaults to 10sec)",
+                None,
+            )
+            .category(Category::Core)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["memoize", "memoization", "cache", "ttl"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+        let key: Option<String> = call.get_flag(engine_state, stack, "key")?;
+        let ttl: Option<i64> = call.get_flag(engine_state, stack, "ttl")?;
+        let ttl = ttl
+            .map(|ns| Duration::from_nanos(ns.max(0) as u64))
+            .unwrap_or(Duration::from_secs(10));
+        let key =
+            key.unwrap_or_else(|| format!("{}@{}", closure.block_id.get(), call.head.start));
+
+        {
+            let cache = engine_state
+                .cached_values
+                .lock()
+                .map_err(|_| ShellError::NushellFailed {
+                    msg: "cached value cache poisoned".into(),
+                })?;
+            if let Some((cached_at, value)) = cache.get(&key) {
+                if cached_at.elapsed() < ttl {
+                    return Ok(value.clone().into_pipeline_data());
+                }
+            }
+        }
+
+        let value = ClosureEvalOnce::new(engine_state, stack, closure)
+            .run_with_input(input)?
+            .into_value(call.head)?;
+
+        engine_state
+            .cached_values
+            .lock()
+            .map_err(|_| ShellError::NushellFailed {
+                msg: "cached value cache poisoned".into(),
+            })?
+            .insert(key, (Instant::now(), value.clone()));
+
+        Ok(value.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Only re-fetch a URL once every 5 minutes",
+                example: "cached --ttl 5min { http get https://example.com/api }",
+                result: None,
+            },
+            Example {
+                description: "Share a cached result across multiple call sites with an explicit key",
+                example: "cached --key expensive-thing --ttl 1min { do-something-expensive }",
+                result: None,
+            },
+        ]
+    }
+}
@@ -1,7 +1,9 @@
+mod cached;
 mod panic;
 mod source;
 mod tutor;
 
+pub use cached::Cached;
 pub use panic::Panic;
 pub use source::Source;
 pub use tutor::Tutor;
@@ -0,0 +1,114 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::record;
+use std::process::Command as SystemCommand;
+
+#[derive(Clone)]
+pub struct DockerPs;
+
+impl Command for DockerPs {
+    fn name(&self) -> &str {
+        "docker ps"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("docker ps")
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .switch("all", "show all containers, including stopped ones", Some('a'))
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "List Docker containers as a structured table."
+    }
+
+    fn extra_description(&self) -> &str {
+        "This shells out to the system's `docker` binary (talking to the daemon over its usual \
+socket) and parses its JSON output, rather than reimplementing the Docker API client."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["container", "containers"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let all = call.has_flag(engine_state, stack, "all")?;
+
+        let mut command = SystemCommand::new("docker");
+        command.arg("ps").arg("--format").arg("{{json .}}");
+        if all {
+            command.arg("--all");
+        }
+
+        let output = command.output().map_err(|err| ShellError::GenericError {
+            error: "Failed to run docker".into(),
+            msg: err.to_string(),
+            span: Some(call.head),
+            help: Some("make sure the `docker` binary is installed and the daemon is reachable".into()),
+            inner: vec![],
+        })?;
+
+        if !output.status.success() {
+            return Err(ShellError::GenericError {
+                error: "docker ps failed".into(),
+                msg: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                span: Some(call.head),
+                help: None,
+                inner: vec![],
+            });
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let containers = stdout
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| container_record(line, call.head))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Value::list(containers, call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List running containers",
+            example: "docker ps",
+            result: None,
+        }]
+    }
+}
+
+fn container_record(line: &str, span: Span) -> Result<Value, ShellError> {
+    let json: serde_json::Value =
+        serde_json::from_str(line).map_err(|err| ShellError::GenericError {
+            error: "Failed to parse docker ps output".into(),
+            msg: err.to_string(),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        })?;
+
+    let field = |name: &str| {
+        json.get(name)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    Ok(Value::record(
+        record! {
+            "id" => Value::string(field("ID"), span),
+            "image" => Value::string(field("Image"), span),
+            "command" => Value::string(field("Command"), span),
+            "created" => Value::string(field("CreatedAt"), span),
+            "status" => Value::string(field("Status"), span),
+            "ports" => Value::string(field("Ports"), span),
+            "names" => Value::string(field("Names"), span),
+        },
+        span,
+    ))
+}
@@ -602,7 +602,26 @@ pub fn request_add_custom_headers(
     Ok(request)
 }
 
-fn handle_response_error(span: Span, requested_url: &str, response_err: Error) -> ShellError {
+/// Parse a `Link` response header (RFC 5988) and return the URL of the entry
+/// whose `rel` parameter is `"next"`, if any. Used by `http get --paginate`
+/// to follow link-based pagination without the caller needing to know the
+/// API's cursor or page-number conventions.
+pub(crate) fn parse_link_header_next(response: &Response) -> Option<String> {
+    let link = response.header("link")?;
+    link.split(',').find_map(|entry| {
+        let mut parts = entry.split(';').map(str::trim);
+        let url = parts.next()?.strip_prefix('<')?.strip_suffix('>')?;
+        parts
+            .any(|param| param.eq_ignore_ascii_case(r#"rel="next""#))
+            .then(|| url.to_string())
+    })
+}
+
+pub(crate) fn handle_response_error(
+    span: Span,
+    requested_url: &str,
+    response_err: Error,
+) -> ShellError {
     match response_err {
         Error::Status(301, _) => ShellError::NetworkFailure { msg: format!("Resource moved permanently (301): {requested_url:?}"), span },
         Error::Status(400, _) => {
@@ -634,7 +653,7 @@ pub struct RequestFlags {
     pub full: bool,
 }
 
-fn transform_response_using_content_type(
+pub(crate) fn transform_response_using_content_type(
     engine_state: &EngineState,
     stack: &mut Stack,
     span: Span,
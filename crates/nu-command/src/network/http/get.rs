@@ -1,11 +1,13 @@
 use crate::network::http::client::{
-    check_response_redirection, http_client, http_parse_redirect_mode, http_parse_url,
-    request_add_authorization_header, request_add_custom_headers, request_handle_response,
-    request_set_timeout, send_request, RequestFlags,
+    check_response_redirection, handle_response_error, http_client, http_parse_redirect_mode,
+    http_parse_url, parse_link_header_next, request_add_authorization_header,
+    request_add_custom_headers, request_handle_response, request_set_timeout, send_request,
+    transform_response_using_content_type, RequestFlags,
 };
 use nu_engine::command_prelude::*;
+use nu_protocol::ListStream;
 
-use super::client::HttpBody;
+use super::client::{HttpBody, ShellErrorOrRequestError};
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -74,6 +76,35 @@ impl Command for SubCommand {
                 "What to do when encountering redirects. Default: 'follow'. Valid options: 'follow' ('f'), 'manual' ('m'), 'error' ('e').",
                 Some('R')
             )
+            .switch(
+                "paginate",
+                "follow pagination across multiple requests, concatenating the pages into a single list; uses Link headers by default",
+                None,
+            )
+            .named(
+                "cursor-field",
+                SyntaxShape::CellPath,
+                "read the next page's cursor from this field of the response body instead of following Link headers",
+                None,
+            )
+            .named(
+                "cursor-param",
+                SyntaxShape::String,
+                "query parameter used to send the --cursor-field value on the next request. Default: 'cursor'",
+                None,
+            )
+            .named(
+                "page-param",
+                SyntaxShape::String,
+                "query parameter that holds the page number; incremented by 1 each request instead of following Link headers or a cursor",
+                None,
+            )
+            .named(
+                "max-pages",
+                SyntaxShape::Int,
+                "stop paginating after fetching this many pages. Default: 100",
+                None,
+            )
             .filter()
             .category(Category::Network)
     }
@@ -124,6 +155,16 @@ impl Command for SubCommand {
                 example: "http get --headers [my-header-key-A my-header-value-A my-header-key-B my-header-value-B] https://www.example.com",
                 result: None,
             },
+            Example {
+                description: "Fetch every page of a Link-header-paginated API into one list",
+                example: "http get --paginate https://api.example.com/items",
+                result: None,
+            },
+            Example {
+                description: "Fetch every page of a cursor-paginated API into one list",
+                example: "http get --paginate --cursor-field body.next_cursor --cursor-param cursor https://api.example.com/items",
+                result: None,
+            },
         ]
     }
 }
@@ -139,6 +180,11 @@ struct Arguments {
     full: bool,
     allow_errors: bool,
     redirect: Option<Spanned<String>>,
+    paginate: bool,
+    cursor_field: Option<CellPath>,
+    cursor_param: String,
+    page_param: Option<String>,
+    max_pages: i64,
 }
 
 fn run_get(
@@ -158,8 +204,22 @@ fn run_get(
         full: call.has_flag(engine_state, stack, "full")?,
         allow_errors: call.has_flag(engine_state, stack, "allow-errors")?,
         redirect: call.get_flag(engine_state, stack, "redirect-mode")?,
+        paginate: call.has_flag(engine_state, stack, "paginate")?,
+        cursor_field: call.get_flag(engine_state, stack, "cursor-field")?,
+        cursor_param: call
+            .get_flag(engine_state, stack, "cursor-param")?
+            .unwrap_or_else(|| "cursor".to_string()),
+        page_param: call.get_flag(engine_state, stack, "page-param")?,
+        max_pages: call
+            .get_flag::<i64>(engine_state, stack, "max-pages")?
+            .unwrap_or(100),
     };
-    helper(engine_state, stack, call, args)
+
+    if args.paginate {
+        helper_paginated(engine_state, stack, call, args)
+    } else {
+        helper(engine_state, stack, call, args)
+    }
 }
 
 // Helper function that actually goes to retrieve the resource from the url given
@@ -207,6 +267,134 @@ fn helper(
     )
 }
 
+// Helper function for `--paginate`. Repeatedly issues GET requests, following
+// either the response's `Link` header, a cursor field in the response body,
+// or an incrementing page-number query parameter, and concatenates every
+// page's items into a single list.
+fn helper_paginated(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    args: Arguments,
+) -> Result<PipelineData, ShellError> {
+    let span = args.url.span();
+    let (mut current_url, _) = http_parse_url(call, span, args.url)?;
+    let redirect_mode = http_parse_redirect_mode(args.redirect)?;
+    let client = http_client(args.insecure, redirect_mode, engine_state, stack)?;
+
+    let mut items = Vec::new();
+    let mut page_number: i64 = 1;
+
+    loop {
+        let mut request = client.get(&current_url);
+        request = request_set_timeout(args.timeout.clone(), request)?;
+        request =
+            request_add_authorization_header(args.user.clone(), args.password.clone(), request);
+        request = request_add_custom_headers(args.headers.clone(), request)?;
+
+        let response = send_request(
+            request,
+            HttpBody::None,
+            None,
+            call.head,
+            engine_state.signals(),
+        );
+        check_response_redirection(redirect_mode, span, &response)?;
+
+        let resp = match response {
+            Ok(resp) => resp,
+            Err(ShellErrorOrRequestError::ShellError(e)) => return Err(e),
+            Err(ShellErrorOrRequestError::RequestError(url, e)) => {
+                return Err(handle_response_error(span, &url, *e))
+            }
+        };
+
+        let link_next = args
+            .cursor_field
+            .is_none()
+            .then(|| parse_link_header_next(&resp))
+            .flatten();
+
+        let content_type = resp.header("content-type").map(|s| s.to_owned());
+        let body = match content_type {
+            Some(content_type) => transform_response_using_content_type(
+                engine_state,
+                stack,
+                span,
+                &current_url,
+                &RequestFlags {
+                    allow_errors: args.allow_errors,
+                    raw: false,
+                    full: false,
+                },
+                resp,
+                &content_type,
+            )?,
+            None => PipelineData::Empty,
+        }
+        .into_value(span)?;
+
+        match &body {
+            Value::List { vals, .. } => items.extend(vals.iter().cloned()),
+            Value::Nothing { .. } => {}
+            _ => items.push(body.clone()),
+        }
+
+        let next_url = if let Some(cursor_field) = &args.cursor_field {
+            body.clone()
+                .follow_cell_path(&cursor_field.members, false)
+                .ok()
+                .filter(|v| !v.is_nothing())
+                .and_then(|v| v.coerce_into_string().ok())
+                .map(|cursor| set_query_param(&current_url, &args.cursor_param, &cursor))
+                .transpose()?
+        } else if let Some(page_param) = &args.page_param {
+            Some(set_query_param(
+                &current_url,
+                page_param,
+                &(page_number + 1).to_string(),
+            )?)
+        } else {
+            link_next
+        };
+
+        page_number += 1;
+        match next_url {
+            Some(url) if page_number <= args.max_pages => current_url = url,
+            _ => break,
+        }
+    }
+
+    Ok(ListStream::new(items.into_iter(), call.head, engine_state.signals().clone()).into())
+}
+
+// Replace (or add) a single query parameter on a URL, used to advance
+// cursor- and page-number-based pagination without disturbing the rest of
+// the query string.
+fn set_query_param(url_str: &str, param: &str, value: &str) -> Result<String, ShellError> {
+    let mut url = url::Url::parse(url_str).map_err(|e| ShellError::GenericError {
+        error: "Invalid URL".into(),
+        msg: e.to_string(),
+        span: None,
+        help: None,
+        inner: vec![],
+    })?;
+
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(k, _)| k != param)
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+
+    url.query_pairs_mut().clear();
+    for (k, v) in &pairs {
+        url.query_pairs_mut().append_pair(k, v);
+    }
+    url.query_pairs_mut().append_pair(param, value);
+
+    Ok(url.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
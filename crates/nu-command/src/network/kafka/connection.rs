@@ -0,0 +1,280 @@
+use super::protocol::{
+    self, crc32, put_bytes, put_i32, put_i64, put_string, truncated_error, Decoder,
+};
+use nu_engine::command_prelude::*;
+use std::net::TcpStream;
+
+/// A connection to a single Kafka broker.
+///
+/// Scoped down hard to keep a hand-rolled client manageable: the broker passed in is assumed
+/// to be both the partition leader *and* (for offset commit/fetch) the consumer group
+/// coordinator - there's no Metadata or FindCoordinator request to discover either, so this
+/// only works against a single-broker cluster or when you already know which broker to talk
+/// to. There's no consumer-group membership either (no JoinGroup/SyncGroup/Heartbeat, so no
+/// rebalancing) - `--group` only scopes which offset gets committed/fetched, not a shared
+/// partition assignment. Every request also only ever addresses one topic and one partition,
+/// so request bodies below write "array of 1" by hand rather than through a generic encoder.
+/// Good enough for inspecting a topic or feeding it from a pipeline; a real consumer-group
+/// deployment needs a real Kafka client library.
+pub(crate) struct Connection {
+    stream: TcpStream,
+    next_correlation_id: i32,
+}
+
+pub(crate) struct ProducedOffset {
+    pub(crate) error_code: i16,
+    pub(crate) base_offset: i64,
+}
+
+pub(crate) struct FetchedRecord {
+    pub(crate) offset: i64,
+    pub(crate) key: Option<Vec<u8>>,
+    pub(crate) value: Vec<u8>,
+}
+
+pub(crate) struct FetchedPartition {
+    pub(crate) error_code: i16,
+    pub(crate) records: Vec<FetchedRecord>,
+}
+
+impl Connection {
+    pub(crate) fn open(broker: &str, port: u16, span: Span) -> Result<Self, ShellError> {
+        let stream =
+            TcpStream::connect((broker, port)).map_err(|err| ShellError::GenericError {
+                error: "Could not connect to Kafka broker".into(),
+                msg: err.to_string(),
+                span: Some(span),
+                help: Some(format!("tried to connect to {broker}:{port}")),
+                inner: vec![],
+            })?;
+        Ok(Self {
+            stream,
+            next_correlation_id: 1,
+        })
+    }
+
+    fn request(&mut self, api_key: i16, body: &[u8], span: Span) -> Result<Vec<u8>, ShellError> {
+        let correlation_id = self.next_correlation_id;
+        self.next_correlation_id = self.next_correlation_id.wrapping_add(1);
+        protocol::write_request(&mut self.stream, api_key, correlation_id, body, span)?;
+        protocol::read_response(&mut self.stream, span)
+    }
+
+    pub(crate) fn produce(
+        &mut self,
+        topic: &str,
+        partition: i32,
+        key: Option<&[u8]>,
+        value: &[u8],
+        span: Span,
+    ) -> Result<ProducedOffset, ShellError> {
+        let message_set = encode_message_set(key, value);
+
+        let mut body = Vec::new();
+        protocol::put_i16(&mut body, 1); // acks: leader only
+        put_i32(&mut body, 5000); // timeout_ms
+        put_i32(&mut body, 1); // topics array: just this one
+        put_string(&mut body, topic);
+        put_i32(&mut body, 1); // partitions array: just this one
+        put_i32(&mut body, partition);
+        put_bytes(&mut body, Some(&message_set));
+
+        let response = self.request(protocol::API_PRODUCE, &body, span)?;
+        let mut decoder = Decoder::new(&response);
+        decoder.i32(); // topics array count, unused
+        decoder.string(); // topic name, unused
+        decoder.i32(); // partitions array count, unused
+        decoder.i32(); // partition id, unused
+        let error_code = decoder.i16();
+        let base_offset = decoder.i64();
+        if decoder.truncated() {
+            return Err(truncated_error(span));
+        }
+        Ok(ProducedOffset {
+            error_code,
+            base_offset,
+        })
+    }
+
+    pub(crate) fn fetch(
+        &mut self,
+        topic: &str,
+        partition: i32,
+        offset: i64,
+        max_bytes: i32,
+        span: Span,
+    ) -> Result<FetchedPartition, ShellError> {
+        let mut body = Vec::new();
+        put_i32(&mut body, -1); // replica_id
+        put_i32(&mut body, 1000); // max_wait_time_ms
+        put_i32(&mut body, 1); // min_bytes
+        put_i32(&mut body, 1); // topics array: just this one
+        put_string(&mut body, topic);
+        put_i32(&mut body, 1); // partitions array: just this one
+        put_i32(&mut body, partition);
+        put_i64(&mut body, offset);
+        put_i32(&mut body, max_bytes);
+
+        let response = self.request(protocol::API_FETCH, &body, span)?;
+        let mut decoder = Decoder::new(&response);
+        decoder.i32(); // topics array count, unused
+        decoder.string(); // topic name, unused
+        decoder.i32(); // partitions array count, unused
+        decoder.i32(); // partition id, unused
+        let error_code = decoder.i16();
+        decoder.i64(); // high_watermark, unused
+        let records = decoder
+            .bytes()
+            .map(|bytes| decode_message_set(&bytes))
+            .unwrap_or_default();
+        if decoder.truncated() {
+            return Err(truncated_error(span));
+        }
+        Ok(FetchedPartition {
+            error_code,
+            records,
+        })
+    }
+
+    pub(crate) fn list_offset(
+        &mut self,
+        topic: &str,
+        partition: i32,
+        timestamp: i64,
+        span: Span,
+    ) -> Result<i64, ShellError> {
+        let mut body = Vec::new();
+        put_i32(&mut body, -1); // replica_id
+        put_i32(&mut body, 1); // topics array: just this one
+        put_string(&mut body, topic);
+        put_i32(&mut body, 1); // partitions array: just this one
+        put_i32(&mut body, partition);
+        put_i64(&mut body, timestamp);
+        put_i32(&mut body, 1); // max_num_offsets
+
+        let response = self.request(protocol::API_LIST_OFFSETS, &body, span)?;
+        let mut decoder = Decoder::new(&response);
+        decoder.i32(); // topics array count, unused
+        decoder.string(); // topic name, unused
+        decoder.i32(); // partitions array count, unused
+        decoder.i32(); // partition id, unused
+        decoder.i16(); // error code, unused: a failure surfaces as a truncated/zero offset below
+        let offsets = decoder.array(|d| d.i64());
+        let offset = offsets
+            .first()
+            .copied()
+            .ok_or_else(|| truncated_error(span))?;
+        if decoder.truncated() {
+            return Err(truncated_error(span));
+        }
+        Ok(offset)
+    }
+
+    pub(crate) fn offset_commit(
+        &mut self,
+        group: &str,
+        topic: &str,
+        partition: i32,
+        offset: i64,
+        span: Span,
+    ) -> Result<(), ShellError> {
+        let mut body = Vec::new();
+        put_string(&mut body, group);
+        put_i32(&mut body, 1); // topics array: just this one
+        put_string(&mut body, topic);
+        put_i32(&mut body, 1); // partitions array: just this one
+        put_i32(&mut body, partition);
+        put_i64(&mut body, offset);
+        put_string(&mut body, ""); // metadata
+
+        let response = self.request(protocol::API_OFFSET_COMMIT, &body, span)?;
+        let mut decoder = Decoder::new(&response);
+        decoder.i32(); // topics array count, unused
+        decoder.string(); // topic name, unused
+        decoder.i32(); // partitions array count, unused
+        decoder.i32(); // partition id, unused
+        let error_code = decoder.i16();
+        if decoder.truncated() {
+            return Err(truncated_error(span));
+        }
+
+        if error_code == 0 {
+            Ok(())
+        } else {
+            Err(ShellError::GenericError {
+                error: "Kafka offset commit failed".into(),
+                msg: format!("error code {error_code}"),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            })
+        }
+    }
+
+    pub(crate) fn offset_fetch(
+        &mut self,
+        group: &str,
+        topic: &str,
+        partition: i32,
+        span: Span,
+    ) -> Result<Option<i64>, ShellError> {
+        let mut body = Vec::new();
+        put_string(&mut body, group);
+        put_i32(&mut body, 1); // topics array: just this one
+        put_string(&mut body, topic);
+        put_i32(&mut body, 1); // partitions array: just this one
+        put_i32(&mut body, partition);
+
+        let response = self.request(protocol::API_OFFSET_FETCH, &body, span)?;
+        let mut decoder = Decoder::new(&response);
+        decoder.i32(); // topics array count, unused
+        decoder.string(); // topic name, unused
+        decoder.i32(); // partitions array count, unused
+        decoder.i32(); // partition id, unused
+        let offset = decoder.i64();
+        decoder.string(); // metadata, unused
+        let error_code = decoder.i16();
+        if decoder.truncated() {
+            return Err(truncated_error(span));
+        }
+        // A group with no committed offset for this partition reads back offset -1, not an error.
+        Ok((error_code == 0 && offset >= 0).then_some(offset))
+    }
+}
+
+/// Encodes one v0 message-set entry ready to embed in a Produce request body: the offset field
+/// is ignored by the broker for produce, so it's left zeroed.
+fn encode_message_set(key: Option<&[u8]>, value: &[u8]) -> Vec<u8> {
+    let mut inner = Vec::new();
+    inner.push(0u8); // magic byte
+    inner.push(0u8); // attributes: no compression
+    put_bytes(&mut inner, key);
+    put_bytes(&mut inner, Some(value));
+
+    let mut entry = Vec::new();
+    put_i64(&mut entry, 0); // offset
+    put_i32(&mut entry, 4 + inner.len() as i32); // message size: crc + inner
+    entry.extend_from_slice(&crc32(&inner).to_be_bytes());
+    entry.extend(inner);
+    entry
+}
+
+/// Decodes a v0 message set, stopping at the first entry that's truncated rather than erroring
+/// - Kafka allows the last message in a Fetch response to be cut short by `max_bytes`.
+fn decode_message_set(data: &[u8]) -> Vec<FetchedRecord> {
+    let mut records = Vec::new();
+    let mut decoder = Decoder::new(data);
+    while !decoder.is_empty() {
+        let offset = decoder.i64();
+        let _message_size = decoder.i32();
+        let _crc = decoder.i32();
+        let _magic_and_attributes = decoder.i16();
+        let key = decoder.bytes();
+        let value = decoder.bytes().unwrap_or_default();
+        if decoder.truncated() {
+            break;
+        }
+        records.push(FetchedRecord { offset, key, value });
+    }
+    records
+}
@@ -0,0 +1,218 @@
+use super::connection::FetchedRecord;
+use super::Connection;
+use nu_engine::command_prelude::*;
+use std::collections::VecDeque;
+
+#[derive(Clone)]
+pub struct KafkaConsume;
+
+impl Command for KafkaConsume {
+    fn name(&self) -> &str {
+        "kafka consume"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .required("broker", SyntaxShape::String, "Broker hostname or address.")
+            .required("topic", SyntaxShape::String, "Topic to consume from.")
+            .named(
+                "port",
+                SyntaxShape::Int,
+                "Broker port (default 9092).",
+                Some('p'),
+            )
+            .named(
+                "partition",
+                SyntaxShape::Int,
+                "Partition to consume from (default 0).",
+                None,
+            )
+            .named(
+                "offset",
+                SyntaxShape::String,
+                "Where to start: `earliest`, `latest` (default), or a specific offset number.",
+                None,
+            )
+            .named(
+                "group",
+                SyntaxShape::String,
+                "Consumer group id: resumes from its last committed offset, and commits as \
+records are read if `--commit` is given. There's no group membership/rebalancing - this is \
+just scoped offset bookkeeping for a single consumer.",
+                None,
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "How to decode each record's value: `binary` (default) or `string`.",
+                None,
+            )
+            .switch(
+                "commit",
+                "Commit the offset of each record to `--group` as it's read.",
+                None,
+            )
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Stream records from a Kafka topic partition."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Speaks just enough of the Kafka wire protocol to poll a single partition with Fetch \
+requests - the broker given is assumed to be both the partition leader and, if `--group` is \
+used, the group's offset coordinator, since there's no Metadata/FindCoordinator request to \
+discover either. Each record's `timestamp` is when this client received it, not a broker-side \
+timestamp, since the old pre-0.10 message format Produce/Fetch v0 use doesn't carry one. \
+Streams indefinitely - pipe into `first`/`take` to stop early."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["broker", "topic", "streaming", "queue"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Watch new records on a topic as they arrive",
+            example: "kafka consume broker.local events | each {|record| $record.value | decode }",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let broker: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let topic: String = call.req(engine_state, stack, 1)?;
+        let port: Option<i64> = call.get_flag(engine_state, stack, "port")?;
+        let partition: Option<i64> = call.get_flag(engine_state, stack, "partition")?;
+        let offset: Option<Spanned<String>> = call.get_flag(engine_state, stack, "offset")?;
+        let group: Option<String> = call.get_flag(engine_state, stack, "group")?;
+        let format: Option<Spanned<String>> = call.get_flag(engine_state, stack, "format")?;
+        let commit = call.has_flag(engine_state, stack, "commit")?;
+
+        let port = port.unwrap_or(9092) as u16;
+        let partition = partition.unwrap_or(0) as i32;
+        let decode_as_string = match &format {
+            Some(format) if format.item == "string" => true,
+            Some(format) if format.item == "binary" => false,
+            None => false,
+            Some(format) => {
+                return Err(ShellError::IncorrectValue {
+                    msg: "expected `binary` or `string`".into(),
+                    val_span: format.span,
+                    call_span: format.span,
+                })
+            }
+        };
+
+        let mut connection = Connection::open(&broker.item, port, broker.span)?;
+
+        let mut next_offset =
+            starting_offset(&mut connection, &topic, partition, &offset, &group, head)?;
+
+        let mut buffer: VecDeque<FetchedRecord> = VecDeque::new();
+        let records = std::iter::from_fn(move || loop {
+            if let Some(record) = buffer.pop_front() {
+                next_offset = record.offset + 1;
+                if commit {
+                    if let Some(group) = &group {
+                        if let Err(err) =
+                            connection.offset_commit(group, &topic, partition, next_offset, head)
+                        {
+                            return Some(Value::error(err, head));
+                        }
+                    }
+                }
+                return Some(record_to_value(record, decode_as_string, partition, head));
+            }
+
+            match connection.fetch(&topic, partition, next_offset, 1_048_576, head) {
+                Ok(fetched) if fetched.error_code != 0 => {
+                    return Some(Value::error(
+                        ShellError::GenericError {
+                            error: "Kafka fetch failed".into(),
+                            msg: format!("error code {}", fetched.error_code),
+                            span: Some(head),
+                            help: None,
+                            inner: vec![],
+                        },
+                        head,
+                    ));
+                }
+                Ok(fetched) => buffer.extend(fetched.records),
+                Err(err) => return Some(Value::error(err, head)),
+            }
+        });
+
+        Ok(records.into_pipeline_data(head, engine_state.signals().clone()))
+    }
+}
+
+fn starting_offset(
+    connection: &mut Connection,
+    topic: &str,
+    partition: i32,
+    offset: &Option<Spanned<String>>,
+    group: &Option<String>,
+    span: Span,
+) -> Result<i64, ShellError> {
+    if let Some(offset) = offset {
+        return match offset.item.as_str() {
+            "earliest" => connection.list_offset(topic, partition, -2, span),
+            "latest" => connection.list_offset(topic, partition, -1, span),
+            number => number.parse().map_err(|_| ShellError::IncorrectValue {
+                msg: "expected `earliest`, `latest`, or a number".into(),
+                val_span: offset.span,
+                call_span: offset.span,
+            }),
+        };
+    }
+
+    if let Some(group) = group {
+        if let Some(committed) = connection.offset_fetch(group, topic, partition, span)? {
+            return Ok(committed);
+        }
+    }
+
+    connection.list_offset(topic, partition, -1, span)
+}
+
+fn record_to_value(
+    record: FetchedRecord,
+    decode_as_string: bool,
+    partition: i32,
+    head: Span,
+) -> Value {
+    let value = if decode_as_string {
+        Value::string(String::from_utf8_lossy(&record.value).into_owned(), head)
+    } else {
+        Value::binary(record.value, head)
+    };
+    let key = match record.key {
+        Some(key) if decode_as_string => {
+            Value::string(String::from_utf8_lossy(&key).into_owned(), head)
+        }
+        Some(key) => Value::binary(key, head),
+        None => Value::nothing(head),
+    };
+    let now = chrono::Local::now();
+
+    Value::record(
+        record! {
+            "key" => key,
+            "value" => value,
+            "partition" => Value::int(partition as i64, head),
+            "offset" => Value::int(record.offset, head),
+            "timestamp" => Value::date(now.with_timezone(now.offset()), head),
+        },
+        head,
+    )
+}
@@ -0,0 +1,9 @@
+mod connection;
+mod consume;
+mod produce;
+mod protocol;
+
+use connection::Connection;
+
+pub use consume::KafkaConsume;
+pub use produce::KafkaProduce;
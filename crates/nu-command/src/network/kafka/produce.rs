@@ -0,0 +1,108 @@
+use super::Connection;
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct KafkaProduce;
+
+impl Command for KafkaProduce {
+    fn name(&self) -> &str {
+        "kafka produce"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::record()),
+                (Type::String, Type::record()),
+                (Type::Binary, Type::record()),
+            ])
+            .required("broker", SyntaxShape::String, "Broker hostname or address.")
+            .required("topic", SyntaxShape::String, "Topic to produce to.")
+            .named(
+                "port",
+                SyntaxShape::Int,
+                "Broker port (default 9092).",
+                Some('p'),
+            )
+            .named(
+                "partition",
+                SyntaxShape::Int,
+                "Partition to produce to (default 0).",
+                None,
+            )
+            .named("key", SyntaxShape::String, "Record key.", Some('k'))
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Produce pipeline input as a Kafka record."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Speaks just enough of the Kafka wire protocol to send one uncompressed Produce request \
+(the old pre-timestamp message format) and read back its offset - the broker given is assumed \
+to be the partition leader, since there's no Metadata request to discover it. Good for \
+pushing a one-off message into a topic from a pipeline without a separate producer CLI."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["broker", "topic", "streaming", "queue"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Produce a JSON event to a topic",
+            example: r#"{ event: "started" } | to json | kafka produce broker.local events"#,
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let broker: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let topic: String = call.req(engine_state, stack, 1)?;
+        let port: Option<i64> = call.get_flag(engine_state, stack, "port")?;
+        let partition: Option<i64> = call.get_flag(engine_state, stack, "partition")?;
+        let key: Option<String> = call.get_flag(engine_state, stack, "key")?;
+
+        let port = port.unwrap_or(9092) as u16;
+        let partition = partition.unwrap_or(0) as i32;
+
+        let mut payload = Vec::new();
+        input.write_to(&mut payload)?;
+
+        let mut connection = Connection::open(&broker.item, port, broker.span)?;
+        let result = connection.produce(
+            &topic,
+            partition,
+            key.as_deref().map(str::as_bytes),
+            &payload,
+            head,
+        )?;
+
+        if result.error_code != 0 {
+            return Err(ShellError::GenericError {
+                error: "Kafka produce failed".into(),
+                msg: format!("error code {}", result.error_code),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            });
+        }
+
+        Ok(Value::record(
+            record! {
+                "partition" => Value::int(partition as i64, head),
+                "offset" => Value::int(result.base_offset, head),
+            },
+            head,
+        )
+        .into_pipeline_data())
+    }
+}
@@ -0,0 +1,210 @@
+//! Wire-format primitives for the subset of the Kafka protocol `kafka produce`/`kafka consume`
+//! speak: request/response framing, the handful of v0 API request bodies, and the old
+//! (pre-timestamp) message-set format Produce/Fetch v0 use. See [`super::Connection`] for what's
+//! deliberately out of scope.
+
+use nu_engine::command_prelude::*;
+use std::io::{Read, Write};
+
+pub(crate) const API_PRODUCE: i16 = 0;
+pub(crate) const API_FETCH: i16 = 1;
+pub(crate) const API_LIST_OFFSETS: i16 = 2;
+pub(crate) const API_OFFSET_COMMIT: i16 = 8;
+pub(crate) const API_OFFSET_FETCH: i16 = 9;
+
+pub(crate) fn put_i16(buf: &mut Vec<u8>, n: i16) {
+    buf.extend_from_slice(&n.to_be_bytes());
+}
+
+pub(crate) fn put_i32(buf: &mut Vec<u8>, n: i32) {
+    buf.extend_from_slice(&n.to_be_bytes());
+}
+
+pub(crate) fn put_i64(buf: &mut Vec<u8>, n: i64) {
+    buf.extend_from_slice(&n.to_be_bytes());
+}
+
+pub(crate) fn put_string(buf: &mut Vec<u8>, s: &str) {
+    put_i16(buf, s.len() as i16);
+    buf.extend_from_slice(s.as_bytes());
+}
+
+pub(crate) fn put_bytes(buf: &mut Vec<u8>, bytes: Option<&[u8]>) {
+    match bytes {
+        Some(bytes) => {
+            put_i32(buf, bytes.len() as i32);
+            buf.extend_from_slice(bytes);
+        }
+        None => put_i32(buf, -1),
+    }
+}
+
+/// A cursor over a decoded response body. Every read advances past what it read; a response
+/// that's shorter than expected just reads as zeroed/empty rather than panicking, since a
+/// malformed or unexpectedly-versioned reply should surface as a normal [`ShellError`], not a
+/// crash - the caller checks [`Decoder::truncated`] once it's read everything it expects.
+pub(crate) struct Decoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+    truncated: bool,
+}
+
+impl<'a> Decoder<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            truncated: false,
+        }
+    }
+
+    fn take(&mut self, len: usize) -> &'a [u8] {
+        match self.data.get(self.pos..self.pos + len) {
+            Some(bytes) => {
+                self.pos += len;
+                bytes
+            }
+            None => {
+                self.truncated = true;
+                self.pos = self.data.len();
+                &[]
+            }
+        }
+    }
+
+    pub(crate) fn i16(&mut self) -> i16 {
+        let bytes = self.take(2);
+        if bytes.len() == 2 {
+            i16::from_be_bytes([bytes[0], bytes[1]])
+        } else {
+            0
+        }
+    }
+
+    pub(crate) fn i32(&mut self) -> i32 {
+        let bytes = self.take(4);
+        if bytes.len() == 4 {
+            i32::from_be_bytes(bytes.try_into().expect("checked len"))
+        } else {
+            0
+        }
+    }
+
+    pub(crate) fn i64(&mut self) -> i64 {
+        let bytes = self.take(8);
+        if bytes.len() == 8 {
+            i64::from_be_bytes(bytes.try_into().expect("checked len"))
+        } else {
+            0
+        }
+    }
+
+    pub(crate) fn string(&mut self) -> String {
+        let len = self.i16();
+        if len < 0 {
+            return String::new();
+        }
+        String::from_utf8_lossy(self.take(len as usize)).into_owned()
+    }
+
+    pub(crate) fn bytes(&mut self) -> Option<Vec<u8>> {
+        let len = self.i32();
+        if len < 0 {
+            None
+        } else {
+            Some(self.take(len as usize).to_vec())
+        }
+    }
+
+    pub(crate) fn array<T>(&mut self, mut read_item: impl FnMut(&mut Self) -> T) -> Vec<T> {
+        let count = self.i32().max(0) as usize;
+        (0..count).map(|_| read_item(self)).collect()
+    }
+
+    pub(crate) fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.truncated || self.pos >= self.data.len()
+    }
+}
+
+/// The standard CRC-32 (IEEE 802.3 / zlib) checksum, computed bit-by-bit since there's no
+/// checksum crate already in the dependency tree worth pulling in for the small messages this
+/// client sends. The classic message-set format has no other use for a table-driven version.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xffff_ffff;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Writes a full request frame: 4-byte size, then the v0 request header (api key/version,
+/// correlation id, client id) followed by the API-specific body.
+pub(crate) fn write_request(
+    stream: &mut impl Write,
+    api_key: i16,
+    correlation_id: i32,
+    body: &[u8],
+    span: Span,
+) -> Result<(), ShellError> {
+    let mut header = Vec::new();
+    put_i16(&mut header, api_key);
+    put_i16(&mut header, 0); // api version 0
+    put_i32(&mut header, correlation_id);
+    put_string(&mut header, "nu"); // client id
+
+    let mut frame = Vec::new();
+    put_i32(&mut frame, (header.len() + body.len()) as i32);
+    frame.extend(header);
+    frame.extend_from_slice(body);
+
+    stream.write_all(&frame).map_err(|err| io_error(err, span))
+}
+
+/// Reads one full response frame and returns its body, with the leading correlation id (which
+/// every response echoes back but none of these commands need to check, since each connection
+/// only ever has one request in flight at a time) already stripped off.
+pub(crate) fn read_response(stream: &mut impl Read, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut size_bytes = [0u8; 4];
+    stream
+        .read_exact(&mut size_bytes)
+        .map_err(|err| io_error(err, span))?;
+    let size = i32::from_be_bytes(size_bytes).max(0) as usize;
+
+    let mut body = vec![0u8; size];
+    stream
+        .read_exact(&mut body)
+        .map_err(|err| io_error(err, span))?;
+
+    Ok(body.get(4..).unwrap_or_default().to_vec())
+}
+
+pub(crate) fn io_error(err: std::io::Error, span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Kafka connection error".into(),
+        msg: err.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
+
+pub(crate) fn truncated_error(span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Malformed Kafka response".into(),
+        msg: "response was shorter than its declared fields".into(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
@@ -0,0 +1,129 @@
+use nu_engine::command_prelude::*;
+use nu_path::home_dir;
+use nu_protocol::record;
+use std::path::PathBuf;
+
+#[derive(Clone)]
+pub struct KubectlContexts;
+
+impl Command for KubectlContexts {
+    fn name(&self) -> &str {
+        "kubectl contexts"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("kubectl contexts")
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "List the contexts, clusters, and namespaces defined in the active kubeconfig."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Reads `$KUBECONFIG` (or `~/.kube/config`) directly instead of shelling out, since this \
+is just a config file read - no cluster connection is made. Meant as the building block for a \
+custom completer, e.g. `$env.config.completions.external.completer` dispatching on `kubectl \
+--context` to `kubectl contexts | get name`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["kubernetes", "k8s", "kubeconfig", "completions"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path = kubeconfig_path(engine_state, stack);
+        let contents = std::fs::read_to_string(&path).map_err(|err| ShellError::GenericError {
+            error: "Failed to read kubeconfig".into(),
+            msg: err.to_string(),
+            span: Some(call.head),
+            help: Some(format!("tried to read {}", path.display())),
+            inner: vec![],
+        })?;
+
+        let config: serde_yml::Value =
+            serde_yml::from_str(&contents).map_err(|err| ShellError::GenericError {
+                error: "Failed to parse kubeconfig".into(),
+                msg: err.to_string(),
+                span: Some(call.head),
+                help: None,
+                inner: vec![],
+            })?;
+
+        let current_context = config
+            .get("current-context")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+
+        let contexts = config
+            .get("contexts")
+            .and_then(|v| v.as_sequence())
+            .cloned()
+            .unwrap_or_default();
+
+        let records = contexts
+            .iter()
+            .map(|entry| context_record(entry, current_context, call.head))
+            .collect();
+
+        Ok(Value::list(records, call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the names of all known contexts",
+            example: "kubectl contexts | get name",
+            result: None,
+        }]
+    }
+}
+
+fn kubeconfig_path(engine_state: &EngineState, stack: &Stack) -> PathBuf {
+    if let Some(path) = stack
+        .get_env_var(engine_state, "KUBECONFIG")
+        .and_then(|v| v.coerce_str().ok())
+    {
+        return PathBuf::from(path.into_owned());
+    }
+
+    home_dir()
+        .map(|home| PathBuf::from(home).join(".kube").join("config"))
+        .unwrap_or_else(|| PathBuf::from(".kube/config"))
+}
+
+fn context_record(entry: &serde_yml::Value, current_context: &str, span: Span) -> Value {
+    let string_field = |name: &str| {
+        entry
+            .get(name)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let name = string_field("name");
+    let context = entry.get("context");
+    let nested_field = |name: &str| {
+        context
+            .and_then(|c| c.get(name))
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    Value::record(
+        record! {
+            "name" => Value::string(name.clone(), span),
+            "cluster" => Value::string(nested_field("cluster"), span),
+            "namespace" => Value::string(nested_field("namespace"), span),
+            "user" => Value::string(nested_field("user"), span),
+            "current" => Value::bool(name == current_context, span),
+        },
+        span,
+    )
+}
@@ -0,0 +1,5 @@
+mod contexts;
+mod get;
+
+pub use contexts::KubectlContexts;
+pub use get::KubectlGet;
@@ -1,12 +1,32 @@
 #[cfg(feature = "network")]
+mod docker;
+#[cfg(feature = "network")]
 mod http;
+#[cfg(all(feature = "network", feature = "kafka"))]
+mod kafka;
+#[cfg(feature = "network")]
+mod kubectl;
+#[cfg(all(feature = "network", feature = "mqtt"))]
+mod mqtt;
 #[cfg(feature = "network")]
 mod port;
+#[cfg(feature = "network")]
+mod ssh;
 mod url;
 
 #[cfg(feature = "network")]
 pub use self::http::*;
 pub use self::url::*;
 
+#[cfg(feature = "network")]
+pub use docker::DockerPs;
+#[cfg(all(feature = "network", feature = "kafka"))]
+pub use kafka::{KafkaConsume, KafkaProduce};
+#[cfg(feature = "network")]
+pub use kubectl::{KubectlContexts, KubectlGet};
+#[cfg(all(feature = "network", feature = "mqtt"))]
+pub use mqtt::{MqttPublish, MqttSubscribe};
 #[cfg(feature = "network")]
 pub use port::SubCommand as Port;
+#[cfg(feature = "network")]
+pub use ssh::SshRun;
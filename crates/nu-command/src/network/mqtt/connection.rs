@@ -0,0 +1,134 @@
+use super::packet::{self, Packet, PublishedMessage};
+use nu_engine::command_prelude::*;
+use std::io::Write;
+use std::net::TcpStream;
+
+/// A connected, CONNACK-acknowledged MQTT session.
+///
+/// Scoped down to what `mqtt publish`/`mqtt subscribe` actually need: QoS 0 only (so there's
+/// no packet-identifier bookkeeping or ack-retry logic to get right) and `keep_alive = 0` in
+/// the CONNECT packet, which tells the broker not to expect PINGREQ at all - avoiding the
+/// background thread a real keep-alive interval would require. Good enough for the kind of
+/// "watch a topic"/"push one reading" pipeline this is meant for; a long-lived production
+/// client should reach for a real MQTT crate instead.
+pub(crate) struct Connection {
+    stream: TcpStream,
+    next_packet_id: u16,
+}
+
+impl Connection {
+    pub(crate) fn open(
+        broker: &str,
+        port: u16,
+        client_id: &str,
+        username: &Option<String>,
+        password: &Option<String>,
+        span: Span,
+    ) -> Result<Self, ShellError> {
+        let mut stream =
+            TcpStream::connect((broker, port)).map_err(|err| ShellError::GenericError {
+                error: "Could not connect to MQTT broker".into(),
+                msg: err.to_string(),
+                span: Some(span),
+                help: Some(format!("tried to connect to {broker}:{port}")),
+                inner: vec![],
+            })?;
+
+        stream
+            .write_all(&packet::connect(client_id, username, password))
+            .map_err(|err| connection_error(err, span))?;
+
+        let ack = packet::read_packet(&mut stream, span)?;
+        if ack.kind != packet::CONNACK {
+            return Err(unexpected_packet_error("CONNACK", ack.kind, span));
+        }
+        if ack.body.len() < 2 || ack.body[1] != 0 {
+            return Err(ShellError::GenericError {
+                error: "MQTT broker rejected the connection".into(),
+                msg: format!(
+                    "CONNACK return code {}",
+                    ack.body.get(1).copied().unwrap_or(0xff)
+                ),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            });
+        }
+
+        Ok(Self {
+            stream,
+            next_packet_id: 1,
+        })
+    }
+
+    pub(crate) fn publish(
+        &mut self,
+        topic: &str,
+        payload: &[u8],
+        retain: bool,
+        span: Span,
+    ) -> Result<(), ShellError> {
+        self.stream
+            .write_all(&packet::publish(topic, payload, retain))
+            .map_err(|err| connection_error(err, span))
+    }
+
+    pub(crate) fn subscribe(&mut self, topic_filter: &str, span: Span) -> Result<(), ShellError> {
+        let packet_id = self.next_packet_id;
+        self.next_packet_id = self.next_packet_id.wrapping_add(1);
+
+        self.stream
+            .write_all(&packet::subscribe(packet_id, topic_filter))
+            .map_err(|err| connection_error(err, span))?;
+
+        let ack = packet::read_packet(&mut self.stream, span)?;
+        if ack.kind != packet::SUBACK {
+            return Err(unexpected_packet_error("SUBACK", ack.kind, span));
+        }
+        Ok(())
+    }
+
+    /// Blocks until the next PUBLISH message arrives, transparently answering any PINGREQ
+    /// the broker sends (brokers may still probe liveness even with `keep_alive = 0`) and
+    /// skipping any other packet type.
+    pub(crate) fn read_publish(&mut self, span: Span) -> Result<PublishedMessage, ShellError> {
+        loop {
+            let Packet { kind, body } = packet::read_packet(&mut self.stream, span)?;
+            match kind & 0xf0 {
+                k if k == packet::PUBLISH & 0xf0 => return packet::parse_publish(&body, span),
+                k if k == packet::PINGREQ => {
+                    self.stream
+                        .write_all(&[packet::PINGRESP, 0])
+                        .map_err(|err| connection_error(err, span))?;
+                }
+                _ => continue,
+            }
+        }
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        let _ = self.stream.write_all(&packet::disconnect());
+    }
+}
+
+fn unexpected_packet_error(expected: &str, got: u8, span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Unexpected MQTT packet".into(),
+        msg: format!("expected {expected}, got packet type 0x{got:02x}"),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
+
+fn connection_error(err: std::io::Error, span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "MQTT connection error".into(),
+        msg: err.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
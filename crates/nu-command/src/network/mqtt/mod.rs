@@ -0,0 +1,14 @@
+mod connection;
+mod packet;
+mod publish;
+mod subscribe;
+
+use connection::Connection;
+use rand::prelude::{thread_rng, Rng};
+
+pub use publish::MqttPublish;
+pub use subscribe::MqttSubscribe;
+
+fn random_client_id() -> String {
+    format!("nu-{:08x}", thread_rng().gen::<u32>())
+}
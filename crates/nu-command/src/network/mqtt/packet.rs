@@ -0,0 +1,175 @@
+//! Minimal MQTT v3.1.1 packet encoding/decoding - just enough of the wire format to connect,
+//! publish, and subscribe at QoS 0. See [`super::Connection`] for what's deliberately left out.
+
+use nu_engine::command_prelude::*;
+use std::io::Read;
+
+pub(crate) const CONNECT: u8 = 0x10;
+pub(crate) const CONNACK: u8 = 0x20;
+pub(crate) const PUBLISH: u8 = 0x30;
+pub(crate) const SUBSCRIBE: u8 = 0x82;
+pub(crate) const SUBACK: u8 = 0x90;
+pub(crate) const PINGREQ: u8 = 0xc0;
+pub(crate) const PINGRESP: u8 = 0xd0;
+pub(crate) const DISCONNECT: u8 = 0xe0;
+
+/// A decoded packet: the first byte (type + flags) and the payload past the remaining-length
+/// field, with fixed-header framing already stripped off.
+pub(crate) struct Packet {
+    pub(crate) kind: u8,
+    pub(crate) body: Vec<u8>,
+}
+
+fn put_u16(buf: &mut Vec<u8>, n: u16) {
+    buf.extend_from_slice(&n.to_be_bytes());
+}
+
+fn put_string(buf: &mut Vec<u8>, s: &str) {
+    put_u16(buf, s.len() as u16);
+    buf.extend_from_slice(s.as_bytes());
+}
+
+fn put_remaining_length(buf: &mut Vec<u8>, mut length: usize) {
+    loop {
+        let mut byte = (length % 128) as u8;
+        length /= 128;
+        if length > 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if length == 0 {
+            break;
+        }
+    }
+}
+
+fn frame(kind: u8, variable_header_and_payload: Vec<u8>) -> Vec<u8> {
+    let mut packet = vec![kind];
+    put_remaining_length(&mut packet, variable_header_and_payload.len());
+    packet.extend(variable_header_and_payload);
+    packet
+}
+
+/// Builds a CONNECT packet with `keep_alive = 0` (no ping thread needed - see
+/// [`super::Connection::open`]) and QoS 0 throughout.
+pub(crate) fn connect(
+    client_id: &str,
+    username: &Option<String>,
+    password: &Option<String>,
+) -> Vec<u8> {
+    let mut flags = 0x02; // clean session
+    if username.is_some() {
+        flags |= 0x80;
+    }
+    if password.is_some() {
+        flags |= 0x40;
+    }
+
+    let mut body = Vec::new();
+    put_string(&mut body, "MQTT");
+    body.push(4); // protocol level: MQTT 3.1.1
+    body.push(flags);
+    put_u16(&mut body, 0); // keep alive
+    put_string(&mut body, client_id);
+    if let Some(username) = username {
+        put_string(&mut body, username);
+    }
+    if let Some(password) = password {
+        put_string(&mut body, password);
+    }
+
+    frame(CONNECT, body)
+}
+
+pub(crate) fn publish(topic: &str, payload: &[u8], retain: bool) -> Vec<u8> {
+    let kind = if retain { PUBLISH | 0x01 } else { PUBLISH };
+    let mut body = Vec::new();
+    put_string(&mut body, topic);
+    body.extend_from_slice(payload);
+    frame(kind, body)
+}
+
+pub(crate) fn subscribe(packet_id: u16, topic_filter: &str) -> Vec<u8> {
+    let mut body = Vec::new();
+    put_u16(&mut body, packet_id);
+    put_string(&mut body, topic_filter);
+    body.push(0); // requested QoS 0
+    frame(SUBSCRIBE, body)
+}
+
+pub(crate) fn disconnect() -> Vec<u8> {
+    frame(DISCONNECT, Vec::new())
+}
+
+/// A PUBLISH packet's variable header and payload, split apart.
+pub(crate) struct PublishedMessage {
+    pub(crate) topic: String,
+    pub(crate) payload: Vec<u8>,
+}
+
+pub(crate) fn parse_publish(body: &[u8], span: Span) -> Result<PublishedMessage, ShellError> {
+    if body.len() < 2 {
+        return Err(malformed_error(span));
+    }
+    let topic_len = u16::from_be_bytes([body[0], body[1]]) as usize;
+    let topic_start = 2;
+    let topic_end = topic_start + topic_len;
+    let topic = body
+        .get(topic_start..topic_end)
+        .ok_or_else(|| malformed_error(span))
+        .and_then(|bytes| String::from_utf8(bytes.to_vec()).map_err(|_| malformed_error(span)))?;
+    let payload = body.get(topic_end..).unwrap_or_default().to_vec();
+    Ok(PublishedMessage { topic, payload })
+}
+
+/// Reads one complete packet (fixed header + remaining length + body) off `stream`.
+pub(crate) fn read_packet(stream: &mut impl Read, span: Span) -> Result<Packet, ShellError> {
+    let mut first_byte = [0u8; 1];
+    stream
+        .read_exact(&mut first_byte)
+        .map_err(|err| io_error(err, span))?;
+
+    let mut remaining_length = 0usize;
+    let mut multiplier = 1usize;
+    loop {
+        let mut byte = [0u8; 1];
+        stream
+            .read_exact(&mut byte)
+            .map_err(|err| io_error(err, span))?;
+        remaining_length += (byte[0] & 0x7f) as usize * multiplier;
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+        multiplier *= 128;
+    }
+
+    let mut body = vec![0u8; remaining_length];
+    stream
+        .read_exact(&mut body)
+        .map_err(|err| io_error(err, span))?;
+
+    Ok(Packet {
+        kind: first_byte[0],
+        body,
+    })
+}
+
+fn malformed_error(span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Malformed MQTT packet".into(),
+        msg: "received a PUBLISH packet that was too short to contain its own topic".into(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
+
+fn io_error(err: std::io::Error, span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "MQTT connection error".into(),
+        msg: err.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
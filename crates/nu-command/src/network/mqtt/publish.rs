@@ -0,0 +1,109 @@
+use super::{random_client_id, Connection};
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct MqttPublish;
+
+impl Command for MqttPublish {
+    fn name(&self) -> &str {
+        "mqtt publish"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::Nothing),
+                (Type::String, Type::Nothing),
+                (Type::Binary, Type::Nothing),
+            ])
+            .required("broker", SyntaxShape::String, "Broker hostname or address.")
+            .required("topic", SyntaxShape::String, "Topic to publish to.")
+            .named(
+                "port",
+                SyntaxShape::Int,
+                "Broker port (default 1883).",
+                Some('p'),
+            )
+            .named(
+                "client-id",
+                SyntaxShape::String,
+                "MQTT client identifier (default: randomly generated).",
+                None,
+            )
+            .named(
+                "username",
+                SyntaxShape::String,
+                "Username to authenticate with.",
+                None,
+            )
+            .named(
+                "password",
+                SyntaxShape::String,
+                "Password to authenticate with.",
+                None,
+            )
+            .switch(
+                "retain",
+                "Ask the broker to retain this message.",
+                Some('r'),
+            )
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Publish pipeline input to an MQTT topic."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Speaks just enough of the MQTT v3.1.1 wire protocol to connect, publish one message at \
+QoS 0, and disconnect - no retained session, no QoS 1/2 acknowledgements. Good for pushing a \
+sensor reading or command into a broker from a pipeline without shelling out to `mosquitto_pub`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["iot", "mosquitto", "broker", "pubsub"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Publish a reading to a topic",
+            example: r#"{ temp: 21.5 } | to json | mqtt publish broker.local sensors/kitchen/temp"#,
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let broker: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let topic: String = call.req(engine_state, stack, 1)?;
+        let port: Option<i64> = call.get_flag(engine_state, stack, "port")?;
+        let client_id: Option<String> = call.get_flag(engine_state, stack, "client-id")?;
+        let username: Option<String> = call.get_flag(engine_state, stack, "username")?;
+        let password: Option<String> = call.get_flag(engine_state, stack, "password")?;
+        let retain = call.has_flag(engine_state, stack, "retain")?;
+
+        let port = port.unwrap_or(1883) as u16;
+        let client_id = client_id.unwrap_or_else(random_client_id);
+
+        let mut payload = Vec::new();
+        input.write_to(&mut payload)?;
+
+        let mut connection = Connection::open(
+            &broker.item,
+            port,
+            &client_id,
+            &username,
+            &password,
+            broker.span,
+        )?;
+        connection.publish(&topic, &payload, retain, head)?;
+
+        Ok(PipelineData::Empty)
+    }
+}
@@ -0,0 +1,118 @@
+use super::{random_client_id, Connection};
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct MqttSubscribe;
+
+impl Command for MqttSubscribe {
+    fn name(&self) -> &str {
+        "mqtt subscribe"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .required("broker", SyntaxShape::String, "Broker hostname or address.")
+            .required(
+                "topic",
+                SyntaxShape::String,
+                "Topic filter to subscribe to, e.g. sensors/+/temp.",
+            )
+            .named(
+                "port",
+                SyntaxShape::Int,
+                "Broker port (default 1883).",
+                Some('p'),
+            )
+            .named(
+                "client-id",
+                SyntaxShape::String,
+                "MQTT client identifier (default: randomly generated).",
+                None,
+            )
+            .named(
+                "username",
+                SyntaxShape::String,
+                "Username to authenticate with.",
+                None,
+            )
+            .named(
+                "password",
+                SyntaxShape::String,
+                "Password to authenticate with.",
+                None,
+            )
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Stream messages published to an MQTT topic."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Subscribes at QoS 0 and yields each message as a record ({ topic, payload, qos, \
+timestamp }) as soon as it arrives, so it streams indefinitely - pipe into `first`/`take` to \
+stop early. Good for feeding an IoT broker topic into the usual table/filter/save pipeline \
+without a separate `mosquitto_sub` process."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["iot", "mosquitto", "broker", "pubsub"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Watch a sensor topic and print each reading",
+            example:
+                "mqtt subscribe broker.local sensors/+/temp | each {|msg| $msg.payload | decode }",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let broker: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let topic: String = call.req(engine_state, stack, 1)?;
+        let port: Option<i64> = call.get_flag(engine_state, stack, "port")?;
+        let client_id: Option<String> = call.get_flag(engine_state, stack, "client-id")?;
+        let username: Option<String> = call.get_flag(engine_state, stack, "username")?;
+        let password: Option<String> = call.get_flag(engine_state, stack, "password")?;
+
+        let port = port.unwrap_or(1883) as u16;
+        let client_id = client_id.unwrap_or_else(random_client_id);
+
+        let mut connection = Connection::open(
+            &broker.item,
+            port,
+            &client_id,
+            &username,
+            &password,
+            broker.span,
+        )?;
+        connection.subscribe(&topic, head)?;
+
+        let messages = std::iter::from_fn(move || match connection.read_publish(head) {
+            Ok(message) => Some(Value::record(
+                record! {
+                    "topic" => Value::string(message.topic, head),
+                    "payload" => Value::binary(message.payload, head),
+                    "qos" => Value::int(0, head),
+                    "timestamp" => {
+                        let now = chrono::Local::now();
+                        Value::date(now.with_timezone(now.offset()), head)
+                    },
+                },
+                head,
+            )),
+            Err(err) => Some(Value::error(err, head)),
+        });
+
+        Ok(messages.into_pipeline_data(head, engine_state.signals().clone()))
+    }
+}
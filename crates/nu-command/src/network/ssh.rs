@@ -0,0 +1,94 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::record;
+use std::process::Command as SystemCommand;
+
+#[derive(Clone)]
+pub struct SshRun;
+
+impl Command for SshRun {
+    fn name(&self) -> &str {
+        "ssh run"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ssh run")
+            .input_output_types(vec![(Type::Nothing, Type::record())])
+            .required("host", SyntaxShape::String, "The host to connect to, as accepted by the system `ssh` (e.g. `user@example.com`).")
+            .required("command", SyntaxShape::String, "The remote command to run.")
+            .named(
+                "port",
+                SyntaxShape::Int,
+                "The port to connect on.",
+                Some('p'),
+            )
+            .named(
+                "identity",
+                SyntaxShape::Filepath,
+                "Path to a private key to authenticate with.",
+                Some('i'),
+            )
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Run a command on a remote host over SSH and return its structured result."
+    }
+
+    fn extra_description(&self) -> &str {
+        "This shells out to the system's `ssh` binary rather than implementing the SSH protocol, \
+so it honors the user's `~/.ssh/config`, agent, and known_hosts as usual."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["remote", "network", "exec"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let host: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let remote_command: Spanned<String> = call.req(engine_state, stack, 1)?;
+        let port: Option<i64> = call.get_flag(engine_state, stack, "port")?;
+        let identity: Option<Spanned<String>> = call.get_flag(engine_state, stack, "identity")?;
+
+        let mut command = SystemCommand::new("ssh");
+        command.arg("-o").arg("BatchMode=yes");
+        if let Some(port) = port {
+            command.arg("-p").arg(port.to_string());
+        }
+        if let Some(identity) = &identity {
+            command.arg("-i").arg(&identity.item);
+        }
+        command.arg(&host.item).arg(&remote_command.item);
+
+        let output = command.output().map_err(|err| ShellError::GenericError {
+            error: "Failed to run ssh".into(),
+            msg: err.to_string(),
+            span: Some(call.head),
+            help: Some("make sure the `ssh` binary is installed and on PATH".into()),
+            inner: vec![],
+        })?;
+
+        let record = record! {
+            "host" => Value::string(host.item, host.span),
+            "command" => Value::string(remote_command.item, remote_command.span),
+            "exit_code" => Value::int(output.status.code().unwrap_or(-1) as i64, call.head),
+            "stdout" => Value::string(String::from_utf8_lossy(&output.stdout), call.head),
+            "stderr" => Value::string(String::from_utf8_lossy(&output.stderr), call.head),
+        };
+
+        Ok(Value::record(record, call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Run `uptime` on a remote host and get its structured result",
+            example: "ssh run myserver.example.com 'uptime'",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,126 @@
+use nu_engine::command_prelude::*;
+use std::path::{Component, Path, PathBuf};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "path common-prefix"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path common-prefix")
+            .input_output_types(vec![(Type::List(Box::new(Type::String)), Type::String)])
+            .category(Category::Path)
+    }
+
+    fn description(&self) -> &str {
+        "Find the longest common prefix shared by a list of paths."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Comparison is done component by component, so '/home/vi' and '/home/viking' share
+only '/home', not '/home/vi'. An empty list, or a list whose paths have no shared parent,
+yields an empty string."#
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        run(call, input)
+    }
+
+    fn run_const(
+        &self,
+        _working_set: &nu_protocol::engine::StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        run(call, input)
+    }
+
+    #[cfg(windows)]
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Find the common prefix of several paths",
+            example: r"[ C:\Users\viking\spam, C:\Users\viking\eggs ] | path common-prefix",
+            result: Some(Value::test_string(r"C:\Users\viking")),
+        }]
+    }
+
+    #[cfg(not(windows))]
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Find the common prefix of several paths",
+            example: r"[ /home/viking/spam, /home/viking/eggs ] | path common-prefix",
+            result: Some(Value::test_string(r"/home/viking")),
+        }]
+    }
+}
+
+fn run(call: &Call, input: PipelineData) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+
+    let paths: Vec<Value> = match input {
+        PipelineData::Value(Value::List { vals, .. }, ..) => vals,
+        PipelineData::ListStream(stream, ..) => stream.into_iter().collect(),
+        PipelineData::Empty { .. } => return Err(ShellError::PipelineEmpty { dst_span: head }),
+        _ => {
+            return Err(ShellError::UnsupportedInput {
+                msg: "Input value cannot be searched for a common prefix".to_string(),
+                input: "value originates from here".into(),
+                msg_span: head,
+                input_span: input.span().unwrap_or(head),
+            })
+        }
+    };
+
+    let paths = paths
+        .iter()
+        .map(Value::coerce_str)
+        .collect::<Result<Vec<_>, ShellError>>()?;
+
+    let prefix = common_prefix(paths.iter().map(|p| Path::new(p.as_ref())));
+    Ok(Value::string(prefix.to_string_lossy(), head).into_pipeline_data())
+}
+
+fn common_prefix<'a>(paths: impl IntoIterator<Item = &'a Path>) -> PathBuf {
+    let mut paths = paths.into_iter();
+    let Some(first) = paths.next() else {
+        return PathBuf::new();
+    };
+
+    let mut prefix: Vec<Component> = first.components().collect();
+    for path in paths {
+        let comps: Vec<Component> = path.components().collect();
+        let shared = prefix
+            .iter()
+            .zip(comps.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        prefix.truncate(shared);
+    }
+
+    prefix.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
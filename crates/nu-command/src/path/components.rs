@@ -0,0 +1,130 @@
+use super::PathSubcommandArguments;
+use nu_engine::command_prelude::*;
+use nu_protocol::engine::StateWorkingSet;
+use std::path::Path;
+
+struct Arguments;
+
+impl PathSubcommandArguments for Arguments {}
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "path components"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path components")
+            .input_output_types(vec![
+                (Type::String, Type::List(Box::new(Type::String))),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::List(Box::new(Type::String)))),
+                ),
+            ])
+            .category(Category::Path)
+    }
+
+    fn description(&self) -> &str {
+        "Split a path into a list of its components."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Unlike 'path split', root and prefix components (such as '/' or 'C:') are kept as a
+single element rather than dropped, so joining the result back together with 'path join'
+round-trips the original path."#
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        // This doesn't match explicit nulls
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+        input.map(
+            move |value| super::operate(&components, &Arguments, value, head),
+            engine_state.signals(),
+        )
+    }
+
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        // This doesn't match explicit nulls
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+        input.map(
+            move |value| super::operate(&components, &Arguments, value, head),
+            working_set.permanent().signals(),
+        )
+    }
+
+    #[cfg(windows)]
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Split a path into its components",
+            example: r"'C:\Users\viking\spam.txt' | path components",
+            result: Some(Value::test_list(vec![
+                Value::test_string(r"C:"),
+                Value::test_string(r"\"),
+                Value::test_string("Users"),
+                Value::test_string("viking"),
+                Value::test_string("spam.txt"),
+            ])),
+        }]
+    }
+
+    #[cfg(not(windows))]
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Split a path into its components",
+            example: "'/home/viking/spam.txt' | path components",
+            result: Some(Value::test_list(vec![
+                Value::test_string("/"),
+                Value::test_string("home"),
+                Value::test_string("viking"),
+                Value::test_string("spam.txt"),
+            ])),
+        }]
+    }
+}
+
+fn components(path: &Path, span: Span, _: &Arguments) -> Value {
+    let parts = path
+        .components()
+        .map(|c| Value::string(c.as_os_str().to_string_lossy(), span))
+        .collect();
+
+    Value::list(parts, span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,4 +1,6 @@
 mod basename;
+mod common_prefix;
+mod components;
 mod dirname;
 mod exists;
 mod expand;
@@ -6,11 +8,14 @@ mod join;
 mod parse;
 pub mod path_;
 mod relative_to;
+mod sanitize;
 mod self_;
 mod split;
 mod r#type;
 
 pub use basename::SubCommand as PathBasename;
+pub use common_prefix::SubCommand as PathCommonPrefix;
+pub use components::SubCommand as PathComponents;
 pub use dirname::SubCommand as PathDirname;
 pub use exists::SubCommand as PathExists;
 pub use expand::SubCommand as PathExpand;
@@ -19,6 +24,7 @@ pub use parse::SubCommand as PathParse;
 pub use path_::PathCommand as Path;
 pub use r#type::SubCommand as PathType;
 pub use relative_to::SubCommand as PathRelativeTo;
+pub use sanitize::SubCommand as PathSanitize;
 pub use self_::SubCommand as PathSelf;
 pub use split::SubCommand as PathSplit;
 
@@ -2,10 +2,11 @@ use super::PathSubcommandArguments;
 use nu_engine::command_prelude::*;
 use nu_path::expand_to_real_path;
 use nu_protocol::engine::StateWorkingSet;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 
 struct Arguments {
     path: Spanned<String>,
+    walk_up: bool,
 }
 
 impl PathSubcommandArguments for Arguments {}
@@ -32,6 +33,11 @@ impl Command for SubCommand {
                 SyntaxShape::String,
                 "Parent shared with the input path.",
             )
+            .switch(
+                "walk-up",
+                "Allow the result to walk upwards with '..' if the argument path is not a parent of the input path.",
+                None,
+            )
             .category(Category::Path)
     }
 
@@ -41,8 +47,8 @@ impl Command for SubCommand {
 
     fn extra_description(&self) -> &str {
         r#"Can be used only when the input and the argument paths are either both
-absolute or both relative. The argument path needs to be a parent of the input
-path."#
+absolute or both relative. Without --walk-up, the argument path needs to be a
+parent of the input path."#
     }
 
     fn is_const(&self) -> bool {
@@ -59,6 +65,7 @@ path."#
         let head = call.head;
         let args = Arguments {
             path: call.req(engine_state, stack, 0)?,
+            walk_up: call.has_flag(engine_state, stack, "walk-up")?,
         };
 
         // This doesn't match explicit nulls
@@ -80,6 +87,7 @@ path."#
         let head = call.head;
         let args = Arguments {
             path: call.req_const(working_set, 0)?,
+            walk_up: call.has_flag_const(working_set, "walk-up")?,
         };
 
         // This doesn't match explicit nulls
@@ -137,6 +145,11 @@ path."#
                 example: r"'eggs/bacon/sausage/spam' | path relative-to 'eggs/bacon/sausage'",
                 result: Some(Value::test_string(r"spam")),
             },
+            Example {
+                description: "Find a relative path that must walk upwards first",
+                example: r"'/home/spam' | path relative-to '/home/viking' --walk-up",
+                result: Some(Value::test_string(r"../spam")),
+            },
         ]
     }
 }
@@ -144,6 +157,9 @@ path."#
 fn relative_to(path: &Path, span: Span, args: &Arguments) -> Value {
     let lhs = expand_to_real_path(path);
     let rhs = expand_to_real_path(&args.path.item);
+    if args.walk_up {
+        return Value::string(walk_up(&lhs, &rhs).to_string_lossy(), span);
+    }
     match lhs.strip_prefix(&rhs) {
         Ok(p) => Value::string(p.to_string_lossy(), span),
         Err(e) => Value::error(
@@ -158,6 +174,29 @@ fn relative_to(path: &Path, span: Span, args: &Arguments) -> Value {
     }
 }
 
+// Express `path` relative to `base`, using ".." to walk up out of `base` as many times as
+// needed to reach their common ancestor. Purely lexical, like `strip_prefix`: it doesn't
+// consult the filesystem, so it can't see through symlinks.
+fn walk_up(path: &Path, base: &Path) -> PathBuf {
+    let path_comps: Vec<_> = path.components().collect();
+    let base_comps: Vec<_> = base.components().collect();
+
+    let shared = path_comps
+        .iter()
+        .zip(base_comps.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut result = PathBuf::new();
+    for _ in &base_comps[shared..] {
+        result.push(Component::ParentDir);
+    }
+    for comp in &path_comps[shared..] {
+        result.push(comp);
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,167 @@
+use super::PathSubcommandArguments;
+use nu_engine::command_prelude::*;
+use nu_path::secure_join;
+use nu_protocol::engine::StateWorkingSet;
+use std::path::PathBuf;
+
+struct Arguments {
+    root: Spanned<PathBuf>,
+    append: Vec<Spanned<String>>,
+}
+
+impl PathSubcommandArguments for Arguments {}
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "path sanitize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path sanitize")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .required(
+                "root",
+                SyntaxShape::Filepath,
+                "The directory the result must stay inside of.",
+            )
+            .rest(
+                "append",
+                SyntaxShape::String,
+                "Untrusted path segment to join onto the root.",
+            )
+            .category(Category::Path)
+    }
+
+    fn description(&self) -> &str {
+        "Join untrusted path segments onto a root, erroring if the result would escape it."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Unlike 'path join', this rejects any input containing enough '..' segments to walk
+back out of 'root', which makes it suitable for building a path out of user-controlled input,
+such as a URL or an archive member name. The check is purely lexical: it does not consult the
+filesystem, so a symlink that lives inside 'root' but points back out of it is not caught."#
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let args = Arguments {
+            root: call.req(engine_state, stack, 0)?,
+            append: call.rest(engine_state, stack, 1)?,
+        };
+
+        run(call, &args, input)
+    }
+
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let args = Arguments {
+            root: call.req_const(working_set, 0)?,
+            append: call.rest_const(working_set, 1)?,
+        };
+
+        run(call, &args, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Join a safe, relative path onto the root",
+                example: "'uploads/avatar.png' | path sanitize /srv/www",
+                result: None,
+            },
+            Example {
+                description: "Reject a path that tries to escape the root",
+                example: "'../../etc/passwd' | path sanitize /srv/www",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn run(call: &Call, args: &Arguments, input: PipelineData) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+
+    match input {
+        PipelineData::Value(val, md) => Ok(PipelineData::Value(handle_value(val, args, head), md)),
+        PipelineData::ListStream(stream, md) => Ok(PipelineData::Value(
+            handle_value(stream.into_value(), args, head),
+            md,
+        )),
+        PipelineData::Empty { .. } => Err(ShellError::PipelineEmpty { dst_span: head }),
+        _ => Err(ShellError::UnsupportedInput {
+            msg: "Input value cannot be sanitized".to_string(),
+            input: "value originates from here".into(),
+            msg_span: head,
+            input_span: input.span().unwrap_or(head),
+        }),
+    }
+}
+
+fn handle_value(v: Value, args: &Arguments, head: Span) -> Value {
+    let span = v.span();
+    match v {
+        Value::String { ref val, .. } => sanitize_one(val, span, head, args),
+        Value::List { vals, .. } => Value::list(
+            vals.into_iter()
+                .map(|v| handle_value(v, args, head))
+                .collect(),
+            span,
+        ),
+        _ => super::handle_invalid_values(v, head),
+    }
+}
+
+fn sanitize_one(part: &str, span: Span, head: Span, args: &Arguments) -> Value {
+    let parts = [part]
+        .into_iter()
+        .chain(args.append.iter().map(|p| p.item.as_str()));
+    match secure_join(&args.root.item, parts) {
+        Ok(joined) => Value::string(joined.to_string_lossy(), head),
+        Err(_) => Value::error(
+            ShellError::IncorrectValue {
+                msg: format!(
+                    "'{part}' escapes the root '{}' via '..'",
+                    args.root.item.display()
+                ),
+                val_span: span,
+                call_span: head,
+            },
+            span,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
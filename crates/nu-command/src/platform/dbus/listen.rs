@@ -0,0 +1,109 @@
+use super::Connection;
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct DbusListen;
+
+impl Command for DbusListen {
+    fn name(&self) -> &str {
+        "dbus listen"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .switch(
+                "system",
+                "Listen on the system bus instead of the session bus",
+                None,
+            )
+            .named(
+                "interface",
+                SyntaxShape::String,
+                "Only match signals from this interface.",
+                Some('i'),
+            )
+            .named(
+                "member",
+                SyntaxShape::String,
+                "Only match signals with this name.",
+                Some('m'),
+            )
+            .named(
+                "path",
+                SyntaxShape::String,
+                "Only match signals from this object path.",
+                Some('p'),
+            )
+            .category(Category::Platform)
+    }
+
+    fn description(&self) -> &str {
+        "Stream D-Bus signals as they arrive."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Registers a match rule on the session or system bus and yields each matching signal \
+as a record ({ sender, path, interface, member, args }) as soon as it arrives, so it streams \
+indefinitely - pipe into `first`/`take` to stop early. Linux only."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Watch NetworkManager state changes",
+            example: "dbus listen --system --interface org.freedesktop.NetworkManager",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let system = call.has_flag(engine_state, stack, "system")?;
+        let interface: Option<String> = call.get_flag(engine_state, stack, "interface")?;
+        let member: Option<String> = call.get_flag(engine_state, stack, "member")?;
+        let path: Option<String> = call.get_flag(engine_state, stack, "path")?;
+
+        let mut connection = Connection::open(system, head)?;
+        connection.add_match(&match_rule(&interface, &member, &path), head)?;
+
+        let signals = std::iter::from_fn(move || match connection.read_signal(head) {
+            Ok(message) => Some(Value::record(
+                record! {
+                    "sender" => message.sender.map(|s| Value::string(s, head)).unwrap_or(Value::nothing(head)),
+                    "path" => message.path.map(|s| Value::string(s, head)).unwrap_or(Value::nothing(head)),
+                    "interface" => message.interface.map(|s| Value::string(s, head)).unwrap_or(Value::nothing(head)),
+                    "member" => message.member.map(|s| Value::string(s, head)).unwrap_or(Value::nothing(head)),
+                    "args" => Value::list(message.body, head),
+                },
+                head,
+            )),
+            Err(err) => Some(Value::error(err, head)),
+        });
+
+        Ok(signals.into_pipeline_data(head, engine_state.signals().clone()))
+    }
+}
+
+fn match_rule(
+    interface: &Option<String>,
+    member: &Option<String>,
+    path: &Option<String>,
+) -> String {
+    let mut rule = "type='signal'".to_string();
+    if let Some(interface) = interface {
+        rule.push_str(&format!(",interface='{interface}'"));
+    }
+    if let Some(member) = member {
+        rule.push_str(&format!(",member='{member}'"));
+    }
+    if let Some(path) = path {
+        rule.push_str(&format!(",path='{path}'"));
+    }
+    rule
+}
@@ -0,0 +1,461 @@
+//! Hand-rolled marshalling for the subset of the D-Bus wire protocol this module needs: the
+//! fixed message header, the header fields array, and method call/return bodies built out of
+//! the basic types (`y b n q i u x t d s o g`), arrays of a single basic type, and variants
+//! wrapping a basic type. Structs and dict entries in message bodies aren't supported - no
+//! `dbus` crate is vendored, so this only covers what desktop-automation one-liners typically
+//! need (strings, numbers, booleans, and arrays of those, plus the variants property getters
+//! return).
+use nu_engine::command_prelude::*;
+use std::io::Read;
+
+pub(crate) const MSG_METHOD_CALL: u8 = 1;
+pub(crate) const MSG_ERROR: u8 = 3;
+pub(crate) const MSG_SIGNAL: u8 = 4;
+
+pub(crate) struct Message {
+    pub(crate) msg_type: u8,
+    pub(crate) reply_serial: Option<u32>,
+    pub(crate) sender: Option<String>,
+    pub(crate) path: Option<String>,
+    pub(crate) interface: Option<String>,
+    pub(crate) member: Option<String>,
+    pub(crate) error_name: Option<String>,
+    pub(crate) body: Vec<Value>,
+}
+
+struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    fn new() -> Self {
+        Self { buf: Vec::new() }
+    }
+
+    fn pad(&mut self, boundary: usize) {
+        while self.buf.len() % boundary != 0 {
+            self.buf.push(0);
+        }
+    }
+
+    fn byte(&mut self, v: u8) {
+        self.buf.push(v);
+    }
+
+    fn u32(&mut self, v: u32) {
+        self.pad(4);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn i64(&mut self, v: i64) {
+        self.pad(8);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn f64(&mut self, v: f64) {
+        self.pad(8);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn string(&mut self, s: &str) {
+        self.u32(s.len() as u32);
+        self.buf.extend_from_slice(s.as_bytes());
+        self.buf.push(0);
+    }
+
+    fn signature(&mut self, s: &str) {
+        self.byte(s.len() as u8);
+        self.buf.extend_from_slice(s.as_bytes());
+        self.buf.push(0);
+    }
+}
+
+/// Picks the D-Bus basic-type signature that `value` should be sent as.
+fn value_signature(value: &Value, span: Span) -> Result<String, ShellError> {
+    match value {
+        Value::Bool { .. } => Ok("b".into()),
+        Value::Int { .. } => Ok("x".into()),
+        Value::Float { .. } => Ok("d".into()),
+        Value::String { .. } => Ok("s".into()),
+        Value::List { vals, .. } => {
+            let elem = match vals.first() {
+                Some(first) => value_signature(first, span)?,
+                None => "s".into(),
+            };
+            Ok(format!("a{elem}"))
+        }
+        other => Err(ShellError::UnsupportedInput {
+            msg: "dbus call only supports bool, int, float, string, and list arguments".into(),
+            input: other.get_type().to_string(),
+            msg_span: span,
+            input_span: other.span(),
+        }),
+    }
+}
+
+fn write_value(
+    writer: &mut Writer,
+    sig: &str,
+    value: &Value,
+    span: Span,
+) -> Result<(), ShellError> {
+    match sig {
+        "b" => writer.u32(value.as_bool()? as u32),
+        "x" => writer.i64(value.as_int()?),
+        "d" => writer.f64(value.as_float()?),
+        "s" | "o" | "g" => {
+            let text = value.coerce_string()?;
+            if sig == "g" {
+                writer.signature(&text);
+            } else {
+                writer.string(&text);
+            }
+        }
+        sig if sig.starts_with('a') => {
+            let elem_sig = &sig[1..];
+            let vals = value.as_list()?;
+            writer.u32(0); // placeholder, patched below
+            let len_pos = writer.buf.len() - 4;
+            writer.pad(element_alignment(elem_sig));
+            let start = writer.buf.len();
+            for val in vals {
+                write_value(writer, elem_sig, val, span)?;
+            }
+            let len = (writer.buf.len() - start) as u32;
+            writer.buf[len_pos..len_pos + 4].copy_from_slice(&len.to_le_bytes());
+        }
+        "v" => {
+            let inner_sig = value_signature(value, span)?;
+            writer.signature(&inner_sig);
+            write_value(writer, &inner_sig, value, span)?;
+        }
+        other => {
+            return Err(ShellError::GenericError {
+                error: "Unsupported D-Bus signature".into(),
+                msg: format!("signature `{other}` is not supported by this client"),
+                span: Some(span),
+                help: Some(
+                    "only basic types, arrays of a basic type, and variants are supported".into(),
+                ),
+                inner: vec![],
+            })
+        }
+    }
+    Ok(())
+}
+
+fn element_alignment(sig: &str) -> usize {
+    match sig.chars().next() {
+        Some('y' | 'g') => 1,
+        Some('n' | 'q') => 2,
+        Some('b' | 'i' | 'u' | 's' | 'o' | 'a') => 4,
+        Some('x' | 't' | 'd') => 8,
+        Some('v') => 1,
+        _ => 8,
+    }
+}
+
+/// Builds a complete, ready-to-send method call message.
+pub(crate) fn method_call(
+    serial: u32,
+    destination: Option<&str>,
+    path: &str,
+    interface: &str,
+    member: &str,
+    args: &[Value],
+    span: Span,
+) -> Result<Vec<u8>, ShellError> {
+    let mut body = Writer::new();
+    let mut signature = String::new();
+    for arg in args {
+        let sig = value_signature(arg, span)?;
+        write_value(&mut body, &sig, arg, span)?;
+        signature.push_str(&sig);
+    }
+
+    let mut fields = Writer::new();
+    write_field(&mut fields, 1, "o", path);
+    write_field(&mut fields, 2, "s", interface);
+    write_field(&mut fields, 3, "s", member);
+    if let Some(destination) = destination {
+        write_field(&mut fields, 6, "s", destination);
+    }
+    if !signature.is_empty() {
+        write_field(&mut fields, 8, "g", &signature);
+    }
+
+    Ok(assemble(MSG_METHOD_CALL, serial, &fields.buf, &body.buf))
+}
+
+fn write_field(fields: &mut Writer, code: u8, sig: &str, value: &str) {
+    fields.pad(8);
+    fields.byte(code);
+    fields.signature(sig);
+    if sig == "g" {
+        fields.signature(value);
+    } else {
+        fields.string(value);
+    }
+}
+
+fn assemble(msg_type: u8, serial: u32, fields: &[u8], body: &[u8]) -> Vec<u8> {
+    let mut header = Writer::new();
+    header.byte(b'l');
+    header.byte(msg_type);
+    header.byte(0); // flags
+    header.byte(1); // protocol version
+    header.u32(body.len() as u32);
+    header.u32(serial);
+    header.u32(fields.len() as u32);
+    header.pad(8);
+    header.buf.extend_from_slice(fields);
+    header.pad(8);
+    let mut out = header.buf;
+    out.extend_from_slice(body);
+    out
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn pad(&mut self, boundary: usize) {
+        self.pos = self.pos.div_ceil(boundary) * boundary;
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let slice = self.buf.get(self.pos..self.pos + len)?;
+        self.pos += len;
+        Some(slice)
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        self.bytes(1).map(|b| b[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.pad(4);
+        self.bytes(4)
+            .map(|b| u32::from_le_bytes(b.try_into().expect("checked len")))
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        self.pad(4);
+        self.bytes(4)
+            .map(|b| i32::from_le_bytes(b.try_into().expect("checked len")))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.pad(8);
+        self.bytes(8)
+            .map(|b| u64::from_le_bytes(b.try_into().expect("checked len")))
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        self.pad(8);
+        self.bytes(8)
+            .map(|b| i64::from_le_bytes(b.try_into().expect("checked len")))
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        self.pad(8);
+        self.bytes(8)
+            .map(|b| f64::from_le_bytes(b.try_into().expect("checked len")))
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let len = self.u32()? as usize;
+        let bytes = self.bytes(len)?;
+        let text = String::from_utf8_lossy(bytes).into_owned();
+        self.byte()?; // trailing NUL
+        Some(text)
+    }
+
+    fn signature(&mut self) -> Option<String> {
+        let len = self.byte()? as usize;
+        let bytes = self.bytes(len)?;
+        let text = String::from_utf8_lossy(bytes).into_owned();
+        self.byte()?; // trailing NUL
+        Some(text)
+    }
+}
+
+fn read_value(reader: &mut Reader, sig: &str, span: Span) -> Option<Value> {
+    let mut chars = sig.chars();
+    let head = chars.next()?;
+    match head {
+        'y' => reader.byte().map(|v| Value::int(v as i64, span)),
+        'b' => reader.u32().map(|v| Value::bool(v != 0, span)),
+        'n' => {
+            reader.pad(2);
+            reader.bytes(2).map(|b| {
+                Value::int(
+                    i16::from_le_bytes(b.try_into().unwrap_or([0; 2])) as i64,
+                    span,
+                )
+            })
+        }
+        'q' => {
+            reader.pad(2);
+            reader.bytes(2).map(|b| {
+                Value::int(
+                    u16::from_le_bytes(b.try_into().unwrap_or([0; 2])) as i64,
+                    span,
+                )
+            })
+        }
+        'i' => reader.i32().map(|v| Value::int(v as i64, span)),
+        'u' => reader.u32().map(|v| Value::int(v as i64, span)),
+        'x' => reader.i64().map(|v| Value::int(v, span)),
+        't' => reader.u64().map(|v| Value::int(v as i64, span)),
+        'd' => reader.f64().map(|v| Value::float(v, span)),
+        's' | 'o' => reader.string().map(|v| Value::string(v, span)),
+        'g' => reader.signature().map(|v| Value::string(v, span)),
+        'v' => {
+            let inner_sig = reader.signature()?;
+            read_value(reader, &inner_sig, span)
+        }
+        'a' => {
+            let elem_sig = &sig[1..];
+            let len = reader.u32()? as usize;
+            reader.pad(element_alignment(elem_sig));
+            let end = reader.pos + len;
+            let mut vals = Vec::new();
+            while reader.pos < end {
+                vals.push(read_value(reader, elem_sig, span)?);
+            }
+            Some(Value::list(vals, span))
+        }
+        _ => None,
+    }
+}
+
+fn split_top_level_signatures(sig: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut depth = 0usize;
+    let mut current = String::new();
+    for ch in sig.chars() {
+        current.push(ch);
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            'a' => continue, // array marker is part of the next element
+            _ if depth == 0 => {
+                result.push(std::mem::take(&mut current));
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Reads one complete message off `stream`, blocking until the header and body arrive.
+pub(crate) fn read_message(stream: &mut impl Read, span: Span) -> Result<Message, ShellError> {
+    let mut fixed = [0u8; 16];
+    stream
+        .read_exact(&mut fixed)
+        .map_err(|err| io_error(err, span))?;
+    let msg_type = fixed[1];
+    let body_len = u32::from_le_bytes(fixed[4..8].try_into().unwrap_or_default()) as usize;
+    let fields_len = u32::from_le_bytes(fixed[12..16].try_into().unwrap_or_default()) as usize;
+
+    let padded_fields_len = fields_len.div_ceil(8) * 8;
+    let mut fields_buf = vec![0u8; padded_fields_len];
+    stream
+        .read_exact(&mut fields_buf)
+        .map_err(|err| io_error(err, span))?;
+
+    let mut body = vec![0u8; body_len];
+    stream
+        .read_exact(&mut body)
+        .map_err(|err| io_error(err, span))?;
+
+    let mut reader = Reader::new(&fields_buf[..fields_len]);
+    let mut path = None;
+    let mut interface = None;
+    let mut member = None;
+    let mut error_name = None;
+    let mut reply_serial = None;
+    let mut sender = None;
+    let mut signature = String::new();
+    while reader.pos < fields_len {
+        reader.pad(8);
+        if reader.pos >= fields_len {
+            break;
+        }
+        let Some(code) = reader.byte() else { break };
+        let Some(field_sig) = reader.signature() else {
+            break;
+        };
+        match code {
+            1 => {
+                path = read_value(&mut reader, &field_sig, span)
+                    .and_then(|v| v.coerce_into_string().ok())
+            }
+            2 => {
+                interface = read_value(&mut reader, &field_sig, span)
+                    .and_then(|v| v.coerce_into_string().ok())
+            }
+            3 => {
+                member = read_value(&mut reader, &field_sig, span)
+                    .and_then(|v| v.coerce_into_string().ok())
+            }
+            4 => {
+                error_name = read_value(&mut reader, &field_sig, span)
+                    .and_then(|v| v.coerce_into_string().ok())
+            }
+            5 => {
+                reply_serial = read_value(&mut reader, &field_sig, span)
+                    .and_then(|v| v.as_int().ok())
+                    .map(|v| v as u32)
+            }
+            7 => {
+                sender = read_value(&mut reader, &field_sig, span)
+                    .and_then(|v| v.coerce_into_string().ok())
+            }
+            8 => {
+                signature = read_value(&mut reader, &field_sig, span)
+                    .and_then(|v| v.coerce_into_string().ok())
+                    .unwrap_or_default()
+            }
+            _ => {
+                let _ = read_value(&mut reader, &field_sig, span);
+            }
+        }
+    }
+    let mut body_reader = Reader::new(&body);
+    let mut values = Vec::new();
+    for sig in split_top_level_signatures(&signature) {
+        match read_value(&mut body_reader, &sig, span) {
+            Some(value) => values.push(value),
+            None => break,
+        }
+    }
+
+    Ok(Message {
+        msg_type,
+        reply_serial,
+        sender,
+        path,
+        interface,
+        member,
+        error_name,
+        body: values,
+    })
+}
+
+fn io_error(err: std::io::Error, span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "D-Bus connection error".into(),
+        msg: err.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
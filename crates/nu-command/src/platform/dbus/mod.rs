@@ -0,0 +1,207 @@
+mod call;
+mod listen;
+mod marshal;
+
+pub use call::DbusCall;
+pub use listen::DbusListen;
+
+use nu_engine::command_prelude::*;
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+
+/// A connection to either the session or system message bus, authenticated and past the
+/// `Hello` handshake, ready to send method calls and read back replies or signals.
+pub(crate) struct Connection {
+    stream: UnixStream,
+    next_serial: u32,
+}
+
+impl Connection {
+    pub(crate) fn open(system: bool, span: Span) -> Result<Self, ShellError> {
+        let address = if system {
+            "/var/run/dbus/system_bus_socket".to_string()
+        } else {
+            session_bus_path(span)?
+        };
+        let mut stream = UnixStream::connect(&address).map_err(|err| ShellError::GenericError {
+            error: "Could not connect to D-Bus".into(),
+            msg: err.to_string(),
+            span: Some(span),
+            help: Some(format!("tried to connect to {address}")),
+            inner: vec![],
+        })?;
+        authenticate(&mut stream, span)?;
+
+        let mut connection = Self {
+            stream,
+            next_serial: 1,
+        };
+        connection.call(
+            "org.freedesktop.DBus",
+            "/org/freedesktop/DBus",
+            "org.freedesktop.DBus",
+            "Hello",
+            &[],
+            span,
+        )?;
+        Ok(connection)
+    }
+
+    fn next_serial(&mut self) -> u32 {
+        let serial = self.next_serial;
+        self.next_serial += 1;
+        serial
+    }
+
+    /// Sends a method call and blocks until its matching reply (or an error) comes back,
+    /// skipping over any signals that arrive in the meantime.
+    pub(crate) fn call(
+        &mut self,
+        destination: &str,
+        path: &str,
+        interface: &str,
+        member: &str,
+        args: &[Value],
+        span: Span,
+    ) -> Result<Vec<Value>, ShellError> {
+        let serial = self.next_serial();
+        let message = marshal::method_call(
+            serial,
+            Some(destination),
+            path,
+            interface,
+            member,
+            args,
+            span,
+        )?;
+        self.stream
+            .write_all(&message)
+            .map_err(|err| connection_error(err, span))?;
+
+        loop {
+            let reply = marshal::read_message(&mut self.stream, span)?;
+            if reply.reply_serial != Some(serial) {
+                continue;
+            }
+            return if reply.msg_type == marshal::MSG_ERROR {
+                Err(ShellError::GenericError {
+                    error: "D-Bus call failed".into(),
+                    msg: reply.error_name.unwrap_or_else(|| "unknown error".into()),
+                    span: Some(span),
+                    help: reply.body.first().and_then(|v| v.coerce_string().ok()),
+                    inner: vec![],
+                })
+            } else {
+                Ok(reply.body)
+            };
+        }
+    }
+
+    /// Registers a signal match rule on the bus, so [`Connection::read_signal`] will see
+    /// matching signals arrive.
+    pub(crate) fn add_match(&mut self, rule: &str, span: Span) -> Result<(), ShellError> {
+        self.call(
+            "org.freedesktop.DBus",
+            "/org/freedesktop/DBus",
+            "org.freedesktop.DBus",
+            "AddMatch",
+            &[Value::string(rule, span)],
+            span,
+        )?;
+        Ok(())
+    }
+
+    /// Blocks until the next SIGNAL message arrives, ignoring any other message types.
+    pub(crate) fn read_signal(&mut self, span: Span) -> Result<marshal::Message, ShellError> {
+        loop {
+            let message = marshal::read_message(&mut self.stream, span)?;
+            if message.msg_type == marshal::MSG_SIGNAL {
+                return Ok(message);
+            }
+        }
+    }
+}
+
+fn session_bus_path(span: Span) -> Result<String, ShellError> {
+    let address =
+        std::env::var("DBUS_SESSION_BUS_ADDRESS").map_err(|_| ShellError::GenericError {
+            error: "Could not find the session bus".into(),
+            msg: "DBUS_SESSION_BUS_ADDRESS is not set".into(),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        })?;
+    address
+        .split(',')
+        .find_map(|part| part.strip_prefix("unix:path="))
+        .map(str::to_string)
+        .ok_or_else(|| ShellError::GenericError {
+            error: "Could not find the session bus".into(),
+            msg: format!("don't know how to connect to `{address}`"),
+            span: Some(span),
+            help: Some("only unix:path= session bus addresses are supported".into()),
+            inner: vec![],
+        })
+}
+
+/// Performs the SASL `EXTERNAL` handshake D-Bus uses over local sockets: the client asserts
+/// its uid (hex-encoded) and the server either accepts or rejects it.
+fn authenticate(stream: &mut UnixStream, span: Span) -> Result<(), ShellError> {
+    stream
+        .write_all(&[0])
+        .map_err(|err| connection_error(err, span))?;
+
+    let uid = unsafe { libc::geteuid() };
+    let hex_uid = uid
+        .to_string()
+        .bytes()
+        .map(|b| format!("{b:02x}"))
+        .collect::<String>();
+    write_sasl_line(stream, &format!("AUTH EXTERNAL {hex_uid}"), span)?;
+    let response = read_sasl_line(stream, span)?;
+    if !response.starts_with("OK") {
+        return Err(ShellError::GenericError {
+            error: "D-Bus authentication failed".into(),
+            msg: response,
+            span: Some(span),
+            help: Some("only the EXTERNAL mechanism is supported".into()),
+            inner: vec![],
+        });
+    }
+    write_sasl_line(stream, "BEGIN", span)
+}
+
+fn write_sasl_line(stream: &mut UnixStream, line: &str, span: Span) -> Result<(), ShellError> {
+    stream
+        .write_all(format!("{line}\r\n").as_bytes())
+        .map_err(|err| connection_error(err, span))
+}
+
+fn read_sasl_line(stream: &mut UnixStream, span: Span) -> Result<String, ShellError> {
+    use std::io::Read;
+
+    let mut line = Vec::new();
+    let mut byte = [0u8; 1];
+    loop {
+        stream
+            .read_exact(&mut byte)
+            .map_err(|err| connection_error(err, span))?;
+        if byte[0] == b'\n' {
+            break;
+        }
+        if byte[0] != b'\r' {
+            line.push(byte[0]);
+        }
+    }
+    Ok(String::from_utf8_lossy(&line).into_owned())
+}
+
+fn connection_error(err: std::io::Error, span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "D-Bus connection error".into(),
+        msg: err.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
@@ -0,0 +1,100 @@
+mod read;
+mod write;
+
+pub use read::DefaultsRead;
+pub use write::DefaultsWrite;
+
+use chrono::{DateTime, Offset, Utc};
+use nu_engine::command_prelude::*;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Resolves a macOS preferences domain (e.g. `com.apple.finder`) to the plist file it's backed
+/// by, the same file `defaults` itself reads and writes under `~/Library/Preferences`.
+fn domain_path(domain: &str) -> Result<PathBuf, ShellError> {
+    let home = nu_path::home_dir().ok_or_else(|| ShellError::GenericError {
+        error: "Could not find home directory".into(),
+        msg: "could not find home directory".into(),
+        span: None,
+        help: None,
+        inner: vec![],
+    })?;
+    let mut path: PathBuf = home.into();
+    path.push("Library");
+    path.push("Preferences");
+    path.push(format!("{domain}.plist"));
+    Ok(path)
+}
+
+fn plist_read_error(err: plist::Error, span: Span, path: &std::path::Path) -> ShellError {
+    ShellError::GenericError {
+        error: "Could not parse preferences plist".into(),
+        msg: err.to_string(),
+        span: Some(span),
+        help: Some(format!("the plist at {} may be corrupt", path.display())),
+        inner: vec![],
+    }
+}
+
+/// Converts a parsed plist into the equivalent Nushell value, using the same mapping as the
+/// `nu_plugin_formats` plugin's `from plist` so a domain read here matches what a user would get
+/// piping the raw file through that plugin.
+fn plist_to_value(plist_val: &plist::Value, span: Span) -> Value {
+    match plist_val {
+        plist::Value::String(s) => Value::string(s, span),
+        plist::Value::Boolean(b) => Value::bool(*b, span),
+        plist::Value::Real(r) => Value::float(*r, span),
+        plist::Value::Integer(i) => match i.as_signed() {
+            Some(signed) => Value::int(signed, span),
+            None => Value::float(i.as_unsigned().unwrap_or_default() as f64, span),
+        },
+        plist::Value::Uid(uid) => Value::float(uid.get() as f64, span),
+        plist::Value::Data(data) => Value::binary(data.clone(), span),
+        plist::Value::Date(date) => {
+            let system_time: SystemTime = date.to_owned().into();
+            let utc_date: DateTime<Utc> = system_time.into();
+            let utc_offset = utc_date.offset().fix();
+            Value::date(utc_date.with_timezone(&utc_offset), span)
+        }
+        plist::Value::Array(arr) => {
+            Value::list(arr.iter().map(|v| plist_to_value(v, span)).collect(), span)
+        }
+        plist::Value::Dictionary(dict) => Value::record(
+            dict.iter()
+                .map(|(k, v)| (k.clone(), plist_to_value(v, span)))
+                .collect(),
+            span,
+        ),
+        _ => Value::nothing(span),
+    }
+}
+
+/// Converts a Nushell value into the equivalent plist value, using the same mapping as the
+/// `nu_plugin_formats` plugin's `to plist`.
+fn value_to_plist(value: &Value) -> Result<plist::Value, ShellError> {
+    match value {
+        Value::String { val, .. } | Value::Glob { val, .. } => {
+            Ok(plist::Value::String(val.clone()))
+        }
+        Value::Bool { val, .. } => Ok(plist::Value::Boolean(*val)),
+        Value::Float { val, .. } => Ok(plist::Value::Real(*val)),
+        Value::Int { val, .. } => Ok(plist::Value::Integer((*val).into())),
+        Value::Filesize { val, .. } => Ok(plist::Value::Integer(val.get().into())),
+        Value::Binary { val, .. } => Ok(plist::Value::Data(val.clone())),
+        Value::Date { val, .. } => Ok(plist::Value::Date(SystemTime::from(*val).into())),
+        Value::List { vals, .. } => Ok(plist::Value::Array(
+            vals.iter().map(value_to_plist).collect::<Result<_, _>>()?,
+        )),
+        Value::Record { val, .. } => Ok(plist::Value::Dictionary(
+            val.iter()
+                .map(|(k, v)| value_to_plist(v).map(|v| (k.clone(), v)))
+                .collect::<Result<_, _>>()?,
+        )),
+        _ => Err(ShellError::CantConvert {
+            to_type: "plist".into(),
+            from_type: value.get_type().to_string(),
+            span: value.span(),
+            help: None,
+        }),
+    }
+}
@@ -0,0 +1,78 @@
+use super::{domain_path, plist_read_error, plist_to_value};
+use nu_engine::command_prelude::*;
+use nu_protocol::ast::PathMember;
+
+#[derive(Clone)]
+pub struct DefaultsRead;
+
+impl Command for DefaultsRead {
+    fn name(&self) -> &str {
+        "defaults read"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .required(
+                "domain",
+                SyntaxShape::String,
+                "Preferences domain, e.g. com.apple.finder.",
+            )
+            .optional("key", SyntaxShape::String, "A single key to read.")
+            .category(Category::Platform)
+    }
+
+    fn description(&self) -> &str {
+        "Read a macOS preferences domain as structured data."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Reads the plist backing `domain` directly from `~/Library/Preferences`, the same file \
+the `defaults` command line tool reads, without shelling out to it. Only available on macOS."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let domain: String = call.req(engine_state, stack, 0)?;
+        let key: Option<Spanned<String>> = call.opt(engine_state, stack, 1)?;
+
+        let path = domain_path(&domain)?;
+        let bytes = std::fs::read(&path).map_err(|err| ShellError::GenericError {
+            error: format!("Could not read preferences for domain `{domain}`"),
+            msg: err.to_string(),
+            span: Some(head),
+            help: Some(format!("expected to find a plist at {}", path.display())),
+            inner: vec![],
+        })?;
+        let plist_val = plist::Value::from_reader(std::io::Cursor::new(bytes))
+            .map_err(|err| plist_read_error(err, head, &path))?;
+        let value = plist_to_value(&plist_val, head);
+
+        match key {
+            Some(key) => value.follow_cell_path(
+                &[PathMember::String {
+                    val: key.item,
+                    span: key.span,
+                    optional: false,
+                }],
+                false,
+            ),
+            None => Ok(value),
+        }
+        .map(|value| value.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Read the whole com.apple.finder preferences domain",
+            example: "defaults read com.apple.finder",
+            result: None,
+        }]
+    }
+}
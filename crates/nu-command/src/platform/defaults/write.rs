@@ -0,0 +1,110 @@
+use super::{domain_path, plist_read_error, plist_to_value, value_to_plist};
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct DefaultsWrite;
+
+impl Command for DefaultsWrite {
+    fn name(&self) -> &str {
+        "defaults write"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "domain",
+                SyntaxShape::String,
+                "Preferences domain, e.g. com.apple.finder.",
+            )
+            .required("key", SyntaxShape::String, "Name of the key to write.")
+            .required("value", SyntaxShape::Any, "Value to write.")
+            .category(Category::Platform)
+    }
+
+    fn description(&self) -> &str {
+        "Write a single key in a macOS preferences domain."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Writes directly to the plist backing `domain` under `~/Library/Preferences`, the same \
+file the `defaults` command line tool reads and writes, without shelling out to it. If the \
+domain has no existing plist, a new one is created holding just `key`. Only available on macOS."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let domain: String = call.req(engine_state, stack, 0)?;
+        let key: String = call.req(engine_state, stack, 1)?;
+        let value: Value = call.req(engine_state, stack, 2)?;
+
+        let path = domain_path(&domain)?;
+        let mut record = match std::fs::read(&path) {
+            Ok(bytes) => {
+                let plist_val = plist::Value::from_reader(std::io::Cursor::new(bytes))
+                    .map_err(|err| plist_read_error(err, head, &path))?;
+                match plist_to_value(&plist_val, head) {
+                    Value::Record { val, .. } => val.into_owned(),
+                    _ => {
+                        return Err(ShellError::GenericError {
+                            error: "Existing preferences are not a dictionary".into(),
+                            msg: format!(
+                                "{} does not contain a top-level plist dict",
+                                path.display()
+                            ),
+                            span: Some(head),
+                            help: None,
+                            inner: vec![],
+                        })
+                    }
+                }
+            }
+            Err(_) => Record::new(),
+        };
+        record.insert(key, value);
+
+        let plist_val = value_to_plist(&Value::record(record, head))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| ShellError::GenericError {
+                error: "Could not create preferences directory".into(),
+                msg: err.to_string(),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            })?;
+        }
+        let mut out = Vec::new();
+        plist_val
+            .to_writer_xml(&mut out)
+            .map_err(|err| ShellError::GenericError {
+                error: "Could not encode preferences plist".into(),
+                msg: err.to_string(),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            })?;
+        std::fs::write(&path, out).map_err(|err| ShellError::GenericError {
+            error: format!("Could not write preferences for domain `{domain}`"),
+            msg: err.to_string(),
+            span: Some(head),
+            help: None,
+            inner: vec![],
+        })?;
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Set AppleShowAllExtensions to true for the finder domain",
+            example: "defaults write com.apple.finder AppleShowAllExtensions true",
+            result: None,
+        }]
+    }
+}
@@ -1,9 +1,16 @@
 mod ansi;
 mod clear;
+#[cfg(all(target_os = "linux", feature = "dbus"))]
+mod dbus;
+#[cfg(all(target_os = "macos", feature = "plist"))]
+mod defaults;
 mod dir_info;
 mod input;
 mod is_terminal;
 mod kill;
+mod prompt;
+#[cfg(unix)]
+mod serial;
 mod sleep;
 mod term;
 #[cfg(unix)]
@@ -12,12 +19,19 @@ mod whoami;
 
 pub use ansi::{Ansi, AnsiLink, AnsiStrip};
 pub use clear::Clear;
+#[cfg(all(target_os = "linux", feature = "dbus"))]
+pub use dbus::{DbusCall, DbusListen};
+#[cfg(all(target_os = "macos", feature = "plist"))]
+pub use defaults::{DefaultsRead, DefaultsWrite};
 pub use dir_info::{DirBuilder, DirInfo, FileInfo};
 pub use input::Input;
 pub use input::InputList;
 pub use input::InputListen;
 pub use is_terminal::IsTerminal;
 pub use kill::Kill;
+pub use prompt::PromptSegments;
+#[cfg(unix)]
+pub use serial::PortOpen;
 pub use sleep::Sleep;
 pub use term::{Term, TermQuery, TermSize};
 #[cfg(unix)]
@@ -0,0 +1,118 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::record;
+use std::process::Command as SystemCommand;
+
+#[derive(Clone)]
+pub struct PromptSegments;
+
+impl Command for PromptSegments {
+    fn name(&self) -> &str {
+        "prompt segments"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("prompt segments")
+            .input_output_types(vec![(Type::Nothing, Type::record())])
+            .category(Category::Platform)
+    }
+
+    fn description(&self) -> &str {
+        "Gather the pieces commonly used to build a prompt into one record."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Returns a record with `cwd`, `exit_code`, `duration`, and `git` (null outside a repo),
+so $env.PROMPT_COMMAND can read off whichever fields it wants instead of recomputing them, e.g.
+`$env.PROMPT_COMMAND = {|| let p = (prompt segments); $\"($p.cwd) ($p.git.branch?)\"}`. This reads
+`$env.LAST_EXIT_CODE` and `$env.CMD_DURATION` rather than recomputing them, since the REPL already
+sets those after every command. `git` is filled in by shelling out to the system `git` binary, the
+same way other external-tool commands in this crate wrap their binaries, rather than vendoring a
+git implementation. There's deliberately no `jobs` segment: this tree has no background-job
+tracking subsystem to report a count from."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["ps1", "prompt_command", "statusline"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let cwd = engine_state
+            .cwd(Some(stack))
+            .map(|p| Value::string(p.to_string_lossy().into_owned(), span))
+            .unwrap_or(Value::nothing(span));
+
+        let exit_code = stack
+            .get_env_var(engine_state, "LAST_EXIT_CODE")
+            .cloned()
+            .unwrap_or(Value::nothing(span));
+
+        let duration = stack
+            .get_env_var(engine_state, "CMD_DURATION")
+            .cloned()
+            .unwrap_or(Value::nothing(span));
+
+        let git = git_segment(engine_state, stack, span);
+
+        Ok(Value::record(
+            record! {
+                "cwd" => cwd,
+                "exit_code" => exit_code,
+                "duration" => duration,
+                "git" => git,
+            },
+            span,
+        )
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show the current branch and dirty flag in a repo",
+            example: "(prompt segments).git",
+            result: None,
+        }]
+    }
+}
+
+fn git_segment(engine_state: &EngineState, stack: &mut Stack, span: Span) -> Value {
+    let cwd = match engine_state.cwd(Some(stack)) {
+        Ok(cwd) => cwd,
+        Err(_) => return Value::nothing(span),
+    };
+
+    let branch = SystemCommand::new("git")
+        .current_dir(&cwd)
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+    let Some(branch) = branch else {
+        // Either `git` isn't installed, or `cwd` isn't inside a repo.
+        return Value::nothing(span);
+    };
+
+    let dirty = SystemCommand::new("git")
+        .current_dir(&cwd)
+        .args(["status", "--porcelain"])
+        .output()
+        .map(|output| !output.stdout.is_empty())
+        .unwrap_or(false);
+
+    Value::record(
+        record! {
+            "branch" => Value::string(branch, span),
+            "dirty" => Value::bool(dirty, span),
+        },
+        span,
+    )
+}
@@ -0,0 +1,291 @@
+use nu_engine::command_prelude::*;
+use std::fs::OpenOptions;
+use std::os::unix::io::AsRawFd;
+
+#[derive(Clone)]
+pub struct PortOpen;
+
+impl Command for PortOpen {
+    fn name(&self) -> &str {
+        "port open"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::Any),
+                (Type::Binary, Type::Any),
+                (Type::String, Type::Any),
+            ])
+            .required(
+                "path",
+                SyntaxShape::Filepath,
+                "Path to the serial device, e.g. /dev/ttyUSB0.",
+            )
+            .named(
+                "baud",
+                SyntaxShape::Int,
+                "Baud rate (default 9600).",
+                Some('b'),
+            )
+            .named(
+                "parity",
+                SyntaxShape::String,
+                "Parity: none, even, or odd (default none).",
+                Some('p'),
+            )
+            .named(
+                "data-bits",
+                SyntaxShape::Int,
+                "Data bits: 5, 6, 7, or 8 (default 8).",
+                None,
+            )
+            .named(
+                "stop-bits",
+                SyntaxShape::Int,
+                "Stop bits: 1 or 2 (default 1).",
+                None,
+            )
+            .switch(
+                "line",
+                "Use canonical (line-buffered) mode instead of raw byte mode.",
+                Some('l'),
+            )
+            .category(Category::Platform)
+    }
+
+    fn description(&self) -> &str {
+        "Open a serial port and expose it as a byte stream."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Configures the device with termios (baud rate, parity, data/stop bits) and returns it \
+as a byte stream of whatever the other end sends back. If there's pipeline input, it's written \
+to the port before the stream is returned, so a single call can do the usual embedded-device \
+`write command, read response` exchange without leaving the shell for minicom or a Python \
+script. By default the port is put in raw mode, passing bytes through as soon as they arrive; \
+`--line` switches to canonical mode instead, where the device is expected to delimit its own \
+output with newlines."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["tty", "uart", "embedded", "minicom"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Open a port at 115200 baud and read whatever it sends",
+                example: "port open /dev/ttyUSB0 --baud 115200",
+                result: None,
+            },
+            Example {
+                description: "Send a command to a device and read its reply",
+                example: "\"AT\\r\\n\" | port open /dev/ttyACM0 --baud 9600 --line",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let baud: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "baud")?;
+        let parity: Option<Spanned<String>> = call.get_flag(engine_state, stack, "parity")?;
+        let data_bits: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "data-bits")?;
+        let stop_bits: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "stop-bits")?;
+        let line_mode = call.has_flag(engine_state, stack, "line")?;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&path.item)
+            .map_err(|err| ShellError::GenericError {
+                error: "Could not open serial port".into(),
+                msg: err.to_string(),
+                span: Some(path.span),
+                help: None,
+                inner: vec![],
+            })?;
+
+        let settings = PortSettings {
+            baud: baud.as_ref().map(|b| b.item).unwrap_or(9600),
+            baud_span: baud.map(|b| b.span).unwrap_or(head),
+            parity: parity
+                .as_ref()
+                .map(|p| p.item.clone())
+                .unwrap_or_else(|| "none".into()),
+            parity_span: parity.map(|p| p.span).unwrap_or(head),
+            data_bits: data_bits.as_ref().map(|d| d.item).unwrap_or(8),
+            data_bits_span: data_bits.map(|d| d.span).unwrap_or(head),
+            stop_bits: stop_bits.as_ref().map(|s| s.item).unwrap_or(1),
+            stop_bits_span: stop_bits.map(|s| s.span).unwrap_or(head),
+            line_mode,
+        };
+        configure_port(&file, &settings)?;
+
+        if !matches!(input, PipelineData::Empty) {
+            input
+                .write_to(&file)
+                .map_err(|err| ShellError::GenericError {
+                    error: "Could not write to serial port".into(),
+                    msg: err.to_string(),
+                    span: Some(head),
+                    help: None,
+                    inner: vec![],
+                })?;
+        }
+
+        Ok(PipelineData::ByteStream(
+            ByteStream::file(file, head, engine_state.signals().clone()),
+            None,
+        ))
+    }
+}
+
+struct PortSettings {
+    baud: i64,
+    baud_span: Span,
+    parity: String,
+    parity_span: Span,
+    data_bits: i64,
+    data_bits_span: Span,
+    stop_bits: i64,
+    stop_bits_span: Span,
+    line_mode: bool,
+}
+
+fn configure_port(file: &std::fs::File, settings: &PortSettings) -> Result<(), ShellError> {
+    let fd = file.as_raw_fd();
+    let speed = baud_to_speed(settings.baud, settings.baud_span)?;
+
+    // SAFETY: `termios` is a plain-old-data struct and `tcgetattr` fully initializes it for a
+    // valid fd before we read any field back out of it.
+    let mut termios: libc::termios = unsafe { std::mem::zeroed() };
+    if unsafe { libc::tcgetattr(fd, &mut termios) } != 0 {
+        return Err(termios_error(
+            "Could not read serial port settings",
+            settings.baud_span,
+        ));
+    }
+
+    if settings.line_mode {
+        termios.c_iflag &= !(libc::IXON | libc::IXOFF);
+        termios.c_oflag &= !libc::OPOST;
+        termios.c_lflag |= libc::ICANON;
+        termios.c_lflag &= !(libc::ECHO | libc::ECHOE | libc::ISIG);
+    } else {
+        // SAFETY: `cfmakeraw` only writes into the `termios` we just initialized above.
+        unsafe { libc::cfmakeraw(&mut termios) };
+    }
+
+    termios.c_cflag |= libc::CREAD | libc::CLOCAL;
+    termios.c_cflag &= !libc::CSIZE;
+    termios.c_cflag |= match settings.data_bits {
+        5 => libc::CS5,
+        6 => libc::CS6,
+        7 => libc::CS7,
+        8 => libc::CS8,
+        _ => {
+            return Err(ShellError::IncorrectValue {
+                msg: "data bits must be 5, 6, 7, or 8".into(),
+                val_span: settings.data_bits_span,
+                call_span: settings.data_bits_span,
+            })
+        }
+    };
+
+    match settings.stop_bits {
+        1 => termios.c_cflag &= !libc::CSTOPB,
+        2 => termios.c_cflag |= libc::CSTOPB,
+        _ => {
+            return Err(ShellError::IncorrectValue {
+                msg: "stop bits must be 1 or 2".into(),
+                val_span: settings.stop_bits_span,
+                call_span: settings.stop_bits_span,
+            })
+        }
+    }
+
+    match settings.parity.as_str() {
+        "none" => termios.c_cflag &= !libc::PARENB,
+        "even" => {
+            termios.c_cflag |= libc::PARENB;
+            termios.c_cflag &= !libc::PARODD;
+        }
+        "odd" => termios.c_cflag |= libc::PARENB | libc::PARODD,
+        _ => {
+            return Err(ShellError::IncorrectValue {
+                msg: "parity must be \"none\", \"even\", or \"odd\"".into(),
+                val_span: settings.parity_span,
+                call_span: settings.parity_span,
+            })
+        }
+    }
+
+    // SAFETY: `termios` is a valid, fully-populated struct at this point.
+    unsafe {
+        if libc::cfsetispeed(&mut termios, speed) != 0
+            || libc::cfsetospeed(&mut termios, speed) != 0
+        {
+            return Err(termios_error(
+                "Could not set serial port baud rate",
+                settings.baud_span,
+            ));
+        }
+        if libc::tcsetattr(fd, libc::TCSANOW, &termios) != 0 {
+            return Err(termios_error(
+                "Could not apply serial port settings",
+                settings.baud_span,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn baud_to_speed(baud: i64, span: Span) -> Result<libc::speed_t, ShellError> {
+    Ok(match baud {
+        50 => libc::B50,
+        75 => libc::B75,
+        110 => libc::B110,
+        134 => libc::B134,
+        150 => libc::B150,
+        200 => libc::B200,
+        300 => libc::B300,
+        600 => libc::B600,
+        1200 => libc::B1200,
+        1800 => libc::B1800,
+        2400 => libc::B2400,
+        4800 => libc::B4800,
+        9600 => libc::B9600,
+        19200 => libc::B19200,
+        38400 => libc::B38400,
+        57600 => libc::B57600,
+        115200 => libc::B115200,
+        230400 => libc::B230400,
+        _ => {
+            return Err(ShellError::IncorrectValue {
+                msg: "unsupported baud rate".into(),
+                val_span: span,
+                call_span: span,
+            })
+        }
+    })
+}
+
+fn termios_error(error: &str, span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: error.into(),
+        msg: std::io::Error::last_os_error().to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
@@ -0,0 +1,165 @@
+use nu_engine::command_prelude::*;
+
+const EXTRA_USAGE: &str = r"Uses the Bitcoin alphabet (no 0, O, I, or l).
+
+Note this command will collect stream input.";
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn encode(input: &[u8]) -> String {
+    let zeros = input.iter().take_while(|&&b| b == 0).count();
+
+    let mut digits: Vec<u8> = vec![0; input.len() * 138 / 100 + 1];
+    for &byte in input {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut().rev() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+    }
+
+    "1".repeat(zeros)
+        + &digits
+            .into_iter()
+            .skip_while(|&d| d == 0)
+            .map(|d| ALPHABET[d as usize] as char)
+            .collect::<String>()
+}
+
+fn decode(input: &str, span: Span) -> Result<Vec<u8>, ShellError> {
+    let zeros = input.chars().take_while(|&c| c == '1').count();
+
+    let mut bytes: Vec<u8> = vec![0; input.len()];
+    for c in input.chars() {
+        let digit = ALPHABET
+            .iter()
+            .position(|&a| a as char == c)
+            .ok_or_else(|| ShellError::IncorrectValue {
+                msg: format!("'{c}' is not a valid Base58 character"),
+                val_span: span,
+                call_span: span,
+            })? as u32;
+
+        let mut carry = digit;
+        for byte in bytes.iter_mut().rev() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+    }
+
+    Ok(std::iter::repeat(0)
+        .take(zeros)
+        .chain(bytes.into_iter().skip_while(|&b| b == 0))
+        .collect())
+}
+
+#[derive(Clone)]
+pub struct DecodeBase58;
+
+impl Command for DecodeBase58 {
+    fn name(&self) -> &str {
+        "decode base58"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("decode base58")
+            .input_output_types(vec![(Type::String, Type::Binary)])
+            .allow_variants_without_examples(true)
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Decode a Base58 value."
+    }
+
+    fn extra_description(&self) -> &str {
+        EXTRA_USAGE
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Decode a Base58 string",
+            example: r#""6zkdh" | decode base58"#,
+            result: Some(Value::test_binary(vec![0x04, 0x0b, 0x51, 0x94])),
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let call_span = call.span();
+        let metadata = input.metadata();
+        let (input_str, input_span) = super::get_string(input, call_span)?;
+        let output = decode(&input_str, input_span)?;
+        Ok(Value::binary(output, call_span).into_pipeline_data_with_metadata(metadata))
+    }
+}
+
+#[derive(Clone)]
+pub struct EncodeBase58;
+
+impl Command for EncodeBase58 {
+    fn name(&self) -> &str {
+        "encode base58"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("encode base58")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::Binary, Type::String),
+            ])
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Encode a string or binary value using Base58."
+    }
+
+    fn extra_description(&self) -> &str {
+        EXTRA_USAGE
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Encode arbitrary data",
+            example: r#"0x[04 0B 51 94] | encode base58"#,
+            result: Some(Value::test_string("6zkdh")),
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let call_span = call.span();
+        let metadata = input.metadata();
+        let (input_bytes, _) = super::get_binary(input, call_span)?;
+        let output = encode(&input_bytes);
+        Ok(Value::string(output, call_span).into_pipeline_data_with_metadata(metadata))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples_decode() {
+        crate::test_examples(DecodeBase58)
+    }
+
+    #[test]
+    fn test_examples_encode() {
+        crate::test_examples(EncodeBase58)
+    }
+}
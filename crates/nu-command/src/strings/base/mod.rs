@@ -6,13 +6,17 @@ use nu_engine::command_prelude::*;
 
 mod base32;
 mod base32hex;
+mod base58;
 mod base64;
 mod hex;
+mod z85;
 
 pub use base32::{DecodeBase32, EncodeBase32};
 pub use base32hex::{DecodeBase32Hex, EncodeBase32Hex};
+pub use base58::{DecodeBase58, EncodeBase58};
 pub use base64::{DecodeBase64, EncodeBase64};
 pub use hex::{DecodeHex, EncodeHex};
+pub use z85::{DecodeZ85, EncodeZ85};
 
 pub fn decode(
     encoding: Encoding,
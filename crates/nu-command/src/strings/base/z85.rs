@@ -0,0 +1,180 @@
+use nu_engine::command_prelude::*;
+
+const EXTRA_USAGE: &str = r"Implements the Z85 encoding from ZeroMQ's RFC 32. Input length must be a
+multiple of 4 bytes when encoding, or a multiple of 5 characters when decoding.
+
+Note this command will collect stream input.";
+
+const ENCODER: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+fn encode(input: &[u8], span: Span) -> Result<String, ShellError> {
+    if input.len() % 4 != 0 {
+        return Err(ShellError::IncorrectValue {
+            msg: "Z85 encoding requires a length that is a multiple of 4".into(),
+            val_span: span,
+            call_span: span,
+        });
+    }
+
+    let mut out = String::with_capacity(input.len() * 5 / 4);
+    for chunk in input.chunks(4) {
+        let value = chunk
+            .iter()
+            .fold(0u32, |acc, &byte| (acc << 8) | byte as u32);
+
+        let mut divisor = 85u32.pow(4);
+        for _ in 0..5 {
+            out.push(ENCODER[((value / divisor) % 85) as usize] as char);
+            divisor /= 85;
+        }
+    }
+    Ok(out)
+}
+
+fn decode(input: &str, span: Span) -> Result<Vec<u8>, ShellError> {
+    if !input.is_ascii() || input.len() % 5 != 0 {
+        return Err(ShellError::IncorrectValue {
+            msg: "Z85 decoding requires an ASCII length that is a multiple of 5".into(),
+            val_span: span,
+            call_span: span,
+        });
+    }
+
+    let mut out = Vec::with_capacity(input.len() * 4 / 5);
+    for chunk in input.as_bytes().chunks(5) {
+        let mut value: u64 = 0;
+        for &c in chunk {
+            let digit =
+                ENCODER
+                    .iter()
+                    .position(|&e| e == c)
+                    .ok_or_else(|| ShellError::IncorrectValue {
+                        msg: format!("'{}' is not a valid Z85 character", c as char),
+                        val_span: span,
+                        call_span: span,
+                    })? as u64;
+            value = value * 85 + digit;
+        }
+        if value > u32::MAX as u64 {
+            return Err(ShellError::IncorrectValue {
+                msg: "Z85 chunk decodes to a value larger than 4 bytes".into(),
+                val_span: span,
+                call_span: span,
+            });
+        }
+        out.extend_from_slice(&(value as u32).to_be_bytes());
+    }
+    Ok(out)
+}
+
+#[derive(Clone)]
+pub struct DecodeZ85;
+
+impl Command for DecodeZ85 {
+    fn name(&self) -> &str {
+        "decode z85"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("decode z85")
+            .input_output_types(vec![(Type::String, Type::Binary)])
+            .allow_variants_without_examples(true)
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Decode a Z85 value."
+    }
+
+    fn extra_description(&self) -> &str {
+        EXTRA_USAGE
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Decode a Z85 string",
+            example: r#""HelloWorld" | decode z85"#,
+            result: Some(Value::test_binary(vec![
+                0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B,
+            ])),
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let call_span = call.span();
+        let metadata = input.metadata();
+        let (input_str, input_span) = super::get_string(input, call_span)?;
+        let output = decode(&input_str, input_span)?;
+        Ok(Value::binary(output, call_span).into_pipeline_data_with_metadata(metadata))
+    }
+}
+
+#[derive(Clone)]
+pub struct EncodeZ85;
+
+impl Command for EncodeZ85 {
+    fn name(&self) -> &str {
+        "encode z85"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("encode z85")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::Binary, Type::String),
+            ])
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Encode a string or binary value using Z85."
+    }
+
+    fn extra_description(&self) -> &str {
+        EXTRA_USAGE
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Encode arbitrary data",
+            example: r#"0x[86 4F D2 6F B5 59 F7 5B] | encode z85"#,
+            result: Some(Value::test_string("HelloWorld")),
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let call_span = call.span();
+        let metadata = input.metadata();
+        let (input_bytes, input_span) = super::get_binary(input, call_span)?;
+        let output = encode(&input_bytes, input_span)?;
+        Ok(Value::string(output, call_span).into_pipeline_data_with_metadata(metadata))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples_decode() {
+        crate::test_examples(DecodeZ85)
+    }
+
+    #[test]
+    fn test_examples_encode() {
+        crate::test_examples(EncodeZ85)
+    }
+}
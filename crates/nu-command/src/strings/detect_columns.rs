@@ -34,6 +34,17 @@ impl Command for DetectColumns {
                 "detect columns by guessing width, it may be useful if default one doesn't work",
                 None,
             )
+            .named(
+                "min-gap",
+                SyntaxShape::Int,
+                "minimum gap width to treat as a column separator, only used with --guess",
+                None,
+            )
+            .switch(
+                "datatypes",
+                "infer int/float/bool for each cell instead of returning strings",
+                Some('d'),
+            )
             .category(Category::Strings)
     }
 
@@ -93,6 +104,16 @@ none             8150224         4   8150220   1% /mnt/c' | detect columns --gue
                 example: "^ls -lh | detect columns --no-headers --skip 1 --combine-columns 5..7",
                 result: None,
             },
+            Example {
+                description: "Infer int/float/bool cell types instead of returning strings",
+                example: "'a 1 1.5 true' | detect columns --no-headers --datatypes",
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                        "column0" => Value::test_string("a"),
+                        "column1" => Value::test_int(1),
+                        "column2" => Value::test_float(1.5),
+                        "column3" => Value::test_bool(true),
+                })])),
+            },
         ]
     }
 
@@ -110,12 +131,16 @@ none             8150224         4   8150220   1% /mnt/c' | detect columns --gue
         let num_rows_to_skip: Option<usize> = call.get_flag(engine_state, stack, "skip")?;
         let noheader = call.has_flag(engine_state, stack, "no-headers")?;
         let range: Option<Range> = call.get_flag(engine_state, stack, "combine-columns")?;
+        let min_gap: Option<usize> = call.get_flag(engine_state, stack, "min-gap")?;
+        let datatypes = call.has_flag(engine_state, stack, "datatypes")?;
         let config = stack.get_config(engine_state);
 
         let args = Arguments {
             noheader,
             num_rows_to_skip,
             range,
+            min_gap,
+            datatypes,
             config,
         };
 
@@ -135,12 +160,16 @@ none             8150224         4   8150220   1% /mnt/c' | detect columns --gue
         let num_rows_to_skip: Option<usize> = call.get_flag_const(working_set, "skip")?;
         let noheader = call.has_flag_const(working_set, "no-headers")?;
         let range: Option<Range> = call.get_flag_const(working_set, "combine-columns")?;
+        let min_gap: Option<usize> = call.get_flag_const(working_set, "min-gap")?;
+        let datatypes = call.has_flag_const(working_set, "datatypes")?;
         let config = working_set.get_config().clone();
 
         let args = Arguments {
             noheader,
             num_rows_to_skip,
             range,
+            min_gap,
+            datatypes,
             config,
         };
 
@@ -156,6 +185,8 @@ struct Arguments {
     num_rows_to_skip: Option<usize>,
     noheader: bool,
     range: Option<Range>,
+    min_gap: Option<usize>,
+    datatypes: bool,
     config: Arc<Config>,
 }
 
@@ -174,6 +205,9 @@ fn guess_width(
     }
 
     let mut guess_width = GuessWidth::new_reader(Box::new(Cursor::new(input)));
+    if let Some(min_gap) = args.min_gap {
+        guess_width.min_gap = min_gap;
+    }
 
     let result = guess_width.read_all();
 
@@ -188,7 +222,7 @@ fn guess_width(
             .map(move |s| {
                 let mut values: Vec<Value> = s
                     .into_iter()
-                    .map(|v| Value::string(v, input_span))
+                    .map(|v| cast_cell(v, args.datatypes, input_span))
                     .collect();
                 // some rows may has less columns, fill it with ""
                 for _ in values.len()..columns.len() {
@@ -213,7 +247,7 @@ fn guess_width(
             .map(move |s| {
                 let mut values: Vec<Value> = s
                     .into_iter()
-                    .map(|v| Value::string(v, input_span))
+                    .map(|v| cast_cell(v, args.datatypes, input_span))
                     .collect();
                 // some rows may has less columns, fill it with ""
                 for _ in values.len()..columns.len() {
@@ -272,7 +306,10 @@ fn detect_columns(
 
                 if headers.len() == row.len() {
                     for (header, val) in headers.iter().zip(row.iter()) {
-                        record.push(&header.item, Value::string(&val.item, name_span));
+                        record.push(
+                            &header.item,
+                            cast_cell(val.item.clone(), args.datatypes, name_span),
+                        );
                     }
                 } else {
                     let mut pre_output = vec![];
@@ -285,7 +322,7 @@ fn detect_columns(
                             {
                                 pre_output.push((
                                     header.item.to_string(),
-                                    Value::string(&cell.item, name_span),
+                                    cast_cell(cell.item.clone(), args.datatypes, name_span),
                                 ));
                             }
                         }
@@ -325,6 +362,23 @@ fn detect_columns(
     }
 }
 
+/// Turn a raw cell into a `Value`, inferring int/float/bool when `datatypes` is set.
+fn cast_cell(raw: String, datatypes: bool, span: Span) -> Value {
+    if !datatypes {
+        return Value::string(raw, span);
+    }
+
+    if let Ok(i) = raw.parse::<i64>() {
+        Value::int(i, span)
+    } else if let Ok(f) = raw.parse::<f64>() {
+        Value::float(f, span)
+    } else if let Ok(b) = raw.parse::<bool>() {
+        Value::bool(b, span)
+    } else {
+        Value::string(raw, span)
+    }
+}
+
 pub fn find_columns(input: &str) -> Vec<Spanned<String>> {
     let mut chars = input.char_indices().peekable();
     let mut output = vec![];
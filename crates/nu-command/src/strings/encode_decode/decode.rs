@@ -51,6 +51,16 @@ impl Command for Decode {
         Signature::build("decode")
             .input_output_types(vec![(Type::Binary, Type::String)])
             .optional("encoding", SyntaxShape::String, "The text encoding to use.")
+            .switch(
+                "strip-bom",
+                "strip a leading UTF-8 or UTF-16 byte order mark before decoding",
+                Some('b'),
+            )
+            .switch(
+                "strict",
+                "error instead of replacing bytes that aren't valid in the given encoding",
+                Some('s'),
+            )
             .category(Category::Strings)
     }
 
@@ -74,6 +84,11 @@ documentation link at https://docs.rs/encoding_rs/latest/encoding_rs/#statics"#
                 example: r#"0x[00 53 00 6F 00 6D 00 65 00 20 00 44 00 61 00 74 00 61] | decode utf-16be"#,
                 result: Some(Value::string("Some Data".to_owned(), Span::test_data())),
             },
+            Example {
+                description: "Strip a UTF-8 byte order mark before decoding",
+                example: r#"0x[EF BB BF 68 69] | decode --strip-bom utf-8"#,
+                result: Some(Value::string("hi".to_owned(), Span::test_data())),
+            },
         ]
     }
 
@@ -89,7 +104,9 @@ documentation link at https://docs.rs/encoding_rs/latest/encoding_rs/#statics"#
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let encoding: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
-        run(call, input, encoding)
+        let strip_bom = call.has_flag(engine_state, stack, "strip-bom")?;
+        let strict = call.has_flag(engine_state, stack, "strict")?;
+        run(call, input, encoding, strip_bom, strict)
     }
 
     fn run_const(
@@ -99,7 +116,9 @@ documentation link at https://docs.rs/encoding_rs/latest/encoding_rs/#statics"#
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let encoding: Option<Spanned<String>> = call.opt_const(working_set, 0)?;
-        run(call, input, encoding)
+        let strip_bom = call.has_flag_const(working_set, "strip-bom")?;
+        let strict = call.has_flag_const(working_set, "strict")?;
+        run(call, input, encoding, strip_bom, strict)
     }
 }
 
@@ -107,6 +126,8 @@ fn run(
     call: &Call,
     input: PipelineData,
     encoding: Option<Spanned<String>>,
+    strip_bom: bool,
+    strict: bool,
 ) -> Result<PipelineData, ShellError> {
     let head = call.head;
 
@@ -115,7 +136,9 @@ fn run(
             let span = stream.span();
             let bytes = stream.into_bytes()?;
             match encoding {
-                Some(encoding_name) => detect_and_decode(encoding_name, head, bytes),
+                Some(encoding_name) => {
+                    detect_and_decode(encoding_name, head, bytes, strip_bom, strict)
+                }
                 None => super::encoding::detect_encoding_name(head, span, &bytes)
                     .map(|encoding| encoding.decode(&bytes).0.into_owned())
                     .map(|s| Value::string(s, head)),
@@ -126,7 +149,9 @@ fn run(
             let input_span = v.span();
             match v {
                 Value::Binary { val: bytes, .. } => match encoding {
-                    Some(encoding_name) => detect_and_decode(encoding_name, head, bytes),
+                    Some(encoding_name) => {
+                        detect_and_decode(encoding_name, head, bytes, strip_bom, strict)
+                    }
                     None => super::encoding::detect_encoding_name(head, input_span, &bytes)
                         .map(|encoding| encoding.decode(&bytes).0.into_owned())
                         .map(|s| Value::string(s, head)),
@@ -161,10 +186,12 @@ fn detect_and_decode(
     encoding_name: Spanned<String>,
     head: Span,
     bytes: Vec<u8>,
+    strip_bom: bool,
+    strict: bool,
 ) -> Result<Value, ShellError> {
     let dec_table_id = encoding_name.item.parse::<usize>().unwrap_or(0usize);
     if dec_table_id == 0 {
-        super::encoding::decode(head, encoding_name, &bytes)
+        super::encoding::decode(head, encoding_name, &bytes, strip_bom, strict)
     } else {
         Ok(Value::string(
             decode_string_complete_table(&bytes, OEM_DECODE[&dec_table_id]),
@@ -29,6 +29,16 @@ impl Command for Encode {
                 "when a character isn't in the given encoding, replace with a HTML entity (like `&#127880;`)",
                 Some('i'),
             )
+            .switch(
+                "transliterate",
+                "replace accented Latin letters with their plain ASCII equivalent (e.g. `é` -> `e`) before encoding",
+                Some('t'),
+            )
+            .switch(
+                "add-bom",
+                "prepend a byte order mark for encodings that have one",
+                Some('b'),
+            )
             .category(Category::Strings)
     }
 
@@ -66,6 +76,19 @@ documentation link at https://docs.rs/encoding_rs/latest/encoding_rs/#statics"#
                     Span::test_data(),
                 )),
             },
+            Example {
+                description: "Transliterate accented letters into ASCII, useful for slugs",
+                example: r#""Café Old é" | encode --transliterate utf-8 | decode utf-8"#,
+                result: Some(Value::string("Cafe Old e".to_owned(), Span::test_data())),
+            },
+            Example {
+                description: "Add a UTF-8 byte order mark to the encoded bytes",
+                example: r#""hi" | encode --add-bom utf-8"#,
+                result: Some(Value::binary(
+                    vec![0xEF, 0xBB, 0xBF, 0x68, 0x69],
+                    Span::test_data(),
+                )),
+            },
         ]
     }
 
@@ -82,7 +105,9 @@ documentation link at https://docs.rs/encoding_rs/latest/encoding_rs/#statics"#
     ) -> Result<PipelineData, ShellError> {
         let encoding: Spanned<String> = call.req(engine_state, stack, 0)?;
         let ignore_errors = call.has_flag(engine_state, stack, "ignore-errors")?;
-        run(call, input, encoding, ignore_errors)
+        let transliterate = call.has_flag(engine_state, stack, "transliterate")?;
+        let add_bom = call.has_flag(engine_state, stack, "add-bom")?;
+        run(call, input, encoding, ignore_errors, transliterate, add_bom)
     }
 
     fn run_const(
@@ -93,7 +118,9 @@ documentation link at https://docs.rs/encoding_rs/latest/encoding_rs/#statics"#
     ) -> Result<PipelineData, ShellError> {
         let encoding: Spanned<String> = call.req_const(working_set, 0)?;
         let ignore_errors = call.has_flag_const(working_set, "ignore-errors")?;
-        run(call, input, encoding, ignore_errors)
+        let transliterate = call.has_flag_const(working_set, "transliterate")?;
+        let add_bom = call.has_flag_const(working_set, "add-bom")?;
+        run(call, input, encoding, ignore_errors, transliterate, add_bom)
     }
 }
 
@@ -102,6 +129,8 @@ fn run(
     input: PipelineData,
     encoding: Spanned<String>,
     ignore_errors: bool,
+    transliterate: bool,
+    add_bom: bool,
 ) -> Result<PipelineData, ShellError> {
     let head = call.head;
 
@@ -109,16 +138,30 @@ fn run(
         PipelineData::ByteStream(stream, ..) => {
             let span = stream.span();
             let s = stream.into_string()?;
-            super::encoding::encode(head, encoding, &s, span, ignore_errors)
-                .map(|val| val.into_pipeline_data())
+            super::encoding::encode(
+                head,
+                encoding,
+                &s,
+                span,
+                ignore_errors,
+                transliterate,
+                add_bom,
+            )
+            .map(|val| val.into_pipeline_data())
         }
         PipelineData::Value(v, ..) => {
             let span = v.span();
             match v {
-                Value::String { val: s, .. } => {
-                    super::encoding::encode(head, encoding, &s, span, ignore_errors)
-                        .map(|val| val.into_pipeline_data())
-                }
+                Value::String { val: s, .. } => super::encoding::encode(
+                    head,
+                    encoding,
+                    &s,
+                    span,
+                    ignore_errors,
+                    transliterate,
+                    add_bom,
+                )
+                .map(|val| val.into_pipeline_data()),
                 Value::Error { error, .. } => Err(*error),
                 _ => Err(ShellError::OnlySupportsThisInputType {
                     exp_input_type: "string".into(),
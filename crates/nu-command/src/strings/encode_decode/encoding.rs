@@ -26,6 +26,8 @@ pub fn decode(
     head: Span,
     encoding_name: Spanned<String>,
     bytes: &[u8],
+    strip_bom: bool,
+    strict: bool,
 ) -> Result<Value, ShellError> {
     // Workaround for a bug in the Encodings Specification.
     let encoding = if encoding_name.item.eq_ignore_ascii_case("utf16") {
@@ -33,16 +35,61 @@ pub fn decode(
     } else {
         parse_encoding(encoding_name.span, &encoding_name.item)
     }?;
-    let (result, ..) = encoding.decode(bytes);
+
+    let bytes = if strip_bom {
+        strip_bom_bytes(bytes)
+    } else {
+        bytes
+    };
+    let (result, _, had_errors) = encoding.decode(bytes);
+    if had_errors && strict {
+        return Err(ShellError::GenericError {
+            error: "error while decoding bytes".into(),
+            msg: format!("input contained bytes not valid in {}", &encoding_name.item),
+            span: Some(head),
+            help: None,
+            inner: vec![],
+        });
+    }
     Ok(Value::string(result.into_owned(), head))
 }
 
+/// Strip a leading UTF-8 or UTF-16 byte order mark, if present.
+fn strip_bom_bytes(bytes: &[u8]) -> &[u8] {
+    const UTF8_BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
+    const UTF16LE_BOM: [u8; 2] = [0xFF, 0xFE];
+    const UTF16BE_BOM: [u8; 2] = [0xFE, 0xFF];
+
+    if let Some(rest) = bytes.strip_prefix(&UTF8_BOM) {
+        rest
+    } else if let Some(rest) = bytes
+        .strip_prefix(&UTF16LE_BOM)
+        .or_else(|| bytes.strip_prefix(&UTF16BE_BOM))
+    {
+        rest
+    } else {
+        bytes
+    }
+}
+
+/// The byte order mark that should be prepended for a given encoding, if it has one.
+fn bom_bytes(encoding: &'static Encoding) -> Option<&'static [u8]> {
+    match encoding.name() {
+        "UTF-8" => Some(&[0xEF, 0xBB, 0xBF]),
+        "UTF-16LE" => Some(&[0xFF, 0xFE]),
+        "UTF-16BE" => Some(&[0xFE, 0xFF]),
+        _ => None,
+    }
+}
+
 pub fn encode(
     head: Span,
     encoding_name: Spanned<String>,
     s: &str,
     s_span: Span,
     ignore_errors: bool,
+    transliterate: bool,
+    add_bom: bool,
 ) -> Result<Value, ShellError> {
     // Workaround for a bug in the Encodings Specification.
     let encoding = if encoding_name.item.eq_ignore_ascii_case("utf16") {
@@ -63,21 +110,94 @@ pub fn encode(
         });
     }
 
+    let transliterated = transliterate.then(|| transliterate_str(s));
+    let s = transliterated.as_deref().unwrap_or(s);
+
     let (result, _actual_encoding, replacements) = encoding.encode(s);
     // Because encoding_rs is a Web-facing crate, it defaults to replacing unknowns with HTML entities.
     // This behaviour can be enabled with -i. Otherwise, it becomes an error.
     if replacements && !ignore_errors {
         // TODO: make GenericError accept two spans (including head)
-        Err(ShellError::GenericError {
+        return Err(ShellError::GenericError {
             error: "error while encoding string".into(),
             msg: format!("string contained characters not in {}", &encoding_name.item),
             span: Some(s_span),
             help: None,
             inner: vec![],
-        })
-    } else {
-        Ok(Value::binary(result.into_owned(), head))
+        });
+    }
+
+    let mut bytes = result.into_owned();
+    if add_bom {
+        if let Some(bom) = bom_bytes(encoding) {
+            let mut with_bom = bom.to_vec();
+            with_bom.append(&mut bytes);
+            bytes = with_bom;
+        }
+    }
+    Ok(Value::binary(bytes, head))
+}
+
+/// Replace common Latin accented letters and ligatures with their plain ASCII
+/// equivalent (e.g. `é` -> `e`), leaving characters it doesn't recognize as-is.
+/// Useful for generating slugs from arbitrary text.
+fn transliterate_str(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match transliterate_char(c) {
+            Some(replacement) => out.push_str(replacement),
+            None => out.push(c),
+        }
     }
+    out
+}
+
+fn transliterate_char(c: char) -> Option<&'static str> {
+    Some(match c {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => "C",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'Ð' | 'Ď' | 'Đ' => "D",
+        'ð' | 'ď' | 'đ' => "d",
+        'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => "E",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => "G",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'Ĥ' | 'Ħ' => "H",
+        'ĥ' | 'ħ' => "h",
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => "I",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'Ĵ' => "J",
+        'ĵ' => "j",
+        'Ķ' => "K",
+        'ķ' => "k",
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => "L",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => "N",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => "O",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Ŕ' | 'Ŗ' | 'Ř' => "R",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' => "S",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ß' => "s",
+        'Ţ' | 'Ť' | 'Ŧ' => "T",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => "U",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'Ŵ' => "W",
+        'ŵ' => "w",
+        'Ý' | 'Ÿ' | 'Ŷ' => "Y",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'Ź' | 'Ż' | 'Ž' => "Z",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    })
 }
 
 fn parse_encoding(span: Span, label: &str) -> Result<&'static Encoding, ShellError> {
@@ -125,15 +245,56 @@ mod test {
             span: test_span,
         };
 
-        let encoded = encode(test_span, encoding.clone(), expected, test_span, true).unwrap();
+        let encoded = encode(
+            test_span,
+            encoding.clone(),
+            expected,
+            test_span,
+            true,
+            false,
+            false,
+        )
+        .unwrap();
         let encoded = encoded.coerce_into_binary().unwrap();
 
-        let decoded = decode(test_span, encoding, &encoded).unwrap();
+        let decoded = decode(test_span, encoding, &encoded, false, false).unwrap();
         let decoded = decoded.coerce_into_string().unwrap();
 
         assert_eq!(decoded, expected);
     }
 
+    #[test]
+    fn transliterate_accented_letters() {
+        assert_eq!(transliterate_str("café"), "cafe");
+        assert_eq!(transliterate_str("naïve"), "naive");
+        assert_eq!(transliterate_str("plain ascii"), "plain ascii");
+    }
+
+    #[test]
+    fn add_and_strip_bom() {
+        let test_span = Span::test_data();
+        let encoding = Spanned {
+            item: "utf-8".to_string(),
+            span: test_span,
+        };
+
+        let encoded = encode(
+            test_span,
+            encoding.clone(),
+            "hi",
+            test_span,
+            true,
+            false,
+            true,
+        )
+        .unwrap();
+        let encoded = encoded.coerce_into_binary().unwrap();
+        assert_eq!(&encoded[..3], &[0xEF, 0xBB, 0xBF]);
+
+        let decoded = decode(test_span, encoding, &encoded, true, false).unwrap();
+        assert_eq!(decoded.coerce_into_string().unwrap(), "hi");
+    }
+
     #[rstest]
     #[case::big5(&[186, 251, 176, 242, 164, 106, 168, 229, 161, 93, 87, 105, 107, 105, 112, 101, 100, 105, 97, 161,
         94, 170, 204, 161, 65, 186, 244, 184, 244, 172, 176, 194, 166, 161, 70, 182, 176, 164, 209, 164, 85,
@@ -0,0 +1,192 @@
+use nu_cmd_base::input_handler::{operate, CmdArgument};
+use nu_engine::command_prelude::*;
+use nu_protocol::engine::StateWorkingSet;
+use nu_utils::{currency_symbol, get_system_locale};
+use num_format::{Locale, ToFormattedString};
+
+struct Arguments {
+    code: String,
+    locale: Locale,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+#[derive(Clone)]
+pub struct FormatCurrency;
+
+impl Command for FormatCurrency {
+    fn name(&self) -> &str {
+        "format currency"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format currency")
+            .input_output_types(vec![
+                (Type::Int, Type::String),
+                (Type::Float, Type::String),
+                (Type::table(), Type::table()),
+                (Type::record(), Type::record()),
+            ])
+            .allow_variants_without_examples(true)
+            .named(
+                "code",
+                SyntaxShape::String,
+                "ISO 4217 currency code, e.g. USD or EUR (defaults to USD)",
+                Some('c'),
+            )
+            .named(
+                "locale",
+                SyntaxShape::String,
+                "locale to use for digit grouping and the decimal separator, e.g. de-DE (defaults to the system locale)",
+                Some('l'),
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, format currency at the given cell paths.",
+            )
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Format a number as a currency amount."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["money", "locale", "price"]
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let code: Option<Spanned<String>> = call.get_flag(engine_state, stack, "code")?;
+        let locale_flag: Option<Spanned<String>> = call.get_flag(engine_state, stack, "locale")?;
+        let arg = build_args(code, locale_flag, call.rest(engine_state, stack, 0)?)?;
+        operate(
+            format_currency_impl,
+            arg,
+            input,
+            call.head,
+            engine_state.signals(),
+        )
+    }
+
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let code: Option<Spanned<String>> = call.get_flag_const(working_set, "code")?;
+        let locale_flag: Option<Spanned<String>> = call.get_flag_const(working_set, "locale")?;
+        let arg = build_args(code, locale_flag, call.rest_const(working_set, 0)?)?;
+        operate(
+            format_currency_impl,
+            arg,
+            input,
+            call.head,
+            working_set.permanent().signals(),
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Format a number as US dollars",
+                example: "1234.5 | format currency",
+                result: Some(Value::test_string("$1,234.50")),
+            },
+            Example {
+                description: "Format a number as euros using German locale conventions",
+                example: "1234.5 | format currency --code EUR --locale de-DE",
+                result: Some(Value::test_string("€1.234,50")),
+            },
+        ]
+    }
+}
+
+fn build_args(
+    code: Option<Spanned<String>>,
+    locale: Option<Spanned<String>>,
+    cell_paths: Vec<CellPath>,
+) -> Result<Arguments, ShellError> {
+    let code = code.map(|c| c.item).unwrap_or_else(|| "USD".to_string());
+    let locale = match locale {
+        Some(name) => Locale::from_name(&name.item).map_err(|_| ShellError::InvalidValue {
+            valid: "a valid locale name, e.g. en-US or de-DE".into(),
+            actual: name.item,
+            span: name.span,
+        })?,
+        None => get_system_locale(),
+    };
+    let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+    Ok(Arguments {
+        code,
+        locale,
+        cell_paths,
+    })
+}
+
+fn format_currency_impl(val: &Value, arg: &Arguments, span: Span) -> Value {
+    let value_span = val.span();
+    let amount = match val {
+        Value::Int { val, .. } => *val as f64,
+        Value::Float { val, .. } => *val,
+        Value::Error { .. } => return val.clone(),
+        _ => {
+            return Value::error(
+                ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "int or float".into(),
+                    wrong_type: val.get_type().to_string(),
+                    dst_span: span,
+                    src_span: value_span,
+                },
+                span,
+            )
+        }
+    };
+
+    let symbol = currency_symbol(&arg.code).unwrap_or(&arg.code);
+    let cents = (amount.abs() * 100.0).round() as i64;
+    let whole = cents / 100;
+    let fraction = cents % 100;
+    let grouped_whole = whole.to_formatted_string(&arg.locale);
+    let sign = if amount.is_sign_negative() && cents != 0 {
+        "-"
+    } else {
+        ""
+    };
+
+    Value::string(
+        format!(
+            "{sign}{symbol}{grouped_whole}{}{fraction:02}",
+            arg.locale.decimal()
+        ),
+        span,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FormatCurrency)
+    }
+}
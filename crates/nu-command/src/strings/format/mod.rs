@@ -1,9 +1,13 @@
+mod currency;
 mod date;
 mod duration;
 mod filesize;
 mod format_;
+mod number;
 
+pub use currency::FormatCurrency;
 pub use date::FormatDate;
 pub use duration::FormatDuration;
 pub use filesize::FormatFilesize;
 pub use format_::Format;
+pub use number::FormatNumber;
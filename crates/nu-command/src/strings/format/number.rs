@@ -0,0 +1,171 @@
+use nu_cmd_base::input_handler::{operate, CmdArgument};
+use nu_engine::command_prelude::*;
+use nu_protocol::engine::StateWorkingSet;
+use nu_utils::get_system_locale;
+use num_format::{Locale, ToFormattedString};
+
+struct Arguments {
+    locale: Locale,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+#[derive(Clone)]
+pub struct FormatNumber;
+
+impl Command for FormatNumber {
+    fn name(&self) -> &str {
+        "format number"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format number")
+            .input_output_types(vec![
+                (Type::Int, Type::String),
+                (Type::Float, Type::String),
+                (Type::table(), Type::table()),
+                (Type::record(), Type::record()),
+            ])
+            .allow_variants_without_examples(true)
+            .named(
+                "locale",
+                SyntaxShape::String,
+                "locale to use for digit grouping and the decimal separator, e.g. de-DE (defaults to the system locale)",
+                Some('l'),
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, format numbers at the given cell paths.",
+            )
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Format a number with locale-aware digit grouping and decimal separator."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["convert", "display", "locale", "thousands", "grouping"]
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let locale_flag: Option<Spanned<String>> = call.get_flag(engine_state, stack, "locale")?;
+        let locale = parse_locale_flag(locale_flag)?;
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+        let arg = Arguments { locale, cell_paths };
+        operate(
+            format_number_impl,
+            arg,
+            input,
+            call.head,
+            engine_state.signals(),
+        )
+    }
+
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let locale_flag: Option<Spanned<String>> = call.get_flag_const(working_set, "locale")?;
+        let locale = parse_locale_flag(locale_flag)?;
+        let cell_paths: Vec<CellPath> = call.rest_const(working_set, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+        let arg = Arguments { locale, cell_paths };
+        operate(
+            format_number_impl,
+            arg,
+            input,
+            call.head,
+            working_set.permanent().signals(),
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Group the digits of a number using the system locale",
+                example: "1234567 | format number",
+                result: None,
+            },
+            Example {
+                description: "Format a number using German locale conventions",
+                example: "1234567.5 | format number --locale de-DE",
+                result: Some(Value::test_string("1.234.567,5")),
+            },
+        ]
+    }
+}
+
+fn parse_locale_flag(name: Option<Spanned<String>>) -> Result<Locale, ShellError> {
+    match name {
+        Some(name) => Locale::from_name(&name.item).map_err(|_| ShellError::InvalidValue {
+            valid: "a valid locale name, e.g. en-US or de-DE".into(),
+            actual: name.item,
+            span: name.span,
+        }),
+        None => Ok(get_system_locale()),
+    }
+}
+
+fn format_number_impl(val: &Value, arg: &Arguments, span: Span) -> Value {
+    let value_span = val.span();
+    match val {
+        Value::Int { val, .. } => Value::string(val.to_formatted_string(&arg.locale), span),
+        Value::Float { val, .. } => {
+            let int_part = val.trunc() as i64;
+            let grouped_int = int_part.to_formatted_string(&arg.locale);
+            let fract = val.fract().abs();
+            if fract == 0.0 {
+                Value::string(grouped_int, span)
+            } else {
+                let fract_str = format!("{fract:.10}");
+                let fract_digits = fract_str.trim_start_matches("0.").trim_end_matches('0');
+                Value::string(
+                    format!("{grouped_int}{}{fract_digits}", arg.locale.decimal()),
+                    span,
+                )
+            }
+        }
+        Value::Error { .. } => val.clone(),
+        _ => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "int or float".into(),
+                wrong_type: val.get_type().to_string(),
+                dst_span: span,
+                src_span: value_span,
+            },
+            span,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FormatNumber)
+    }
+}
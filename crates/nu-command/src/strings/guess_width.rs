@@ -49,6 +49,8 @@ pub struct GuessWidth {
     pub(crate) pre_count: usize,
     // the maximum number of columns to split.
     pub(crate) limit_split: usize,
+    // the minimum gap width (in aligned lines) to recognize as a column separator.
+    pub(crate) min_gap: usize,
 }
 
 impl GuessWidth {
@@ -60,6 +62,7 @@ impl GuessWidth {
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         }
     }
 
@@ -91,7 +94,7 @@ impl GuessWidth {
             self.pre_lines.push(line);
         }
 
-        self.pos = positions(&self.pre_lines, HEADER, MIN_LINES);
+        self.pos = positions(&self.pre_lines, HEADER, self.min_gap);
         if self.limit_split > 0 && self.pos.len() > self.limit_split {
             self.pos.truncate(self.limit_split);
         }
@@ -340,6 +343,7 @@ mod tests {
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         #[rustfmt::skip]
@@ -368,6 +372,7 @@ noborus   721971  0.0  0.0  13716  3524 pts/3    R+   10:39   0:00 ps aux";
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         #[rustfmt::skip]
@@ -423,6 +428,7 @@ D:             104792064  17042676  87749388  17% /d";
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         #[rustfmt::skip]
@@ -447,6 +453,7 @@ D:             104792064  17042676  87749388  17% /d";
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         let want = vec![vec!["A…", "B"], vec!["C…", "D"]];
@@ -468,6 +475,7 @@ Ștefan         Țincu ";
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         let want = vec![vec!["Name", "Surname"], vec!["Ștefan", "Țincu"]];
@@ -492,6 +500,7 @@ C";
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         let want = vec![vec!["A"], vec!["B"], vec!["C"]];
@@ -514,6 +523,7 @@ E F G H";
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         let want = vec![vec!["A", "B", "C", "D"], vec!["E", "F", "G", "H"]];
@@ -536,6 +546,7 @@ F G H I";
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         let want = vec![
@@ -562,6 +573,7 @@ E F G H";
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         let want = vec![vec!["A", "B", "C", "D"], vec!["E", "F", "G", "H"]];
@@ -584,6 +596,7 @@ E F G H";
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         let want = vec![vec!["A", "B", "C", "D"], vec!["E", "F", "G", "H"]];
@@ -607,6 +620,7 @@ nu_plugin_from_sse = '0.4.0'            # Nushell plugin to convert a HTTP serve
             pre_lines: Vec::new(),
             pre_count: 0,
             limit_split: 0,
+            min_gap: MIN_LINES,
         };
 
         let first_column_want = [
@@ -9,8 +9,8 @@ mod split;
 mod str_;
 
 pub use base::{
-    DecodeBase32, DecodeBase32Hex, DecodeBase64, DecodeHex, EncodeBase32, EncodeBase32Hex,
-    EncodeBase64, EncodeHex,
+    DecodeBase32, DecodeBase32Hex, DecodeBase58, DecodeBase64, DecodeHex, DecodeZ85, EncodeBase32,
+    EncodeBase32Hex, EncodeBase58, EncodeBase64, EncodeHex, EncodeZ85,
 };
 pub use char_::Char;
 pub use detect_columns::*;
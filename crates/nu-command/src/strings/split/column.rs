@@ -32,6 +32,11 @@ impl Command for SubCommand {
                 Some('n'),
             )
             .switch("regex", "separator is a regular expression", Some('r'))
+            .switch(
+                "keep-delimiter",
+                "keep the delimiter at the end of the preceding column instead of dropping it",
+                Some('k'),
+            )
             .rest(
                 "rest",
                 SyntaxShape::String,
@@ -110,6 +115,15 @@ impl Command for SubCommand {
                     }),
                 ])),
             },
+            Example {
+                description: "Split into columns, keeping the delimiter at the end of each one",
+                example: "'a--b--c' | split column --keep-delimiter '--'",
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                        "column1" => Value::test_string("a--"),
+                        "column2" => Value::test_string("b--"),
+                        "column3" => Value::test_string("c"),
+                })])),
+            },
         ]
     }
 
@@ -129,6 +143,7 @@ impl Command for SubCommand {
         let collapse_empty = call.has_flag(engine_state, stack, "collapse-empty")?;
         let max_split: Option<usize> = call.get_flag(engine_state, stack, "number")?;
         let has_regex = call.has_flag(engine_state, stack, "regex")?;
+        let keep_delimiter = call.has_flag(engine_state, stack, "keep-delimiter")?;
 
         let args = Arguments {
             separator,
@@ -136,6 +151,7 @@ impl Command for SubCommand {
             collapse_empty,
             max_split,
             has_regex,
+            keep_delimiter,
         };
         split_column(engine_state, call, input, args)
     }
@@ -151,6 +167,7 @@ impl Command for SubCommand {
         let collapse_empty = call.has_flag_const(working_set, "collapse-empty")?;
         let max_split: Option<usize> = call.get_flag_const(working_set, "number")?;
         let has_regex = call.has_flag_const(working_set, "regex")?;
+        let keep_delimiter = call.has_flag_const(working_set, "keep-delimiter")?;
 
         let args = Arguments {
             separator,
@@ -158,6 +175,7 @@ impl Command for SubCommand {
             collapse_empty,
             max_split,
             has_regex,
+            keep_delimiter,
         };
         split_column(working_set.permanent(), call, input, args)
     }
@@ -169,6 +187,7 @@ struct Arguments {
     collapse_empty: bool,
     max_split: Option<usize>,
     has_regex: bool,
+    keep_delimiter: bool,
 }
 
 fn split_column(
@@ -200,6 +219,7 @@ fn split_column(
                 &args.rest,
                 args.collapse_empty,
                 args.max_split,
+                args.keep_delimiter,
                 name_span,
             )
         },
@@ -213,20 +233,44 @@ fn split_column_helper(
     rest: &[Spanned<String>],
     collapse_empty: bool,
     max_split: Option<usize>,
+    keep_delimiter: bool,
     head: Span,
 ) -> Vec<Value> {
     if let Ok(s) = v.coerce_str() {
-        let split_result: Vec<_> = match max_split {
-            Some(max_split) => separator
-                .splitn(&s, max_split)
-                .filter_map(|x| x.ok())
-                .filter(|x| !(collapse_empty && x.is_empty()))
-                .collect(),
-            None => separator
-                .split(&s)
-                .filter_map(|x| x.ok())
-                .filter(|x| !(collapse_empty && x.is_empty()))
-                .collect(),
+        let split_result: Vec<String> = if keep_delimiter {
+            match super::row::split_keeping_delimiter(separator, &s, max_split) {
+                Ok(parts) => parts
+                    .into_iter()
+                    .filter(|x| !(collapse_empty && x.is_empty()))
+                    .collect(),
+                Err(err) => {
+                    return vec![Value::error(
+                        ShellError::GenericError {
+                            error: "Error with regular expression".into(),
+                            msg: err.to_string(),
+                            span: Some(head),
+                            help: None,
+                            inner: vec![],
+                        },
+                        head,
+                    )]
+                }
+            }
+        } else {
+            match max_split {
+                Some(max_split) => separator
+                    .splitn(&s, max_split)
+                    .filter_map(|x| x.ok())
+                    .filter(|x| !(collapse_empty && x.is_empty()))
+                    .map(|x| x.to_string())
+                    .collect(),
+                None => separator
+                    .split(&s)
+                    .filter_map(|x| x.ok())
+                    .filter(|x| !(collapse_empty && x.is_empty()))
+                    .map(|x| x.to_string())
+                    .collect(),
+            }
         };
         let positional: Vec<_> = rest.iter().map(|f| f.item.clone()).collect();
 
@@ -238,12 +282,12 @@ fn split_column_helper(
                 gen_columns.push(format!("column{}", i + 1));
             }
 
-            for (&k, v) in split_result.iter().zip(&gen_columns) {
-                record.push(v, Value::string(k, head));
+            for (k, v) in split_result.iter().zip(&gen_columns) {
+                record.push(v, Value::string(k.clone(), head));
             }
         } else {
-            for (&k, v) in split_result.iter().zip(&positional) {
-                record.push(v, Value::string(k, head));
+            for (k, v) in split_result.iter().zip(&positional) {
+                record.push(v, Value::string(k.clone(), head));
             }
         }
         vec![Value::record(record, head)]
@@ -31,6 +31,11 @@ impl Command for SubCommand {
                 Some('n'),
             )
             .switch("regex", "use regex syntax for separator", Some('r'))
+            .switch(
+                "keep-delimiter",
+                "keep the delimiter at the end of the preceding row instead of dropping it",
+                Some('k'),
+            )
             .category(Category::Strings)
     }
 
@@ -96,6 +101,19 @@ impl Command for SubCommand {
                     Span::test_data(),
                 )),
             },
+            Example {
+                description:
+                    "Split a string by regex, keeping the delimiter at the end of each row",
+                example: r"'a,b,c' | split row --regex --keep-delimiter ','",
+                result: Some(Value::list(
+                    vec![
+                        Value::test_string("a,"),
+                        Value::test_string("b,"),
+                        Value::test_string("c"),
+                    ],
+                    Span::test_data(),
+                )),
+            },
         ]
     }
 
@@ -113,11 +131,13 @@ impl Command for SubCommand {
         let separator: Spanned<String> = call.req(engine_state, stack, 0)?;
         let max_split: Option<usize> = call.get_flag(engine_state, stack, "number")?;
         let has_regex = call.has_flag(engine_state, stack, "regex")?;
+        let keep_delimiter = call.has_flag(engine_state, stack, "keep-delimiter")?;
 
         let args = Arguments {
             separator,
             max_split,
             has_regex,
+            keep_delimiter,
         };
         split_row(engine_state, call, input, args)
     }
@@ -131,11 +151,13 @@ impl Command for SubCommand {
         let separator: Spanned<String> = call.req_const(working_set, 0)?;
         let max_split: Option<usize> = call.get_flag_const(working_set, "number")?;
         let has_regex = call.has_flag_const(working_set, "regex")?;
+        let keep_delimiter = call.has_flag_const(working_set, "keep-delimiter")?;
 
         let args = Arguments {
             separator,
             max_split,
             has_regex,
+            keep_delimiter,
         };
         split_row(working_set.permanent(), call, input, args)
     }
@@ -145,6 +167,7 @@ struct Arguments {
     has_regex: bool,
     separator: Spanned<String>,
     max_split: Option<usize>,
+    keep_delimiter: bool,
 }
 
 fn split_row(
@@ -168,12 +191,18 @@ fn split_row(
         inner: vec![],
     })?;
     input.flat_map(
-        move |x| split_row_helper(&x, &regex, args.max_split, name_span),
+        move |x| split_row_helper(&x, &regex, args.max_split, args.keep_delimiter, name_span),
         engine_state.signals(),
     )
 }
 
-fn split_row_helper(v: &Value, regex: &Regex, max_split: Option<usize>, name: Span) -> Vec<Value> {
+fn split_row_helper(
+    v: &Value,
+    regex: &Regex,
+    max_split: Option<usize>,
+    keep_delimiter: bool,
+    name: Span,
+) -> Vec<Value> {
     let span = v.span();
     match v {
         Value::Error { error, .. } => {
@@ -183,6 +212,25 @@ fn split_row_helper(v: &Value, regex: &Regex, max_split: Option<usize>, name: Sp
             let v_span = v.span();
 
             if let Ok(s) = v.coerce_str() {
+                if keep_delimiter {
+                    return match split_keeping_delimiter(regex, &s, max_split) {
+                        Ok(parts) => parts
+                            .into_iter()
+                            .map(|part| Value::string(part, v_span))
+                            .collect(),
+                        Err(err) => vec![Value::error(
+                            ShellError::GenericError {
+                                error: "Error with regular expression".into(),
+                                msg: err.to_string(),
+                                span: Some(v_span),
+                                help: None,
+                                inner: vec![],
+                            },
+                            v_span,
+                        )],
+                    };
+                }
+
                 match max_split {
                     Some(max_split) => regex
                         .splitn(&s, max_split)
@@ -231,6 +279,39 @@ fn split_row_helper(v: &Value, regex: &Regex, max_split: Option<usize>, name: Sp
     }
 }
 
+/// Split `text` on `regex`, appending each matched delimiter to the end of the
+/// row that precedes it instead of discarding it.
+pub(crate) fn split_keeping_delimiter(
+    regex: &Regex,
+    text: &str,
+    max_split: Option<usize>,
+) -> Result<Vec<String>, fancy_regex::Error> {
+    let mut result = Vec::new();
+    let mut last_end = 0;
+
+    for found in regex.find_iter(text) {
+        let found = found?;
+        if found.start() == found.end() {
+            // avoid looping forever on a zero-width match
+            continue;
+        }
+        if let Some(max_split) = max_split {
+            if result.len() + 1 >= max_split {
+                break;
+            }
+        }
+        result.push(format!(
+            "{}{}",
+            &text[last_end..found.start()],
+            found.as_str()
+        ));
+        last_end = found.end();
+    }
+    result.push(text[last_end..].to_string());
+
+    Ok(result)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,6 +1,7 @@
 mod complete;
 mod exec;
 mod nu_check;
+mod on;
 #[cfg(any(
     target_os = "android",
     target_os = "linux",
@@ -12,7 +13,7 @@ mod nu_check;
 ))]
 mod ps;
 #[cfg(windows)]
-mod registry_query;
+mod registry;
 mod run_external;
 mod sys;
 mod uname;
@@ -21,6 +22,7 @@ mod which_;
 pub use complete::Complete;
 pub use exec::Exec;
 pub use nu_check::NuCheck;
+pub use on::*;
 #[cfg(any(
     target_os = "android",
     target_os = "linux",
@@ -32,7 +34,7 @@ pub use nu_check::NuCheck;
 ))]
 pub use ps::Ps;
 #[cfg(windows)]
-pub use registry_query::RegistryQuery;
+pub use registry::{RegistryDelete, RegistryQuery, RegistryWrite};
 pub use run_external::{command_not_found, eval_arguments_from_call, which, External};
 pub use sys::*;
 pub use uname::UName;
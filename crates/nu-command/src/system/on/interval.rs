@@ -0,0 +1,97 @@
+use nu_engine::{command_prelude::*, ClosureEval};
+use nu_protocol::{
+    engine::{Closure, StateWorkingSet},
+    format_shell_error,
+};
+use std::time::Duration;
+
+// chosen mostly arbitrarily, same as `watch`'s polling frequency
+const CHECK_CTRL_C_FREQUENCY: Duration = Duration::from_millis(100);
+
+#[derive(Clone)]
+pub struct OnInterval;
+
+impl Command for OnInterval {
+    fn name(&self) -> &str {
+        "on interval"
+    }
+
+    fn description(&self) -> &str {
+        "Run a closure repeatedly, waiting a fixed duration between each run."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Runs until interrupted with ctrl+c. The closure is passed the number of times it has\nbeen run so far, starting at 0. Errors raised by the closure are printed but don't stop the\nloop, matching `watch`'s behavior for its own closure."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["timer", "cron", "reactive", "poll", "schedule"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("on interval")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "duration",
+                SyntaxShape::Duration,
+                "How long to wait between runs of the closure.",
+            )
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Int])),
+                "The closure to run on each tick.",
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let interval: i64 = call.req(engine_state, stack, 0)?;
+        if interval <= 0 {
+            return Err(ShellError::NeedsPositiveValue { span: head });
+        }
+        let interval = Duration::from_nanos(interval as u64);
+        let closure: Closure = call.req(engine_state, stack, 1)?;
+        let mut closure = ClosureEval::new(engine_state, stack, closure);
+
+        let mut tick: i64 = 0;
+        loop {
+            let result = closure
+                .add_arg(Value::int(tick, head))
+                .run_with_input(PipelineData::Empty);
+
+            match result {
+                Ok(val) => val.print_table(engine_state, stack, false, false)?,
+                Err(err) => {
+                    let working_set = StateWorkingSet::new(engine_state);
+                    eprintln!("{}", format_shell_error(&working_set, &err));
+                }
+            }
+            tick += 1;
+
+            let mut waited = Duration::ZERO;
+            while waited < interval {
+                if engine_state.signals().interrupted() {
+                    return Ok(PipelineData::empty());
+                }
+                let nap = CHECK_CTRL_C_FREQUENCY.min(interval - waited);
+                std::thread::sleep(nap);
+                waited += nap;
+            }
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Print the time every 5 seconds",
+            example: r#"on interval 5sec {|| date now }"#,
+            result: None,
+        }]
+    }
+}
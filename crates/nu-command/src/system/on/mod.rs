@@ -0,0 +1,7 @@
+mod interval;
+mod on_;
+mod signal;
+
+pub use interval::OnInterval;
+pub use on_::On;
+pub use signal::OnSignal;
@@ -0,0 +1,36 @@
+use nu_engine::{command_prelude::*, get_full_help};
+
+#[derive(Clone)]
+pub struct On;
+
+impl Command for On {
+    fn name(&self) -> &str {
+        "on"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("on")
+            .category(Category::System)
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+    }
+
+    fn description(&self) -> &str {
+        "Run a closure repeatedly in reaction to something, such as a timer."
+    }
+
+    fn extra_description(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message.
+
+For reacting to filesystem changes, see the `watch` command instead."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::string(get_full_help(self, engine_state, stack), call.head).into_pipeline_data())
+    }
+}
@@ -0,0 +1,159 @@
+use nu_engine::{command_prelude::*, ClosureEval};
+use nu_protocol::{
+    engine::{Closure, StateWorkingSet},
+    format_shell_error,
+};
+#[cfg(unix)]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+// chosen mostly arbitrarily, same as `on interval`'s polling frequency
+const CHECK_SIGNAL_FREQUENCY: Duration = Duration::from_millis(100);
+
+// Large enough to cover every signal in `nix::sys::signal::Signal`; real-time signals aren't
+// represented there, so this doesn't need to stretch to `SIGRTMAX`.
+#[cfg(unix)]
+const SIGNAL_FLAG_COUNT: usize = 32;
+
+#[cfg(unix)]
+static SIGNAL_FLAGS: [AtomicBool; SIGNAL_FLAG_COUNT] =
+    [const { AtomicBool::new(false) }; SIGNAL_FLAG_COUNT];
+
+#[cfg(unix)]
+extern "C" fn record_signal(signal: std::os::raw::c_int) {
+    if let Some(flag) = SIGNAL_FLAGS.get(signal as usize) {
+        flag.store(true, Ordering::SeqCst);
+    }
+}
+
+#[derive(Clone)]
+pub struct OnSignal;
+
+impl Command for OnSignal {
+    fn name(&self) -> &str {
+        "on signal"
+    }
+
+    fn description(&self) -> &str {
+        "Run a closure each time the process receives a given Unix signal."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Runs until interrupted with ctrl+c. The closure is passed no arguments and is run on\nthe main thread between signal checks, not from inside the signal handler itself, so it can\nsafely do anything an ordinary closure can. If several deliveries of the same signal arrive\nbetween checks, the closure only runs once for them. Unix only."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["sigusr1", "sighup", "sigterm", "trap", "reload"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("on signal")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "signal",
+                SyntaxShape::String,
+                "The signal to listen for, e.g. `SIGUSR1` or `SIGHUP`.",
+            )
+            .required(
+                "closure",
+                SyntaxShape::Closure(None),
+                "The closure to run each time the signal is received.",
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let signal_name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let closure: Closure = call.req(engine_state, stack, 1)?;
+
+        run_on_signal(engine_state, stack, head, signal_name, closure)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Reload configuration when the process receives SIGHUP",
+            example: r#"on signal SIGHUP {|| print "reloading config" }"#,
+            result: None,
+        }]
+    }
+}
+
+#[cfg(unix)]
+fn run_on_signal(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    head: Span,
+    signal_name: Spanned<String>,
+    closure: Closure,
+) -> Result<PipelineData, ShellError> {
+    use nix::sys::signal::{signal, SigHandler, Signal};
+    use std::str::FromStr;
+
+    let sig = Signal::from_str(&signal_name.item.to_uppercase()).map_err(|_| {
+        ShellError::IncorrectValue {
+            msg: format!(
+                "`{}` is not a known Unix signal name, e.g. `SIGUSR1` or `SIGHUP`",
+                signal_name.item
+            ),
+            val_span: signal_name.span,
+            call_span: head,
+        }
+    })?;
+
+    // Safety: `record_signal` only touches a `static` array of `AtomicBool`s, which is
+    // async-signal-safe.
+    unsafe { signal(sig, SigHandler::Handler(record_signal)) }.map_err(|errno| {
+        ShellError::GenericError {
+            error: format!("Failed to register handler for {sig}"),
+            msg: errno.to_string(),
+            span: Some(signal_name.span),
+            help: None,
+            inner: vec![],
+        }
+    })?;
+
+    let flag = &SIGNAL_FLAGS[sig as usize];
+    let mut closure = ClosureEval::new(engine_state, stack, closure);
+
+    loop {
+        if flag.swap(false, Ordering::SeqCst) {
+            let result = closure.run_with_input(PipelineData::Empty);
+            match result {
+                Ok(val) => val.print_table(engine_state, stack, false, false)?,
+                Err(err) => {
+                    let working_set = StateWorkingSet::new(engine_state);
+                    eprintln!("{}", format_shell_error(&working_set, &err));
+                }
+            }
+        }
+
+        if engine_state.signals().interrupted() {
+            return Ok(PipelineData::empty());
+        }
+        std::thread::sleep(CHECK_SIGNAL_FREQUENCY);
+    }
+}
+
+#[cfg(not(unix))]
+fn run_on_signal(
+    _engine_state: &EngineState,
+    _stack: &mut Stack,
+    head: Span,
+    _signal_name: Spanned<String>,
+    _closure: Closure,
+) -> Result<PipelineData, ShellError> {
+    Err(ShellError::GenericError {
+        error: "`on signal` is only supported on Unix".into(),
+        msg: "Unix signal handling isn't available on this platform".into(),
+        span: Some(head),
+        help: None,
+        inner: vec![],
+    })
+}
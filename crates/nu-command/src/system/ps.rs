@@ -133,6 +133,40 @@ fn run_ps(
                 record.push("priority", Value::int(proc_stat.priority, span));
                 record.push("process_threads", Value::int(proc_stat.num_threads, span));
                 record.push("cwd", Value::string(proc.cwd(), span));
+                record.push(
+                    "read_bytes",
+                    proc.read_bytes()
+                        .map(|b| Value::filesize(b as i64, span))
+                        .unwrap_or(Value::nothing(span)),
+                );
+                record.push(
+                    "write_bytes",
+                    proc.write_bytes()
+                        .map(|b| Value::filesize(b as i64, span))
+                        .unwrap_or(Value::nothing(span)),
+                );
+                record.push(
+                    "open_fds",
+                    proc.open_fds()
+                        .map(|n| Value::int(n as i64, span))
+                        .unwrap_or(Value::nothing(span)),
+                );
+                if let Some(cgroup_mem) = proc.cgroup_memory() {
+                    record.push(
+                        "cgroup_mem_usage",
+                        cgroup_mem
+                            .usage
+                            .map(|u| Value::filesize(u as i64, span))
+                            .unwrap_or(Value::nothing(span)),
+                    );
+                    record.push(
+                        "cgroup_mem_limit",
+                        cgroup_mem
+                            .limit
+                            .map(|l| Value::filesize(l as i64, span))
+                            .unwrap_or(Value::nothing(span)),
+                    );
+                }
             }
             #[cfg(windows)]
             {
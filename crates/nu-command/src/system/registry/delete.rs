@@ -0,0 +1,123 @@
+use super::{get_reg_hive, hive_switches};
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct RegistryDelete;
+
+impl Command for RegistryDelete {
+    fn name(&self) -> &str {
+        "registry delete"
+    }
+
+    fn signature(&self) -> Signature {
+        hive_switches(Signature::build("registry delete"))
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .switch(
+                "recurse",
+                "delete a key and all of its subkeys, instead of requiring it to be empty",
+                Some('r'),
+            )
+            .required(
+                "key",
+                SyntaxShape::String,
+                "Registry key to delete, or to delete a value from.",
+            )
+            .optional(
+                "value",
+                SyntaxShape::String,
+                "If given, only this value is deleted, and the key itself is left alone.",
+            )
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "Delete a Windows registry key or value."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Currently supported only on Windows systems."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        registry_delete(engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Delete a single value, leaving the key in place",
+                example: r"registry delete --hkcu 'Software\MyApp' retries",
+                result: None,
+            },
+            Example {
+                description: "Delete an empty key",
+                example: r"registry delete --hkcu 'Software\MyApp'",
+                result: None,
+            },
+            Example {
+                description: "Delete a key and everything under it",
+                example: r"registry delete --hkcu --recurse 'Software\MyApp'",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn registry_delete(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let call_span = call.head;
+    let recurse = call.has_flag(engine_state, stack, "recurse")?;
+
+    let registry_key: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let registry_value: Option<Spanned<String>> = call.opt(engine_state, stack, 1)?;
+
+    let reg_hive = get_reg_hive(engine_state, stack, call)?;
+
+    if let Some(value) = registry_value {
+        if recurse {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "--recurse".into(),
+                left_span: call.head,
+                right_message: "value".into(),
+                right_span: value.span,
+            });
+        }
+        let reg_key = reg_hive
+            .open_subkey(&registry_key.item)
+            .map_err(|err| registry_error("Unable to open registry key", err, registry_key.span))?;
+        reg_key
+            .delete_value(&value.item)
+            .map_err(|err| registry_error("Unable to delete registry value", err, value.span))?;
+    } else if recurse {
+        reg_hive
+            .delete_subkey_all(&registry_key.item)
+            .map_err(|err| {
+                registry_error("Unable to delete registry key", err, registry_key.span)
+            })?;
+    } else {
+        reg_hive.delete_subkey(&registry_key.item).map_err(|err| {
+            registry_error("Unable to delete registry key", err, registry_key.span)
+        })?;
+    }
+
+    Ok(Value::nothing(call_span).into_pipeline_data())
+}
+
+fn registry_error(error: &str, err: std::io::Error, span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: error.into(),
+        msg: err.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
@@ -0,0 +1,79 @@
+mod delete;
+mod query;
+mod write;
+
+pub use delete::RegistryDelete;
+pub use query::RegistryQuery;
+pub use write::RegistryWrite;
+
+use nu_engine::command_prelude::*;
+use winreg::{enums::*, RegKey};
+
+/// Adds the shared `--hkcr`/`--hkcu`/... hive-selection switches to a registry subcommand's
+/// signature, so `registry query`, `write`, and `delete` all expose the same set of hives.
+fn hive_switches(signature: Signature) -> Signature {
+    signature
+        .switch("hkcr", "use the hkey_classes_root hive", None)
+        .switch("hkcu", "use the hkey_current_user hive", None)
+        .switch("hklm", "use the hkey_local_machine hive", None)
+        .switch("hku", "use the hkey_users hive", None)
+        .switch("hkpd", "use the hkey_performance_data hive", None)
+        .switch("hkpt", "use the hkey_performance_text hive", None)
+        .switch("hkpnls", "use the hkey_performance_nls_text hive", None)
+        .switch("hkcc", "use the hkey_current_config hive", None)
+        .switch("hkdd", "use the hkey_dyn_data hive", None)
+        .switch(
+            "hkculs",
+            "use the hkey_current_user_local_settings hive",
+            None,
+        )
+}
+
+/// Resolves the hive selected by [`hive_switches`]' flags, defaulting to `HKEY_CURRENT_USER`.
+fn get_reg_hive(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<RegKey, ShellError> {
+    let flags = [
+        "hkcr", "hkcu", "hklm", "hku", "hkpd", "hkpt", "hkpnls", "hkcc", "hkdd", "hkculs",
+    ]
+    .iter()
+    .copied()
+    .filter_map(|flag| match call.has_flag(engine_state, stack, flag) {
+        Ok(true) => Some(Ok(flag)),
+        Ok(false) => None,
+        Err(e) => Some(Err(e)),
+    })
+    .collect::<Result<Vec<_>, ShellError>>()?;
+    if flags.len() > 1 {
+        return Err(ShellError::GenericError {
+            error: "Only one registry key can be specified".into(),
+            msg: "Only one registry key can be specified".into(),
+            span: Some(call.head),
+            help: None,
+            inner: vec![],
+        });
+    }
+    let hive = flags.first().copied().unwrap_or("hkcu");
+    let hkey = match hive {
+        "hkcr" => HKEY_CLASSES_ROOT,
+        "hkcu" => HKEY_CURRENT_USER,
+        "hklm" => HKEY_LOCAL_MACHINE,
+        "hku" => HKEY_USERS,
+        "hkpd" => HKEY_PERFORMANCE_DATA,
+        "hkpt" => HKEY_PERFORMANCE_TEXT,
+        "hkpnls" => HKEY_PERFORMANCE_NLSTEXT,
+        "hkcc" => HKEY_CURRENT_CONFIG,
+        "hkdd" => HKEY_DYN_DATA,
+        "hkculs" => HKEY_CURRENT_USER_LOCAL_SETTINGS,
+        _ => {
+            return Err(ShellError::NushellFailedSpanned {
+                msg: "Entered unreachable code".into(),
+                label: "Unknown registry hive".into(),
+                span: call.head,
+            })
+        }
+    };
+    Ok(RegKey::predef(hkey))
+}
@@ -1,3 +1,4 @@
+use super::{get_reg_hive, hive_switches};
 use nu_engine::command_prelude::*;
 
 use windows::{core::PCWSTR, Win32::System::Environment::ExpandEnvironmentStringsW};
@@ -12,27 +13,18 @@ impl Command for RegistryQuery {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("registry query")
+        hive_switches(Signature::build("registry query"))
             .input_output_types(vec![(Type::Nothing, Type::Any)])
-            .switch("hkcr", "query the hkey_classes_root hive", None)
-            .switch("hkcu", "query the hkey_current_user hive", None)
-            .switch("hklm", "query the hkey_local_machine hive", None)
-            .switch("hku", "query the hkey_users hive", None)
-            .switch("hkpd", "query the hkey_performance_data hive", None)
-            .switch("hkpt", "query the hkey_performance_text hive", None)
-            .switch("hkpnls", "query the hkey_performance_nls_text hive", None)
-            .switch("hkcc", "query the hkey_current_config hive", None)
-            .switch("hkdd", "query the hkey_dyn_data hive", None)
-            .switch(
-                "hkculs",
-                "query the hkey_current_user_local_settings hive",
-                None,
-            )
             .switch(
                 "no-expand",
                 "do not expand %ENV% placeholders in REG_EXPAND_SZ",
                 Some('u'),
             )
+            .switch(
+                "recurse",
+                "recursively include subkeys and their values in the output",
+                Some('r'),
+            )
             .required("key", SyntaxShape::String, "Registry key to query.")
             .optional(
                 "value",
@@ -72,6 +64,11 @@ impl Command for RegistryQuery {
                 example: r"registry query --hklm 'SYSTEM\CurrentControlSet\Control\Session Manager\Environment'",
                 result: None,
             },
+            Example {
+                description: "Recursively export a key and its subkeys to a table",
+                example: "registry query --hkcu --recurse environment",
+                result: None,
+            },
         ]
     }
 }
@@ -84,110 +81,120 @@ fn registry_query(
     let call_span = call.head;
 
     let skip_expand = call.has_flag(engine_state, stack, "no-expand")?;
+    let recurse = call.has_flag(engine_state, stack, "recurse")?;
 
     let registry_key: Spanned<String> = call.req(engine_state, stack, 0)?;
-    let registry_key_span = &registry_key.clone().span;
+    let registry_key_span = registry_key.span;
     let registry_value: Option<Spanned<String>> = call.opt(engine_state, stack, 1)?;
 
     let reg_hive = get_reg_hive(engine_state, stack, call)?;
-    let reg_key = reg_hive.open_subkey(registry_key.item)?;
+    let reg_key = reg_hive.open_subkey(&registry_key.item)?;
+
+    match registry_value {
+        None if recurse => Ok(registry_key_to_nu_value(
+            &reg_key,
+            &registry_key.item,
+            call_span,
+            registry_key_span,
+            skip_expand,
+        )
+        .into_pipeline_data()),
+        None => {
+            let mut reg_values = vec![];
+            for (name, val) in reg_key.enum_values().flatten() {
+                let reg_type = format!("{:?}", val.vtype);
+                let nu_value = reg_value_to_nu_value(val, call_span, skip_expand);
+                reg_values.push(Value::record(
+                    record! {
+                        "name" => Value::string(name, call_span),
+                        "value" => nu_value,
+                        "type" => Value::string(reg_type, call_span),
+                    },
+                    registry_key_span,
+                ))
+            }
+            Ok(reg_values.into_pipeline_data(call_span, engine_state.signals().clone()))
+        }
+        Some(value) => {
+            let reg_value = reg_key.get_raw_value(value.item.as_str());
+            match reg_value {
+                Ok(val) => {
+                    let reg_type = format!("{:?}", val.vtype);
+                    let nu_value = reg_value_to_nu_value(val, call_span, skip_expand);
+                    Ok(Value::record(
+                        record! {
+                            "name" => Value::string(value.item, call_span),
+                            "value" => nu_value,
+                            "type" => Value::string(reg_type, call_span),
+                        },
+                        value.span,
+                    )
+                    .into_pipeline_data())
+                }
+                Err(_) => Err(ShellError::GenericError {
+                    error: "Unable to find registry key/value".into(),
+                    msg: format!("Registry value: {} was not found", value.item),
+                    span: Some(value.span),
+                    help: None,
+                    inner: vec![],
+                }),
+            }
+        }
+    }
+}
 
-    if registry_value.is_none() {
-        let mut reg_values = vec![];
-        for (name, val) in reg_key.enum_values().flatten() {
+/// Recursively exports `reg_key` (named `name`) and all of its subkeys into a single nu record,
+/// each level carrying its own `name`, `values`, and nested `subkeys` table.
+fn registry_key_to_nu_value(
+    reg_key: &RegKey,
+    name: &str,
+    call_span: Span,
+    key_span: Span,
+    skip_expand: bool,
+) -> Value {
+    let values = reg_key
+        .enum_values()
+        .flatten()
+        .map(|(name, val)| {
             let reg_type = format!("{:?}", val.vtype);
             let nu_value = reg_value_to_nu_value(val, call_span, skip_expand);
-            reg_values.push(Value::record(
+            Value::record(
                 record! {
                     "name" => Value::string(name, call_span),
                     "value" => nu_value,
                     "type" => Value::string(reg_type, call_span),
                 },
-                *registry_key_span,
+                key_span,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let subkeys = reg_key
+        .enum_keys()
+        .flatten()
+        .filter_map(|subkey_name| {
+            let subkey = reg_key.open_subkey(&subkey_name).ok()?;
+            Some(registry_key_to_nu_value(
+                &subkey,
+                &subkey_name,
+                call_span,
+                key_span,
+                skip_expand,
             ))
-        }
-        Ok(reg_values.into_pipeline_data(call_span, engine_state.signals().clone()))
-    } else {
-        match registry_value {
-            Some(value) => {
-                let reg_value = reg_key.get_raw_value(value.item.as_str());
-                match reg_value {
-                    Ok(val) => {
-                        let reg_type = format!("{:?}", val.vtype);
-                        let nu_value = reg_value_to_nu_value(val, call_span, skip_expand);
-                        Ok(Value::record(
-                            record! {
-                                "name" => Value::string(value.item, call_span),
-                                "value" => nu_value,
-                                "type" => Value::string(reg_type, call_span),
-                            },
-                            value.span,
-                        )
-                        .into_pipeline_data())
-                    }
-                    Err(_) => Err(ShellError::GenericError {
-                        error: "Unable to find registry key/value".into(),
-                        msg: format!("Registry value: {} was not found", value.item),
-                        span: Some(value.span),
-                        help: None,
-                        inner: vec![],
-                    }),
-                }
-            }
-            None => Ok(Value::nothing(call_span).into_pipeline_data()),
-        }
-    }
-}
+        })
+        .collect::<Vec<_>>();
 
-fn get_reg_hive(
-    engine_state: &EngineState,
-    stack: &mut Stack,
-    call: &Call,
-) -> Result<RegKey, ShellError> {
-    let flags = [
-        "hkcr", "hkcu", "hklm", "hku", "hkpd", "hkpt", "hkpnls", "hkcc", "hkdd", "hkculs",
-    ]
-    .iter()
-    .copied()
-    .filter_map(|flag| match call.has_flag(engine_state, stack, flag) {
-        Ok(true) => Some(Ok(flag)),
-        Ok(false) => None,
-        Err(e) => Some(Err(e)),
-    })
-    .collect::<Result<Vec<_>, ShellError>>()?;
-    if flags.len() > 1 {
-        return Err(ShellError::GenericError {
-            error: "Only one registry key can be specified".into(),
-            msg: "Only one registry key can be specified".into(),
-            span: Some(call.head),
-            help: None,
-            inner: vec![],
-        });
-    }
-    let hive = flags.first().copied().unwrap_or("hkcu");
-    let hkey = match hive {
-        "hkcr" => HKEY_CLASSES_ROOT,
-        "hkcu" => HKEY_CURRENT_USER,
-        "hklm" => HKEY_LOCAL_MACHINE,
-        "hku" => HKEY_USERS,
-        "hkpd" => HKEY_PERFORMANCE_DATA,
-        "hkpt" => HKEY_PERFORMANCE_TEXT,
-        "hkpnls" => HKEY_PERFORMANCE_NLSTEXT,
-        "hkcc" => HKEY_CURRENT_CONFIG,
-        "hkdd" => HKEY_DYN_DATA,
-        "hkculs" => HKEY_CURRENT_USER_LOCAL_SETTINGS,
-        _ => {
-            return Err(ShellError::NushellFailedSpanned {
-                msg: "Entered unreachable code".into(),
-                label: "Unknown registry hive".into(),
-                span: call.head,
-            })
-        }
-    };
-    Ok(RegKey::predef(hkey))
+    Value::record(
+        record! {
+            "name" => Value::string(name, call_span),
+            "values" => Value::list(values, call_span),
+            "subkeys" => Value::list(subkeys, call_span),
+        },
+        key_span,
+    )
 }
 
-fn reg_value_to_nu_value(
+pub(super) fn reg_value_to_nu_value(
     mut reg_value: winreg::RegValue,
     call_span: Span,
     skip_expand: bool,
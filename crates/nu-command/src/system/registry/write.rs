@@ -0,0 +1,173 @@
+use super::{get_reg_hive, hive_switches};
+use nu_engine::command_prelude::*;
+
+use winreg::{enums::*, RegValue};
+
+#[derive(Clone)]
+pub struct RegistryWrite;
+
+impl Command for RegistryWrite {
+    fn name(&self) -> &str {
+        "registry write"
+    }
+
+    fn signature(&self) -> Signature {
+        hive_switches(Signature::build("registry write"))
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .switch(
+                "expand",
+                "store a string value as REG_EXPAND_SZ instead of REG_SZ",
+                Some('x'),
+            )
+            .switch(
+                "qword",
+                "store an int value as REG_QWORD instead of REG_DWORD",
+                Some('q'),
+            )
+            .required(
+                "key",
+                SyntaxShape::String,
+                "Registry key to create or update.",
+            )
+            .required("value", SyntaxShape::String, "Name of the value to write.")
+            .required(
+                "data",
+                SyntaxShape::Any,
+                "Data to write; its nu type selects the registry value type.",
+            )
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "Create or update a Windows registry value."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Currently supported only on Windows systems. The key is created, along with any \
+missing parent keys, if it doesn't already exist. A string is written as REG_SZ (or \
+REG_EXPAND_SZ with --expand), an int as REG_DWORD (or REG_QWORD with --qword or if it \
+doesn't fit in 32 bits), a binary value as REG_BINARY, and a list of strings as REG_MULTI_SZ."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        registry_write(engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Write a REG_SZ value",
+                example: r#"registry write --hkcu Environment MY_VAR "hello""#,
+                result: None,
+            },
+            Example {
+                description: "Write a REG_DWORD value",
+                example: r"registry write --hkcu 'Software\MyApp' retries 3",
+                result: None,
+            },
+            Example {
+                description: "Write a REG_MULTI_SZ value",
+                example: r"registry write --hkcu 'Software\MyApp' paths [a b c]",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn registry_write(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let call_span = call.head;
+    let expand = call.has_flag(engine_state, stack, "expand")?;
+    let qword = call.has_flag(engine_state, stack, "qword")?;
+
+    let registry_key: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let value_name: Spanned<String> = call.req(engine_state, stack, 1)?;
+    let data: Value = call.req(engine_state, stack, 2)?;
+    let data_span = data.span();
+
+    let reg_value = nu_value_to_reg_value(&data, expand, qword)?;
+
+    let reg_hive = get_reg_hive(engine_state, stack, call)?;
+    let (reg_key, _) =
+        reg_hive
+            .create_subkey(&registry_key.item)
+            .map_err(|err| ShellError::GenericError {
+                error: "Unable to create or open registry key".into(),
+                msg: err.to_string(),
+                span: Some(registry_key.span),
+                help: None,
+                inner: vec![],
+            })?;
+
+    reg_key
+        .set_raw_value(&value_name.item, &reg_value)
+        .map_err(|err| ShellError::GenericError {
+            error: "Unable to write registry value".into(),
+            msg: err.to_string(),
+            span: Some(data_span),
+            help: None,
+            inner: vec![],
+        })?;
+
+    Ok(Value::nothing(call_span).into_pipeline_data())
+}
+
+fn nu_value_to_reg_value(data: &Value, expand: bool, qword: bool) -> Result<RegValue, ShellError> {
+    match data {
+        Value::String { val, .. } => Ok(RegValue {
+            bytes: string_to_utf16_bytes(val),
+            vtype: if expand { REG_EXPAND_SZ } else { REG_SZ },
+        }),
+        Value::Int { val, .. } => {
+            if qword || u32::try_from(*val).is_err() {
+                Ok(RegValue {
+                    bytes: (*val as u64).to_le_bytes().to_vec(),
+                    vtype: REG_QWORD,
+                })
+            } else {
+                Ok(RegValue {
+                    bytes: (*val as u32).to_le_bytes().to_vec(),
+                    vtype: REG_DWORD,
+                })
+            }
+        }
+        Value::Binary { val, .. } => Ok(RegValue {
+            bytes: val.clone(),
+            vtype: REG_BINARY,
+        }),
+        Value::List { vals, .. } => {
+            let mut bytes = vec![];
+            for item in vals {
+                bytes.extend(string_to_utf16_bytes(item.as_str()?));
+            }
+            // REG_MULTI_SZ is terminated by an extra empty string, i.e. a second null.
+            bytes.extend([0, 0]);
+            Ok(RegValue {
+                bytes,
+                vtype: REG_MULTI_SZ,
+            })
+        }
+        _ => Err(ShellError::UnsupportedInput {
+            msg: "registry values must be a string, int, binary, or list of strings".into(),
+            input: format!("input of type {}", data.get_type()),
+            msg_span: data.span(),
+            input_span: data.span(),
+        }),
+    }
+}
+
+fn string_to_utf16_bytes(s: &str) -> Vec<u8> {
+    s.encode_utf16()
+        .chain([0])
+        .flat_map(u16::to_le_bytes)
+        .collect()
+}
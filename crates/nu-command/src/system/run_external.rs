@@ -44,6 +44,23 @@ impl Command for External {
                 SyntaxShape::OneOf(vec![SyntaxShape::GlobPattern, SyntaxShape::Any]),
                 "Arguments for external command.",
             )
+            .named(
+                "limits",
+                SyntaxShape::Record(vec![]),
+                "Resource limits to apply to the process: `cpu` (duration) and `mem` (filesize). Unix only.",
+                None,
+            )
+            .named(
+                "allow-env",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "Only pass these environment variables to the process, instead of the full environment.",
+                None,
+            )
+            .switch(
+                "deny-network",
+                "Run the process in a new network namespace with no network access. Linux only.",
+                None,
+            )
             .category(Category::System)
     }
 
@@ -146,7 +163,10 @@ impl Command for External {
         command.current_dir(cwd);
 
         // Configure environment variables.
-        let envs = env_to_strings(engine_state, stack)?;
+        let mut envs = env_to_strings(engine_state, stack)?;
+        if let Some(allow_env) = call.get_flag::<Vec<String>>(engine_state, stack, "allow-env")? {
+            envs.retain(|name, _| allow_env.iter().any(|allowed| allowed == name));
+        }
         command.env_clear();
         command.envs(envs);
 
@@ -183,6 +203,16 @@ impl Command for External {
         #[cfg(not(windows))]
         command.args(args.into_iter().map(|s| s.item));
 
+        // Configure resource limits, if requested.
+        if let Some(limits) = call.get_flag::<Value>(engine_state, stack, "limits")? {
+            apply_limits(&mut command, &limits)?;
+        }
+
+        // Configure network isolation, if requested.
+        if call.has_flag(engine_state, stack, "deny-network")? {
+            deny_network(&mut command, call.head)?;
+        }
+
         // Configure stdout and stderr. If both are set to `OutDest::Pipe`,
         // we'll set up a pipe that merges two streams into one.
         let stdout = stack.stdout();
@@ -201,10 +231,12 @@ impl Command for External {
         // Configure stdin. We'll try connecting input to the child process
         // directly. If that's not possible, we'll set up a pipe and spawn a
         // thread to copy data into the child process.
+        let mut predecessor = None;
         let data_to_copy_into_stdin = match input {
             PipelineData::ByteStream(stream, metadata) => match stream.into_stdio() {
-                Ok(stdin) => {
+                Ok((stdin, prev_child)) => {
                     command.stdin(stdin);
+                    predecessor = prev_child;
                     None
                 }
                 Err(stream) => {
@@ -263,6 +295,10 @@ impl Command for External {
             child.ignore_error(true);
         }
 
+        if let Some(predecessor) = predecessor {
+            child.push_predecessor(predecessor);
+        }
+
         Ok(PipelineData::ByteStream(
             ByteStream::child(child, call.head),
             None,
@@ -286,10 +322,134 @@ impl Command for External {
                 example: r#"run-external "nu" "-c" "print -e hello" e>| split chars"#,
                 result: None,
             },
+            Example {
+                description: "Run a command with a CPU time and memory limit (Unix only)",
+                example: r#"run-external --limits {cpu: 10sec, mem: 1GB} "some-command""#,
+                result: None,
+            },
+            Example {
+                description: "Run a command with a scrubbed environment and no network access (Linux only)",
+                example: r#"run-external --allow-env [PATH, HOME] --deny-network "some-command""#,
+                result: None,
+            },
         ]
     }
 }
 
+/// Apply the `--limits` record (`cpu` duration, `mem` filesize) to a not-yet-spawned command.
+///
+/// Only implemented on Unix, via POSIX rlimits (the same mechanism as the `ulimit` command);
+/// there's no Windows job-object equivalent wired up yet.
+#[cfg(unix)]
+fn apply_limits(command: &mut std::process::Command, limits: &Value) -> Result<(), ShellError> {
+    use nix::sys::resource::{rlim_t, setrlimit, Resource};
+    use std::os::unix::process::CommandExt;
+
+    let record = limits.as_record()?;
+
+    let cpu_seconds = match record.get("cpu") {
+        Some(Value::Duration { val, .. }) => {
+            Some(
+                rlim_t::try_from(val / 1_000_000_000).map_err(|e| ShellError::CantConvert {
+                    to_type: "rlim_t".into(),
+                    from_type: "duration".into(),
+                    span: limits.span(),
+                    help: Some(e.to_string()),
+                })?,
+            )
+        }
+        Some(other) => {
+            return Err(ShellError::TypeMismatch {
+                err_message: format!("`cpu` limit must be a duration, got {}", other.get_type()),
+                span: other.span(),
+            })
+        }
+        None => None,
+    };
+
+    let mem_bytes = match record.get("mem") {
+        Some(Value::Filesize { val, .. }) => {
+            Some(
+                rlim_t::try_from(val.get()).map_err(|e| ShellError::CantConvert {
+                    to_type: "rlim_t".into(),
+                    from_type: "filesize".into(),
+                    span: limits.span(),
+                    help: Some(e.to_string()),
+                })?,
+            )
+        }
+        Some(other) => {
+            return Err(ShellError::TypeMismatch {
+                err_message: format!("`mem` limit must be a filesize, got {}", other.get_type()),
+                span: other.span(),
+            })
+        }
+        None => None,
+    };
+
+    unsafe {
+        command.pre_exec(move || {
+            if let Some(seconds) = cpu_seconds {
+                setrlimit(Resource::RLIMIT_CPU, seconds, seconds)
+                    .map_err(|e| std::io::Error::other(e.to_string()))?;
+            }
+            if let Some(bytes) = mem_bytes {
+                setrlimit(Resource::RLIMIT_AS, bytes, bytes)
+                    .map_err(|e| std::io::Error::other(e.to_string()))?;
+            }
+            Ok(())
+        });
+    }
+
+    Ok(())
+}
+
+/// See the Unix implementation above; Windows has no rlimit equivalent wired up yet.
+#[cfg(not(unix))]
+fn apply_limits(_command: &mut std::process::Command, limits: &Value) -> Result<(), ShellError> {
+    Err(ShellError::GenericError {
+        error: "`--limits` is only supported on Unix".into(),
+        msg: "resource limits require POSIX rlimits, which aren't available on this platform"
+            .into(),
+        span: Some(limits.span()),
+        help: None,
+        inner: vec![],
+    })
+}
+
+/// Put the not-yet-spawned command in a fresh, unconfigured network namespace, so it has no
+/// network interfaces other than loopback.
+///
+/// Requires `CAP_NET_ADMIN` or, on kernels with unprivileged user namespaces enabled, no special
+/// privilege at all. There's no Windows AppContainer equivalent wired up yet.
+#[cfg(target_os = "linux")]
+fn deny_network(command: &mut std::process::Command, _span: Span) -> Result<(), ShellError> {
+    use std::os::unix::process::CommandExt;
+
+    unsafe {
+        command.pre_exec(move || {
+            if libc::unshare(libc::CLONE_NEWNET) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+    Ok(())
+}
+
+/// See the Linux implementation above; other platforms have no equivalent wired up yet.
+#[cfg(not(target_os = "linux"))]
+fn deny_network(_command: &mut std::process::Command, span: Span) -> Result<(), ShellError> {
+    Err(ShellError::GenericError {
+        error: "`--deny-network` is only supported on Linux".into(),
+        msg: "network isolation requires Linux network namespaces, which aren't available on this platform"
+            .into(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    })
+}
+
 /// Evaluate all arguments from a call, performing expansions when necessary.
 pub fn eval_arguments_from_call(
     engine_state: &EngineState,
@@ -0,0 +1,133 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct SysBattery;
+
+impl Command for SysBattery {
+    fn name(&self) -> &str {
+        "sys battery"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sys battery")
+            .filter()
+            .category(Category::System)
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+    }
+
+    fn description(&self) -> &str {
+        "View detailed information about the system's batteries."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Currently only implemented for Linux (via /sys/class/power_supply); other platforms always return an empty list."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(battery(call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show detailed information about the system's batteries",
+            example: "sys battery",
+            result: None,
+        }]
+    }
+}
+
+fn battery(span: Span) -> Value {
+    #[cfg(target_os = "linux")]
+    let batteries = linux_batteries(span);
+    #[cfg(not(target_os = "linux"))]
+    let batteries = vec![];
+
+    Value::list(batteries, span)
+}
+
+#[cfg(target_os = "linux")]
+fn linux_batteries(span: Span) -> Vec<Value> {
+    use std::path::Path;
+
+    fn read_string(dir: &Path, file: &str) -> Option<String> {
+        std::fs::read_to_string(dir.join(file))
+            .ok()
+            .map(|s| s.trim().to_string())
+    }
+
+    fn read_u64(dir: &Path, file: &str) -> Option<u64> {
+        read_string(dir, file).and_then(|s| s.parse().ok())
+    }
+
+    fn hours_to_duration(hours: f64, span: Span) -> Value {
+        Value::duration((hours * 3_600e9) as i64, span)
+    }
+
+    let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") else {
+        return vec![];
+    };
+
+    let mut batteries = vec![];
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        if read_string(&dir, "type").as_deref() != Some("Battery") {
+            continue;
+        }
+
+        // Charge-based power supplies (mAh) and energy-based ones (Wh) expose the same shape of
+        // information under different attribute names.
+        let now = read_u64(&dir, "energy_now").or_else(|| read_u64(&dir, "charge_now"));
+        let full = read_u64(&dir, "energy_full").or_else(|| read_u64(&dir, "charge_full"));
+        let full_design =
+            read_u64(&dir, "energy_full_design").or_else(|| read_u64(&dir, "charge_full_design"));
+        let rate = read_u64(&dir, "power_now").or_else(|| read_u64(&dir, "current_now"));
+        let status = read_string(&dir, "status").unwrap_or_default();
+
+        let percentage = match (now, full) {
+            (Some(now), Some(full)) if full > 0 => Some(now as f64 / full as f64 * 100.0),
+            _ => None,
+        };
+        let health_percent = match (full, full_design) {
+            (Some(full), Some(design)) if design > 0 => Some(full as f64 / design as f64 * 100.0),
+            _ => None,
+        };
+        // `time_to_empty`/`time_to_full` are only meaningful while the battery is actively
+        // charging or discharging at a steady rate.
+        let time_to_empty = match (now, rate) {
+            (Some(now), Some(rate)) if rate > 0 && status == "Discharging" => {
+                Some(now as f64 / rate as f64)
+            }
+            _ => None,
+        };
+        let time_to_full = match (now, full, rate) {
+            (Some(now), Some(full), Some(rate)) if rate > 0 && status == "Charging" => {
+                Some(full.saturating_sub(now) as f64 / rate as f64)
+            }
+            _ => None,
+        };
+
+        batteries.push(Value::record(
+            record! {
+                "name" => Value::string(entry.file_name().to_string_lossy().into_owned(), span),
+                "vendor" => Value::string(read_string(&dir, "manufacturer").unwrap_or_default(), span),
+                "model" => Value::string(read_string(&dir, "model_name").unwrap_or_default(), span),
+                "technology" => Value::string(read_string(&dir, "technology").unwrap_or_default(), span),
+                "state" => Value::string(status, span),
+                "percentage" => percentage.map(|p| Value::float(p, span)).unwrap_or(Value::nothing(span)),
+                "cycle_count" => read_u64(&dir, "cycle_count").map(|c| Value::int(c as i64, span)).unwrap_or(Value::nothing(span)),
+                "health_percent" => health_percent.map(|h| Value::float(h, span)).unwrap_or(Value::nothing(span)),
+                "time_to_empty" => time_to_empty.map(|h| hours_to_duration(h, span)).unwrap_or(Value::nothing(span)),
+                "time_to_full" => time_to_full.map(|h| hours_to_duration(h, span)).unwrap_or(Value::nothing(span)),
+            },
+            span,
+        ));
+    }
+
+    batteries
+}
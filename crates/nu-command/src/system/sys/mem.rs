@@ -43,7 +43,7 @@ fn mem(span: Span) -> Value {
     let mut sys = System::new();
     sys.refresh_memory();
 
-    let record = record! {
+    let mut record = record! {
         "total" => Value::filesize(sys.total_memory() as i64, span),
         "free" => Value::filesize(sys.free_memory() as i64, span),
         "used" => Value::filesize(sys.used_memory() as i64, span),
@@ -53,5 +53,25 @@ fn mem(span: Span) -> Value {
         "swap used" => Value::filesize(sys.used_swap() as i64, span),
     };
 
+    // Inside a container, `total` above reports the host's RAM rather than the container's
+    // memory limit; add the cgroup's own view so `sys mem` is useful there too.
+    #[cfg(target_os = "linux")]
+    if let Some(cgroup_mem) = nu_system::current_process_cgroup_memory() {
+        record.push(
+            "cgroup limit",
+            cgroup_mem
+                .limit
+                .map(|l| Value::filesize(l as i64, span))
+                .unwrap_or(Value::nothing(span)),
+        );
+        record.push(
+            "cgroup used",
+            cgroup_mem
+                .usage
+                .map(|u| Value::filesize(u as i64, span))
+                .unwrap_or(Value::nothing(span)),
+        );
+    }
+
     Value::record(record, span)
 }
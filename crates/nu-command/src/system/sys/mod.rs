@@ -1,17 +1,21 @@
+mod battery;
 mod cpu;
 mod disks;
 mod host;
 mod mem;
 mod net;
+mod sensors;
 mod sys_;
 mod temp;
 mod users;
 
+pub use battery::SysBattery;
 pub use cpu::SysCpu;
 pub use disks::SysDisks;
 pub use host::SysHost;
 pub use mem::SysMem;
 pub use net::SysNet;
+pub use sensors::SysSensors;
 pub use sys_::Sys;
 pub use temp::SysTemp;
 pub use users::SysUsers;
@@ -0,0 +1,113 @@
+use nu_engine::command_prelude::*;
+use sysinfo::Components;
+
+#[derive(Clone)]
+pub struct SysSensors;
+
+impl Command for SysSensors {
+    fn name(&self) -> &str {
+        "sys sensors"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sys sensors")
+            .filter()
+            .category(Category::System)
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+    }
+
+    fn description(&self) -> &str {
+        "View readings from temperature and fan sensors."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Fan speeds are currently only read on Linux (via hwmon); other platforms report temperatures only, same as `sys temp`."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(sensors(call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show the system's temperature and fan sensor readings",
+            example: "sys sensors",
+            result: None,
+        }]
+    }
+}
+
+fn sensors(span: Span) -> Value {
+    let mut rows: Vec<Value> = Components::new_with_refreshed_list()
+        .iter()
+        .map(|component| {
+            Value::record(
+                record! {
+                    "unit" => Value::string(component.label(), span),
+                    "kind" => Value::string("temperature", span),
+                    "value" => Value::float(component.temperature().into(), span),
+                    "high" => Value::float(component.max().into(), span),
+                },
+                span,
+            )
+        })
+        .collect();
+
+    #[cfg(target_os = "linux")]
+    rows.extend(linux_fan_speeds(span));
+
+    Value::list(rows, span)
+}
+
+/// Reads fan speeds (in RPM) from `/sys/class/hwmon/*/fan*_input`.
+#[cfg(target_os = "linux")]
+fn linux_fan_speeds(span: Span) -> Vec<Value> {
+    let mut fans = vec![];
+
+    let Ok(hwmon_dirs) = std::fs::read_dir("/sys/class/hwmon") else {
+        return fans;
+    };
+
+    for hwmon in hwmon_dirs.flatten() {
+        let label = std::fs::read_to_string(hwmon.path().join("name"))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_else(|_| hwmon.file_name().to_string_lossy().into_owned());
+
+        let Ok(entries) = std::fs::read_dir(hwmon.path()) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if !(name.starts_with("fan") && name.ends_with("_input")) {
+                continue;
+            }
+
+            let Some(rpm) = std::fs::read_to_string(entry.path())
+                .ok()
+                .and_then(|s| s.trim().parse::<f64>().ok())
+            else {
+                continue;
+            };
+
+            fans.push(Value::record(
+                record! {
+                    "unit" => Value::string(format!("{label} {name}"), span),
+                    "kind" => Value::string("fan", span),
+                    "value" => Value::float(rpm, span),
+                    "high" => Value::nothing(span),
+                },
+                span,
+            ));
+        }
+    }
+
+    fans
+}
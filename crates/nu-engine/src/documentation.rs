@@ -4,8 +4,8 @@ use nu_protocol::{
     debugger::WithoutDebug,
     engine::CommandType,
     engine::{Command, EngineState, Stack, UNKNOWN_SPAN_ID},
-    record, Category, Config, Example, IntoPipelineData, PipelineData, PositionalArg, Signature,
-    Span, SpanId, Spanned, SyntaxShape, Type, Value,
+    record, Category, Config, Deprecation, Example, IntoPipelineData, PipelineData, PositionalArg,
+    Signature, Span, SpanId, Spanned, SyntaxShape, Type, Value,
 };
 use std::{collections::HashMap, fmt::Write};
 use terminal_size::{Height, Width};
@@ -36,6 +36,7 @@ pub fn get_full_help(
         engine_state,
         stack,
         command.is_keyword(),
+        &command.deprecation_info(),
     )
 }
 
@@ -67,6 +68,7 @@ fn get_documentation(
     engine_state: &EngineState,
     stack: &mut Stack,
     is_parser_keyword: bool,
+    deprecations: &[Deprecation],
 ) -> String {
     let nu_config = stack.get_config(engine_state);
 
@@ -79,6 +81,13 @@ fn get_documentation(
     let cmd_name = &sig.name;
     let mut long_desc = String::new();
 
+    for deprecation in deprecations {
+        let _ = writeln!(long_desc, "Warning: {}", deprecation.message(cmd_name));
+    }
+    if !deprecations.is_empty() {
+        long_desc.push('\n');
+    }
+
     let desc = &sig.description;
     if !desc.is_empty() {
         long_desc.push_str(desc);
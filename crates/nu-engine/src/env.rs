@@ -25,6 +25,13 @@ impl From<ShellError> for ConversionError {
 }
 
 /// Translate environment variables from Strings to Values.
+///
+/// A cache keyed by variable name is kept on the [`Stack`] so that a variable whose raw string
+/// value hasn't changed since the last conversion isn't run through its `from_string` closure
+/// again. The cache is also keyed on the closure's [`BlockId`](nu_protocol::BlockId), so
+/// redefining `ENV_CONVERSIONS.<var>.from_string` invalidates the entry even though the raw
+/// variable itself didn't change; overwriting the raw value also drops the entry (see
+/// [`Stack::add_env_var`]).
 pub fn convert_env_vars(
     stack: &mut Stack,
     engine_state: &EngineState,
@@ -47,12 +54,24 @@ pub fn convert_env_vars(
                 })?
                 .as_closure()?;
 
+            if let Some(cached) = stack.cached_env_conversion(key, val, conversion.block_id) {
+                stack.set_env_var_value(key.clone(), cached);
+                continue;
+            }
+
             let new_val = ClosureEvalOnce::new(engine_state, stack, conversion.clone())
                 .debug(false)
                 .run_with_value(val.clone())?
                 .into_value(val.span())?;
 
-            stack.add_env_var(key.clone(), new_val);
+            stack.cache_env_conversion(
+                key.clone(),
+                val.clone(),
+                conversion.block_id,
+                new_val.clone(),
+            );
+
+            stack.set_env_var_value(key.clone(), new_val);
         }
     }
     Ok(())
@@ -1,18 +1,41 @@
 use crate::eval_ir_block;
 #[allow(deprecated)]
 use crate::get_full_help;
+use crate::CallExt;
 use nu_path::{expand_path_with, AbsolutePathBuf};
 use nu_protocol::{
     ast::{Assignment, Block, Call, Expr, Expression, ExternalArgument, PathMember},
     debugger::DebugContext,
-    engine::{Closure, EngineState, Stack},
+    engine::{self, Closure, Command, EngineState, Stack},
     eval_base::Eval,
-    BlockId, Config, DataSource, IntoPipelineData, PipelineData, PipelineMetadata, ShellError,
-    Span, Type, Value, VarId, ENV_VARIABLE_ID,
+    BlockId, Config, DataSource, DeclId, IntoPipelineData, PipelineData, PipelineMetadata,
+    ShellError, Span, Type, Value, VarId, ENV_VARIABLE_ID,
 };
 use nu_utils::IgnoreCaseExt;
 use std::sync::Arc;
 
+/// Checks whether `decl` (or one of its flags, per `call`) has an active
+/// [`Deprecation`](nu_protocol::Deprecation), and if so, warns the user the first time it's
+/// called in this session.
+pub fn warn_if_deprecated(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    decl: &dyn Command,
+    decl_id: DeclId,
+    call: &engine::Call,
+) -> Result<(), ShellError> {
+    for deprecation in decl.deprecation_info() {
+        let triggered = match &deprecation.flag {
+            None => true,
+            Some(flag) => call.has_flag(engine_state, stack, flag)?,
+        };
+        if triggered && engine_state.notify_deprecation_once(decl_id, deprecation.flag.clone()) {
+            eprintln!("Warning: {}", deprecation.message(decl.name()));
+        }
+    }
+    Ok(())
+}
+
 pub fn eval_call<D: DebugContext>(
     engine_state: &EngineState,
     caller_stack: &mut Stack,
@@ -172,7 +195,9 @@ pub fn eval_call<D: DebugContext>(
         // We pass caller_stack here with the knowledge that internal commands
         // are going to be specifically looking for global state in the stack
         // rather than any local state.
-        decl.run(engine_state, caller_stack, &call.into(), input)
+        let engine_call = call.into();
+        warn_if_deprecated(engine_state, caller_stack, decl, call.decl_id, &engine_call)?;
+        decl.run(engine_state, caller_stack, &engine_call, input)
     }
 }
 
@@ -5,7 +5,8 @@ use nu_protocol::{
     ast::{Bits, Block, Boolean, CellPath, Comparison, Math, Operator},
     debugger::DebugContext,
     engine::{
-        Argument, Closure, EngineState, ErrorHandler, Matcher, Redirection, Stack, StateWorkingSet,
+        self, Argument, Closure, EngineState, ErrorHandler, Matcher, Redirection, Stack,
+        StateWorkingSet,
     },
     ir::{Call, DataSlice, Instruction, IrAstRef, IrBlock, Literal, RedirectMode},
     DataSource, DeclId, ErrSpan, Flag, IntoPipelineData, IntoSpanned, ListStream, OutDest,
@@ -1060,9 +1061,12 @@ fn eval_call<D: DebugContext>(
             args_base: *args_base,
             args_len,
         };
+        let call = engine::Call::from(&call);
+
+        crate::eval::warn_if_deprecated(engine_state, &mut caller_stack, decl, decl_id, &call)?;
 
         // Run the call
-        result = decl.run(engine_state, &mut caller_stack, &(&call).into(), input);
+        result = decl.run(engine_state, &mut caller_stack, &call, input);
     };
 
     drop(caller_stack);
@@ -1392,11 +1396,15 @@ fn drain(ctx: &mut EvalContext<'_>, data: PipelineData) -> Result<InstructionRes
     match data {
         PipelineData::ByteStream(stream, ..) => {
             let span = stream.span();
-            if let Err(err) = stream.drain() {
-                ctx.stack.set_last_error(&err);
-                return Err(err);
-            } else {
-                ctx.stack.set_last_exit_code(0, span);
+            match stream.drain_pipeline_status() {
+                Ok(codes) => {
+                    ctx.stack.set_last_exit_code(0, span);
+                    ctx.stack.set_pipeline_exit_codes(codes, span);
+                }
+                Err(err) => {
+                    ctx.stack.set_last_error(&err);
+                    return Err(err);
+                }
             }
         }
         PipelineData::ListStream(stream, ..) => stream.drain()?,
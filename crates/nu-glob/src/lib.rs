@@ -50,6 +50,10 @@
 //!     require_literal_separator: false,
 //!     require_literal_leading_dot: false,
 //!     recursive_match_hidden_dir: true,
+//!     follow_symlinks: true,
+//!     max_symlink_depth: 40,
+//!     min_depth: 0,
+//!     max_depth: usize::MAX,
 //! };
 //! for entry in glob_with("local/*a*", options).unwrap() {
 //!     if let Ok(path) = entry {
@@ -80,8 +84,9 @@ use std::fs;
 use std::io;
 use std::path::{self, Component, Path, PathBuf};
 use std::str::FromStr;
+use std::time::SystemTime;
 
-use CharSpecifier::{CharRange, SingleChar};
+use CharSpecifier::{CharRange, Class, SingleChar};
 use MatchResult::{EntirePatternDoesntMatch, Match, SubPatternDoesntMatch};
 use PatternToken::AnyExcept;
 use PatternToken::{AnyChar, AnyRecursiveSequence, AnySequence, AnyWithin, Char};
@@ -100,8 +105,9 @@ pub struct Paths {
     dir_patterns: Vec<Pattern>,
     require_dir: bool,
     options: MatchOptions,
-    todo: Vec<Result<(PathBuf, usize), GlobError>>,
+    todo: Vec<Result<(PathBuf, usize, u32, usize), GlobError>>,
     scope: Option<PathBuf>,
+    filter: Option<EntryFilter>,
 }
 
 impl Paths {
@@ -111,10 +117,112 @@ impl Paths {
             dir_patterns: vec![Pattern::new("*").expect("hard coded pattern")],
             require_dir: false,
             options: MatchOptions::default(),
-            todo: vec![Ok((path.to_path_buf(), 0))],
+            todo: vec![Ok((path.to_path_buf(), 0, 0, 0))],
             scope: Some(relative_to.into()),
+            filter: None,
         }
     }
+
+    /// Only yield matches that satisfy `filter`, checked as each candidate is
+    /// considered rather than collecting every match first and filtering
+    /// afterward.
+    pub fn with_filter(mut self, filter: EntryFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+}
+
+/// The kind of filesystem entry an [`EntryFilter`] should match.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EntryType {
+    /// A regular file.
+    File,
+    /// A directory.
+    Dir,
+    /// A symbolic link, not followed to see what it points at.
+    Symlink,
+}
+
+/// A predicate for pruning matches during traversal by file type, size, or
+/// modification time, without needing a separate filtering pass over the
+/// results. Build one with [`EntryFilter::new`] and attach it to a [`Paths`]
+/// iterator with [`Paths::with_filter`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EntryFilter {
+    entry_type: Option<EntryType>,
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+    modified_after: Option<SystemTime>,
+}
+
+impl EntryFilter {
+    /// Creates a filter that matches everything; narrow it down with the
+    /// builder methods below.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match entries of the given type.
+    pub fn entry_type(mut self, entry_type: EntryType) -> Self {
+        self.entry_type = Some(entry_type);
+        self
+    }
+
+    /// Only match files at least `min_size` bytes long.
+    pub fn min_size(mut self, min_size: u64) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    /// Only match files at most `max_size` bytes long.
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Only match entries modified at or after `modified_after`.
+    pub fn modified_after(mut self, modified_after: SystemTime) -> Self {
+        self.modified_after = Some(modified_after);
+        self
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let Ok(meta) = fs::symlink_metadata(path) else {
+            return false;
+        };
+
+        if let Some(entry_type) = self.entry_type {
+            let matches_type = match entry_type {
+                EntryType::File => meta.is_file(),
+                EntryType::Dir => meta.is_dir(),
+                EntryType::Symlink => meta.file_type().is_symlink(),
+            };
+            if !matches_type {
+                return false;
+            }
+        }
+
+        if self.min_size.is_some() || self.max_size.is_some() {
+            let size = meta.len();
+            if self.min_size.is_some_and(|min| size < min) {
+                return false;
+            }
+            if self.max_size.is_some_and(|max| size > max) {
+                return false;
+            }
+        }
+
+        if let Some(modified_after) = self.modified_after {
+            let Ok(modified) = meta.modified() else {
+                return false;
+            };
+            if modified < modified_after {
+                return false;
+            }
+        }
+
+        true
+    }
 }
 
 /// Return an iterator that produces all the `Path`s that match the given
@@ -253,6 +361,7 @@ pub fn glob_with(pattern: &str, options: MatchOptions) -> Result<Paths, PatternE
             options,
             todo: Vec::new(),
             scope: None,
+            filter: None,
         });
     }
 
@@ -284,6 +393,7 @@ pub fn glob_with(pattern: &str, options: MatchOptions) -> Result<Paths, PatternE
         options,
         todo,
         scope: Some(scope),
+        filter: None,
     })
 }
 
@@ -388,6 +498,45 @@ fn is_dir(p: &Path) -> bool {
     fs::metadata(p).map(|m| m.is_dir()).unwrap_or(false)
 }
 
+/// Decides whether `path`, reached after following `symlink_depth` symlinked
+/// directories so far, should be descended into as a directory.
+///
+/// Returns `Ok(Some(next_depth))` if `path` is a directory (possibly via a
+/// symlink) that traversal should read, where `next_depth` is the symlink
+/// depth to use for anything found underneath it. Returns `Ok(None)` if
+/// `path` is not a directory, or is a symlinked directory that
+/// `options.follow_symlinks` says to skip. Returns `Err` if following `path`
+/// would exceed `options.max_symlink_depth`, which is reported to the caller
+/// as a [`GlobError`] rather than silently recursing forever on a symlink
+/// loop.
+fn check_descend(
+    path: &Path,
+    options: MatchOptions,
+    symlink_depth: u32,
+) -> Result<Option<u32>, io::Error> {
+    let Ok(meta) = fs::symlink_metadata(path) else {
+        return Ok(None);
+    };
+
+    if !meta.file_type().is_symlink() {
+        return Ok(meta.is_dir().then_some(symlink_depth));
+    }
+
+    if !options.follow_symlinks {
+        return Ok(None);
+    }
+
+    let next_depth = symlink_depth + 1;
+    if next_depth > options.max_symlink_depth {
+        return Err(io::Error::other(format!(
+            "maximum symlink depth ({}) exceeded, possible symlink loop",
+            options.max_symlink_depth
+        )));
+    }
+
+    Ok(is_dir(path).then_some(next_depth))
+}
+
 /// An alias for a glob iteration result.
 ///
 /// This represents either a matched path or a glob iteration error,
@@ -409,7 +558,15 @@ impl Iterator for Paths {
 
                 // if there's one prefilled result, take it, otherwise fill the todo buffer
                 if self.todo.len() != 1 {
-                    fill_todo(&mut self.todo, &self.dir_patterns, 0, &scope, self.options);
+                    fill_todo(
+                        &mut self.todo,
+                        &self.dir_patterns,
+                        0,
+                        &scope,
+                        self.options,
+                        0,
+                        0,
+                    );
                 }
             }
         }
@@ -419,12 +576,12 @@ impl Iterator for Paths {
                 return None;
             }
 
-            let (path, mut idx) = match self
+            let (path, mut idx, symlink_depth, recursive_depth) = match self
                 .todo
                 .pop()
                 .expect("internal error: already checked for non-empty")
             {
-                Ok(pair) => pair,
+                Ok(quad) => quad,
                 Err(e) => return Some(Err(e)),
             };
 
@@ -447,42 +604,60 @@ impl Iterator for Paths {
                     next += 1;
                 }
 
-                if is_dir(&path) {
-                    // the path is a directory, check if matched according
-                    // to `hidden_dir_recursive` option.
-                    if !self.options.recursive_match_hidden_dir
-                        && path
-                            .file_name()
-                            .map(|name| name.to_string_lossy().starts_with('.'))
-                            .unwrap_or(false)
-                    {
-                        continue;
-                    }
+                match check_descend(&path, self.options, symlink_depth) {
+                    Ok(Some(next_depth)) => {
+                        // the path is a directory, check if matched according
+                        // to `hidden_dir_recursive` option.
+                        if !self.options.recursive_match_hidden_dir
+                            && path
+                                .file_name()
+                                .map(|name| name.to_string_lossy().starts_with('.'))
+                                .unwrap_or(false)
+                        {
+                            continue;
+                        }
 
-                    // push this directory's contents
-                    fill_todo(
-                        &mut self.todo,
-                        &self.dir_patterns,
-                        next,
-                        &path,
-                        self.options,
-                    );
+                        // push this directory's contents, unless doing so would
+                        // exceed `max_depth` (pruning `**` expansion up front
+                        // rather than filtering matches after the fact)
+                        if recursive_depth < self.options.max_depth {
+                            fill_todo(
+                                &mut self.todo,
+                                &self.dir_patterns,
+                                next,
+                                &path,
+                                self.options,
+                                next_depth,
+                                recursive_depth + 1,
+                            );
+                        }
 
-                    if next == self.dir_patterns.len() - 1 {
-                        // pattern ends in recursive pattern, so return this
-                        // directory as a result
-                        return Some(Ok(path));
-                    } else {
+                        if next == self.dir_patterns.len() - 1 {
+                            // pattern ends in recursive pattern, so return this
+                            // directory as a result, unless `**` hasn't
+                            // descended far enough yet to satisfy `min_depth`
+                            if recursive_depth >= self.options.min_depth
+                                && self.filter.as_ref().map_or(true, |f| f.matches(&path))
+                            {
+                                return Some(Ok(path));
+                            } else {
+                                continue;
+                            }
+                        } else {
+                            // advanced to the next pattern for this path
+                            idx = next + 1;
+                        }
+                    }
+                    Ok(None) if next == self.dir_patterns.len() - 1 => {
+                        // not a directory (or a symlinked one we're skipping)
+                        // and it's the last pattern, meaning no match
+                        continue;
+                    }
+                    Ok(None) => {
                         // advanced to the next pattern for this path
                         idx = next + 1;
                     }
-                } else if next == self.dir_patterns.len() - 1 {
-                    // not a directory and it's the last pattern, meaning no
-                    // match
-                    continue;
-                } else {
-                    // advanced to the next pattern for this path
-                    idx = next + 1;
+                    Err(error) => return Some(Err(GlobError { path, error })),
                 }
             }
 
@@ -507,7 +682,10 @@ impl Iterator for Paths {
                     // *AND* its children so we don't need to check the
                     // children
 
-                    if !self.require_dir || is_dir(&path) {
+                    if (!self.require_dir || is_dir(&path))
+                        && recursive_depth >= self.options.min_depth
+                        && self.filter.as_ref().map_or(true, |f| f.matches(&path))
+                    {
                         return Some(Ok(path));
                     }
                 } else {
@@ -517,6 +695,8 @@ impl Iterator for Paths {
                         idx + 1,
                         &path,
                         self.options,
+                        symlink_depth,
+                        recursive_depth,
                     );
                 }
             }
@@ -569,6 +749,11 @@ impl fmt::Display for PatternError {
 /// - `[!...]` is the negation of `[...]`, i.e. it matches any characters
 ///   **not** in the brackets.
 ///
+/// - `[...]` may also contain POSIX character classes, e.g. `[[:alpha:]]`
+///   matches any alphabetic character. Supported classes are `alpha`,
+///   `digit`, `alnum`, `space`, `upper`, `lower`, and `punct`, and they can be
+///   combined with literal characters and ranges, e.g. `[[:alpha:]_]`.
+///
 /// - The metacharacters `?`, `*`, `[`, `]` can be matched by using brackets
 ///   (e.g. `[?]`).  When a `]` occurs immediately following `[` or `[!` then it
 ///   is interpreted as being part of, rather then ending, the character set, so
@@ -611,6 +796,7 @@ enum PatternToken {
 enum CharSpecifier {
     SingleChar(char),
     CharRange(char, char),
+    Class(CharClass),
 }
 
 #[derive(Copy, Clone, PartialEq)]
@@ -706,25 +892,18 @@ impl Pattern {
                 }
                 '[' => {
                     if i + 4 <= chars.len() && chars[i + 1] == '!' {
-                        match chars[i + 3..].iter().position(|x| *x == ']') {
-                            None => (),
-                            Some(j) => {
-                                let chars = &chars[i + 2..i + 3 + j];
-                                let cs = parse_char_specifiers(chars);
-                                tokens.push(AnyExcept(cs));
-                                i += j + 4;
-                                continue;
-                            }
+                        if let Some(j) = find_set_end(&chars, i + 2) {
+                            let cs = parse_char_specifiers(&chars[i + 2..j]);
+                            tokens.push(AnyExcept(cs));
+                            i = j + 1;
+                            continue;
                         }
                     } else if i + 3 <= chars.len() && chars[i + 1] != '!' {
-                        match chars[i + 2..].iter().position(|x| *x == ']') {
-                            None => (),
-                            Some(j) => {
-                                let cs = parse_char_specifiers(&chars[i + 1..i + 2 + j]);
-                                tokens.push(AnyWithin(cs));
-                                i += j + 3;
-                                continue;
-                            }
+                        if let Some(j) = find_set_end(&chars, i + 1) {
+                            let cs = parse_char_specifiers(&chars[i + 1..j]);
+                            tokens.push(AnyWithin(cs));
+                            i = j + 1;
+                            continue;
                         }
                     }
 
@@ -898,15 +1077,96 @@ impl Pattern {
     }
 }
 
+/// A pre-compiled set of [`Pattern`]s that can test a single path against all
+/// of them in one pass, reporting which pattern (if any) matched.
+///
+/// Building a `GlobSet` once and reusing it for every path avoids
+/// re-parsing the same patterns over and over, which matters for commands
+/// like `ls` or `watch` that need to check many paths against the same
+/// collection of include/exclude globs.
+///
+/// # Examples
+///
+/// ```rust
+/// use nu_glob::{GlobSet, MatchOptions};
+///
+/// let set = GlobSet::new(&["*.rs", "*.toml"], MatchOptions::default()).unwrap();
+/// assert!(set.is_match("main.rs"));
+/// assert!(set.is_match("Cargo.toml"));
+/// assert!(!set.is_match("README.md"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GlobSet {
+    patterns: Vec<Pattern>,
+    options: MatchOptions,
+}
+
+impl GlobSet {
+    /// Compile `patterns` into a `GlobSet` using the given `options`.
+    ///
+    /// Returns a [`PatternError`] if any of the patterns fail to parse.
+    pub fn new<S: AsRef<str>>(
+        patterns: impl IntoIterator<Item = S>,
+        options: MatchOptions,
+    ) -> Result<Self, PatternError> {
+        let patterns = patterns
+            .into_iter()
+            .map(|p| Pattern::new(p.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { patterns, options })
+    }
+
+    /// Return the number of patterns compiled into this set.
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Return `true` if this set contains no patterns.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Return `true` if `str` matches any pattern in this set.
+    pub fn is_match(&self, str: &str) -> bool {
+        self.matching_pattern(str).is_some()
+    }
+
+    /// Return `true` if `path`, when converted to a `str`, matches any
+    /// pattern in this set.
+    pub fn is_match_path(&self, path: &Path) -> bool {
+        path.to_str().map_or(false, |s| self.is_match(s))
+    }
+
+    /// Test `str` against every pattern in the set and return the index and
+    /// [`Pattern`] of the first one that matches, or `None` if none do.
+    ///
+    /// Patterns are tested in the order they were given to [`GlobSet::new`],
+    /// so when several patterns could match, the earliest one wins.
+    pub fn matching_pattern(&self, str: &str) -> Option<(usize, &Pattern)> {
+        self.patterns
+            .iter()
+            .enumerate()
+            .find(|(_, pattern)| pattern.matches_with(str, self.options))
+    }
+
+    /// Like [`GlobSet::matching_pattern`], but operates on a `Path`.
+    pub fn matching_pattern_path(&self, path: &Path) -> Option<(usize, &Pattern)> {
+        path.to_str().and_then(|s| self.matching_pattern(s))
+    }
+}
+
 // Fills `todo` with paths under `path` to be matched by `patterns[idx]`,
 // special-casing patterns to match `.` and `..`, and avoiding `readdir()`
 // calls when there are no metacharacters in the pattern.
 fn fill_todo(
-    todo: &mut Vec<Result<(PathBuf, usize), GlobError>>,
+    todo: &mut Vec<Result<(PathBuf, usize, u32, usize), GlobError>>,
     patterns: &[Pattern],
     idx: usize,
     path: &Path,
     options: MatchOptions,
+    symlink_depth: u32,
+    recursive_depth: usize,
 ) {
     // convert a pattern that's just many Char(_) to a string
     fn pattern_as_str(pattern: &Pattern) -> Option<String> {
@@ -921,19 +1181,36 @@ fn fill_todo(
         Some(s)
     }
 
-    let add = |todo: &mut Vec<_>, next_path: PathBuf| {
+    let add = |todo: &mut Vec<_>, next_path: PathBuf, depth: u32| {
         if idx + 1 == patterns.len() {
             // We know it's good, so don't make the iterator match this path
             // against the pattern again. In particular, it can't match
             // . or .. globs since these never show up as path components.
-            todo.push(Ok((next_path, !0)));
+            todo.push(Ok((next_path, !0, depth, recursive_depth)));
         } else {
-            fill_todo(todo, patterns, idx + 1, &next_path, options);
+            fill_todo(
+                todo,
+                patterns,
+                idx + 1,
+                &next_path,
+                options,
+                depth,
+                recursive_depth,
+            );
         }
     };
 
     let pattern = &patterns[idx];
-    let is_dir = is_dir(path);
+    let descend = match check_descend(path, options, symlink_depth) {
+        Ok(descend) => descend,
+        Err(error) => {
+            todo.push(Err(GlobError {
+                path: path.to_path_buf(),
+                error,
+            }));
+            return;
+        }
+    };
     let curdir = path == Path::new(".");
     match pattern_as_str(pattern) {
         Some(s) => {
@@ -948,15 +1225,16 @@ fn fill_todo(
             } else {
                 path.join(&s)
             };
-            if (special && is_dir)
+            if (special && descend.is_some())
                 || (!special
                     && (fs::metadata(&next_path).is_ok()
                         || fs::symlink_metadata(&next_path).is_ok()))
             {
-                add(todo, next_path);
+                add(todo, next_path, descend.unwrap_or(symlink_depth));
             }
         }
-        None if is_dir => {
+        None if descend.is_some() => {
+            let depth = descend.expect("checked by the match guard");
             let dirs = fs::read_dir(path).and_then(|d| {
                 d.map(|e| {
                     e.map(|e| {
@@ -986,7 +1264,11 @@ fn fill_todo(
                     //     });
                     // }
                     children.sort_by(|p1, p2| p2.file_name().cmp(&p1.file_name()));
-                    todo.extend(children.into_iter().map(|x| Ok((x, idx))));
+                    todo.extend(
+                        children
+                            .into_iter()
+                            .map(|x| Ok((x, idx, depth, recursive_depth))),
+                    );
 
                     // Matching the special directory entries . and .. that
                     // refer to the current and parent directory respectively
@@ -996,7 +1278,7 @@ fn fill_todo(
                     if !pattern.tokens.is_empty() && pattern.tokens[0] == Char('.') {
                         for &special in &[".", ".."] {
                             if pattern.matches_with(special, options) {
-                                add(todo, path.join(special));
+                                add(todo, path.join(special), depth);
                             }
                         }
                     }
@@ -1015,10 +1297,96 @@ fn fill_todo(
     }
 }
 
+// Finds the index of the `]` that closes a bracket expression started at
+// `start` (the index just after the opening `[` or `[!`), skipping over any
+// POSIX character classes such as `[:alpha:]` nested inside it, since those
+// contain a `]` of their own that does not end the bracket expression.
+fn find_set_end(chars: &[char], mut i: usize) -> Option<usize> {
+    // A `]` occurring immediately after the opening `[` or `[!` is a literal
+    // member of the set, not its closing bracket.
+    if chars.get(i) == Some(&']') {
+        i += 1;
+    }
+
+    while i < chars.len() {
+        if chars[i] == '[' && chars.get(i + 1) == Some(&':') {
+            if let Some(end) = chars[i + 2..].iter().position(|&c| c == ':').and_then(|p| {
+                let close = i + 2 + p + 1;
+                (chars.get(close) == Some(&']')).then_some(close)
+            }) {
+                i = end + 1;
+                continue;
+            }
+        }
+
+        if chars[i] == ']' {
+            return Some(i);
+        }
+
+        i += 1;
+    }
+    None
+}
+
+/// A named POSIX character class, as used inside a `[[:name:]]` bracket
+/// expression.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+enum CharClass {
+    Alpha,
+    Digit,
+    Alnum,
+    Space,
+    Upper,
+    Lower,
+    Punct,
+}
+
+impl CharClass {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "alpha" => Some(Self::Alpha),
+            "digit" => Some(Self::Digit),
+            "alnum" => Some(Self::Alnum),
+            "space" => Some(Self::Space),
+            "upper" => Some(Self::Upper),
+            "lower" => Some(Self::Lower),
+            "punct" => Some(Self::Punct),
+            _ => None,
+        }
+    }
+
+    fn contains(self, c: char) -> bool {
+        match self {
+            Self::Alpha => c.is_alphabetic(),
+            Self::Digit => c.is_ascii_digit(),
+            Self::Alnum => c.is_alphanumeric(),
+            Self::Space => c.is_whitespace(),
+            Self::Upper => c.is_uppercase(),
+            Self::Lower => c.is_lowercase(),
+            Self::Punct => c.is_ascii_punctuation(),
+        }
+    }
+}
+
 fn parse_char_specifiers(s: &[char]) -> Vec<CharSpecifier> {
     let mut cs = Vec::new();
     let mut i = 0;
     while i < s.len() {
+        // A POSIX character class, e.g. `[:alpha:]`.
+        if s[i] == '[' && s.get(i + 1) == Some(&':') {
+            if let Some(end) = s[i + 2..].iter().position(|&c| c == ':').and_then(|p| {
+                let close = i + 2 + p + 1;
+                (s.get(close) == Some(&']')).then_some(close)
+            }) {
+                let name: String = s[i + 2..end - 1].iter().collect();
+                if let Some(class) = CharClass::from_name(&name) {
+                    cs.push(Class(class));
+                    i = end + 1;
+                    continue;
+                }
+            }
+        }
+
         if i + 3 <= s.len() && s[i + 1] == '-' {
             cs.push(CharRange(s[i], s[i + 2]));
             i += 3;
@@ -1039,17 +1407,12 @@ fn in_char_specifiers(specifiers: &[CharSpecifier], c: char, options: MatchOptio
                 }
             }
             CharRange(start, end) => {
-                // FIXME: work with non-ascii chars properly (issue #1347)
-                if !options.case_sensitive && c.is_ascii() && start.is_ascii() && end.is_ascii() {
-                    // only allow case insensitive matching when
-                    // both start and end are within a-z or A-Z
-                    if start.is_ascii_alphabetic() && end.is_ascii_alphabetic() {
-                        let start = start.to_ascii_lowercase();
-                        let end = end.to_ascii_lowercase();
-                        let c = c.to_ascii_lowercase();
-                        if (start..=end).contains(&c) {
-                            return true;
-                        }
+                if !options.case_sensitive {
+                    // Unicode-aware case folding: lowercase both ends of the
+                    // range and the candidate before comparing.
+                    let lower = |ch: char| ch.to_lowercase().next().unwrap_or(ch);
+                    if (lower(start)..=lower(end)).contains(&lower(c)) {
+                        return true;
                     }
                 }
 
@@ -1057,6 +1420,11 @@ fn in_char_specifiers(specifiers: &[CharSpecifier], c: char, options: MatchOptio
                     return true;
                 }
             }
+            Class(class) => {
+                if class.contains(c) {
+                    return true;
+                }
+            }
         }
     }
 
@@ -1067,9 +1435,10 @@ fn in_char_specifiers(specifiers: &[CharSpecifier], c: char, options: MatchOptio
 fn chars_eq(a: char, b: char, case_sensitive: bool) -> bool {
     if cfg!(windows) && path::is_separator(a) && path::is_separator(b) {
         true
-    } else if !case_sensitive && a.is_ascii() && b.is_ascii() {
-        // FIXME: work with non-ascii chars properly (issue #9084)
-        a.to_ascii_lowercase() == b.to_ascii_lowercase()
+    } else if !case_sensitive {
+        // Unicode-aware case folding rather than ASCII-only lowercasing, so
+        // e.g. "STRASSE" can match "straße".
+        a.to_lowercase().eq(b.to_lowercase())
     } else {
         a == b
     }
@@ -1099,6 +1468,31 @@ pub struct MatchOptions {
     /// if given pattern contains `**`, this flag check if `**` matches hidden directory.
     /// For example: if true, `**` will match `.abcdef/ghi`.
     pub recursive_match_hidden_dir: bool,
+
+    /// Whether symlinked directories are followed during traversal. If
+    /// false, a symlink that points at a directory is still yielded as a
+    /// match if its name matches the pattern, but it is never descended
+    /// into, so nothing underneath it can match.
+    pub follow_symlinks: bool,
+
+    /// The maximum number of symlinked directories that may be followed
+    /// along a single traversal path before iteration gives up and reports
+    /// a [`GlobError`] instead. This guards against symlink cycles causing
+    /// unbounded recursion; it has no effect when `follow_symlinks` is
+    /// false.
+    pub max_symlink_depth: u32,
+
+    /// The minimum number of directory levels a recursive `**` wildcard must
+    /// descend before a match is yielded. A match found fewer than
+    /// `min_depth` levels below the directory `**` started at is discarded.
+    /// Has no effect on path components matched outside of a `**` wildcard.
+    pub min_depth: usize,
+
+    /// The maximum number of directory levels a recursive `**` wildcard is
+    /// allowed to descend. Once reached, `**` stops expanding into further
+    /// subdirectories, similar to `find -maxdepth`. Has no effect on path
+    /// components matched outside of a `**` wildcard.
+    pub max_depth: usize,
 }
 
 // Overwrite default behavior, because we want to make `recursive_match_hidden_dir` to true.
@@ -1109,13 +1503,19 @@ impl Default for MatchOptions {
             require_literal_separator: false,
             require_literal_leading_dot: false,
             recursive_match_hidden_dir: true,
+            follow_symlinks: true,
+            // Mirrors the symlink nesting limit enforced by common OS glibc/libc
+            // implementations (e.g. Linux's `ELOOP` threshold of 40).
+            max_symlink_depth: 40,
+            min_depth: 0,
+            max_depth: usize::MAX,
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{glob, MatchOptions, Pattern};
+    use super::{glob, GlobSet, MatchOptions, Pattern};
     use std::path::Path;
 
     #[test]
@@ -1349,6 +1749,50 @@ mod test {
         assert!(!Pattern::new("[!-]").unwrap().matches("-"));
     }
 
+    #[test]
+    fn test_posix_char_classes() {
+        let pat = Pattern::new("[[:alpha:]]").unwrap();
+        assert!(pat.matches("a"));
+        assert!(pat.matches("Z"));
+        assert!(!pat.matches("1"));
+        assert!(!pat.matches(" "));
+
+        let pat = Pattern::new("[[:digit:]]").unwrap();
+        assert!(pat.matches("7"));
+        assert!(!pat.matches("a"));
+
+        let pat = Pattern::new("[[:space:]]").unwrap();
+        assert!(pat.matches(" "));
+        assert!(pat.matches("\t"));
+        assert!(!pat.matches("a"));
+
+        // Character classes can be combined with literal characters.
+        let pat = Pattern::new("[[:alpha:]_]").unwrap();
+        assert!(pat.matches("a"));
+        assert!(pat.matches("_"));
+        assert!(!pat.matches("1"));
+
+        // Negation still works with a class inside the brackets.
+        let pat = Pattern::new("[![:digit:]]").unwrap();
+        assert!(pat.matches("a"));
+        assert!(!pat.matches("5"));
+    }
+
+    #[test]
+    fn test_unicode_case_insensitive_matching() {
+        let options = MatchOptions {
+            case_sensitive: false,
+            ..MatchOptions::default()
+        };
+
+        assert!(Pattern::new("MÜNCHEN")
+            .unwrap()
+            .matches_with("münchen", options));
+        assert!(Pattern::new("[a-ö]")
+            .unwrap()
+            .matches_with("Ö", options));
+    }
+
     #[test]
     fn test_pattern_matches() {
         let txt_pat = Pattern::new("*hello.txt").unwrap();
@@ -1367,6 +1811,47 @@ mod test {
         assert!(!dir_pat.matches("some/other/path/to/hello.txt"));
     }
 
+    #[test]
+    fn test_glob_set_matches_any_pattern() {
+        let set = GlobSet::new(["*.rs", "*.toml"], MatchOptions::default()).unwrap();
+        assert!(set.is_match("main.rs"));
+        assert!(set.is_match("Cargo.toml"));
+        assert!(!set.is_match("README.md"));
+    }
+
+    #[test]
+    fn test_glob_set_matching_pattern_reports_first_match() {
+        let set = GlobSet::new(["*.rs", "main.*"], MatchOptions::default()).unwrap();
+        let (index, pattern) = set.matching_pattern("main.rs").unwrap();
+        assert_eq!(index, 0);
+        assert_eq!(pattern.as_str(), "*.rs");
+
+        let (index, pattern) = set.matching_pattern("main.toml").unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(pattern.as_str(), "main.*");
+
+        assert!(set.matching_pattern("README.md").is_none());
+    }
+
+    #[test]
+    fn test_glob_set_empty_matches_nothing() {
+        let set = GlobSet::new(std::iter::empty::<&str>(), MatchOptions::default()).unwrap();
+        assert!(set.is_empty());
+        assert!(!set.is_match("anything"));
+    }
+
+    #[test]
+    fn test_glob_set_propagates_pattern_error() {
+        assert!(GlobSet::new(["[unclosed"], MatchOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_glob_set_matches_path() {
+        let set = GlobSet::new(["*.rs"], MatchOptions::default()).unwrap();
+        assert!(set.is_match_path(Path::new("main.rs")));
+        assert!(set.matching_pattern_path(Path::new("main.rs")).is_some());
+    }
+
     #[test]
     fn test_pattern_escape() {
         let s = "_[_]_?_*_!_";
@@ -1382,6 +1867,10 @@ mod test {
             require_literal_separator: false,
             require_literal_leading_dot: false,
             recursive_match_hidden_dir: true,
+            follow_symlinks: true,
+            max_symlink_depth: 40,
+            min_depth: 0,
+            max_depth: usize::MAX,
         };
 
         assert!(pat.matches_with("aBcDeFg", options));
@@ -1400,12 +1889,20 @@ mod test {
             require_literal_separator: false,
             require_literal_leading_dot: false,
             recursive_match_hidden_dir: false,
+            follow_symlinks: true,
+            max_symlink_depth: 40,
+            min_depth: 0,
+            max_depth: usize::MAX,
         };
         let options_case_sensitive = MatchOptions {
             case_sensitive: true,
             require_literal_separator: false,
             require_literal_leading_dot: false,
             recursive_match_hidden_dir: false,
+            follow_symlinks: true,
+            max_symlink_depth: 40,
+            min_depth: 0,
+            max_depth: usize::MAX,
         };
 
         assert!(pat_within.matches_with("a", options_case_insensitive));
@@ -1424,12 +1921,20 @@ mod test {
             require_literal_separator: true,
             require_literal_leading_dot: false,
             recursive_match_hidden_dir: true,
+            follow_symlinks: true,
+            max_symlink_depth: 40,
+            min_depth: 0,
+            max_depth: usize::MAX,
         };
         let options_not_require_literal = MatchOptions {
             case_sensitive: true,
             require_literal_separator: false,
             require_literal_leading_dot: false,
             recursive_match_hidden_dir: true,
+            follow_symlinks: true,
+            max_symlink_depth: 40,
+            min_depth: 0,
+            max_depth: usize::MAX,
         };
 
         assert!(Pattern::new("abc/def")
@@ -1466,12 +1971,20 @@ mod test {
             require_literal_separator: false,
             require_literal_leading_dot: true,
             recursive_match_hidden_dir: true,
+            follow_symlinks: true,
+            max_symlink_depth: 40,
+            min_depth: 0,
+            max_depth: usize::MAX,
         };
         let options_not_require_literal_leading_dot = MatchOptions {
             case_sensitive: true,
             require_literal_separator: false,
             require_literal_leading_dot: false,
             recursive_match_hidden_dir: true,
+            follow_symlinks: true,
+            max_symlink_depth: 40,
+            min_depth: 0,
+            max_depth: usize::MAX,
         };
 
         let f = |options| {
@@ -0,0 +1,399 @@
+use crate::{Id, LanguageServer};
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams, Range,
+    SymbolKind, Url,
+};
+use nu_parser::{flatten_block, parse, FlatShape};
+use nu_protocol::{
+    engine::{EngineState, StateWorkingSet},
+    DeclId, Span,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Identifies a custom command's declaration across the two separate, independently re-parsed
+/// requests (`prepareCallHierarchy` then `incomingCalls`/`outgoingCalls`) that make up one call
+/// hierarchy lookup. A raw [`DeclId`] wouldn't survive that round trip, since every LSP request
+/// here builds its own fresh [`StateWorkingSet`] and reparses from scratch rather than keeping a
+/// persistent index; name plus declaration span is stable as long as the file hasn't changed
+/// between requests.
+#[derive(Serialize, Deserialize)]
+struct CallHierarchyItemData {
+    uri: Url,
+    name: String,
+    block_span_start: usize,
+    block_span_end: usize,
+}
+
+impl LanguageServer {
+    /// Call hierarchy for a custom command is scoped to the file it's defined in: there's no
+    /// persistent workspace-wide index here (each request reparses one file from scratch), so
+    /// calls from other files in the workspace won't show up.
+    pub(crate) fn prepare_call_hierarchy(
+        &mut self,
+        engine_state: &mut EngineState,
+        params: &CallHierarchyPrepareParams,
+    ) -> Option<Vec<CallHierarchyItem>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let (file, path, mut working_set) = self.read_in_file(engine_state, uri)?;
+
+        let (id, _, _) = Self::find_id(
+            &mut working_set,
+            path,
+            file,
+            self.lsp_position_to_byte_offset(&params.text_document_position_params.position, file),
+        )?;
+
+        let Id::Declaration(decl_id) = id else {
+            return None;
+        };
+
+        Some(vec![self.call_hierarchy_item(&working_set, decl_id)?])
+    }
+
+    pub(crate) fn incoming_calls(
+        &mut self,
+        engine_state: &mut EngineState,
+        params: &CallHierarchyIncomingCallsParams,
+    ) -> Option<Vec<CallHierarchyIncomingCall>> {
+        let data: CallHierarchyItemData = serde_json::from_value(params.item.data.clone()?).ok()?;
+        let position_encoding = self.position_encoding.clone();
+
+        let (file, path, mut working_set) = self.read_in_file(engine_state, &data.uri)?;
+        let contents = file.bytes().collect::<Vec<u8>>();
+        let offset = working_set.next_span_start();
+        working_set.files.push(path.clone(), Span::unknown()).ok()?;
+        let block = parse(
+            &mut working_set,
+            Some(&path.to_string_lossy()),
+            &contents,
+            false,
+        );
+        let target_decl_id = resolve_decl(&working_set, &data)?;
+
+        let mut calls_by_caller: HashMap<DeclId, Vec<Range>> = HashMap::new();
+        for (span, shape) in flatten_block(&working_set, &block) {
+            if shape == FlatShape::InternalCall(target_decl_id) {
+                if let Some(caller) = enclosing_decl(&working_set, span) {
+                    let range = Self::span_to_range(&span, file, offset, &position_encoding);
+                    calls_by_caller.entry(caller).or_default().push(range);
+                }
+            }
+        }
+
+        Some(
+            calls_by_caller
+                .into_iter()
+                .filter_map(|(caller, from_ranges)| {
+                    let from = self.call_hierarchy_item(&working_set, caller)?;
+                    Some(CallHierarchyIncomingCall { from, from_ranges })
+                })
+                .collect(),
+        )
+    }
+
+    pub(crate) fn outgoing_calls(
+        &mut self,
+        engine_state: &mut EngineState,
+        params: &CallHierarchyOutgoingCallsParams,
+    ) -> Option<Vec<CallHierarchyOutgoingCall>> {
+        let data: CallHierarchyItemData = serde_json::from_value(params.item.data.clone()?).ok()?;
+        let position_encoding = self.position_encoding.clone();
+
+        let (file, path, mut working_set) = self.read_in_file(engine_state, &data.uri)?;
+        let contents = file.bytes().collect::<Vec<u8>>();
+        let offset = working_set.next_span_start();
+        working_set.files.push(path.clone(), Span::unknown()).ok()?;
+        let block = parse(
+            &mut working_set,
+            Some(&path.to_string_lossy()),
+            &contents,
+            false,
+        );
+        let source_decl_id = resolve_decl(&working_set, &data)?;
+        let source_span = working_set
+            .get_decl(source_decl_id)
+            .block_id()
+            .and_then(|block_id| working_set.get_block(block_id).span)?;
+
+        let mut calls_by_callee: HashMap<DeclId, Vec<Range>> = HashMap::new();
+        for (span, shape) in flatten_block(&working_set, &block) {
+            if !source_span.contains(span.start) {
+                continue;
+            }
+            if let FlatShape::InternalCall(decl_id) = shape {
+                let range = Self::span_to_range(&span, file, offset, &position_encoding);
+                calls_by_callee.entry(decl_id).or_default().push(range);
+            }
+        }
+
+        Some(
+            calls_by_callee
+                .into_iter()
+                .filter_map(|(callee, to_ranges)| {
+                    let to = self.call_hierarchy_item(&working_set, callee)?;
+                    Some(CallHierarchyOutgoingCall {
+                        to,
+                        from_ranges: to_ranges,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// Builds a [`CallHierarchyItem`] for a custom command, locating the file its declaration
+    /// lives in among every file known to `working_set` (not just the one currently open), the
+    /// same way [`LanguageServer::goto_definition`] resolves cross-file declaration spans.
+    fn call_hierarchy_item(
+        &mut self,
+        working_set: &StateWorkingSet,
+        decl_id: DeclId,
+    ) -> Option<CallHierarchyItem> {
+        let decl = working_set.get_decl(decl_id);
+        let block_id = decl.block_id()?;
+        let span = working_set.get_block(block_id).span?;
+
+        for cached_file in working_set.files() {
+            if cached_file.covered_span.contains(span.start) {
+                let position_encoding = self.position_encoding.clone();
+                let (uri, rope_of_file) = self.rope_file_from_cached_file(cached_file).ok()?;
+                let range = Self::span_to_range(
+                    &span,
+                    rope_of_file,
+                    cached_file.covered_span.start,
+                    &position_encoding,
+                );
+                return Some(CallHierarchyItem {
+                    name: decl.name().to_string(),
+                    kind: SymbolKind::FUNCTION,
+                    tags: None,
+                    detail: None,
+                    uri: uri.clone(),
+                    range,
+                    selection_range: range,
+                    data: Some(
+                        serde_json::to_value(CallHierarchyItemData {
+                            uri,
+                            name: decl.name().to_string(),
+                            block_span_start: span.start,
+                            block_span_end: span.end,
+                        })
+                        .ok()?,
+                    ),
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_include;
+    use lsp_server::Message;
+    use lsp_types::{
+        request::{
+            CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare, Request,
+        },
+        CallHierarchyIncomingCallsParams, CallHierarchyItem, CallHierarchyOutgoingCallsParams,
+        CallHierarchyPrepareParams, PartialResultParams, Position, TextDocumentIdentifier,
+        TextDocumentPositionParams, Url, WorkDoneProgressParams,
+    };
+    use nu_test_support::fs::fixtures;
+
+    use crate::tests::{initialize_language_server, open_unchecked};
+
+    fn prepare(
+        client_connection: &lsp_server::Connection,
+        uri: Url,
+        line: u32,
+        character: u32,
+    ) -> Message {
+        client_connection
+            .sender
+            .send(Message::Request(lsp_server::Request {
+                id: 2.into(),
+                method: CallHierarchyPrepare::METHOD.to_string(),
+                params: serde_json::to_value(CallHierarchyPrepareParams {
+                    text_document_position_params: TextDocumentPositionParams {
+                        text_document: TextDocumentIdentifier { uri },
+                        position: Position { line, character },
+                    },
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                })
+                .unwrap(),
+            }))
+            .unwrap();
+
+        client_connection
+            .receiver
+            .recv_timeout(std::time::Duration::from_secs(2))
+            .unwrap()
+    }
+
+    fn incoming_calls(
+        client_connection: &lsp_server::Connection,
+        item: CallHierarchyItem,
+    ) -> Message {
+        client_connection
+            .sender
+            .send(Message::Request(lsp_server::Request {
+                id: 3.into(),
+                method: CallHierarchyIncomingCalls::METHOD.to_string(),
+                params: serde_json::to_value(CallHierarchyIncomingCallsParams {
+                    item,
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                })
+                .unwrap(),
+            }))
+            .unwrap();
+
+        client_connection
+            .receiver
+            .recv_timeout(std::time::Duration::from_secs(2))
+            .unwrap()
+    }
+
+    fn outgoing_calls(
+        client_connection: &lsp_server::Connection,
+        item: CallHierarchyItem,
+    ) -> Message {
+        client_connection
+            .sender
+            .send(Message::Request(lsp_server::Request {
+                id: 3.into(),
+                method: CallHierarchyOutgoingCalls::METHOD.to_string(),
+                params: serde_json::to_value(CallHierarchyOutgoingCallsParams {
+                    item,
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                })
+                .unwrap(),
+            }))
+            .unwrap();
+
+        client_connection
+            .receiver
+            .recv_timeout(std::time::Duration::from_secs(2))
+            .unwrap()
+    }
+
+    fn script_uri() -> Url {
+        let mut script = fixtures();
+        script.push("lsp");
+        script.push("call_hierarchy");
+        script.push("command.nu");
+        Url::from_file_path(script).unwrap()
+    }
+
+    #[test]
+    fn prepare_call_hierarchy_on_custom_command() {
+        let (client_connection, _recv) = initialize_language_server(None);
+        let script = script_uri();
+        open_unchecked(&client_connection, script.clone());
+
+        // `inner` call inside the body of `outer` (line 5).
+        let resp = prepare(&client_connection, script, 5, 2);
+        let result = if let Message::Response(response) = resp {
+            response.result
+        } else {
+            panic!()
+        };
+
+        assert_json_include!(
+            actual: result,
+            expected: serde_json::json!([{ "name": "inner" }])
+        );
+    }
+
+    #[test]
+    fn outgoing_calls_from_outer() {
+        let (client_connection, _recv) = initialize_language_server(None);
+        let script = script_uri();
+        open_unchecked(&client_connection, script.clone());
+
+        let resp = prepare(&client_connection, script, 5, 2);
+        let item: CallHierarchyItem = serde_json::from_value(match resp {
+            Message::Response(response) => response.result.unwrap(),
+            _ => panic!(),
+        })
+        .map(|mut items: Vec<CallHierarchyItem>| items.remove(0))
+        .unwrap();
+
+        let resp = outgoing_calls(&client_connection, item);
+        let result = if let Message::Response(response) = resp {
+            response.result
+        } else {
+            panic!()
+        };
+
+        assert_json_include!(
+            actual: result,
+            expected: serde_json::json!([{ "to": { "name": "inner" } }])
+        );
+    }
+
+    #[test]
+    fn incoming_calls_to_inner() {
+        let (client_connection, _recv) = initialize_language_server(None);
+        let script = script_uri();
+        open_unchecked(&client_connection, script.clone());
+
+        // `inner`'s own `def` (line 0).
+        let resp = prepare(&client_connection, script, 0, 5);
+        let item: CallHierarchyItem = serde_json::from_value(match resp {
+            Message::Response(response) => response.result.unwrap(),
+            _ => panic!(),
+        })
+        .map(|mut items: Vec<CallHierarchyItem>| items.remove(0))
+        .unwrap();
+
+        let resp = incoming_calls(&client_connection, item);
+        let result = if let Message::Response(response) = resp {
+            response.result
+        } else {
+            panic!()
+        };
+
+        assert_json_include!(
+            actual: result,
+            expected: serde_json::json!([{ "from": { "name": "outer" } }])
+        );
+    }
+}
+
+fn resolve_decl(working_set: &StateWorkingSet, data: &CallHierarchyItemData) -> Option<DeclId> {
+    (0..working_set.num_decls())
+        .map(DeclId::new)
+        .find(|&decl_id| {
+            let decl = working_set.get_decl(decl_id);
+            if decl.name() != data.name {
+                return false;
+            }
+            decl.block_id()
+                .and_then(|block_id| working_set.get_block(block_id).span)
+                .is_some_and(|span| {
+                    span.start == data.block_span_start && span.end == data.block_span_end
+                })
+        })
+}
+
+/// Finds the innermost custom command whose body contains `span`, i.e. the caller of whatever
+/// call site `span` points at.
+fn enclosing_decl(working_set: &StateWorkingSet, span: Span) -> Option<DeclId> {
+    (0..working_set.num_decls())
+        .map(DeclId::new)
+        .filter_map(|decl_id| {
+            let decl = working_set.get_decl(decl_id);
+            let block_span = decl
+                .block_id()
+                .and_then(|block_id| working_set.get_block(block_id).span)?;
+            block_span
+                .contains(span.start)
+                .then_some((decl_id, block_span))
+        })
+        .min_by_key(|(_, block_span)| block_span.end - block_span.start)
+        .map(|(decl_id, _)| decl_id)
+}
@@ -0,0 +1,187 @@
+use crate::LanguageServer;
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
+    TextEdit, WorkspaceEdit,
+};
+use nu_parser::parse;
+use nu_protocol::{
+    engine::{EngineState, StateWorkingSet},
+    Span, Value,
+};
+use std::collections::HashMap;
+
+impl LanguageServer {
+    /// Offers a quick-fix code action for each parse error in range that carries a [`Fix`],
+    /// turning it into a single-click edit in the client.
+    ///
+    /// [`Fix`]: nu_protocol::Fix
+    pub(crate) fn code_action(
+        &self,
+        engine_state: &mut EngineState,
+        params: &CodeActionParams,
+    ) -> Option<CodeActionResponse> {
+        let cwd = std::env::current_dir().expect("Could not get current working directory.");
+        engine_state.add_env_var("PWD".into(), Value::test_string(cwd.to_string_lossy()));
+        engine_state.generate_nu_constant();
+
+        let mut working_set = StateWorkingSet::new(engine_state);
+
+        let uri = &params.text_document.uri;
+        let (rope_of_file, file_path) = self.rope(uri)?;
+
+        let contents = rope_of_file.bytes().collect::<Vec<u8>>();
+        let offset = working_set.next_span_start();
+        working_set
+            .files
+            .push(file_path.clone(), Span::unknown())
+            .ok()?;
+        parse(
+            &mut working_set,
+            Some(&file_path.to_string_lossy()),
+            &contents,
+            false,
+        );
+
+        let requested_start = self.lsp_position_to_byte_offset(&params.range.start, rope_of_file);
+        let requested_end = self.lsp_position_to_byte_offset(&params.range.end, rope_of_file);
+
+        let actions = working_set
+            .parse_errors
+            .iter()
+            .filter_map(|err| {
+                let fix = err.fix()?;
+                let err_span = err.span();
+                let err_start = err_span.start.saturating_sub(offset);
+                let err_end = err_span.end.saturating_sub(offset);
+                let overlaps = err_start <= requested_end && requested_start <= err_end;
+                if !overlaps {
+                    return None;
+                }
+
+                let edit_range =
+                    Self::span_to_range(&fix.span, rope_of_file, offset, &self.position_encoding);
+
+                Some(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: fix.description.clone(),
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(HashMap::from([(
+                            uri.clone(),
+                            vec![TextEdit {
+                                range: edit_range,
+                                new_text: fix.replacement,
+                            }],
+                        )])),
+                        ..Default::default()
+                    }),
+                    is_preferred: Some(true),
+                    ..Default::default()
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        if actions.is_empty() {
+            None
+        } else {
+            Some(actions)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_include;
+    use lsp_server::Message;
+    use lsp_types::{Range, Url};
+    use nu_test_support::fs::fixtures;
+
+    use crate::tests::{code_action, initialize_language_server, open_unchecked};
+
+    #[test]
+    fn quickfix_for_unknown_flag() {
+        let (client_connection, _recv) = initialize_language_server(None);
+
+        let mut script = fixtures();
+        script.push("lsp");
+        script.push("code_action");
+        script.push("unknown_flag.nu");
+        let script = Url::from_file_path(script).unwrap();
+
+        open_unchecked(&client_connection, script.clone());
+
+        let resp = code_action(
+            &client_connection,
+            script,
+            Range {
+                start: lsp_types::Position {
+                    line: 0,
+                    character: 0,
+                },
+                end: lsp_types::Position {
+                    line: 0,
+                    character: 7,
+                },
+            },
+        );
+
+        let result = if let Message::Response(response) = resp {
+            response.result
+        } else {
+            panic!()
+        };
+
+        assert_json_include!(
+            actual: result,
+            expected: serde_json::json!([
+                {
+                    "title": "Replace with `--all`",
+                    "kind": "quickfix",
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn quickfix_for_missing_mut() {
+        let (client_connection, _recv) = initialize_language_server(None);
+
+        let mut script = fixtures();
+        script.push("lsp");
+        script.push("code_action");
+        script.push("missing_mut.nu");
+        let script = Url::from_file_path(script).unwrap();
+
+        open_unchecked(&client_connection, script.clone());
+
+        let resp = code_action(
+            &client_connection,
+            script,
+            Range {
+                start: lsp_types::Position {
+                    line: 1,
+                    character: 0,
+                },
+                end: lsp_types::Position {
+                    line: 1,
+                    character: 2,
+                },
+            },
+        );
+
+        let result = if let Message::Response(response) = resp {
+            response.result
+        } else {
+            panic!()
+        };
+
+        assert_json_include!(
+            actual: result,
+            expected: serde_json::json!([
+                {
+                    "title": "Add missing `mut`",
+                    "kind": "quickfix",
+                }
+            ])
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use crate::LanguageServer;
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, CompletionTextEdit,
+    Range, TextEdit,
+};
+use nu_cli::{NuCompleter, SemanticSuggestion, SuggestionKind};
+use nu_parser::parse;
+use nu_protocol::{
+    ast::{Pipeline, PipelineElement},
+    engine::{EngineState, Stack, StateWorkingSet},
+    Type, Value,
+};
+use reedline::Suggestion;
+use std::sync::Arc;
+
+impl LanguageServer {
+    pub(crate) fn complete(
+        &mut self,
+        engine_state: &mut EngineState,
+        params: &CompletionParams,
+    ) -> Option<CompletionResponse> {
+        let cwd = std::env::current_dir().expect("Could not get current working directory.");
+        engine_state.add_env_var("PWD".into(), Value::test_string(cwd.to_string_lossy()));
+
+        let (rope_of_file, _, _) = self.read_in_file(
+            engine_state,
+            &params.text_document_position.text_document.uri,
+        )?;
+
+        let location =
+            self.lsp_position_to_byte_offset(&params.text_document_position.position, rope_of_file);
+        let text_to_cursor = rope_of_file.to_string()[..location].to_string();
+
+        let mut completer =
+            NuCompleter::new(Arc::new(engine_state.clone()), Arc::new(Stack::new()));
+        let mut results = completer.fetch_completions_at(&text_to_cursor, location);
+        results.extend(cell_path_completions(engine_state, &text_to_cursor));
+
+        if results.is_empty() {
+            None
+        } else {
+            Some(CompletionResponse::Array(
+                results
+                    .into_iter()
+                    .map(|r| {
+                        let mut start = params.text_document_position.position;
+                        start.character -= (r.suggestion.span.end - r.suggestion.span.start) as u32;
+
+                        CompletionItem {
+                            label: r.suggestion.value.clone(),
+                            detail: r.suggestion.description,
+                            kind: Self::lsp_completion_item_kind(r.kind),
+                            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                                range: Range {
+                                    start,
+                                    end: params.text_document_position.position,
+                                },
+                                new_text: r.suggestion.value,
+                            })),
+                            ..Default::default()
+                        }
+                    })
+                    .collect(),
+            ))
+        }
+    }
+
+    fn lsp_completion_item_kind(
+        suggestion_kind: Option<SuggestionKind>,
+    ) -> Option<CompletionItemKind> {
+        suggestion_kind.and_then(|suggestion_kind| match suggestion_kind {
+            SuggestionKind::Type(t) => match t {
+                Type::String => Some(CompletionItemKind::VARIABLE),
+                _ => None,
+            },
+            SuggestionKind::Command(c) => match c {
+                nu_protocol::engine::CommandType::Keyword => Some(CompletionItemKind::KEYWORD),
+                nu_protocol::engine::CommandType::Builtin => Some(CompletionItemKind::FUNCTION),
+                _ => None,
+            },
+        })
+    }
+}
+
+/// Completes cell paths (`$in.foo.<cursor>`, `get foo.<cursor>`) whose base record/table shape
+/// can't be known by evaluating a value - there isn't one, since the LSP only ever parses code,
+/// it never runs it - but *can* be inferred from the declared output type of the command that
+/// feeds the pipeline, for commands that declare their output shape with concrete field names
+/// rather than a bare `any`/`record`/`table`.
+fn cell_path_completions(
+    engine_state: &EngineState,
+    text_to_cursor: &str,
+) -> Vec<SemanticSuggestion> {
+    let Some((path, prefix)) = cell_path_prefix(text_to_cursor) else {
+        return Vec::new();
+    };
+    let Some(base_type) = pipeline_input_type(engine_state, text_to_cursor) else {
+        return Vec::new();
+    };
+    let Some(fields) = fields_at_path(&base_type, &path) else {
+        return Vec::new();
+    };
+
+    fields
+        .iter()
+        .filter(|(name, _)| name.starts_with(prefix.as_str()))
+        .map(|(name, ty)| SemanticSuggestion {
+            suggestion: Suggestion {
+                value: name.clone(),
+                span: reedline::Span {
+                    start: 0,
+                    end: prefix.len(),
+                },
+                description: Some(ty.to_string()),
+                ..Suggestion::default()
+            },
+            kind: Some(SuggestionKind::Type(ty.clone())),
+        })
+        .collect()
+}
+
+/// Finds the cell path segments typed so far and the partial segment under the cursor, for
+/// either `$in.a.b.<cursor>` or `get a.b.<cursor>` (the argument to `get` is a cell path into
+/// whatever is piped into it, same as `$in.<path>` would be).
+fn cell_path_prefix(text_to_cursor: &str) -> Option<(Vec<String>, String)> {
+    let word_start = text_to_cursor
+        .rfind(|c: char| c.is_whitespace() || matches!(c, '|' | '(' | '[' | ';'))
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let word = &text_to_cursor[word_start..];
+    if !word.contains('.') {
+        return None;
+    }
+
+    let path = match word.strip_prefix("$in.") {
+        Some(rest) => rest,
+        None => {
+            let preceding_command = text_to_cursor[..word_start].split_whitespace().last();
+            if preceding_command != Some("get") {
+                return None;
+            }
+            word
+        }
+    };
+
+    let mut segments: Vec<String> = path.split('.').map(str::to_string).collect();
+    let prefix = segments.pop().unwrap_or_default();
+    Some((segments, prefix))
+}
+
+/// Infers the type flowing into the pipeline stage the cursor is in, by reusing the type the
+/// parser already assigned to the stage directly before it (it resolves each call's output type
+/// from its declared `input_output_types` as part of parsing, same as `Block::output_type`
+/// does for the last pipeline element). Only looks at the top-level pipeline the cursor is in -
+/// cell path completion inside a nested block/closure isn't supported.
+fn pipeline_input_type(engine_state: &EngineState, text_to_cursor: &str) -> Option<Type> {
+    let mut working_set = StateWorkingSet::new(engine_state);
+    let block = parse(
+        &mut working_set,
+        Some("completer"),
+        text_to_cursor.as_bytes(),
+        false,
+    );
+    let pipeline = block.pipelines.last()?;
+    let preceding = preceding_element(pipeline)?;
+    Some(preceding.expr.ty.clone())
+}
+
+fn preceding_element(pipeline: &Pipeline) -> Option<&PipelineElement> {
+    let index = pipeline.elements.len().checked_sub(2)?;
+    pipeline.elements.get(index)
+}
+
+/// Walks `path` through a record/table type's declared fields, returning the field list at the
+/// end of the path. Returns `None` if any segment along the way isn't a record/table field with
+/// concrete field names, which is the common case for commands that only declare `any`.
+fn fields_at_path<'a>(ty: &'a Type, path: &[String]) -> Option<&'a [(String, Type)]> {
+    let fields = match ty {
+        Type::Record(fields) | Type::Table(fields) => fields.as_ref(),
+        _ => return None,
+    };
+    match path.split_first() {
+        None => Some(fields),
+        Some((head, rest)) => {
+            let (_, next) = fields.iter().find(|(name, _)| name == head)?;
+            fields_at_path(next, rest)
+        }
+    }
+}
@@ -9,10 +9,45 @@ use nu_protocol::{
     engine::{EngineState, StateWorkingSet},
     Span, Value,
 };
+use ropey::Rope;
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+/// Caches the diagnostics produced for the last parse of each file, keyed by a hash of its
+/// contents. `DeclId`/`VarId`/`BlockId` allocated by a parse don't survive past the
+/// `StateWorkingSet` that produced them, so we can't cache the parse itself across requests;
+/// diagnostics are just spans and strings, so they can be reused as long as the text they were
+/// computed from hasn't changed, letting an unchanged file skip reparsing entirely.
+#[derive(Default)]
+pub(crate) struct DiagnosticsCache(BTreeMap<PathBuf, (u64, Vec<Diagnostic>)>);
+
+impl DiagnosticsCache {
+    fn get(&self, path: &Path, content_hash: u64) -> Option<&Vec<Diagnostic>> {
+        let (cached_hash, diagnostics) = self.0.get(path)?;
+        (*cached_hash == content_hash).then_some(diagnostics)
+    }
+
+    fn insert(&mut self, path: PathBuf, content_hash: u64, diagnostics: Vec<Diagnostic>) {
+        self.0.insert(path, (content_hash, diagnostics));
+    }
+
+    pub(crate) fn remove(&mut self, path: &Path) {
+        self.0.remove(path);
+    }
+}
+
+fn hash_contents(contents: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
 
 impl LanguageServer {
     pub(crate) fn publish_diagnostics_for_file(
-        &self,
+        &mut self,
         uri: Url,
         engine_state: &mut EngineState,
     ) -> Result<()> {
@@ -20,51 +55,110 @@ impl LanguageServer {
         engine_state.add_env_var("PWD".into(), Value::test_string(cwd.to_string_lossy()));
         engine_state.generate_nu_constant();
 
-        let mut working_set = StateWorkingSet::new(engine_state);
-
         let Some((rope_of_file, file_path)) = self.rope(&uri) else {
             return Ok(());
         };
-
+        let file_path = file_path.to_owned();
         let contents = rope_of_file.bytes().collect::<Vec<u8>>();
-        let offset = working_set.next_span_start();
-        working_set.files.push(file_path.into(), Span::unknown())?;
-        parse(
-            &mut working_set,
-            Some(&file_path.to_string_lossy()),
-            &contents,
-            false,
-        );
-
-        let mut diagnostics = PublishDiagnosticsParams {
-            uri,
-            diagnostics: Vec::new(),
-            version: None,
+        let content_hash = hash_contents(&contents);
+
+        let diagnostics = match self.diagnostics_cache.get(&file_path, content_hash) {
+            Some(cached) => cached.clone(),
+            None => {
+                let mut working_set = StateWorkingSet::new(engine_state);
+                let offset = working_set.next_span_start();
+                working_set.files.push(file_path.clone(), Span::unknown())?;
+                parse(
+                    &mut working_set,
+                    Some(&file_path.to_string_lossy()),
+                    &contents,
+                    false,
+                );
+
+                working_set
+                    .parse_errors
+                    .iter()
+                    .map(|err| Diagnostic {
+                        range: Self::span_to_range(
+                            &err.span(),
+                            rope_of_file,
+                            offset,
+                            &self.position_encoding,
+                        ),
+                        severity: Some(DiagnosticSeverity::ERROR),
+                        message: err.to_string(),
+                        ..Default::default()
+                    })
+                    .collect::<Vec<_>>()
+            }
         };
 
-        for err in working_set.parse_errors.iter() {
-            let message = err.to_string();
-
-            diagnostics.diagnostics.push(Diagnostic {
-                range: Self::span_to_range(
-                    &err.span(),
-                    rope_of_file,
-                    offset,
-                    &self.position_encoding,
-                ),
-                severity: Some(DiagnosticSeverity::ERROR),
-                message,
-                ..Default::default()
-            });
-        }
+        self.diagnostics_cache
+            .insert(file_path, content_hash, diagnostics.clone());
 
         self.connection
             .sender
             .send(lsp_server::Message::Notification(
-                lsp_server::Notification::new(PublishDiagnostics::METHOD.to_string(), diagnostics),
+                lsp_server::Notification::new(
+                    PublishDiagnostics::METHOD.to_string(),
+                    PublishDiagnosticsParams {
+                        uri,
+                        diagnostics,
+                        version: None,
+                    },
+                ),
             ))
             .into_diagnostic()
     }
+
+    /// Parses every `.nu` file under the workspace folders and publishes diagnostics for the
+    /// ones that aren't already open in the client, so modules get flagged as broken before
+    /// anything sources them. Files the client already has open are left alone here; they're
+    /// kept current by [`LanguageServer::publish_diagnostics_for_file`] as edits come in.
+    pub(crate) fn publish_diagnostics_for_workspace(
+        &mut self,
+        engine_state: &mut EngineState,
+    ) -> Result<()> {
+        for folder in self.workspace_folders.clone() {
+            for path in collect_nu_files(&folder) {
+                if self.ropes.contains_key(&path) {
+                    continue;
+                }
+                let Ok(contents) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                let Ok(uri) = Url::from_file_path(&path) else {
+                    continue;
+                };
+                self.ropes.insert(path, Rope::from_str(&contents));
+                self.publish_diagnostics_for_file(uri, engine_state)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Recursively collects `.nu` files under `dir`, skipping hidden directories (`.git`, `.venv`,
+/// ...) so the workspace lint pass doesn't wander into unrelated trees.
+fn collect_nu_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return files;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            let hidden = path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            if !hidden {
+                files.extend(collect_nu_files(&path));
+            }
+        } else if path.extension().is_some_and(|ext| ext == "nu") {
+            files.push(path);
+        }
+    }
+    files
 }
 
 #[cfg(test)]
@@ -0,0 +1,66 @@
+use crate::LanguageServer;
+use lsp_types::{Range, TextDocumentIdentifier};
+use nu_engine::eval_block;
+use nu_parser::parse;
+use nu_protocol::{
+    debugger::WithoutDebug,
+    engine::{EngineState, Stack, StateWorkingSet},
+    PipelineData, Span,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluateSelectionParams {
+    pub text_document: TextDocumentIdentifier,
+    pub range: Range,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluateSelectionResult {
+    pub rendered: String,
+}
+
+impl LanguageServer {
+    /// Handles the custom `nu/evaluateSelection` request: runs the selected snippet in a
+    /// throwaway clone of the engine state - the same per-request clone [`Self::serve_requests`]
+    /// already hands every handler, so nothing the snippet does (defining overlays, mutating
+    /// `$env`, ...) leaks into later requests - and returns its output rendered as text.
+    pub(crate) fn evaluate_selection(
+        &mut self,
+        engine_state: &mut EngineState,
+        params: &EvaluateSelectionParams,
+    ) -> Option<EvaluateSelectionResult> {
+        let (rope_of_file, _) = self.rope(&params.text_document.uri)?;
+
+        let start = self.lsp_position_to_byte_offset(&params.range.start, rope_of_file);
+        let end = self.lsp_position_to_byte_offset(&params.range.end, rope_of_file);
+        let snippet = rope_of_file.to_string().get(start..end)?.to_string();
+
+        let block = {
+            let mut working_set = StateWorkingSet::new(engine_state);
+            let block = parse(
+                &mut working_set,
+                Some("nu/evaluateSelection"),
+                snippet.as_bytes(),
+                false,
+            );
+            if !working_set.parse_errors.is_empty() {
+                return None;
+            }
+            let delta = working_set.render();
+            engine_state.merge_delta(delta).ok()?;
+            block
+        };
+
+        let mut stack = Stack::new();
+        let result =
+            eval_block::<WithoutDebug>(engine_state, &mut stack, &block, PipelineData::Empty)
+                .ok()?;
+        let value = result.into_value(Span::unknown()).ok()?;
+        let rendered = value.to_expanded_string(", ", engine_state.get_config());
+
+        Some(EvaluateSelectionResult { rendered })
+    }
+}
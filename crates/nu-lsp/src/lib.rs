@@ -1,17 +1,20 @@
 #![doc = include_str!("../README.md")]
 use lsp_server::{Connection, IoThreads, Message, Response, ResponseError};
 use lsp_types::{
-    request::{Completion, GotoDefinition, HoverRequest, Request},
-    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, CompletionTextEdit,
-    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverContents, HoverParams, Location,
-    MarkupContent, MarkupKind, OneOf, Position, PositionEncodingKind, Range, ServerCapabilities,
-    TextDocumentSyncKind, TextEdit, Url,
+    request::{
+        CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare,
+        CodeActionRequest, Completion, GotoDefinition, HoverRequest, Request,
+        SemanticTokensFullRequest,
+    },
+    CallHierarchyServerCapability, CodeActionProviderCapability, GotoDefinitionParams,
+    GotoDefinitionResponse, Hover, HoverContents, HoverParams, InitializeParams, Location,
+    MarkupContent, MarkupKind, OneOf, Position, PositionEncodingKind, Range,
+    SemanticTokensServerCapabilities, ServerCapabilities, TextDocumentSyncKind, Url,
 };
 use miette::{IntoDiagnostic, Result};
-use nu_cli::{NuCompleter, SuggestionKind};
 use nu_parser::{flatten_block, parse, FlatShape};
 use nu_protocol::{
-    engine::{CachedFile, EngineState, Stack, StateWorkingSet},
+    engine::{CachedFile, EngineState, StateWorkingSet},
     DeclId, Span, Value, VarId,
 };
 use ropey::Rope;
@@ -19,12 +22,16 @@ use serde_json::json;
 use std::{
     collections::BTreeMap,
     path::{Path, PathBuf},
-    sync::Arc,
     time::Duration,
 };
 
+mod call_hierarchy;
+mod code_actions;
+mod completion;
 mod diagnostics;
+mod evaluation;
 mod notification;
+mod semantic_tokens;
 
 #[derive(Debug)]
 enum Id {
@@ -38,6 +45,8 @@ pub struct LanguageServer {
     io_threads: Option<IoThreads>,
     ropes: BTreeMap<PathBuf, Rope>,
     position_encoding: PositionEncodingKind,
+    diagnostics_cache: diagnostics::DiagnosticsCache,
+    workspace_folders: Vec<PathBuf>,
 }
 
 impl LanguageServer {
@@ -55,6 +64,8 @@ impl LanguageServer {
             io_threads,
             ropes: BTreeMap::new(),
             position_encoding: PositionEncodingKind::UTF16,
+            diagnostics_cache: diagnostics::DiagnosticsCache::default(),
+            workspace_folders: Vec::new(),
         })
     }
 
@@ -77,6 +88,14 @@ impl LanguageServer {
             definition_provider: Some(OneOf::Left(true)),
             hover_provider: Some(lsp_types::HoverProviderCapability::Simple(true)),
             completion_provider: Some(lsp_types::CompletionOptions::default()),
+            code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+            call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+            semantic_tokens_provider: Some(
+                SemanticTokensServerCapabilities::SemanticTokensOptions(
+                    semantic_tokens::semantic_tokens_options(),
+                ),
+            ),
+            experimental: Some(json!({ "evaluateSelectionProvider": true })),
             ..Default::default()
         })
         .expect("Must be serializable");
@@ -88,7 +107,17 @@ impl LanguageServer {
             })
             .into_diagnostic()?;
         self.position_encoding =
-            PositionEncodingKind::from(self.get_offset_encoding(initialization_params));
+            PositionEncodingKind::from(self.get_offset_encoding(initialization_params.clone()));
+        self.workspace_folders = serde_json::from_value::<InitializeParams>(initialization_params)
+            .ok()
+            .and_then(|params| params.workspace_folders)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|folder| folder.uri.to_file_path().ok())
+            .collect();
+
+        let mut startup_engine_state = engine_state.clone();
+        self.publish_diagnostics_for_workspace(&mut startup_engine_state)?;
 
         while !engine_state.signals().interrupted() {
             let msg = match self
@@ -130,6 +159,38 @@ impl LanguageServer {
                             request,
                             |engine_state, params| self.complete(engine_state, params),
                         ),
+                        CodeActionRequest::METHOD => Self::handle_lsp_request(
+                            &mut engine_state,
+                            request,
+                            |engine_state, params| self.code_action(engine_state, params),
+                        ),
+                        CallHierarchyPrepare::METHOD => Self::handle_lsp_request(
+                            &mut engine_state,
+                            request,
+                            |engine_state, params| {
+                                self.prepare_call_hierarchy(engine_state, params)
+                            },
+                        ),
+                        CallHierarchyIncomingCalls::METHOD => Self::handle_lsp_request(
+                            &mut engine_state,
+                            request,
+                            |engine_state, params| self.incoming_calls(engine_state, params),
+                        ),
+                        CallHierarchyOutgoingCalls::METHOD => Self::handle_lsp_request(
+                            &mut engine_state,
+                            request,
+                            |engine_state, params| self.outgoing_calls(engine_state, params),
+                        ),
+                        SemanticTokensFullRequest::METHOD => Self::handle_lsp_request(
+                            &mut engine_state,
+                            request,
+                            |engine_state, params| self.semantic_tokens_full(engine_state, params),
+                        ),
+                        "nu/evaluateSelection" => Self::handle_lsp_request(
+                            &mut engine_state,
+                            request,
+                            |engine_state, params| self.evaluate_selection(engine_state, params),
+                        ),
                         _ => {
                             continue;
                         }
@@ -299,6 +360,19 @@ impl LanguageServer {
         None
     }
 
+    /// Parses `text` in isolation and evaluates it as a constant expression, for showing a
+    /// literal's value on hover. Only meaningful for the literal [`FlatShape`]s - anything that
+    /// needs a runtime (a call, a variable that isn't `const`, ...) will fail to const-eval and
+    /// fall through to [`Option::None`], which is fine since hover just omits the `= value` part.
+    fn eval_literal_value(working_set: &mut StateWorkingSet, text: &[u8]) -> Option<Value> {
+        let block = parse(working_set, None, text, false);
+        if !working_set.parse_errors.is_empty() {
+            return None;
+        }
+        let element = block.pipelines.first()?.elements.first()?;
+        nu_protocol::eval_const::eval_constant(working_set, &element.expr).ok()
+    }
+
     fn rope<'a, 'b: 'a>(&'b self, file_url: &Url) -> Option<(&'a Rope, &'a PathBuf)> {
         let file_path = file_url.to_file_path().ok()?;
 
@@ -410,7 +484,7 @@ impl LanguageServer {
             &params.text_document_position_params.text_document.uri,
         )?;
 
-        let (id, _, _) = Self::find_id(
+        let (id, offset, span) = Self::find_id(
             &mut working_set,
             path,
             file,
@@ -420,7 +494,14 @@ impl LanguageServer {
         match id {
             Id::Variable(var_id) => {
                 let var = working_set.get_variable(var_id);
-                let contents = format!("{}{}", if var.mutable { "mutable " } else { "" }, var.ty);
+                let mut contents =
+                    format!("{}{}", if var.mutable { "mutable " } else { "" }, var.ty);
+                if let Some(const_val) = &var.const_val {
+                    contents.push_str(&format!(
+                        " = {}",
+                        const_val.to_abbreviated_string(working_set.get_config())
+                    ));
+                }
 
                 Some(Hover {
                     contents: HoverContents::Scalar(lsp_types::MarkedString::String(contents)),
@@ -578,7 +659,23 @@ impl LanguageServer {
                 })
             }
             Id::Value(shape) => {
-                let hover = String::from(match shape {
+                let is_literal = matches!(
+                    shape,
+                    FlatShape::Binary
+                        | FlatShape::Bool
+                        | FlatShape::DateTime
+                        | FlatShape::Float
+                        | FlatShape::Int
+                        | FlatShape::List
+                        | FlatShape::Nothing
+                        | FlatShape::Range
+                        | FlatShape::Record
+                        | FlatShape::String
+                        | FlatShape::StringInterpolation
+                        | FlatShape::Table
+                );
+
+                let mut hover = String::from(match shape {
                     FlatShape::Binary => "binary",
                     FlatShape::Block => "block",
                     FlatShape::Bool => "bool",
@@ -606,6 +703,19 @@ impl LanguageServer {
                     }
                 });
 
+                if is_literal {
+                    let contents = file.bytes().collect::<Vec<u8>>();
+                    let snippet = contents.get(span.start - offset..span.end - offset);
+                    if let Some(value) =
+                        snippet.and_then(|s| Self::eval_literal_value(&mut working_set, s))
+                    {
+                        hover.push_str(&format!(
+                            " = {}",
+                            value.to_abbreviated_string(working_set.get_config())
+                        ));
+                    }
+                }
+
                 Some(Hover {
                     contents: HoverContents::Scalar(lsp_types::MarkedString::String(hover)),
                     // TODO
@@ -614,71 +724,6 @@ impl LanguageServer {
             }
         }
     }
-
-    fn complete(
-        &mut self,
-        engine_state: &mut EngineState,
-        params: &CompletionParams,
-    ) -> Option<CompletionResponse> {
-        let cwd = std::env::current_dir().expect("Could not get current working directory.");
-        engine_state.add_env_var("PWD".into(), Value::test_string(cwd.to_string_lossy()));
-
-        let (rope_of_file, _, _) = self.read_in_file(
-            engine_state,
-            &params.text_document_position.text_document.uri,
-        )?;
-
-        let mut completer =
-            NuCompleter::new(Arc::new(engine_state.clone()), Arc::new(Stack::new()));
-
-        let location =
-            self.lsp_position_to_byte_offset(&params.text_document_position.position, rope_of_file);
-        let results =
-            completer.fetch_completions_at(&rope_of_file.to_string()[..location], location);
-        if results.is_empty() {
-            None
-        } else {
-            Some(CompletionResponse::Array(
-                results
-                    .into_iter()
-                    .map(|r| {
-                        let mut start = params.text_document_position.position;
-                        start.character -= (r.suggestion.span.end - r.suggestion.span.start) as u32;
-
-                        CompletionItem {
-                            label: r.suggestion.value.clone(),
-                            detail: r.suggestion.description,
-                            kind: Self::lsp_completion_item_kind(r.kind),
-                            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
-                                range: Range {
-                                    start,
-                                    end: params.text_document_position.position,
-                                },
-                                new_text: r.suggestion.value,
-                            })),
-                            ..Default::default()
-                        }
-                    })
-                    .collect(),
-            ))
-        }
-    }
-
-    fn lsp_completion_item_kind(
-        suggestion_kind: Option<SuggestionKind>,
-    ) -> Option<CompletionItemKind> {
-        suggestion_kind.and_then(|suggestion_kind| match suggestion_kind {
-            SuggestionKind::Type(t) => match t {
-                nu_protocol::Type::String => Some(CompletionItemKind::VARIABLE),
-                _ => None,
-            },
-            SuggestionKind::Command(c) => match c {
-                nu_protocol::engine::CommandType::Keyword => Some(CompletionItemKind::KEYWORD),
-                nu_protocol::engine::CommandType::Builtin => Some(CompletionItemKind::FUNCTION),
-                _ => None,
-            },
-        })
-    }
 }
 
 #[cfg(test)]
@@ -689,11 +734,14 @@ mod tests {
         notification::{
             DidChangeTextDocument, DidOpenTextDocument, Exit, Initialized, Notification,
         },
-        request::{Completion, GotoDefinition, HoverRequest, Initialize, Request, Shutdown},
-        CompletionParams, DidChangeTextDocumentParams, DidOpenTextDocumentParams,
-        GotoDefinitionParams, InitializeParams, InitializedParams, PartialResultParams,
-        TextDocumentContentChangeEvent, TextDocumentIdentifier, TextDocumentItem,
-        TextDocumentPositionParams, Url, WorkDoneProgressParams,
+        request::{
+            CodeActionRequest, Completion, GotoDefinition, HoverRequest, Initialize, Request,
+            Shutdown,
+        },
+        CodeActionContext, CodeActionParams, CompletionParams, DidChangeTextDocumentParams,
+        DidOpenTextDocumentParams, GotoDefinitionParams, InitializeParams, InitializedParams,
+        PartialResultParams, TextDocumentContentChangeEvent, TextDocumentIdentifier,
+        TextDocumentItem, TextDocumentPositionParams, Url, WorkDoneProgressParams,
     };
     use nu_test_support::fs::{fixtures, root};
     use std::sync::mpsc::Receiver;
@@ -889,6 +937,29 @@ mod tests {
         }
     }
 
+    pub fn code_action(client_connection: &Connection, uri: Url, range: Range) -> Message {
+        client_connection
+            .sender
+            .send(Message::Request(lsp_server::Request {
+                id: 2.into(),
+                method: CodeActionRequest::METHOD.to_string(),
+                params: serde_json::to_value(CodeActionParams {
+                    text_document: TextDocumentIdentifier { uri },
+                    range,
+                    context: CodeActionContext::default(),
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                })
+                .unwrap(),
+            }))
+            .unwrap();
+
+        client_connection
+            .receiver
+            .recv_timeout(std::time::Duration::from_secs(2))
+            .unwrap()
+    }
+
     fn goto_definition(
         client_connection: &Connection,
         uri: Url,
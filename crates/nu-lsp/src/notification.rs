@@ -38,6 +38,7 @@ impl LanguageServer {
             >(notification, |param| {
                 if let Ok(file_path) = param.text_document.uri.to_file_path() {
                     self.ropes.remove(&file_path);
+                    self.diagnostics_cache.remove(&file_path);
                 }
                 None
             }),
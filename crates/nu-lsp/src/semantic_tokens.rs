@@ -0,0 +1,162 @@
+use crate::LanguageServer;
+use lsp_types::{
+    SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens,
+    SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions, SemanticTokensParams,
+    SemanticTokensResult,
+};
+use nu_parser::{flatten_block, parse, FlatShape};
+use nu_protocol::{
+    engine::{EngineState, StateWorkingSet},
+    Span, VarId,
+};
+
+/// Token types emitted by [`LanguageServer::semantic_tokens_full`], in the order their index
+/// appears in [`semantic_tokens_legend`]. `FlatShape` doesn't carry a distinct variant for type
+/// annotations in a signature, so those aren't tokenized here.
+const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::STRING,
+    SemanticTokenType::KEYWORD,
+];
+
+const FUNCTION: u32 = 0;
+const NAMESPACE: u32 = 1;
+const VARIABLE: u32 = 2;
+const PARAMETER: u32 = 3;
+const PROPERTY: u32 = 4;
+const STRING: u32 = 5;
+const KEYWORD: u32 = 6;
+
+/// Modifiers emitted alongside [`TOKEN_TYPES`], in bit order.
+const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[SemanticTokenModifier::READONLY];
+const MODIFIER_READONLY: u32 = 1 << 0;
+
+pub(crate) fn semantic_tokens_legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+pub(crate) fn semantic_tokens_options() -> SemanticTokensOptions {
+    SemanticTokensOptions {
+        legend: semantic_tokens_legend(),
+        full: Some(SemanticTokensFullOptions::Bool(true)),
+        ..Default::default()
+    }
+}
+
+impl LanguageServer {
+    /// Classifies every flattened token of the file into a `(type, modifiers)` pair and returns
+    /// them as the delta-encoded `SemanticToken` stream the LSP spec requires. Internal commands
+    /// are `FUNCTION`, external commands are `NAMESPACE`; a `VarDecl` inside a `def` signature is
+    /// a `PARAMETER`, any other variable declaration or reference is a `VARIABLE` (`readonly`
+    /// unless declared with `mut`); `--flags` are `PROPERTY`, parser keywords are `KEYWORD`, and
+    /// string literals (including each segment of a string interpolation) are `STRING`.
+    pub(crate) fn semantic_tokens_full(
+        &mut self,
+        engine_state: &mut EngineState,
+        params: &SemanticTokensParams,
+    ) -> Option<SemanticTokensResult> {
+        let uri = &params.text_document.uri;
+        let (file, path, mut working_set) = self.read_in_file(engine_state, uri)?;
+
+        let file_path = path.to_string_lossy();
+        let contents = file.bytes().collect::<Vec<u8>>();
+        working_set.files.push(path.clone(), Span::unknown()).ok()?;
+        let block = parse(&mut working_set, Some(&file_path), &contents, false);
+        let offset = working_set.get_span_for_filename(&file_path)?.start;
+
+        let flattened = flatten_block(&working_set, &block);
+        let signature_spans: Vec<Span> = flattened
+            .iter()
+            .filter(|(_, shape)| matches!(shape, FlatShape::Signature))
+            .map(|(span, _)| *span)
+            .collect();
+        let is_parameter = |var_id: VarId, span: Span| {
+            working_set.get_variable(var_id).declaration_span == span
+                && signature_spans.iter().any(|sig| sig.contains_span(span))
+        };
+
+        let mut entries = Vec::new();
+        for (span, shape) in &flattened {
+            let Some((token_type, modifiers)) = classify(&working_set, shape, *span, is_parameter)
+            else {
+                continue;
+            };
+            let range = Self::span_to_range(span, file, offset, &self.position_encoding);
+            if range.start.line != range.end.line {
+                // Multi-line tokens (e.g. a string spanning several lines) aren't representable
+                // by a single delta-encoded entry; skip rather than emit a wrong length.
+                continue;
+            }
+            entries.push((
+                range.start.line,
+                range.start.character,
+                range.end.character - range.start.character,
+                token_type,
+                modifiers,
+            ));
+        }
+        entries.sort_by_key(|&(line, start, ..)| (line, start));
+
+        let mut data = Vec::with_capacity(entries.len());
+        let mut prev_line = 0u32;
+        let mut prev_start = 0u32;
+        for (line, start, length, token_type, token_modifiers_bitset) in entries {
+            let delta_line = line - prev_line;
+            let delta_start = if delta_line == 0 {
+                start - prev_start
+            } else {
+                start
+            };
+            data.push(SemanticToken {
+                delta_line,
+                delta_start,
+                length,
+                token_type,
+                token_modifiers_bitset,
+            });
+            prev_line = line;
+            prev_start = start;
+        }
+
+        Some(SemanticTokensResult::Tokens(SemanticTokens {
+            result_id: None,
+            data,
+        }))
+    }
+}
+
+fn classify(
+    working_set: &StateWorkingSet,
+    shape: &FlatShape,
+    span: Span,
+    is_parameter: impl Fn(VarId, Span) -> bool,
+) -> Option<(u32, u32)> {
+    match shape {
+        FlatShape::InternalCall(_) => Some((FUNCTION, 0)),
+        FlatShape::External | FlatShape::ExternalResolved => Some((NAMESPACE, 0)),
+        FlatShape::Variable(var_id) | FlatShape::VarDecl(var_id) => {
+            if is_parameter(*var_id, span) {
+                return Some((PARAMETER, 0));
+            }
+            let modifiers = if working_set.get_variable(*var_id).mutable {
+                0
+            } else {
+                MODIFIER_READONLY
+            };
+            Some((VARIABLE, modifiers))
+        }
+        FlatShape::Flag => Some((PROPERTY, 0)),
+        FlatShape::Keyword => Some((KEYWORD, 0)),
+        FlatShape::String | FlatShape::StringInterpolation | FlatShape::RawString => {
+            Some((STRING, 0))
+        }
+        _ => None,
+    }
+}
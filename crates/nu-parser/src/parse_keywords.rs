@@ -1682,6 +1682,39 @@ pub fn parse_export_env(
     (pipeline, Some(block_id))
 }
 
+/// Checks whether a single `@cfg(predicate)` in `predicate` matches the platform nushell was
+/// compiled for. Supports bare family/os names (e.g. `windows`, `unix`, `macos`, `linux`) and
+/// `not(...)` negation; anything else is treated as non-matching.
+fn cfg_predicate_matches(predicate: &str) -> bool {
+    let predicate = predicate.trim();
+
+    if let Some(inner) = predicate
+        .strip_prefix("not(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        return !cfg_predicate_matches(inner);
+    }
+
+    match predicate {
+        "unix" => std::env::consts::FAMILY == "unix",
+        "windows" => std::env::consts::FAMILY == "windows",
+        other => std::env::consts::OS == other,
+    }
+}
+
+/// Looks for a `# @cfg(predicate)` annotation among a statement's leading comments and, if
+/// found, evaluates it against the current platform. Returns `None` when there's no such
+/// annotation, meaning the statement is unconditional.
+fn cfg_annotation_matches(working_set: &StateWorkingSet, comments: &[Span]) -> Option<bool> {
+    comments.iter().find_map(|span| {
+        let text = String::from_utf8_lossy(working_set.get_span_contents(*span)).into_owned();
+        let text = text.trim_start_matches('#').trim();
+        let predicate = text.strip_prefix("@cfg(")?.strip_suffix(')')?;
+
+        Some(cfg_predicate_matches(predicate))
+    })
+}
+
 fn collect_first_comments(tokens: &[Token]) -> Vec<Span> {
     let mut comments = vec![];
 
@@ -1735,7 +1768,11 @@ pub fn parse_module_block(
 
     for pipeline in &output.block {
         if pipeline.commands.len() == 1 {
-            parse_def_predecl(working_set, &pipeline.commands[0].parts);
+            let command = &pipeline.commands[0];
+            if cfg_annotation_matches(working_set, &command.comments) == Some(false) {
+                continue;
+            }
+            parse_def_predecl(working_set, &command.parts);
         }
     }
 
@@ -1747,6 +1784,10 @@ pub fn parse_module_block(
         if pipeline.commands.len() == 1 {
             let command = &pipeline.commands[0];
 
+            if cfg_annotation_matches(working_set, &command.comments) == Some(false) {
+                continue;
+            }
+
             let name = working_set.get_span_contents(command.parts[0]);
 
             match name {
@@ -2016,6 +2057,19 @@ pub fn parse_module_file_or_dir(
         return None;
     }
 
+    // Won't-fix: downloading and hash-pinning remote modules (`use https://.../x.nu
+    // sha256=<hash>`) would need a cache, an offline mode, and a hashing dependency
+    // in nu-parser, which has none today and parses on every run, not just on miss.
+    // That's a new subsystem, not a parser tweak, so we reject the syntax with a
+    // clear error instead of pretending to support it.
+    if module_path_str.starts_with("https://") || module_path_str.starts_with("http://") {
+        working_set.error(ParseError::ModuleUrlNotSupported(
+            path_span,
+            module_path_str,
+        ));
+        return None;
+    }
+
     #[allow(deprecated)]
     let cwd = working_set.get_cwd();
 
@@ -616,6 +616,7 @@ fn parse_long_flag(
                     long_name.clone(),
                     arg_span,
                     sig.clone().formatted_flags(),
+                    sig.named.iter().map(|flag| flag.long.clone()).collect(),
                 ));
                 (
                     Some(Spanned {
@@ -696,6 +697,7 @@ fn parse_short_flags(
                     format!("-{}", String::from_utf8_lossy(contents)),
                     *first,
                     sig.clone().formatted_flags(),
+                    sig.named.iter().map(|flag| flag.long.clone()).collect(),
                 ));
             }
 
@@ -1015,7 +1017,7 @@ pub fn parse_internal_call(
             // We found a long flag, like --bar
             if working_set.parse_errors[starting_error_count..]
                 .iter()
-                .any(|x| matches!(x, ParseError::UnknownFlag(_, _, _, _)))
+                .any(|x| matches!(x, ParseError::UnknownFlag(_, _, _, _, _)))
                 && signature.allows_unknown_args
             {
                 working_set.parse_errors.truncate(starting_error_count);
@@ -1057,7 +1059,7 @@ pub fn parse_internal_call(
 
             if working_set.parse_errors[starting_error_count..]
                 .iter()
-                .any(|x| matches!(x, ParseError::UnknownFlag(_, _, _, _)))
+                .any(|x| matches!(x, ParseError::UnknownFlag(_, _, _, _, _)))
                 && signature.allows_unknown_args
             {
                 working_set.parse_errors.truncate(starting_error_count);
@@ -1541,6 +1543,7 @@ pub fn parse_int(working_set: &mut StateWorkingSet, span: Span) -> Expression {
                 format!("invalid digits for radix {}", radix),
                 "int".into(),
                 span,
+                None,
             ));
 
             garbage(working_set, span)
@@ -1825,6 +1828,7 @@ pub fn parse_raw_string(working_set: &mut StateWorkingSet, span: Span) -> Expres
 
     let bytes = &bytes[prefix_sharp_cnt + 1 + 1..bytes.len() - 1 - prefix_sharp_cnt];
     if let Ok(token) = String::from_utf8(bytes.into()) {
+        let token = dedent_raw_string(token);
         Expression::new(working_set, Expr::RawString(token), span, Type::String)
     } else {
         working_set.error(ParseError::Expected("utf8 raw-string", span));
@@ -1832,6 +1836,36 @@ pub fn parse_raw_string(working_set: &mut StateWorkingSet, span: Span) -> Expres
     }
 }
 
+/// Applies heredoc-style indentation stripping to a multi-line raw string.
+///
+/// If the closing `'#` sits on its own line with nothing but leading whitespace before it, that
+/// whitespace is treated as the literal's configured indentation: it's stripped from the start of
+/// every line (as much as is present), the leading newline right after the opening quote is
+/// dropped, and the now-empty closing line is removed. This lets a raw string be indented to match
+/// the surrounding code without that indentation leaking into its content. A raw string whose
+/// closing quote isn't on its own line is left untouched, so existing single-line and
+/// inline-closing raw strings keep their exact contents.
+fn dedent_raw_string(token: String) -> String {
+    let Some(rest) = token.strip_prefix('\n') else {
+        return token;
+    };
+
+    let Some(last_newline) = rest.rfind('\n') else {
+        return token;
+    };
+    let closing_line = &rest[last_newline + 1..];
+    if !closing_line.bytes().all(|b| b == b' ' || b == b'\t') {
+        return token;
+    }
+    let indent = closing_line;
+
+    rest[..last_newline]
+        .split('\n')
+        .map(|line| line.strip_prefix(indent).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 pub fn parse_paren_expr(
     working_set: &mut StateWorkingSet,
     span: Span,
@@ -2850,6 +2884,7 @@ pub fn unescape_string(bytes: &[u8], span: Span) -> (Vec<u8>, Option<ParseError>
                                         "missing '}' for unicode escape '\\u{X...}'".into(),
                                         "string".into(),
                                         Span::new(span.start + idx, span.end),
+                                        None,
                                     )));
                                     break 'us_loop;
                                 }
@@ -2883,6 +2918,7 @@ pub fn unescape_string(bytes: &[u8], span: Span) -> (Vec<u8>, Option<ParseError>
                             "invalid unicode escape '\\u{X...}', must be 1-6 hex digits, max value 10FFFF".into(),
                             "string".into(),
                             Span::new(span.start + idx, span.end),
+                            None,
                     )));
                     break 'us_loop;
                 }
@@ -2892,6 +2928,10 @@ pub fn unescape_string(bytes: &[u8], span: Span) -> (Vec<u8>, Option<ParseError>
                         "unrecognized escape after '\\'".into(),
                         "string".into(),
                         Span::new(span.start + idx, span.end),
+                        Some(
+                            "for a literal backslash, as in a Windows path, use a raw string instead: r#'...'#"
+                                .into(),
+                        ),
                     )));
                     break 'us_loop;
                 }
@@ -5046,10 +5086,12 @@ pub fn parse_assignment_expression(
     match &lhs.expr {
         Expr::FullCellPath(p) => {
             if let Expr::Var(var_id) = p.head.expr {
-                if var_id != nu_protocol::ENV_VARIABLE_ID
-                    && !working_set.get_variable(var_id).mutable
-                {
-                    working_set.error(ParseError::AssignmentRequiresMutableVar(lhs.span))
+                let variable = working_set.get_variable(var_id);
+                if var_id != nu_protocol::ENV_VARIABLE_ID && !variable.mutable {
+                    working_set.error(ParseError::AssignmentRequiresMutableVar(
+                        lhs.span,
+                        variable.declaration_span,
+                    ))
                 }
             }
         }
@@ -5778,6 +5820,7 @@ fn check_record_key_or_value(
                     "colon".to_string(),
                     format!("bare word specifying record {}", position),
                     Span::new(colon_position, colon_position + 1),
+                    None,
                 )
             })
     };
@@ -1588,6 +1588,38 @@ mod string {
         }
         panic!("wrong expression: {:?}", element.expr.expr)
     }
+
+    #[test]
+    fn parse_raw_string_strips_closing_line_indentation() {
+        let engine_state = EngineState::new();
+        let mut working_set = StateWorkingSet::new(&engine_state);
+
+        let block = parse(
+            &mut working_set,
+            None,
+            b"r#'\n    SELECT *\n    FROM foo\n    '#",
+            true,
+        );
+
+        assert!(working_set.parse_errors.is_empty());
+        let element = &block.pipelines[0].elements[0];
+        assert_eq!(
+            element.expr.expr,
+            Expr::RawString("SELECT *\nFROM foo".into())
+        );
+    }
+
+    #[test]
+    fn parse_raw_string_keeps_contents_when_closing_shares_a_line() {
+        let engine_state = EngineState::new();
+        let mut working_set = StateWorkingSet::new(&engine_state);
+
+        let block = parse(&mut working_set, None, b"r#'\n    text'#", true);
+
+        assert!(working_set.parse_errors.is_empty());
+        let element = &block.pipelines[0].elements[0];
+        assert_eq!(element.expr.expr, Expr::RawString("\n    text".into()));
+    }
 }
 
 #[rstest]
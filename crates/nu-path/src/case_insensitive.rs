@@ -0,0 +1,59 @@
+use std::path::Path;
+
+/// Compares two paths component-by-component, ignoring case on Windows (where the filesystem is
+/// normally case-insensitive) and respecting case everywhere else.
+///
+/// This only looks at path text; it never touches the filesystem, so it won't notice that two
+/// differently-cased paths happen to resolve to the same file through e.g. a symlink or bind
+/// mount. Useful for things like completions and glob matching, where a path typed by hand is
+/// compared against one already known to exist.
+pub fn eq_paths(a: impl AsRef<Path>, b: impl AsRef<Path>) -> bool {
+    let mut a = a.as_ref().components();
+    let mut b = b.as_ref().components();
+    loop {
+        match (a.next(), b.next()) {
+            (Some(a), Some(b)) => {
+                #[cfg(windows)]
+                let matches = a
+                    .as_os_str()
+                    .to_string_lossy()
+                    .eq_ignore_ascii_case(&b.as_os_str().to_string_lossy());
+                #[cfg(not(windows))]
+                let matches = a == b;
+
+                if !matches {
+                    return false;
+                }
+            }
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_paths_are_equal() {
+        assert!(eq_paths("/foo/bar", "/foo/bar"));
+    }
+
+    #[test]
+    fn different_paths_are_not_equal() {
+        assert!(!eq_paths("/foo/bar", "/foo/baz"));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn differently_cased_paths_are_equal_on_windows() {
+        assert!(eq_paths(r"C:\Users\Foo", r"c:\users\foo"));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn differently_cased_paths_are_not_equal_elsewhere() {
+        assert!(!eq_paths("/Users/Foo", "/users/foo"));
+    }
+}
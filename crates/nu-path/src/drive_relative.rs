@@ -0,0 +1,70 @@
+/// The drive letter and (possibly empty) relative tail parsed out of a Windows drive-relative
+/// path such as `d:` or `d:foo\bar`.
+///
+/// Unlike `d:\foo`, a drive-relative path has no leading separator after the colon, so it refers
+/// to a path relative to that drive's *current* directory rather than its root -- the same
+/// convention `cmd.exe` uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DriveRelativePath<'a> {
+    pub drive: char,
+    pub rest: &'a str,
+}
+
+/// Parses `path` as a Windows drive-relative path (`d:` or `d:foo\bar`), returning `None` for
+/// anything else, including drive-absolute paths like `d:\foo` or `d:/foo`.
+pub fn parse_drive_relative_path(path: &str) -> Option<DriveRelativePath<'_>> {
+    let mut chars = path.chars();
+    let drive = chars.next()?;
+    if !drive.is_ascii_alphabetic() || chars.next() != Some(':') {
+        return None;
+    }
+
+    let rest = &path[2..];
+    if rest.starts_with(['\\', '/']) {
+        // Drive-absolute, e.g. `d:\foo`; not our concern here.
+        return None;
+    }
+
+    Some(DriveRelativePath {
+        drive: drive.to_ascii_uppercase(),
+        rest,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bare_drive() {
+        let parsed = parse_drive_relative_path("d:").expect("should parse");
+        assert_eq!(parsed.drive, 'D');
+        assert_eq!(parsed.rest, "");
+    }
+
+    #[test]
+    fn parses_drive_with_relative_tail() {
+        let parsed = parse_drive_relative_path("d:foo\\bar").expect("should parse");
+        assert_eq!(parsed.drive, 'D');
+        assert_eq!(parsed.rest, "foo\\bar");
+    }
+
+    #[test]
+    fn normalizes_drive_letter_case() {
+        let parsed = parse_drive_relative_path("c:foo").expect("should parse");
+        assert_eq!(parsed.drive, 'C');
+    }
+
+    #[test]
+    fn rejects_drive_absolute_paths() {
+        assert_eq!(parse_drive_relative_path("d:\\foo"), None);
+        assert_eq!(parse_drive_relative_path("d:/foo"), None);
+    }
+
+    #[test]
+    fn rejects_paths_without_a_drive() {
+        assert_eq!(parse_drive_relative_path("foo"), None);
+        assert_eq!(parse_drive_relative_path("/foo"), None);
+        assert_eq!(parse_drive_relative_path(""), None);
+    }
+}
@@ -1,17 +1,23 @@
 #![doc = include_str!("../README.md")]
 mod assert_path_eq;
+mod case_insensitive;
 mod components;
+mod drive_relative;
 pub mod dots;
 pub mod expansions;
 pub mod form;
 mod helpers;
 mod path;
+mod secure_join;
 mod tilde;
 mod trailing_slash;
 
+pub use case_insensitive::eq_paths;
 pub use components::components;
+pub use drive_relative::{parse_drive_relative_path, DriveRelativePath};
 pub use expansions::{canonicalize_with, expand_path_with, expand_to_real_path, locate_in_dirs};
 pub use helpers::{cache_dir, data_dir, home_dir, nu_config_dir};
 pub use path::*;
+pub use secure_join::{secure_join, EscapesRootError};
 pub use tilde::expand_tilde;
 pub use trailing_slash::{has_trailing_slash, strip_trailing_slash};
@@ -0,0 +1,77 @@
+use crate::dots::expand_dots;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// Returned by [`secure_join`] when a part would escape `root` via `..`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EscapesRootError;
+
+impl fmt::Display for EscapesRootError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "path escapes the given root via '..'")
+    }
+}
+
+impl std::error::Error for EscapesRootError {}
+
+/// Join `parts` onto `root`, one at a time, rejecting the result if lexical `..` resolution
+/// would ever cross above `root`.
+///
+/// This is meant for building paths out of untrusted input (e.g. an archive member name or a
+/// request path), where a `..` segment could otherwise be used to escape the intended directory.
+/// It works purely on the text of the path: it doesn't touch the filesystem, so it can't detect
+/// an escape hidden behind a symlink that lives under `root`.
+///
+/// `root` itself is not required to exist, and is not normalized beyond what [`expand_dots`]
+/// does; pass in an already-canonicalized root if symlinks in the root's own ancestry matter.
+pub fn secure_join(
+    root: impl AsRef<Path>,
+    parts: impl IntoIterator<Item = impl AsRef<Path>>,
+) -> Result<PathBuf, EscapesRootError> {
+    let root = root.as_ref();
+    let mut result = root.to_path_buf();
+    for part in parts {
+        result.push(part);
+        result = expand_dots(&result);
+        if !result.starts_with(root) {
+            return Err(EscapesRootError);
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_path_eq;
+
+    #[test]
+    fn joins_within_root() {
+        let joined = secure_join("/root", ["foo", "bar.txt"]).unwrap();
+        assert_path_eq!(joined, "/root/foo/bar.txt");
+    }
+
+    #[test]
+    fn harmless_dotdot_is_allowed() {
+        let joined = secure_join("/root", ["foo", "..", "bar.txt"]).unwrap();
+        assert_path_eq!(joined, "/root/bar.txt");
+    }
+
+    #[test]
+    fn escaping_dotdot_is_rejected() {
+        assert_eq!(
+            secure_join("/root", ["..", "etc", "passwd"]),
+            Err(EscapesRootError)
+        );
+    }
+
+    #[test]
+    fn escaping_dotdot_mid_sequence_is_rejected() {
+        assert_eq!(
+            secure_join("/root", ["foo", "..", "..", "etc"]),
+            Err(EscapesRootError)
+        );
+    }
+}
@@ -245,6 +245,16 @@ pub trait Plugin: Sync {
 ///    serve_plugin(&MyPlugin::new(), MsgPackSerializer)
 /// }
 /// ```
+///
+/// Won't-fix: a stable in-process C ABI/vtable bridge for dynamic-library plugins has been
+/// proposed and rejected, not merely undocumented. Plugins are only ever addressed through
+/// this serialized, out-of-process protocol, never an in-process calling convention. A plugin
+/// runs as its own process and exchanges [`PluginInput`]/[`PluginOutput`] messages with the
+/// engine over stdio, which is what lets plugins be written in any language, crash without
+/// taking the engine down with them, and keeps the engine's binary interface independent of
+/// the Rust version a given plugin happened to be built with. A C ABI would need a versioned
+/// vtable maintained in lockstep across every plugin author's build and would reintroduce all
+/// three problems, so it isn't on the roadmap.
 pub fn serve_plugin(plugin: &impl Plugin, encoder: impl PluginEncoder + 'static) {
     let args: Vec<OsString> = env::args_os().skip(1).collect();
 
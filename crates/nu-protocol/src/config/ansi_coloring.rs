@@ -30,7 +30,9 @@ impl UseAnsiColoring {
     ///
     /// When set to `Auto`, the following environment variables are checked in order:
     /// 1. `FORCE_COLOR`: If set, ANSI colors are always enabled, overriding all other settings.
-    /// 2. `NO_COLOR`: If set, ANSI colors are disabled, overriding `CLICOLOR` and terminal checks.
+    /// 2. `NO_COLOR`: If present, ANSI colors are disabled, overriding `CLICOLOR` and terminal
+    ///    checks. Per the [NO_COLOR convention](https://no-color.org), this applies regardless
+    ///    of the variable's value, including an empty string.
     /// 3. `CLICOLOR`: If set, its value determines whether ANSI colors are enabled (`1` for enabled, `0` for disabled).
     ///
     /// If none of these variables are set, ANSI coloring is enabled only if the standard output is
@@ -56,7 +58,9 @@ impl UseAnsiColoring {
             return true;
         }
 
-        if env_value("no_color") {
+        // Per the NO_COLOR convention (https://no-color.org), the mere presence of
+        // `NO_COLOR` disables color, regardless of its value (even an empty string).
+        if engine_state.get_env_var_insensitive("no_color").is_some() {
             return false;
         }
 
@@ -245,4 +249,22 @@ mod tests {
             .use_ansi_coloring
             .get(&engine_state));
     }
+
+    #[test]
+    fn test_use_ansi_coloring_no_color_empty_string_still_disables() {
+        let mut engine_state = EngineState::new();
+        engine_state.config = Config {
+            use_ansi_coloring: UseAnsiColoring::Auto,
+            ..Default::default()
+        }
+        .into();
+
+        // Per the NO_COLOR convention, presence of the variable disables color even
+        // when its value is an empty string, which `coerce_bool` would treat as falsy.
+        engine_state.add_env_var("no_color".to_string(), Value::test_string(""));
+        assert!(!engine_state
+            .get_config()
+            .use_ansi_coloring
+            .get(&engine_state));
+    }
 }
@@ -106,6 +106,9 @@ pub struct CompletionConfig {
     pub algorithm: CompletionAlgorithm,
     pub external: ExternalCompleterConfig,
     pub use_ls_colors: bool,
+    /// Fall back to the completion engine for the inline hint when history has no suggestion
+    /// for the current line.
+    pub use_completer_hint: bool,
 }
 
 impl Default for CompletionConfig {
@@ -118,6 +121,7 @@ impl Default for CompletionConfig {
             algorithm: CompletionAlgorithm::default(),
             external: ExternalCompleterConfig::default(),
             use_ls_colors: true,
+            use_completer_hint: false,
         }
     }
 }
@@ -144,6 +148,7 @@ impl UpdateFromValue for CompletionConfig {
                 "case_sensitive" => self.case_sensitive.update(val, path, errors),
                 "external" => self.external.update(val, path, errors),
                 "use_ls_colors" => self.use_ls_colors.update(val, path, errors),
+                "use_completer_hint" => self.use_completer_hint.update(val, path, errors),
                 _ => errors.unknown_option(path, val),
             }
         }
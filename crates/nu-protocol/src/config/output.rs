@@ -6,6 +6,9 @@ use crate::{self as nu_protocol};
 pub enum ErrorStyle {
     Plain,
     Fancy,
+    /// Render errors as a single line of machine-readable JSON (code, message,
+    /// spans, source excerpt), for wrappers and CI to parse.
+    Json,
 }
 
 impl FromStr for ErrorStyle {
@@ -15,7 +18,8 @@ impl FromStr for ErrorStyle {
         match s.to_ascii_lowercase().as_str() {
             "fancy" => Ok(Self::Fancy),
             "plain" => Ok(Self::Plain),
-            _ => Err("'fancy' or 'plain'"),
+            "json" => Ok(Self::Json),
+            _ => Err("'fancy', 'plain', or 'json'"),
         }
     }
 }
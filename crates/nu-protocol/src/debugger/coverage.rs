@@ -0,0 +1,62 @@
+//! Nushell coverage collector
+//!
+//! CoverageCollector implements the Debugger trait and is used via the `debug coverage` command
+//! to record which lines of a script were actually executed, for measuring test coverage of nu
+//! script libraries.
+
+use super::{profiler::find_file_of_span, Debugger};
+use crate::{ast::PipelineElement, engine::EngineState, record, ShellError, Span, Value};
+use std::collections::BTreeMap;
+
+/// Collects per-file line hit counts by recording the line of every pipeline element the
+/// evaluator enters.
+#[derive(Debug, Clone, Default)]
+pub struct CoverageCollector {
+    // file name -> executed line numbers (1-indexed) -> number of times executed
+    hits: BTreeMap<String, BTreeMap<usize, usize>>,
+}
+
+impl CoverageCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Per-file map of executed line number to hit count, for formatting a coverage report.
+    pub fn hits(&self) -> &BTreeMap<String, BTreeMap<usize, usize>> {
+        &self.hits
+    }
+}
+
+impl Debugger for CoverageCollector {
+    fn enter_element(&mut self, engine_state: &EngineState, element: &PipelineElement) {
+        if let Some((file, line)) = find_file_of_span(engine_state, element.expr.span) {
+            *self
+                .hits
+                .entry(file.to_string())
+                .or_default()
+                .entry(line)
+                .or_insert(0) += 1;
+        }
+    }
+
+    fn report(&self, _engine_state: &EngineState, span: Span) -> Result<Value, ShellError> {
+        let rows = self
+            .hits
+            .iter()
+            .flat_map(|(file, lines)| {
+                lines.iter().map(move |(line, count)| {
+                    Value::record(
+                        record! {
+                            "file" => Value::string(file.clone(), span),
+                            "line" => Value::int(*line as i64, span),
+                            "count" => Value::int(*count as i64, span),
+                        },
+                        span,
+                    )
+                })
+            })
+            .collect();
+
+        Ok(Value::list(rows, span))
+    }
+}
@@ -1,6 +1,8 @@
 //! Module containing the trait to instrument the engine for debugging and profiling
+pub mod coverage;
 pub mod debugger_trait;
 pub mod profiler;
 
+pub use coverage::*;
 pub use debugger_trait::*;
 pub use profiler::*;
@@ -369,7 +369,7 @@ fn format_result(
 }
 
 // Find a file name and a line number (indexed from 1) of a span
-fn find_file_of_span(engine_state: &EngineState, span: Span) -> Option<(&str, usize)> {
+pub(crate) fn find_file_of_span(engine_state: &EngineState, span: Span) -> Option<(&str, usize)> {
     for file in engine_state.files() {
         if file.covered_span.contains_span(span) {
             // count the number of lines between file start and the searched span start
@@ -0,0 +1,32 @@
+/// Describes a [`Command`](crate::engine::Command) (or one of its flags) that's on its way out.
+///
+/// Attach one via [`Command::deprecation_info`](crate::engine::Command::deprecation_info) to have
+/// `help` flag the command and to have the engine emit a warning the first time it's called in a
+/// session. This only covers whole commands and flags; for parameter-level or more nuanced
+/// migrations, mention them in the command's `extra_description` instead.
+#[derive(Debug, Clone)]
+pub struct Deprecation {
+    /// The flag being deprecated, or `None` if the whole command is deprecated.
+    pub flag: Option<String>,
+    /// What to do instead, e.g. `"use `str trim` instead"`.
+    pub suggestion: String,
+    /// The release the command is expected to be removed in, if decided.
+    pub expected_removal: Option<String>,
+}
+
+impl Deprecation {
+    /// A human-readable warning suitable for display to the user, e.g. in a REPL or `help` entry.
+    pub fn message(&self, command_name: &str) -> String {
+        let subject = match &self.flag {
+            Some(flag) => format!("`{command_name} --{flag}`"),
+            None => format!("`{command_name}`"),
+        };
+        match &self.expected_removal {
+            Some(version) => format!(
+                "{subject} is deprecated and will be removed in {version}; {}",
+                self.suggestion
+            ),
+            None => format!("{subject} is deprecated; {}", self.suggestion),
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use super::{EngineState, Stack, StateWorkingSet};
-use crate::{engine::Call, Alias, BlockId, Example, OutDest, PipelineData, ShellError, Signature};
+use crate::{
+    engine::Call, Alias, BlockId, Deprecation, Example, OutDest, PipelineData, ShellError,
+    Signature,
+};
 use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -73,6 +76,14 @@ pub trait Command: Send + Sync + CommandClone {
         vec![]
     }
 
+    /// If this command (or one of its flags) is deprecated, describes what to use instead.
+    ///
+    /// `help` marks a deprecated command in its listing, and the engine emits a warning the
+    /// first time the command is called in a session.
+    fn deprecation_info(&self) -> Vec<Deprecation> {
+        Vec::new()
+    }
+
     // Whether can run in const evaluation in the parser
     fn is_const(&self) -> bool {
         false
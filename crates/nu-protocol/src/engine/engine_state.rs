@@ -16,7 +16,7 @@ use lru::LruCache;
 use nu_path::AbsolutePathBuf;
 use nu_utils::IgnoreCaseExt;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     num::NonZeroUsize,
     path::PathBuf,
     sync::{
@@ -24,6 +24,7 @@ use std::{
         Arc, Mutex, MutexGuard, PoisonError,
     },
 };
+use web_time::Instant;
 
 type PoisonDebuggerError<'a> = PoisonError<MutexGuard<'a, Box<dyn Debugger>>>;
 
@@ -105,11 +106,22 @@ pub struct EngineState {
     // Path to the file Nushell is currently evaluating, or None if we're in an interactive session.
     pub file: Option<PathBuf>,
     pub regex_cache: Arc<Mutex<LruCache<String, Regex>>>,
+    /// Results of `cached` calls, keyed by cache key, along with when each result was produced
+    /// so `cached` can tell whether its `--ttl` has elapsed.
+    pub cached_values: Arc<Mutex<HashMap<String, (Instant, Value)>>>,
+    /// `(decl, flag)` pairs whose [`Deprecation`](crate::Deprecation) warning has already been
+    /// shown this session, so calling a deprecated command repeatedly doesn't spam the user.
+    /// `flag` is `None` for a whole-command deprecation.
+    pub warned_deprecations: Arc<Mutex<HashSet<(DeclId, Option<String>)>>>,
     pub is_interactive: bool,
     pub is_login: bool,
     startup_time: i64,
     is_debugging: IsDebugging,
     pub debugger: Arc<Mutex<Box<dyn Debugger>>>,
+    /// Remembered current directory for each Windows drive letter, so that `cd d:` can switch
+    /// back to wherever that drive was last visited, matching `cmd.exe`. Uppercase drive letters
+    /// are used as keys. No-op on platforms without drive letters.
+    per_drive_cwd: Arc<Mutex<HashMap<char, PathBuf>>>,
 }
 
 // The max number of compiled regexes to keep around in a LRU cache, arbitrarily chosen
@@ -174,11 +186,32 @@ impl EngineState {
             regex_cache: Arc::new(Mutex::new(LruCache::new(
                 NonZeroUsize::new(REGEX_CACHE_SIZE).expect("tried to create cache of size zero"),
             ))),
+            cached_values: Arc::new(Mutex::new(HashMap::new())),
+            warned_deprecations: Arc::new(Mutex::new(HashSet::new())),
             is_interactive: false,
             is_login: false,
             startup_time: -1,
             is_debugging: IsDebugging::new(false),
             debugger: Arc::new(Mutex::new(Box::new(NoopDebugger))),
+            per_drive_cwd: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the last remembered current directory for `drive`, if any, for resolving
+    /// drive-relative paths like `d:` or `d:foo`.
+    pub fn remembered_drive_cwd(&self, drive: char) -> Option<PathBuf> {
+        self.per_drive_cwd
+            .lock()
+            .ok()?
+            .get(&drive.to_ascii_uppercase())
+            .cloned()
+    }
+
+    /// Remembers `cwd` as the current directory for `drive`, so a later `cd` back to that drive
+    /// returns to it.
+    pub fn remember_drive_cwd(&self, drive: char, cwd: PathBuf) {
+        if let Ok(mut per_drive_cwd) = self.per_drive_cwd.lock() {
+            per_drive_cwd.insert(drive.to_ascii_uppercase(), cwd);
         }
     }
 
@@ -693,20 +726,22 @@ impl EngineState {
         &self,
         mut predicate: impl FnMut(&[u8]) -> bool,
         ignore_deprecated: bool,
-    ) -> Vec<(Vec<u8>, Option<String>, CommandType)> {
+    ) -> Vec<(Vec<u8>, Option<String>, CommandType, Category)> {
         let mut output = vec![];
 
         for overlay_frame in self.active_overlays(&[]).rev() {
             for decl in &overlay_frame.decls {
                 if overlay_frame.visibility.is_decl_id_visible(decl.1) && predicate(decl.0) {
                     let command = self.get_decl(*decl.1);
-                    if ignore_deprecated && command.signature().category == Category::Removed {
+                    let category = command.signature().category;
+                    if ignore_deprecated && category == Category::Removed {
                         continue;
                     }
                     output.push((
                         decl.0.clone(),
                         Some(command.description().to_string()),
                         command.command_type(),
+                        category,
                     ));
                 }
             }
@@ -780,6 +815,14 @@ impl EngineState {
             .as_ref()
     }
 
+    /// Records that the deprecation warning for `decl_id`/`flag` has now been shown, returning
+    /// `true` the first time this is called for a given pair in a session and `false` afterward.
+    pub fn notify_deprecation_once(&self, decl_id: DeclId, flag: Option<String>) -> bool {
+        self.warned_deprecations
+            .lock()
+            .is_ok_and(|mut warned| warned.insert((decl_id, flag)))
+    }
+
     /// Get all commands within scope, sorted by the commands' names
     pub fn get_decls_sorted(&self, include_hidden: bool) -> Vec<(Vec<u8>, DeclId)> {
         let mut decls_map = HashMap::new();
@@ -1019,6 +1062,12 @@ impl EngineState {
                 NonZeroUsize::new(REGEX_CACHE_SIZE).expect("tried to create cache of size zero"),
             )));
         }
+        if Mutex::is_poisoned(&self.cached_values) {
+            self.cached_values = Arc::new(Mutex::new(HashMap::new()));
+        }
+        if Mutex::is_poisoned(&self.warned_deprecations) {
+            self.warned_deprecations = Arc::new(Mutex::new(HashSet::new()));
+        }
     }
 
     /// Add new span and return its ID
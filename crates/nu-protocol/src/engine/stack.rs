@@ -3,13 +3,14 @@ use crate::{
         ArgumentStack, EngineState, ErrorHandlerStack, Redirection, StackCallArgGuard,
         StackCollectValueGuard, StackIoGuard, StackOutDest, DEFAULT_OVERLAY_NAME,
     },
-    Config, IntoValue, OutDest, ShellError, Span, Value, VarId, ENV_VARIABLE_ID, NU_VARIABLE_ID,
+    BlockId, Config, IntoValue, OutDest, ShellError, Span, Value, VarId, ENV_VARIABLE_ID,
+    NU_VARIABLE_ID,
 };
 use nu_utils::IgnoreCaseExt;
 use std::{
     collections::{HashMap, HashSet},
     fs::File,
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 
 /// Environment variables per overlay
@@ -53,6 +54,23 @@ pub struct Stack {
     /// Locally updated config. Use [`.get_config()`](Self::get_config) to access correctly.
     pub config: Option<Arc<Config>>,
     pub(crate) out_dest: StackOutDest,
+    /// Cache of `ENV_CONVERSIONS` results, keyed by variable name. Shared across clones so that
+    /// a value converted once (e.g. `PATH`) isn't re-converted on every block evaluation. A cache
+    /// hit requires both the raw string value *and* the `from_string` closure's [`BlockId`] to
+    /// still match what produced the cached value, so redefining `ENV_CONVERSIONS.PATH.from_string`
+    /// invalidates the entry even though `$env.PATH` itself didn't change. Kept private: use
+    /// [`Stack::cached_env_conversion`]/[`Stack::cache_env_conversion`] rather than reaching in
+    /// directly, so this invariant can't be bypassed from outside.
+    env_conversion_cache: Arc<Mutex<HashMap<String, CachedEnvConversion>>>,
+}
+
+/// A cached `ENV_CONVERSIONS` result: converting `raw` through the closure identified by
+/// `conversion_block` previously produced `converted`.
+#[derive(Debug, Clone)]
+struct CachedEnvConversion {
+    raw: Value,
+    conversion_block: BlockId,
+    converted: Value,
 }
 
 impl Default for Stack {
@@ -82,6 +100,7 @@ impl Stack {
             parent_deletions: vec![],
             config: None,
             out_dest: StackOutDest::new(),
+            env_conversion_cache: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -102,6 +121,7 @@ impl Stack {
             parent_deletions: vec![],
             config: parent.config.clone(),
             out_dest: parent.out_dest.clone(),
+            env_conversion_cache: parent.env_conversion_cache.clone(),
             parent_stack: Some(parent),
         }
     }
@@ -251,6 +271,20 @@ impl Stack {
     }
 
     pub fn add_env_var(&mut self, var: String, value: Value) {
+        // The variable is being overwritten with a new raw value, so any cached
+        // `ENV_CONVERSIONS` result for it is no longer valid.
+        if let Ok(mut cache) = self.env_conversion_cache.lock() {
+            cache.remove(&var);
+        }
+
+        self.set_env_var_value(var, value)
+    }
+
+    /// Like [`Stack::add_env_var`], but doesn't invalidate the `ENV_CONVERSIONS` cache.
+    ///
+    /// Used by `convert_env_vars` to install a (possibly cached) converted value without
+    /// discarding the cache entry that produced it.
+    pub fn set_env_var_value(&mut self, var: String, value: Value) {
         if let Some(last_overlay) = self.active_overlays.last() {
             if let Some(env_hidden) = Arc::make_mut(&mut self.env_hidden).get_mut(last_overlay) {
                 // if the env var was hidden, let's activate it again
@@ -277,10 +311,59 @@ impl Stack {
         }
     }
 
+    /// Looks up a cached `ENV_CONVERSIONS` result for `var`, returning the previously converted
+    /// value only if `raw` and `conversion_block` both still match what produced it.
+    pub fn cached_env_conversion(
+        &self,
+        var: &str,
+        raw: &Value,
+        conversion_block: BlockId,
+    ) -> Option<Value> {
+        self.env_conversion_cache
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(var).cloned())
+            .filter(|cached| cached.raw == *raw && cached.conversion_block == conversion_block)
+            .map(|cached| cached.converted)
+    }
+
+    /// Records that converting `raw` through the `from_string` closure identified by
+    /// `conversion_block` produced `converted`, for [`Stack::cached_env_conversion`] to reuse
+    /// until either input changes.
+    pub fn cache_env_conversion(
+        &self,
+        var: String,
+        raw: Value,
+        conversion_block: BlockId,
+        converted: Value,
+    ) {
+        if let Ok(mut cache) = self.env_conversion_cache.lock() {
+            cache.insert(
+                var,
+                CachedEnvConversion {
+                    raw,
+                    conversion_block,
+                    converted,
+                },
+            );
+        }
+    }
+
     pub fn set_last_exit_code(&mut self, code: i32, span: Span) {
         self.add_env_var("LAST_EXIT_CODE".into(), Value::int(code.into(), span));
     }
 
+    /// Set `$env.PIPESTATUS` to the raw exit code of each external command in the last pipeline,
+    /// in pipeline order. Mirrors the `$PIPESTATUS`/`PIPESTATUS` array found in bash/zsh, so a
+    /// script can tell which stage of `foo | bar | baz` failed rather than only the last one.
+    pub fn set_pipeline_exit_codes(&mut self, codes: Vec<i32>, span: Span) {
+        let codes = codes
+            .into_iter()
+            .map(|code| Value::int(code.into(), span))
+            .collect();
+        self.add_env_var("PIPESTATUS".into(), Value::list(codes, span));
+    }
+
     pub fn set_last_error(&mut self, error: &ShellError) {
         if let Some(code) = error.external_exit_code() {
             self.set_last_exit_code(code.item, code.span);
@@ -319,6 +402,7 @@ impl Stack {
             parent_deletions: vec![],
             config: self.config.clone(),
             out_dest: self.out_dest.clone(),
+            env_conversion_cache: self.env_conversion_cache.clone(),
         }
     }
 
@@ -352,6 +436,7 @@ impl Stack {
             parent_deletions: vec![],
             config: self.config.clone(),
             out_dest: self.out_dest.clone(),
+            env_conversion_cache: self.env_conversion_cache.clone(),
         }
     }
 
@@ -770,7 +855,7 @@ impl Stack {
 mod test {
     use std::sync::Arc;
 
-    use crate::{engine::EngineState, Span, Value, VarId};
+    use crate::{engine::EngineState, BlockId, Span, Value, VarId};
 
     use super::Stack;
 
@@ -885,4 +970,40 @@ mod test {
             Some(Value::test_string("New Env Var")),
         );
     }
+
+    #[test]
+    fn test_env_conversion_cache_hit() {
+        let stack = Stack::new();
+        let raw = Value::test_string("/usr/bin:/bin");
+        let converted = Value::test_list(vec![
+            Value::test_string("/usr/bin"),
+            Value::test_string("/bin"),
+        ]);
+        let block_id = BlockId::new(0);
+
+        stack.cache_env_conversion("PATH".into(), raw.clone(), block_id, converted.clone());
+
+        assert_eq!(
+            stack.cached_env_conversion("PATH", &raw, block_id),
+            Some(converted)
+        );
+    }
+
+    #[test]
+    fn test_env_conversion_cache_invalidated_by_new_closure() {
+        let stack = Stack::new();
+        let raw = Value::test_string("/usr/bin:/bin");
+        let converted = Value::test_list(vec![
+            Value::test_string("/usr/bin"),
+            Value::test_string("/bin"),
+        ]);
+        let old_block_id = BlockId::new(0);
+        let new_block_id = BlockId::new(1);
+
+        stack.cache_env_conversion("PATH".into(), raw.clone(), old_block_id, converted);
+
+        // The raw value is unchanged, but `ENV_CONVERSIONS.PATH.from_string` was redefined
+        // (i.e. it now points at a different block), so the cached entry must not be served.
+        assert_eq!(stack.cached_env_conversion("PATH", &raw, new_block_id), None);
+    }
 }
@@ -753,7 +753,7 @@ impl<'a> StateWorkingSet<'a> {
         &self,
         mut predicate: impl FnMut(&[u8]) -> bool,
         ignore_deprecated: bool,
-    ) -> Vec<(Vec<u8>, Option<String>, CommandType)> {
+    ) -> Vec<(Vec<u8>, Option<String>, CommandType, Category)> {
         let mut output = vec![];
 
         for scope_frame in self.delta.scope.iter().rev() {
@@ -763,13 +763,15 @@ impl<'a> StateWorkingSet<'a> {
                 for decl in &overlay_frame.decls {
                     if overlay_frame.visibility.is_decl_id_visible(decl.1) && predicate(decl.0) {
                         let command = self.get_decl(*decl.1);
-                        if ignore_deprecated && command.signature().category == Category::Removed {
+                        let category = command.signature().category;
+                        if ignore_deprecated && category == Category::Removed {
                             continue;
                         }
                         output.push((
                             decl.0.clone(),
                             Some(command.description().to_string()),
                             command.command_type(),
+                            category,
                         ));
                     }
                 }
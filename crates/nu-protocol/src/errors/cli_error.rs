@@ -3,11 +3,11 @@
 //! Relies on the `miette` crate for pretty layout
 use crate::{
     engine::{EngineState, StateWorkingSet},
-    CompileError, ErrorStyle, ParseError, ParseWarning, ShellError,
+    CompileError, ErrorStyle, LabeledError, ParseError, ParseWarning, ShellError,
 };
 use miette::{
-    LabeledSpan, MietteHandlerOpts, NarratableReportHandler, ReportHandler, RgbColors, Severity,
-    SourceCode,
+    Diagnostic, LabeledSpan, MietteHandlerOpts, NarratableReportHandler, ReportHandler, RgbColors,
+    Severity, SourceCode,
 };
 use thiserror::Error;
 
@@ -21,7 +21,16 @@ struct CliError<'src>(
 );
 
 pub fn format_shell_error(working_set: &StateWorkingSet, error: &ShellError) -> String {
-    format!("Error: {:?}", CliError(error, working_set))
+    let mut rendered = render_cli_error(working_set, error, "Error: ");
+    // The JSON error style emits one machine-readable line per error; a human-oriented
+    // "Caused by:" trailer would break that contract, so causes are left out there.
+    if working_set.get_config().error_style != ErrorStyle::Json {
+        for cause in error.causes() {
+            rendered.push_str("\nCaused by:\n  ");
+            rendered.push_str(&render_cli_error(working_set, cause, "").replace('\n', "\n  "));
+        }
+    }
+    rendered
 }
 
 pub fn report_shell_error(engine_state: &EngineState, error: &ShellError) {
@@ -49,7 +58,7 @@ pub fn report_compile_error(working_set: &StateWorkingSet, error: &CompileError)
 }
 
 fn report_error(working_set: &StateWorkingSet, error: &dyn miette::Diagnostic) {
-    eprintln!("Error: {:?}", CliError(error, working_set));
+    eprintln!("{}", render_cli_error(working_set, error, "Error: "));
     // reset vt processing, aka ansi because illbehaved externals can break it
     #[cfg(windows)]
     {
@@ -58,7 +67,7 @@ fn report_error(working_set: &StateWorkingSet, error: &dyn miette::Diagnostic) {
 }
 
 fn report_warning(working_set: &StateWorkingSet, error: &dyn miette::Diagnostic) {
-    eprintln!("Warning: {:?}", CliError(error, working_set));
+    eprintln!("{}", render_cli_error(working_set, error, "Warning: "));
     // reset vt processing, aka ansi because illbehaved externals can break it
     #[cfg(windows)]
     {
@@ -66,6 +75,21 @@ fn report_warning(working_set: &StateWorkingSet, error: &dyn miette::Diagnostic)
     }
 }
 
+// With `ErrorStyle::Json`, the rendered line is meant to be parsed by wrappers and CI, so it
+// is emitted on its own without the human-oriented "Error: "/"Warning: " prefix.
+fn render_cli_error(
+    working_set: &StateWorkingSet,
+    error: &dyn miette::Diagnostic,
+    prefix: &str,
+) -> String {
+    let cli_error = CliError(error, working_set);
+    if working_set.get_config().error_style == ErrorStyle::Json {
+        format!("{cli_error:?}")
+    } else {
+        format!("{prefix}{cli_error:?}")
+    }
+}
+
 impl std::fmt::Debug for CliError<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let config = self.1.get_config();
@@ -76,6 +100,12 @@ impl std::fmt::Debug for CliError<'_> {
 
         let miette_handler: Box<dyn ReportHandler> = match error_style {
             ErrorStyle::Plain => Box::new(NarratableReportHandler::new()),
+            ErrorStyle::Json => {
+                // Ignore error to prevent format! panics, for consistency with the other
+                // error styles below.
+                let _ = write!(f, "{}", self.to_json_string());
+                return Ok(());
+            }
             ErrorStyle::Fancy => Box::new(
                 MietteHandlerOpts::new()
                     // For better support of terminal themes use the ANSI coloring
@@ -96,6 +126,43 @@ impl std::fmt::Debug for CliError<'_> {
     }
 }
 
+impl CliError<'_> {
+    /// Render this error as a single line of JSON containing its message, code, help text, and
+    /// labeled spans (each with a source excerpt), for `ErrorStyle::Json`.
+    fn to_json_string(&self) -> String {
+        let labeled = LabeledError::from_diagnostic(self);
+
+        let labels: Vec<serde_json::Value> = labeled
+            .labels
+            .iter()
+            .map(|label| {
+                let span: miette::SourceSpan =
+                    (label.span.start, label.span.end - label.span.start).into();
+                let excerpt = self
+                    .source_code()
+                    .and_then(|source| source.read_span(&span, 0, 0).ok())
+                    .map(|contents| String::from_utf8_lossy(contents.data()).into_owned());
+
+                serde_json::json!({
+                    "label": label.text,
+                    "start": label.span.start,
+                    "end": label.span.end,
+                    "source": excerpt,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "message": labeled.msg,
+            "code": labeled.code,
+            "help": labeled.help,
+            "url": labeled.url,
+            "labels": labels,
+        })
+        .to_string()
+    }
+}
+
 impl<'src> miette::Diagnostic for CliError<'src> {
     fn code<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
         self.0.code()
@@ -134,3 +201,30 @@ impl<'src> miette::Diagnostic for CliError<'src> {
         self.0.diagnostic_source()
     }
 }
+
+#[test]
+fn format_shell_error_as_json() {
+    use crate::{engine::EngineState, Config, Span};
+
+    let mut engine_state = EngineState::new();
+    engine_state.config = Config {
+        error_style: ErrorStyle::Json,
+        ..Default::default()
+    }
+    .into();
+
+    let working_set = StateWorkingSet::new(&engine_state);
+    let error = ShellError::CantConvert {
+        span: Span::new(0, 3),
+        to_type: "Foo".into(),
+        from_type: "Bar".into(),
+        help: None,
+    };
+
+    let rendered = format_shell_error(&working_set, &error);
+    assert!(!rendered.starts_with("Error: "), "{rendered}");
+
+    let json: serde_json::Value = serde_json::from_str(&rendered).expect("valid JSON");
+    assert_eq!(json["message"], error.to_string());
+    assert!(json["labels"][0]["start"].is_number());
+}
@@ -0,0 +1,26 @@
+use crate::Span;
+use serde::{Deserialize, Serialize};
+
+/// A concrete, machine-applicable correction for a diagnostic: replace the text at `span` with
+/// `replacement`. Used to surface quick-fixes through the LSP (as code actions) and in the REPL
+/// (as a "did you mean, press tab to apply" hint), without either of those callers needing to
+/// know anything about the diagnostic that produced it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fix {
+    /// The span of source text to replace.
+    pub span: Span,
+    /// The text to replace it with.
+    pub replacement: String,
+    /// A short, human-readable summary of what applying the fix does, e.g. "Replace with `--all`".
+    pub description: String,
+}
+
+impl Fix {
+    pub fn new(span: Span, replacement: impl Into<String>, description: impl Into<String>) -> Fix {
+        Fix {
+            span,
+            replacement: replacement.into(),
+            description: description.into(),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 pub mod cli_error;
 mod compile_error;
 mod config_error;
+mod fix;
 mod labeled_error;
 mod parse_error;
 mod parse_warning;
@@ -12,6 +13,7 @@ pub use cli_error::{
 };
 pub use compile_error::CompileError;
 pub use config_error::ConfigError;
+pub use fix::Fix;
 pub use labeled_error::{ErrorLabel, LabeledError};
 pub use parse_error::{DidYouMean, ParseError};
 pub use parse_warning::ParseWarning;
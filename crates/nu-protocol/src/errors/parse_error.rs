@@ -3,7 +3,7 @@ use std::{
     str::{from_utf8, Utf8Error},
 };
 
-use crate::{ast::RedirectionSource, did_you_mean, Span, Type};
+use crate::{ast::RedirectionSource, did_you_mean, Fix, Span, Type};
 use miette::Diagnostic;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -252,6 +252,13 @@ pub enum ParseError {
     )]
     ModuleNotFound(#[label = "module {1} not found"] Span, String),
 
+    #[error("URL-based modules are not supported.")]
+    #[diagnostic(
+        code(nu::parser::module_url_not_supported),
+        help("`source`/`use` only resolve modules from the local filesystem. Download the module yourself and `use` the local path instead.")
+    )]
+    ModuleUrlNotSupported(#[label = "module paths can't be URLs"] Span, String),
+
     #[error("Missing mod.nu file.")]
     #[diagnostic(
         code(nu::parser::module_missing_mod_nu_file),
@@ -352,7 +359,13 @@ pub enum ParseError {
 
     #[error("The `{0}` command doesn't have flag `{1}`.")]
     #[diagnostic(code(nu::parser::unknown_flag), help("{3}"))]
-    UnknownFlag(String, String, #[label = "unknown flag"] Span, String),
+    UnknownFlag(
+        String,
+        String,
+        #[label = "unknown flag"] Span,
+        String,
+        Vec<String>,
+    ),
 
     #[error("Unknown type.")]
     #[diagnostic(code(nu::parser::unknown_type))]
@@ -488,15 +501,20 @@ pub enum ParseError {
     },
 
     #[error("Invalid literal")] // <problem> in <entity>.
-    #[diagnostic()]
-    InvalidLiteral(String, String, #[label("{0} in {1}")] Span),
+    #[diagnostic(code(nu::parser::invalid_literal))]
+    InvalidLiteral(
+        String,
+        String,
+        #[label("{0} in {1}")] Span,
+        #[help] Option<String>,
+    ),
 
     #[error("{0}")]
-    #[diagnostic()]
+    #[diagnostic(code(nu::parser::labeled_error))]
     LabeledError(String, String, #[label("{1}")] Span),
 
     #[error("{error}")]
-    #[diagnostic(help("{help}"))]
+    #[diagnostic(code(nu::parser::labeled_error), help("{help}"))]
     LabeledErrorWithHelp {
         error: String,
         label: String,
@@ -506,7 +524,7 @@ pub enum ParseError {
     },
 
     #[error("Redirection can not be used with {0}.")]
-    #[diagnostic()]
+    #[diagnostic(code(nu::parser::redirecting_builtin_command))]
     RedirectingBuiltinCommand(
         &'static str,
         #[label("not allowed here")] Span,
@@ -530,7 +548,12 @@ pub enum ParseError {
         code(nu::parser::assignment_requires_mutable_variable),
         help("declare the variable with `mut`, or shadow it again with `let`")
     )]
-    AssignmentRequiresMutableVar(#[label("needs to be a mutable variable")] Span),
+    AssignmentRequiresMutableVar(
+        #[label("needs to be a mutable variable")] Span,
+        /// Span of the variable's name at its `let`/`const` declaration, used to offer a
+        /// "add missing `mut`" fix.
+        Span,
+    ),
 
     /// Invalid assignment left-hand side
     ///
@@ -576,6 +599,7 @@ impl ParseError {
             ParseError::AliasNotValid(s) => *s,
             ParseError::CommandDefNotValid(s) => *s,
             ParseError::ModuleNotFound(s, _) => *s,
+            ParseError::ModuleUrlNotSupported(s, _) => *s,
             ParseError::ModuleMissingModNuFile(_, s) => *s,
             ParseError::NamedAsModule(_, _, _, s) => *s,
             ParseError::ModuleDoubleMain(_, s) => *s,
@@ -590,7 +614,7 @@ impl ParseError {
             ParseError::DuplicateCommandDef(s) => *s,
             ParseError::UnknownCommand(s) => *s,
             ParseError::NonUtf8(s) => *s,
-            ParseError::UnknownFlag(_, _, s, _) => *s,
+            ParseError::UnknownFlag(_, _, s, _, _) => *s,
             ParseError::RequiredAfterOptional(_, s) => *s,
             ParseError::UnknownType(s) => *s,
             ParseError::MissingFlagParam(_, s) => *s,
@@ -627,13 +651,34 @@ impl ParseError {
             ParseError::MultipleRedirections(_, _, s) => *s,
             ParseError::UnexpectedRedirection { span } => *span,
             ParseError::UnknownOperator(_, _, s) => *s,
-            ParseError::InvalidLiteral(_, _, s) => *s,
+            ParseError::InvalidLiteral(_, _, s, _) => *s,
             ParseError::LabeledErrorWithHelp { span: s, .. } => *s,
             ParseError::RedirectingBuiltinCommand(_, s, _) => *s,
             ParseError::UnexpectedSpreadArg(_, s) => *s,
             ParseError::ExtraTokensAfterClosingDelimiter(s) => *s,
             ParseError::AssignmentRequiresVar(s) => *s,
-            ParseError::AssignmentRequiresMutableVar(s) => *s,
+            ParseError::AssignmentRequiresMutableVar(s, _) => *s,
+        }
+    }
+
+    /// A suggested edit that would resolve this error, if one can be determined automatically.
+    pub fn fix(&self) -> Option<Fix> {
+        match self {
+            ParseError::UnknownFlag(_, flag, span, _, available) => {
+                let prefix = if flag.starts_with("--") { "--" } else { "-" };
+                let suggestion = did_you_mean(available, flag.trim_start_matches('-'))?;
+                Some(Fix::new(
+                    *span,
+                    format!("{prefix}{suggestion}"),
+                    format!("Replace with `{prefix}{suggestion}`"),
+                ))
+            }
+            ParseError::AssignmentRequiresMutableVar(_, declaration_span) => Some(Fix::new(
+                Span::new(declaration_span.start, declaration_span.start),
+                "mut ",
+                "Add missing `mut`",
+            )),
+            _ => None,
         }
     }
 }
@@ -1152,7 +1152,7 @@ pub enum ShellError {
 
     /// This is a generic error type used for different situations.
     #[error("{error}")]
-    #[diagnostic()]
+    #[diagnostic(code(nu::shell::generic_error))]
     GenericError {
         error: String,
         msg: String,
@@ -1166,7 +1166,7 @@ pub enum ShellError {
 
     /// This is a generic error type used for different situations.
     #[error("{error}")]
-    #[diagnostic()]
+    #[diagnostic(code(nu::shell::outside_spanned_labeled_error))]
     OutsideSpannedLabeledError {
         #[source_code]
         src: String,
@@ -1208,6 +1208,7 @@ pub enum ShellError {
 
     /// Break event, which may become an error if used outside of a loop
     #[error("Break used outside of loop")]
+    #[diagnostic(code(nu::shell::break_not_in_loop))]
     Break {
         #[label("used outside of loop")]
         span: Span,
@@ -1215,6 +1216,7 @@ pub enum ShellError {
 
     /// Continue event, which may become an error if used outside of a loop
     #[error("Continue used outside of loop")]
+    #[diagnostic(code(nu::shell::continue_not_in_loop))]
     Continue {
         #[label("used outside of loop")]
         span: Span,
@@ -1222,6 +1224,7 @@ pub enum ShellError {
 
     /// Return event, which may become an error if used outside of a custom command or closure
     #[error("Return used outside of custom command or closure")]
+    #[diagnostic(code(nu::shell::return_not_in_command))]
     Return {
         #[label("used outside of custom command or closure")]
         span: Span,
@@ -1243,6 +1246,7 @@ pub enum ShellError {
 
     /// Operation interrupted
     #[error("Operation interrupted")]
+    #[diagnostic(code(nu::shell::interrupted))]
     Interrupted {
         #[label("This operation was interrupted")]
         span: Span,
@@ -1250,6 +1254,7 @@ pub enum ShellError {
 
     /// Operation interrupted by user
     #[error("Operation interrupted by user")]
+    #[diagnostic(code(nu::shell::interrupted_by_user))]
     InterruptedByUser {
         #[label("This operation was interrupted")]
         span: Option<Span>,
@@ -1331,7 +1336,7 @@ This is an internal Nushell error, please file an issue https://github.com/nushe
     },
 
     #[error("{deprecated} is deprecated and will be removed in a future release")]
-    #[diagnostic()]
+    #[diagnostic(code(nu::shell::deprecated))]
     Deprecated {
         deprecated: &'static str,
         suggestion: &'static str,
@@ -1492,6 +1497,13 @@ impl ShellError {
 
     pub fn into_value(self, working_set: &StateWorkingSet, span: Span) -> Value {
         let exit_code = self.external_exit_code();
+        let cause = Value::list(
+            self.causes()
+                .cloned()
+                .map(|cause| cause.into_value(working_set, span))
+                .collect(),
+            span,
+        );
 
         let mut record = record! {
             "msg" => Value::string(self.to_string(), span),
@@ -1499,6 +1511,7 @@ impl ShellError {
             "raw" => Value::error(self.clone(), span),
             "rendered" => Value::string(format_shell_error(working_set, &self), span),
             "json" => Value::string(serde_json::to_string(&self).expect("Could not serialize error"), span),
+            "cause" => cause,
         };
 
         if let Some(code) = exit_code {
@@ -1508,6 +1521,49 @@ impl ShellError {
         Value::record(record, span)
     }
 
+    /// Attach `cause` as the underlying cause of this error, so it is included when iterating
+    /// [`ShellError::causes`] and shown in a "Caused by:" section when the error is rendered or
+    /// displayed to a script through `$err.cause`.
+    ///
+    /// If `self` isn't already a [`ShellError::GenericError`], it is converted into one that
+    /// preserves the original message, so the chain can still be walked and rendered uniformly.
+    pub fn with_cause(self, cause: ShellError) -> ShellError {
+        match self {
+            ShellError::GenericError {
+                error,
+                msg,
+                span,
+                help,
+                mut inner,
+            } => {
+                inner.push(cause);
+                ShellError::GenericError {
+                    error,
+                    msg,
+                    span,
+                    help,
+                    inner,
+                }
+            }
+            other => ShellError::GenericError {
+                error: other.to_string(),
+                msg: String::new(),
+                span: None,
+                help: None,
+                inner: vec![cause],
+            },
+        }
+    }
+
+    /// Iterate over the chain of causes attached to this error via [`ShellError::with_cause`],
+    /// outermost first.
+    pub fn causes(&self) -> impl Iterator<Item = &ShellError> {
+        match self {
+            ShellError::GenericError { inner, .. } => inner.iter(),
+            _ => [].iter(),
+        }
+    }
+
     // TODO: Implement as From trait
     pub fn wrap(self, working_set: &StateWorkingSet, span: Span) -> ParseError {
         let msg = format_shell_error(working_set, &self);
@@ -1670,3 +1726,17 @@ fn shell_error_serialize_roundtrip() {
         deserialized.help().map(|c| c.to_string())
     );
 }
+
+#[test]
+fn shell_error_with_cause_builds_a_chain() {
+    let root_cause = ShellError::NushellFailed {
+        msg: "disk on fire".into(),
+    };
+    let error = ShellError::IOError {
+        msg: "could not read file".into(),
+    }
+    .with_cause(root_cause.clone());
+
+    let causes = error.causes().collect::<Vec<_>>();
+    assert_eq!(causes, vec![&root_cause]);
+}
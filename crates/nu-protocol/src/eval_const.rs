@@ -2,6 +2,14 @@
 //!
 //! This enables you to assign `const`-constants and execute parse-time code dependent on this.
 //! e.g. `source $my_const`
+//!
+//! Security model: a command opts into parse-time execution via `Command::is_const()` and
+//! implements `Command::run_const()`. Because this code runs while parsing a script, before any
+//! user confirmation or sandboxing decision has been made, const-evaluable commands should be
+//! restricted to operations that are pure (string/path manipulation, `$nu` platform info) or that
+//! have a narrow, explicit blast radius (e.g. `open --allow-const-read`, which requires an
+//! explicit flag and caps the file size it will read). Commands with side effects, network access,
+//! or unbounded I/O must not implement `run_const()`.
 use crate::{
     ast::{Assignment, Block, Call, Expr, Expression, ExternalArgument},
     debugger::{DebugContext, WithoutDebug},
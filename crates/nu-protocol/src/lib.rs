@@ -4,6 +4,7 @@ mod alias;
 pub mod ast;
 pub mod config;
 pub mod debugger;
+mod deprecation;
 mod did_you_mean;
 pub mod engine;
 mod errors;
@@ -29,6 +30,7 @@ mod value;
 pub use alias::*;
 pub use ast::Unit;
 pub use config::*;
+pub use deprecation::*;
 pub use did_you_mean::did_you_mean;
 pub use engine::{ENV_VARIABLE_ID, IN_VARIABLE_ID, NU_VARIABLE_ID};
 pub use errors::*;
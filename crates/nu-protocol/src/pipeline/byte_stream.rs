@@ -458,24 +458,29 @@ impl ByteStream {
     /// - [`File`](ByteStreamSource::File)
     /// - [`Child`](ByteStreamSource::Child) and the child has a stdout that is `Some(ChildPipe::Pipe(..))`.
     ///
+    /// On success, also returns the source [`ChildProcess`] if there was one, with its stdout
+    /// already taken. Callers piping this into another external command's stdin should chain it
+    /// on with [`ChildProcess::push_predecessor`] so its exit code isn't lost for
+    /// `$env.PIPESTATUS`.
+    ///
     /// All other cases return an `Err` with the original [`ByteStream`] in it.
-    pub fn into_stdio(mut self) -> Result<Stdio, Self> {
+    pub fn into_stdio(mut self) -> Result<(Stdio, Option<ChildProcess>), Self> {
         match self.stream {
             ByteStreamSource::Read(..) => Err(self),
-            ByteStreamSource::File(file) => Ok(file.into()),
+            ByteStreamSource::File(file) => Ok((file.into(), None)),
             #[cfg(feature = "os")]
             ByteStreamSource::Child(child) => {
-                if let ChildProcess {
-                    stdout: Some(ChildPipe::Pipe(stdout)),
-                    stderr,
-                    ..
-                } = *child
-                {
-                    debug_assert!(stderr.is_none(), "stderr should not exist");
-                    Ok(stdout.into())
-                } else {
-                    self.stream = ByteStreamSource::Child(child);
-                    Err(self)
+                let mut child = *child;
+                match child.stdout.take() {
+                    Some(ChildPipe::Pipe(stdout)) => {
+                        debug_assert!(child.stderr.is_none(), "stderr should not exist");
+                        Ok((stdout.into(), Some(child)))
+                    }
+                    stdout => {
+                        child.stdout = stdout;
+                        self.stream = ByteStreamSource::Child(Box::new(child));
+                        Err(self)
+                    }
                 }
             }
         }
@@ -590,6 +595,22 @@ impl ByteStream {
         }
     }
 
+    /// Like [`ByteStream::drain`], but if the source is an external command (or a chain of them
+    /// piped together via [`ChildProcess::push_predecessor`]), returns every stage's raw exit
+    /// code in pipeline order instead of erroring on a non-zero one. Used to populate
+    /// `$env.PIPESTATUS`.
+    pub fn drain_pipeline_status(self) -> Result<Vec<i32>, ShellError> {
+        match self.stream {
+            ByteStreamSource::Read(read) => {
+                copy_with_signals(read, io::sink(), self.span, &self.signals)?;
+                Ok(Vec::new())
+            }
+            ByteStreamSource::File(_) => Ok(Vec::new()),
+            #[cfg(feature = "os")]
+            ByteStreamSource::Child(child) => child.wait_pipeline_status(),
+        }
+    }
+
     /// Print all bytes of the [`ByteStream`] to stdout or stderr.
     pub fn print(self, to_stderr: bool) -> Result<(), ShellError> {
         if to_stderr {
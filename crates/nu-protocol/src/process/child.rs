@@ -152,6 +152,11 @@ pub struct ChildProcess {
     exit_status: ExitStatusFuture,
     ignore_error: bool,
     span: Span,
+    /// Earlier stages of the same external pipeline (oldest first), chained on via
+    /// [`ChildProcess::push_predecessor`]. Nushell wires one external command's stdout directly
+    /// to the next command's stdin, so once a predecessor's pipe is handed off, this is the only
+    /// remaining way to recover its exit code for `$env.PIPESTATUS`.
+    predecessors: Vec<ChildProcess>,
 }
 
 impl ChildProcess {
@@ -199,6 +204,7 @@ impl ChildProcess {
                 .unwrap_or(ExitStatusFuture::Finished(Ok(ExitStatus::Exited(0)))),
             ignore_error: false,
             span,
+            predecessors: Vec::new(),
         }
     }
 
@@ -207,6 +213,13 @@ impl ChildProcess {
         self
     }
 
+    /// Chain an earlier stage of the same external pipeline onto this process, so that
+    /// [`ChildProcess::wait_pipeline_status`] also reports its exit code.
+    pub fn push_predecessor(&mut self, predecessor: ChildProcess) -> &mut Self {
+        self.predecessors.push(predecessor);
+        self
+    }
+
     pub fn span(&self) -> Span {
         self.span
     }
@@ -236,6 +249,51 @@ impl ChildProcess {
     }
 
     pub fn wait(mut self) -> Result<(), ShellError> {
+        self.consume_pipes()?;
+        check_ok(
+            self.exit_status.wait(self.span)?,
+            self.ignore_error,
+            self.span,
+        )
+    }
+
+    /// Like [`ChildProcess::wait`], but also waits for any predecessor stages chained on with
+    /// [`ChildProcess::push_predecessor`], returning every stage's raw exit code in pipeline
+    /// order (oldest first) on success. Used to populate `$env.PIPESTATUS`.
+    ///
+    /// A non-zero exit code from a predecessor is not treated as an error (matching nushell's
+    /// existing behavior of only surfacing the last external command's failure), but a non-zero
+    /// exit code from this, the final stage, is - same as [`ChildProcess::wait`].
+    pub fn wait_pipeline_status(mut self) -> Result<Vec<i32>, ShellError> {
+        let predecessors = std::mem::take(&mut self.predecessors);
+        let mut codes = Vec::with_capacity(predecessors.len() + 1);
+        for predecessor in predecessors {
+            codes.extend(predecessor.wait_ignoring_status()?);
+        }
+
+        self.consume_pipes()?;
+        let status = self.exit_status.wait(self.span)?;
+        check_ok(status, self.ignore_error, self.span)?;
+        codes.push(status.code());
+        Ok(codes)
+    }
+
+    /// Wait for this stage (and any of its own predecessors) without treating a non-zero exit
+    /// code as an error. Used by [`ChildProcess::wait_pipeline_status`] for every stage except
+    /// the last.
+    fn wait_ignoring_status(mut self) -> Result<Vec<i32>, ShellError> {
+        let predecessors = std::mem::take(&mut self.predecessors);
+        let mut codes = Vec::with_capacity(predecessors.len() + 1);
+        for predecessor in predecessors {
+            codes.extend(predecessor.wait_ignoring_status()?);
+        }
+
+        self.consume_pipes()?;
+        codes.push(self.exit_status.wait(self.span)?.code());
+        Ok(codes)
+    }
+
+    fn consume_pipes(&mut self) -> Result<(), ShellError> {
         if let Some(stdout) = self.stdout.take() {
             let stderr = self
                 .stderr
@@ -271,11 +329,7 @@ impl ChildProcess {
             consume_pipe(stderr).err_span(self.span)?;
         }
 
-        check_ok(
-            self.exit_status.wait(self.span)?,
-            self.ignore_error,
-            self.span,
-        )
+        Ok(())
     }
 
     pub fn try_wait(&mut self) -> Result<Option<ExitStatus>, ShellError> {
@@ -231,7 +231,60 @@ impl ProcessInfo {
         self.curr_proc.stat().map(|p| p.vsize).unwrap_or_default()
     }
 
+    /// Total bytes read from storage since the process started, if `/proc/<pid>/io` is readable
+    pub fn read_bytes(&self) -> Option<u64> {
+        self.curr_io.as_ref().map(|io| io.read_bytes)
+    }
+
+    /// Total bytes written to storage since the process started, if `/proc/<pid>/io` is readable
+    pub fn write_bytes(&self) -> Option<u64> {
+        self.curr_io.as_ref().map(|io| io.write_bytes)
+    }
+
+    /// Number of open file descriptors, if `/proc/<pid>/fd` is readable
+    pub fn open_fds(&self) -> Option<usize> {
+        self.curr_proc.fd().ok().map(|fds| fds.len())
+    }
+
+    /// Memory usage and limit of the cgroup this process belongs to, if it's in a cgroup v2
+    /// hierarchy mounted at the conventional `/sys/fs/cgroup` location.
+    ///
+    /// Inside a container this reports the container's memory limit rather than the host's total
+    /// RAM, which is what `sys mem` should show when it's asked about the current process.
+    pub fn cgroup_memory(&self) -> Option<CgroupMemory> {
+        cgroup_memory_from(self.curr_proc.cgroups().ok()?)
+    }
+
     fn comm(&self) -> Option<String> {
         self.curr_proc.stat().map(|st| st.comm).ok()
     }
 }
+
+/// Memory accounting for a single cgroup, as reported by the cgroup v2 `memory.max`/
+/// `memory.current` files.
+pub struct CgroupMemory {
+    /// Memory limit in bytes, or `None` if the cgroup has no limit set (`memory.max` is `max`)
+    pub limit: Option<u64>,
+    /// Current memory usage in bytes
+    pub usage: Option<u64>,
+}
+
+fn cgroup_memory_from(cgroups: ProcessCGroups) -> Option<CgroupMemory> {
+    let unified = cgroups.0.iter().find(|group| group.hierarchy == 0)?;
+    let cgroup_dir = PathBuf::from("/sys/fs/cgroup").join(unified.pathname.trim_start_matches('/'));
+
+    let usage = std::fs::read_to_string(cgroup_dir.join("memory.current"))
+        .ok()
+        .and_then(|s| s.trim().parse().ok());
+    let limit = std::fs::read_to_string(cgroup_dir.join("memory.max"))
+        .ok()
+        .and_then(|s| s.trim().parse().ok());
+
+    Some(CgroupMemory { limit, usage })
+}
+
+/// Memory usage and limit of the cgroup the current process belongs to. See
+/// [`ProcessInfo::cgroup_memory`] for details.
+pub fn current_process_cgroup_memory() -> Option<CgroupMemory> {
+    cgroup_memory_from(Process::myself().ok()?.cgroups().ok()?)
+}
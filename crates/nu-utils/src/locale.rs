@@ -20,6 +20,24 @@ pub fn get_system_locale() -> Locale {
     })
 }
 
+/// The currency symbol conventionally used to display an ISO 4217 currency
+/// code, if we know it. Falls back to the code itself (e.g. "CHF") when not
+/// found, since not every currency has a widely used symbol.
+pub fn currency_symbol(code: &str) -> Option<&'static str> {
+    Some(match code.to_ascii_uppercase().as_str() {
+        "USD" | "CAD" | "AUD" | "NZD" | "MXN" | "HKD" | "SGD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" | "CNY" => "¥",
+        "INR" => "₹",
+        "KRW" => "₩",
+        "RUB" => "₽",
+        "BRL" => "R$",
+        "CHF" => "CHF ",
+        _ => return None,
+    })
+}
+
 #[cfg(debug_assertions)]
 pub fn get_system_locale_string() -> Option<String> {
     std::env::var(LOCALE_OVERRIDE_ENV_VAR)
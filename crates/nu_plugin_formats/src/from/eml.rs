@@ -1,4 +1,5 @@
 use crate::FormatCmdsPlugin;
+use base64::{engine::general_purpose::STANDARD, Engine};
 use eml_parser::eml::*;
 use eml_parser::EmlParser;
 use indexmap::IndexMap;
@@ -19,7 +20,7 @@ impl SimplePluginCommand for FromEml {
     }
 
     fn description(&self) -> &str {
-        "Parse text as .eml and create record."
+        "Parse text as .eml and create record, exposing MIME attachments as binary values."
     }
 
     fn signature(&self) -> Signature {
@@ -73,6 +74,7 @@ Test' | from eml",
                         "Address" =>     Value::test_string("someone@somewhere.com"),
                     }),
                     "Body" => Value::test_string("Test"),
+                    "Attachments" => Value::test_list(vec![]),
             })),
         },
         Example {
@@ -93,6 +95,7 @@ Test' | from eml -b 1",
                         "Address" =>     Value::test_string("someone@somewhere.com"),
                     }),
                     "Body" => Value::test_string("T"),
+                    "Attachments" => Value::test_list(vec![]),
             })),
         },
     ]
@@ -134,10 +137,118 @@ fn headerfieldvalue_to_value(head: Span, value: &HeaderFieldValue) -> Value {
     }
 }
 
-fn from_eml(input: &Value, body_preview: usize, head: Span) -> Result<Value, LabeledError> {
+/// Join RFC 2822 header folding (continuation lines starting with whitespace) so a
+/// single logical header can be found even when it spans several physical lines.
+fn unfold_headers(block: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in block.lines() {
+        if (line.starts_with(' ') || line.starts_with('\t')) && !lines.is_empty() {
+            let last = lines.last_mut().expect("checked non-empty above");
+            last.push(' ');
+            last.push_str(line.trim());
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    lines
+}
+
+fn find_header(block: &str, name: &str) -> Option<String> {
+    let prefix = format!("{name}:");
+    unfold_headers(block).into_iter().find_map(|line| {
+        if line.len() >= prefix.len() && line[..prefix.len()].eq_ignore_ascii_case(&prefix) {
+            Some(line[prefix.len()..].trim().to_string())
+        } else {
+            None
+        }
+    })
+}
+
+fn header_param(header_value: &str, param: &str) -> Option<String> {
+    let needle = format!("{param}=");
+    header_value.split(';').find_map(|segment| {
+        let segment = segment.trim();
+        if segment.len() >= needle.len() && segment[..needle.len()].eq_ignore_ascii_case(&needle) {
+            Some(segment[needle.len()..].trim().trim_matches('"').to_string())
+        } else {
+            None
+        }
+    })
+}
+
+fn split_headers_and_content(part: &str) -> (&str, &str) {
+    match part.find("\n\n") {
+        Some(i) => (&part[..i], &part[i + 2..]),
+        None => (part, ""),
+    }
+}
+
+struct Attachment {
+    name: Option<String>,
+    content_type: String,
+    data: Vec<u8>,
+}
+
+fn decode_part_content(content: &str, transfer_encoding: Option<&str>) -> Vec<u8> {
+    match transfer_encoding.map(|e| e.to_ascii_lowercase()) {
+        Some(ref enc) if enc == "base64" => {
+            let cleaned: String = content.chars().filter(|c| !c.is_whitespace()).collect();
+            STANDARD.decode(cleaned).unwrap_or_default()
+        }
+        _ => content.trim_end_matches(['\r', '\n']).as_bytes().to_vec(),
+    }
+}
+
+/// Split a multipart MIME body on its boundary and pull out every part that carries
+/// a filename, i.e. every attachment or inline file. Parts without a filename (the
+/// plain-text/HTML alternatives that make up the visible body) are ignored here since
+/// `eml-parser` already surfaces the body text.
+fn extract_attachments(raw: &str, boundary: &str) -> Vec<Attachment> {
+    let delimiter = format!("--{boundary}");
+    raw.split(&delimiter)
+        .filter_map(|part| {
+            let part = part.trim_start_matches(['\r', '\n']);
+            if part.is_empty() || part.starts_with("--") {
+                return None;
+            }
+
+            let (headers, content) = split_headers_and_content(part);
+            let disposition = find_header(headers, "Content-Disposition");
+            let content_type_header =
+                find_header(headers, "Content-Type").unwrap_or_else(|| "text/plain".to_string());
+
+            let filename = disposition
+                .as_deref()
+                .and_then(|d| header_param(d, "filename"))
+                .or_else(|| header_param(&content_type_header, "name"));
+
+            filename.as_ref()?;
+
+            let content_type = content_type_header
+                .split(';')
+                .next()
+                .unwrap_or(&content_type_header)
+                .trim()
+                .to_string();
+            let transfer_encoding = find_header(headers, "Content-Transfer-Encoding");
+
+            Some(Attachment {
+                name: filename,
+                content_type,
+                data: decode_part_content(content, transfer_encoding.as_deref()),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn from_eml(
+    input: &Value,
+    body_preview: usize,
+    head: Span,
+) -> Result<Value, LabeledError> {
     let value = input.coerce_string()?;
 
-    let eml = EmlParser::from_string(value)
+    let eml = EmlParser::from_string(value.clone())
         .with_body_preview(body_preview)
         .parse()
         .map_err(|_| ShellError::CantConvert {
@@ -169,6 +280,33 @@ fn from_eml(input: &Value, body_preview: usize, head: Span) -> Result<Value, Lab
         collected.insert("Body".to_string(), Value::string(body, head));
     }
 
+    let (top_headers, top_content) = split_headers_and_content(&value);
+    let attachments = find_header(top_headers, "Content-Type")
+        .as_deref()
+        .and_then(|ct| header_param(ct, "boundary"))
+        .map(|boundary| extract_attachments(top_content, &boundary))
+        .unwrap_or_default();
+
+    collected.insert(
+        "Attachments".to_string(),
+        Value::list(
+            attachments
+                .into_iter()
+                .map(|a| {
+                    Value::record(
+                        record! {
+                            "Name" => a.name.map_or_else(|| Value::nothing(head), |n| Value::string(n, head)),
+                            "Content Type" => Value::string(a.content_type, head),
+                            "Data" => Value::binary(a.data, head),
+                        },
+                        head,
+                    )
+                })
+                .collect(),
+            head,
+        ),
+    );
+
     Ok(Value::record(collected.into_iter().collect(), head))
 }
 
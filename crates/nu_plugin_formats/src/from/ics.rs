@@ -1,10 +1,16 @@
 use crate::FormatCmdsPlugin;
 
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    Timelike, TimeZone, Utc, Weekday,
+};
+use chrono_tz::Tz;
 use ical::{parser::ical::component::*, property::Property};
 use indexmap::IndexMap;
 use nu_plugin::{EngineInterface, EvaluatedCall, SimplePluginCommand};
 use nu_protocol::{
-    record, Category, Example, LabeledError, ShellError, Signature, Span, Type, Value,
+    record, Category, Example, LabeledError, Record, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
 };
 use std::io::BufReader;
 
@@ -24,6 +30,23 @@ impl SimplePluginCommand for FromIcs {
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .input_output_types(vec![(Type::String, Type::table())])
+            .switch(
+                "expand",
+                "expand RRULE-recurring events into concrete occurrences (requires --from and --to)",
+                None,
+            )
+            .named(
+                "from",
+                SyntaxShape::DateTime,
+                "start of the expansion window",
+                None,
+            )
+            .named(
+                "to",
+                SyntaxShape::DateTime,
+                "end of the expansion window",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -42,6 +65,20 @@ impl SimplePluginCommand for FromIcs {
         let input_string = input.coerce_str()?;
         let head = call.head;
 
+        let range = if call.has_flag("expand")? {
+            let from: DateTime<FixedOffset> = call.get_flag("from")?.ok_or_else(|| {
+                LabeledError::new("Missing --from")
+                    .with_label("`--expand` requires both --from and --to", head)
+            })?;
+            let to: DateTime<FixedOffset> = call.get_flag("to")?.ok_or_else(|| {
+                LabeledError::new("Missing --to")
+                    .with_label("`--expand` requires both --from and --to", head)
+            })?;
+            Some((from, to))
+        } else {
+            None
+        };
+
         let input_string = input_string
             .lines()
             .enumerate()
@@ -64,7 +101,7 @@ impl SimplePluginCommand for FromIcs {
 
         for calendar in parser {
             match calendar {
-                Ok(c) => output.push(calendar_to_value(c, head)),
+                Ok(c) => output.push(calendar_to_value(c, head, range)),
                 Err(e) => output.push(Value::error(
                     ShellError::UnsupportedInput {
                         msg: format!("input cannot be parsed as .ics ({e})"),
@@ -94,14 +131,22 @@ END:VCALENDAR' | from ics",
                 "free-busys" => Value::test_list(vec![]),
                 "timezones" =>  Value::test_list(vec![]),
         })])),
+    }, Example {
+        example: "open reminders.ics | from ics --expand --from 2025-01-01 --to 2025-02-01",
+        description: "Expand recurring (RRULE) events into their concrete occurrences within a date range",
+        result: None,
     }]
 }
 
-fn calendar_to_value(calendar: IcalCalendar, span: Span) -> Value {
+fn calendar_to_value(
+    calendar: IcalCalendar,
+    span: Span,
+    range: Option<(DateTime<FixedOffset>, DateTime<FixedOffset>)>,
+) -> Value {
     Value::record(
         record! {
             "properties" => properties_to_value(calendar.properties, span),
-            "events" => events_to_value(calendar.events, span),
+            "events" => events_to_value(calendar.events, span, range),
             "alarms" => alarms_to_value(calendar.alarms, span),
             "to-Dos" => todos_to_value(calendar.todos, span),
             "journals" => journals_to_value(calendar.journals, span),
@@ -112,18 +157,27 @@ fn calendar_to_value(calendar: IcalCalendar, span: Span) -> Value {
     )
 }
 
-fn events_to_value(events: Vec<IcalEvent>, span: Span) -> Value {
+fn events_to_value(
+    events: Vec<IcalEvent>,
+    span: Span,
+    range: Option<(DateTime<FixedOffset>, DateTime<FixedOffset>)>,
+) -> Value {
     Value::list(
         events
             .into_iter()
             .map(|event| {
-                Value::record(
-                    record! {
-                        "properties" => properties_to_value(event.properties, span),
-                        "alarms" => alarms_to_value(event.alarms, span),
-                    },
-                    span,
-                )
+                // Computed from the raw properties before they're consumed below, since
+                // `Property` isn't `Clone`.
+                let occurrences =
+                    range.and_then(|range| expand_event_occurrences(&event.properties, range, span));
+
+                let mut record = Record::new();
+                record.push("properties", properties_to_value(event.properties, span));
+                record.push("alarms", alarms_to_value(event.alarms, span));
+                if let Some(occurrences) = occurrences {
+                    record.push("occurrences", occurrences);
+                }
+                Value::record(record, span)
             })
             .collect::<Vec<Value>>(),
         span,
@@ -270,6 +324,294 @@ fn params_to_value(params: Vec<(String, Vec<String>)>, span: Span) -> Value {
     Value::record(row.into_iter().collect(), span)
 }
 
+// Bounds how many occurrences a single RRULE can generate, so an unbounded rule (no COUNT or
+// UNTIL) with a wide --from/--to window can't spin forever.
+const MAX_OCCURRENCES: usize = 10_000;
+
+/// The zone a DTSTART was expressed in, kept around so recurrences can be generated in local wall
+/// clock time and have the correct (DST-aware) offset re-applied to each occurrence individually,
+/// rather than reusing DTSTART's offset for every future occurrence.
+enum StartZone {
+    Utc,
+    Named(Tz),
+    /// No `Z` suffix and no `TZID` param: RFC 5545 calls this a "floating" time. There's no
+    /// zone to resolve DST against, so it's treated as UTC.
+    Floating,
+}
+
+impl StartZone {
+    fn resolve(&self, naive: NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+        match self {
+            StartZone::Utc | StartZone::Floating => {
+                Some(Utc.from_utc_datetime(&naive).fixed_offset())
+            }
+            StartZone::Named(tz) => tz.from_local_datetime(&naive).earliest().map(|dt| dt.fixed_offset()),
+        }
+    }
+}
+
+fn parse_ics_naive(raw: &str) -> Option<NaiveDateTime> {
+    if raw.len() == 8 {
+        NaiveDate::parse_from_str(raw, "%Y%m%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+    } else {
+        NaiveDateTime::parse_from_str(raw, "%Y%m%dT%H%M%S").ok()
+    }
+}
+
+fn dtstart_of(properties: &[Property]) -> Option<(NaiveDateTime, StartZone)> {
+    let prop = properties.iter().find(|p| p.name == "DTSTART")?;
+    let raw = prop.value.as_ref()?;
+
+    if let Some(stripped) = raw.strip_suffix('Z') {
+        return Some((parse_ics_naive(stripped)?, StartZone::Utc));
+    }
+
+    let tzid = prop
+        .params
+        .as_ref()
+        .and_then(|params| params.iter().find(|(k, _)| k == "TZID"))
+        .and_then(|(_, vals)| vals.first());
+
+    let naive = parse_ics_naive(raw)?;
+    match tzid {
+        Some(id) => id.parse::<Tz>().ok().map(|tz| (naive, StartZone::Named(tz))),
+        None => Some((naive, StartZone::Floating)),
+    }
+}
+
+enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+struct RRule {
+    freq: Freq,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<NaiveDateTime>,
+    by_day: Vec<Weekday>,
+}
+
+/// Parses the common cases of RFC 5545's RRULE value: `FREQ` (daily/weekly/monthly/yearly),
+/// `INTERVAL`, `COUNT`, `UNTIL`, and `BYDAY` (only meaningful for weekly rules here). Anything
+/// else -- `BYMONTHDAY`, `BYSETPOS`, `BYWEEKNO`, nth-weekday-of-month `BYDAY` prefixes like
+/// `1MO`, `SECONDLY`/`MINUTELY`/`HOURLY` frequencies, `WKST`, and so on -- is out of scope for
+/// this expander and is silently ignored rather than rejected, since most calendars combine a
+/// supported core rule with extra parts we can safely drop.
+fn parse_rrule(raw: &str) -> Option<RRule> {
+    let mut freq = None;
+    let mut interval = 1u32;
+    let mut count = None;
+    let mut until = None;
+    let mut by_day = vec![];
+
+    for part in raw.split(';') {
+        let mut kv = part.splitn(2, '=');
+        let key = kv.next()?.trim();
+        let val = kv.next().unwrap_or("").trim();
+        match key {
+            "FREQ" => {
+                freq = Some(match val {
+                    "DAILY" => Freq::Daily,
+                    "WEEKLY" => Freq::Weekly,
+                    "MONTHLY" => Freq::Monthly,
+                    "YEARLY" => Freq::Yearly,
+                    _ => return None,
+                });
+            }
+            "INTERVAL" => interval = val.parse().ok()?,
+            "COUNT" => count = val.parse().ok(),
+            "UNTIL" => until = parse_ics_naive(val.trim_end_matches('Z')),
+            "BYDAY" => {
+                by_day = val
+                    .split(',')
+                    .filter_map(|day| match day {
+                        "MO" => Some(Weekday::Mon),
+                        "TU" => Some(Weekday::Tue),
+                        "WE" => Some(Weekday::Wed),
+                        "TH" => Some(Weekday::Thu),
+                        "FR" => Some(Weekday::Fri),
+                        "SA" => Some(Weekday::Sat),
+                        "SU" => Some(Weekday::Sun),
+                        _ => None,
+                    })
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
+    Some(RRule {
+        freq: freq?,
+        interval: interval.max(1),
+        count,
+        until,
+        by_day,
+    })
+}
+
+fn date_of(dt: NaiveDateTime) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(dt.year(), dt.month(), dt.day())
+}
+
+fn time_of(dt: NaiveDateTime) -> Option<NaiveTime> {
+    NaiveTime::from_hms_opt(dt.hour(), dt.minute(), dt.second())
+}
+
+/// Generates the local wall-clock occurrences of `rule` starting from `start`, up to
+/// `MAX_OCCURRENCES` of them. The zone is applied afterwards, one occurrence at a time, so DST
+/// transitions between occurrences are handled correctly for named-timezone starts.
+fn candidate_naive_datetimes(start: NaiveDateTime, rule: &RRule) -> Vec<NaiveDateTime> {
+    let mut out = Vec::new();
+
+    match rule.freq {
+        Freq::Daily => {
+            let mut current = start;
+            while out.len() < MAX_OCCURRENCES {
+                out.push(current);
+                let Some(next) = current.checked_add_signed(Duration::days(rule.interval as i64))
+                else {
+                    break;
+                };
+                current = next;
+            }
+        }
+        Freq::Weekly if !rule.by_day.is_empty() => {
+            let mut week_start = start - Duration::days(start.weekday().num_days_from_monday() as i64);
+            let Some(time_of_day) = time_of(start) else {
+                return out;
+            };
+            'weeks: loop {
+                let Some(week_start_date) = date_of(week_start) else {
+                    break;
+                };
+                let mut days_in_week: Vec<NaiveDateTime> = rule
+                    .by_day
+                    .iter()
+                    .map(|day| {
+                        NaiveDateTime::new(
+                            week_start_date + Duration::days(day.num_days_from_monday() as i64),
+                            time_of_day,
+                        )
+                    })
+                    .collect();
+                days_in_week.sort();
+
+                for day in days_in_week {
+                    if day >= start {
+                        out.push(day);
+                        if out.len() >= MAX_OCCURRENCES {
+                            break 'weeks;
+                        }
+                    }
+                }
+
+                let Some(next_week) = week_start.checked_add_signed(Duration::weeks(rule.interval as i64))
+                else {
+                    break;
+                };
+                week_start = next_week;
+            }
+        }
+        Freq::Weekly => {
+            let mut current = start;
+            while out.len() < MAX_OCCURRENCES {
+                out.push(current);
+                let Some(next) = current.checked_add_signed(Duration::weeks(rule.interval as i64))
+                else {
+                    break;
+                };
+                current = next;
+            }
+        }
+        Freq::Monthly => {
+            let mut current = start;
+            while out.len() < MAX_OCCURRENCES {
+                out.push(current);
+                let Some(current_date) = date_of(current) else {
+                    break;
+                };
+                let Some(next_date) = current_date.checked_add_months(Months::new(rule.interval))
+                else {
+                    break;
+                };
+                let Some(time) = time_of(current) else {
+                    break;
+                };
+                current = NaiveDateTime::new(next_date, time);
+            }
+        }
+        Freq::Yearly => {
+            let mut current = start;
+            while out.len() < MAX_OCCURRENCES {
+                out.push(current);
+                let Some(current_date) = date_of(current) else {
+                    break;
+                };
+                let Some(next_date) = current_date.checked_add_months(Months::new(rule.interval * 12))
+                else {
+                    break;
+                };
+                let Some(time) = time_of(current) else {
+                    break;
+                };
+                current = NaiveDateTime::new(next_date, time);
+            }
+        }
+    }
+
+    out
+}
+
+fn expand_event_occurrences(
+    properties: &[Property],
+    range: (DateTime<FixedOffset>, DateTime<FixedOffset>),
+    span: Span,
+) -> Option<Value> {
+    let (range_start, range_end) = range;
+    let (start_naive, zone) = dtstart_of(properties)?;
+    let rrule_raw = properties
+        .iter()
+        .find(|p| p.name == "RRULE")
+        .and_then(|p| p.value.as_ref())?;
+    let rule = parse_rrule(rrule_raw)?;
+
+    let mut occurrences = Vec::new();
+    let mut seen = 0u32;
+
+    for naive in candidate_naive_datetimes(start_naive, &rule) {
+        if let Some(until) = rule.until {
+            if naive > until {
+                break;
+            }
+        }
+
+        let Some(dt) = zone.resolve(naive) else {
+            continue;
+        };
+
+        seen += 1;
+        if let Some(count) = rule.count {
+            if seen > count {
+                break;
+            }
+        }
+
+        if dt > range_end {
+            break;
+        }
+        if dt >= range_start {
+            occurrences.push(Value::date(dt, span));
+        }
+    }
+
+    Some(Value::list(occurrences, span))
+}
+
 #[test]
 fn test_examples() -> Result<(), nu_protocol::ShellError> {
     use nu_plugin_test_support::PluginTest;
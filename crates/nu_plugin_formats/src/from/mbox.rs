@@ -0,0 +1,124 @@
+use crate::FormatCmdsPlugin;
+
+use super::eml::from_eml;
+use nu_plugin::{EngineInterface, EvaluatedCall, SimplePluginCommand};
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+const DEFAULT_BODY_PREVIEW: usize = 50;
+
+pub struct FromMbox;
+
+impl SimplePluginCommand for FromMbox {
+    type Plugin = FormatCmdsPlugin;
+
+    fn name(&self) -> &str {
+        "from mbox"
+    }
+
+    fn description(&self) -> &str {
+        "Parse text as .mbox and create table, reading one message at a time."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::String, Type::table())])
+            .named(
+                "preview-body",
+                SyntaxShape::Int,
+                "How many bytes of each message's body to preview",
+                Some('b'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        examples()
+    }
+
+    fn run(
+        &self,
+        _plugin: &FormatCmdsPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let preview_body: usize = call
+            .get_flag::<i64>("preview-body")?
+            .map(|l| if l < 0 { 0 } else { l as usize })
+            .unwrap_or(DEFAULT_BODY_PREVIEW);
+        let head = call.head;
+
+        let text = input.coerce_str()?;
+        let messages = split_messages(&text)
+            .map(|message| {
+                let message = Value::string(unescape_from_lines(message), head);
+                from_eml(&message, preview_body, head)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Value::list(messages, head))
+    }
+}
+
+/// mbox stores messages back-to-back, each introduced by a line starting with
+/// `From ` (the envelope sender and delivery date) at the very start of a line.
+/// We only need that line to find message boundaries; `from eml` parses the
+/// RFC 5322 headers that follow it.
+fn split_messages(text: &str) -> impl Iterator<Item = &str> {
+    let mut starts: Vec<usize> = text.match_indices("\nFrom ").map(|(i, _)| i + 1).collect();
+    if text.starts_with("From ") {
+        starts.insert(0, 0);
+    }
+    let mut ends: Vec<usize> = starts.iter().skip(1).copied().collect();
+    ends.push(text.len());
+
+    starts
+        .into_iter()
+        .zip(ends)
+        .filter_map(move |(start, end)| {
+            let body_start = text[start..end].find('\n').map(|n| start + n + 1)?;
+            Some(text[body_start..end].trim_end_matches('\n'))
+        })
+}
+
+/// mbox escapes any line beginning with `From ` inside a message body as `>From `
+/// so it isn't mistaken for the next message's boundary; undo that on the way out.
+fn unescape_from_lines(message: &str) -> String {
+    message
+        .lines()
+        .map(|line| {
+            if line.starts_with(">From ") {
+                &line[1..]
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn examples() -> Vec<Example<'static>> {
+    vec![Example {
+        description: "Convert a two-message mbox into a table of records",
+        example: "'From alice@example.com Mon Jun 24 10:00:00 2024
+From: alice@example.com
+Subject: Hi
+To: bob@example.com
+
+Hello Bob
+From bob@example.com Mon Jun 24 10:05:00 2024
+From: bob@example.com
+Subject: Re: Hi
+To: alice@example.com
+
+Hello Alice' | from mbox | length",
+        result: Some(Value::test_int(2)),
+    }]
+}
+
+#[test]
+fn test_examples() -> Result<(), nu_protocol::ShellError> {
+    use nu_plugin_test_support::PluginTest;
+
+    PluginTest::new("formats", crate::FormatCmdsPlugin.into())?.test_command_examples(&FromMbox)
+}
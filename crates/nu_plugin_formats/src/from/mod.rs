@@ -1,5 +1,6 @@
 pub(crate) mod eml;
 pub(crate) mod ics;
 pub(crate) mod ini;
+pub(crate) mod mbox;
 pub(crate) mod plist;
 pub(crate) mod vcf;
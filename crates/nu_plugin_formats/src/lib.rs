@@ -6,9 +6,12 @@ use nu_plugin::{Plugin, PluginCommand};
 use from::eml::FromEml;
 use from::ics::FromIcs;
 use from::ini::FromIni;
+use from::mbox::FromMbox;
 use from::plist::FromPlist;
 use from::vcf::FromVcf;
+use to::ics::IntoIcs;
 use to::plist::IntoPlist;
+use to::vcf::IntoVcf;
 
 pub struct FormatCmdsPlugin;
 
@@ -22,9 +25,12 @@ impl Plugin for FormatCmdsPlugin {
             Box::new(FromEml),
             Box::new(FromIcs),
             Box::new(FromIni),
+            Box::new(FromMbox),
             Box::new(FromVcf),
             Box::new(FromPlist),
+            Box::new(IntoIcs),
             Box::new(IntoPlist),
+            Box::new(IntoVcf),
         ]
     }
 }
@@ -0,0 +1,224 @@
+use crate::FormatCmdsPlugin;
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand, SimplePluginCommand};
+use nu_protocol::{Category, Example, LabeledError, Record, Signature, Span, Type, Value};
+
+pub struct IntoIcs;
+
+impl SimplePluginCommand for IntoIcs {
+    type Plugin = FormatCmdsPlugin;
+
+    fn name(&self) -> &str {
+        "to ics"
+    }
+
+    fn description(&self) -> &str {
+        "Convert table into .ics text, the inverse of `from ics`."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self))
+            .input_output_types(vec![(Type::table(), Type::String), (Type::record(), Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "{properties: [], events: [], alarms: [], to-Dos: [], journals: [], free-busys: [], timezones: []} | to ics",
+            description: "Converts a calendar record back into ics formatted text",
+            result: Some(Value::test_string("BEGIN:VCALENDAR\r\nEND:VCALENDAR\r\n")),
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &FormatCmdsPlugin,
+        _engine: &EngineInterface,
+        _call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let span = input.span();
+        let calendars = match input {
+            Value::List { vals, .. } => vals.iter().collect::<Vec<_>>(),
+            Value::Record { .. } => vec![input],
+            _ => {
+                return Err(build_label_error(
+                    "input to `to ics` must be a record or a table of calendars".into(),
+                    span,
+                ))
+            }
+        };
+
+        let mut out = String::new();
+        for calendar in calendars {
+            out.push_str(&calendar_to_ics(calendar)?);
+        }
+
+        Ok(Value::string(out, span))
+    }
+}
+
+fn build_label_error(msg: String, span: Span) -> LabeledError {
+    LabeledError::new("Cannot convert to ics").with_label(msg, span)
+}
+
+fn get_field<'a>(record: &'a Record, name: &str, span: Span) -> Result<&'a Value, LabeledError> {
+    record
+        .get(name)
+        .ok_or_else(|| build_label_error(format!("missing '{name}' field"), span))
+}
+
+fn calendar_to_ics(calendar: &Value) -> Result<String, LabeledError> {
+    let span = calendar.span();
+    let record = calendar
+        .as_record()
+        .map_err(|_| build_label_error("each calendar must be a record".into(), span))?;
+
+    let mut out = String::from("BEGIN:VCALENDAR\r\n");
+    out.push_str(&properties_to_ics(get_field(record, "properties", span)?)?);
+    out.push_str(&components_to_ics(
+        get_field(record, "events", span)?,
+        "VEVENT",
+    )?);
+    out.push_str(&components_to_ics(
+        get_field(record, "alarms", span)?,
+        "VALARM",
+    )?);
+    out.push_str(&components_to_ics(
+        get_field(record, "to-Dos", span)?,
+        "VTODO",
+    )?);
+    out.push_str(&components_to_ics(
+        get_field(record, "journals", span)?,
+        "VJOURNAL",
+    )?);
+    out.push_str(&components_to_ics(
+        get_field(record, "free-busys", span)?,
+        "VFREEBUSY",
+    )?);
+    out.push_str(&timezones_to_ics(get_field(record, "timezones", span)?)?);
+    out.push_str("END:VCALENDAR\r\n");
+    Ok(out)
+}
+
+// Renders a list of `{properties, alarms?}`-shaped records as `BEGIN:<kind>` / `END:<kind>`
+// blocks. This covers events, alarms, to-Dos, journals and free-busys, all of which share the
+// same shape except that only events and to-Dos carry a nested `alarms` list.
+fn components_to_ics(components: &Value, kind: &str) -> Result<String, LabeledError> {
+    let span = components.span();
+    let Value::List { vals, .. } = components else {
+        return Err(build_label_error(format!("'{kind}' components must be a list"), span));
+    };
+
+    let mut out = String::new();
+    for component in vals {
+        let record = component
+            .as_record()
+            .map_err(|_| build_label_error(format!("each {kind} must be a record"), span))?;
+
+        out.push_str(&format!("BEGIN:{kind}\r\n"));
+        out.push_str(&properties_to_ics(get_field(record, "properties", span)?)?);
+        if let Some(alarms) = record.get("alarms") {
+            out.push_str(&components_to_ics(alarms, "VALARM")?);
+        }
+        out.push_str(&format!("END:{kind}\r\n"));
+    }
+    Ok(out)
+}
+
+fn timezones_to_ics(timezones: &Value) -> Result<String, LabeledError> {
+    let span = timezones.span();
+    let Value::List { vals, .. } = timezones else {
+        return Err(build_label_error("'timezones' must be a list".into(), span));
+    };
+
+    let mut out = String::new();
+    for timezone in vals {
+        let record = timezone
+            .as_record()
+            .map_err(|_| build_label_error("each timezone must be a record".into(), span))?;
+
+        out.push_str("BEGIN:VTIMEZONE\r\n");
+        out.push_str(&properties_to_ics(get_field(record, "properties", span)?)?);
+        if let Some(transitions) = record.get("transitions") {
+            let Value::List { vals, .. } = transitions else {
+                return Err(build_label_error("'transitions' must be a list".into(), span));
+            };
+            for transition in vals {
+                let record = transition
+                    .as_record()
+                    .map_err(|_| build_label_error("each transition must be a record".into(), span))?;
+                // `from ics` doesn't record whether a transition was originally a STANDARD or a
+                // DAYLIGHT block, so this always emits STANDARD. Round-tripping a calendar that
+                // has DAYLIGHT transitions will lose that distinction.
+                out.push_str("BEGIN:STANDARD\r\n");
+                out.push_str(&properties_to_ics(get_field(record, "properties", span)?)?);
+                out.push_str("END:STANDARD\r\n");
+            }
+        }
+        out.push_str("END:VTIMEZONE\r\n");
+    }
+    Ok(out)
+}
+
+fn properties_to_ics(properties: &Value) -> Result<String, LabeledError> {
+    let span = properties.span();
+    let Value::List { vals, .. } = properties else {
+        return Err(build_label_error("'properties' must be a list".into(), span));
+    };
+
+    let mut out = String::new();
+    for prop in vals {
+        let record = prop
+            .as_record()
+            .map_err(|_| build_label_error("each property must be a record".into(), span))?;
+
+        let name = get_field(record, "name", span)?.coerce_str()?;
+        let value = match get_field(record, "value", span)? {
+            Value::Nothing { .. } => String::new(),
+            other => other.coerce_str()?.into_owned(),
+        };
+
+        let mut line = name.into_owned();
+        if let Some(params) = record.get("params") {
+            if let Value::Record { val, .. } = params {
+                for (param_name, param_values) in val.iter() {
+                    let Value::List { vals, .. } = param_values else {
+                        continue;
+                    };
+                    let joined = vals
+                        .iter()
+                        .map(|v| v.coerce_str().map(|s| s.into_owned()))
+                        .collect::<Result<Vec<_>, _>>()?
+                        .join(",");
+                    line.push(';');
+                    line.push_str(param_name);
+                    line.push('=');
+                    line.push_str(&joined);
+                }
+            }
+        }
+        line.push(':');
+        line.push_str(&value);
+
+        out.push_str(&line);
+        out.push_str("\r\n");
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use nu_plugin_test_support::PluginTest;
+    use nu_protocol::ShellError;
+
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<(), ShellError> {
+        let plugin = FormatCmdsPlugin {};
+        let cmd = IntoIcs {};
+
+        let mut plugin_test = PluginTest::new("formats", plugin.into())?;
+        plugin_test.test_command_examples(&cmd)
+    }
+}
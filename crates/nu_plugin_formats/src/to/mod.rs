@@ -1 +1,3 @@
+pub(crate) mod ics;
 pub(crate) mod plist;
+pub(crate) mod vcf;
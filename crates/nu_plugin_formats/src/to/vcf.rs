@@ -0,0 +1,142 @@
+use crate::FormatCmdsPlugin;
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand, SimplePluginCommand};
+use nu_protocol::{Category, Example, LabeledError, Record, Signature, Span, Type, Value};
+
+pub struct IntoVcf;
+
+impl SimplePluginCommand for IntoVcf {
+    type Plugin = FormatCmdsPlugin;
+
+    fn name(&self) -> &str {
+        "to vcf"
+    }
+
+    fn description(&self) -> &str {
+        "Convert table into .vcf text, the inverse of `from vcf`."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self))
+            .input_output_types(vec![(Type::table(), Type::String), (Type::record(), Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "{properties: [{name: N, value: Foo, params: null}]} | to vcf",
+            description: "Converts a contact record back into vcf formatted text",
+            result: Some(Value::test_string("BEGIN:VCARD\r\nN:Foo\r\nEND:VCARD\r\n")),
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &FormatCmdsPlugin,
+        _engine: &EngineInterface,
+        _call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let span = input.span();
+        let contacts = match input {
+            Value::List { vals, .. } => vals.iter().collect::<Vec<_>>(),
+            Value::Record { .. } => vec![input],
+            _ => {
+                return Err(build_label_error(
+                    "input to `to vcf` must be a record or a table of contacts".into(),
+                    span,
+                ))
+            }
+        };
+
+        let mut out = String::new();
+        for contact in contacts {
+            out.push_str(&contact_to_vcf(contact)?);
+        }
+
+        Ok(Value::string(out, span))
+    }
+}
+
+fn build_label_error(msg: String, span: Span) -> LabeledError {
+    LabeledError::new("Cannot convert to vcf").with_label(msg, span)
+}
+
+fn contact_to_vcf(contact: &Value) -> Result<String, LabeledError> {
+    let span = contact.span();
+    let record = contact
+        .as_record()
+        .map_err(|_| build_label_error("each contact must be a record".into(), span))?;
+
+    let properties = record
+        .get("properties")
+        .ok_or_else(|| build_label_error("missing 'properties' field".into(), span))?;
+
+    let mut out = String::from("BEGIN:VCARD\r\n");
+    out.push_str(&properties_to_vcf(properties)?);
+    out.push_str("END:VCARD\r\n");
+    Ok(out)
+}
+
+fn properties_to_vcf(properties: &Value) -> Result<String, LabeledError> {
+    let span = properties.span();
+    let Value::List { vals, .. } = properties else {
+        return Err(build_label_error("'properties' must be a list".into(), span));
+    };
+
+    let mut out = String::new();
+    for prop in vals {
+        let record: &Record = prop
+            .as_record()
+            .map_err(|_| build_label_error("each property must be a record".into(), span))?;
+
+        let name = record
+            .get("name")
+            .ok_or_else(|| build_label_error("property is missing 'name'".into(), span))?
+            .coerce_str()?;
+        let value = match record.get("value") {
+            Some(Value::Nothing { .. }) | None => String::new(),
+            Some(other) => other.coerce_str()?.into_owned(),
+        };
+
+        let mut line = name.into_owned();
+        if let Some(Value::Record { val, .. }) = record.get("params") {
+            for (param_name, param_values) in val.iter() {
+                let Value::List { vals, .. } = param_values else {
+                    continue;
+                };
+                let joined = vals
+                    .iter()
+                    .map(|v| v.coerce_str().map(|s| s.into_owned()))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(",");
+                line.push(';');
+                line.push_str(param_name);
+                line.push('=');
+                line.push_str(&joined);
+            }
+        }
+        line.push(':');
+        line.push_str(&value);
+
+        out.push_str(&line);
+        out.push_str("\r\n");
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use nu_plugin_test_support::PluginTest;
+    use nu_protocol::ShellError;
+
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<(), ShellError> {
+        let plugin = FormatCmdsPlugin {};
+        let cmd = IntoVcf {};
+
+        let mut plugin_test = PluginTest::new("formats", plugin.into())?;
+        plugin_test.test_command_examples(&cmd)
+    }
+}
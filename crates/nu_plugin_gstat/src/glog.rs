@@ -0,0 +1,60 @@
+use git2::Repository;
+use nu_protocol::{record, LabeledError, Span, Spanned, Value};
+use std::path::Path;
+
+#[derive(Default)]
+pub struct GLog;
+
+impl GLog {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn glog(
+        &self,
+        current_dir: &str,
+        path: Option<Spanned<String>>,
+        max_count: Option<i64>,
+        span: Span,
+    ) -> Result<Value, LabeledError> {
+        let path = path.map(|p| p.item).unwrap_or_else(|| ".".to_string());
+        let absolute_path = Path::new(current_dir).join(path);
+
+        let repo = Repository::discover(&absolute_path).map_err(|err| {
+            LabeledError::new("not a git repository")
+                .with_label(err.to_string(), span)
+        })?;
+
+        let mut revwalk = repo
+            .revwalk()
+            .map_err(|err| LabeledError::new("failed to walk git log").with_label(err.to_string(), span))?;
+        revwalk
+            .push_head()
+            .map_err(|err| LabeledError::new("failed to walk git log").with_label(err.to_string(), span))?;
+
+        let max_count = max_count.unwrap_or(50).max(0) as usize;
+
+        let mut commits = Vec::new();
+        for oid in revwalk.take(max_count) {
+            let oid = oid.map_err(|err| LabeledError::new("failed to read commit").with_label(err.to_string(), span))?;
+            let commit = repo
+                .find_commit(oid)
+                .map_err(|err| LabeledError::new("failed to read commit").with_label(err.to_string(), span))?;
+
+            let author = commit.author();
+            commits.push(Value::record(
+                record! {
+                    "hash" => Value::string(oid.to_string(), span),
+                    "short_hash" => Value::string(oid.to_string()[..7.min(oid.to_string().len())].to_string(), span),
+                    "author" => Value::string(author.name().unwrap_or("").to_string(), span),
+                    "email" => Value::string(author.email().unwrap_or("").to_string(), span),
+                    "date" => Value::int(commit.time().seconds(), span),
+                    "summary" => Value::string(commit.summary().unwrap_or("").to_string(), span),
+                },
+                span,
+            ));
+        }
+
+        Ok(Value::list(commits, span))
+    }
+}
@@ -1,5 +1,9 @@
+#[cfg(feature = "git-log")]
+mod glog;
 mod gstat;
 mod nu;
 
+#[cfg(feature = "git-log")]
+pub use glog::GLog;
 pub use gstat::GStat;
 pub use nu::GStatPlugin;
@@ -1,3 +1,5 @@
+#[cfg(feature = "git-log")]
+use crate::GLog;
 use crate::GStat;
 use nu_plugin::{EngineInterface, EvaluatedCall, Plugin, PluginCommand, SimplePluginCommand};
 use nu_protocol::{Category, LabeledError, Signature, Spanned, SyntaxShape, Value};
@@ -10,6 +12,9 @@ impl Plugin for GStatPlugin {
     }
 
     fn commands(&self) -> Vec<Box<dyn PluginCommand<Plugin = Self>>> {
+        #[cfg(feature = "git-log")]
+        return vec![Box::new(GStat), Box::new(GLog)];
+        #[cfg(not(feature = "git-log"))]
         vec![Box::new(GStat)]
     }
 }
@@ -44,3 +49,41 @@ impl SimplePluginCommand for GStat {
         self.gstat(input, &current_dir, repo_path, call.head)
     }
 }
+
+#[cfg(feature = "git-log")]
+impl SimplePluginCommand for GLog {
+    type Plugin = GStatPlugin;
+
+    fn name(&self) -> &str {
+        "glog"
+    }
+
+    fn description(&self) -> &str {
+        "Get the git log of a repo as a structured table"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self))
+            .optional("path", SyntaxShape::Filepath, "path to repo")
+            .named(
+                "max-count",
+                SyntaxShape::Int,
+                "maximum number of commits to return (default 50)",
+                Some('n'),
+            )
+            .category(Category::Custom("prompt".to_string()))
+    }
+
+    fn run(
+        &self,
+        _plugin: &GStatPlugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let repo_path: Option<Spanned<String>> = call.opt(0)?;
+        let max_count: Option<i64> = call.get_flag("max-count")?;
+        let current_dir = engine.get_current_dir()?;
+        self.glog(&current_dir, repo_path, max_count, call.head)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    query_json::QueryJson, query_web::QueryWeb, query_webpage_info::QueryWebpageInfo,
-    query_xml::QueryXml,
+    query_jaq::QueryJaq, query_json::QueryJson, query_web::QueryWeb,
+    query_webpage_info::QueryWebpageInfo, query_xml::QueryXml,
 };
 use nu_plugin::{EvaluatedCall, Plugin, PluginCommand, SimplePluginCommand};
 use nu_protocol::{Category, LabeledError, Signature, Value};
@@ -22,6 +22,7 @@ impl Plugin for Query {
     fn commands(&self) -> Vec<Box<dyn PluginCommand<Plugin = Self>>> {
         vec![
             Box::new(QueryCommand),
+            Box::new(QueryJaq),
             Box::new(QueryJson),
             Box::new(QueryXml),
             Box::new(QueryWeb),
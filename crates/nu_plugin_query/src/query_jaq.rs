@@ -0,0 +1,392 @@
+use crate::Query;
+use nu_plugin::{EngineInterface, EvaluatedCall, SimplePluginCommand};
+use nu_protocol::{Category, LabeledError, Record, Signature, Spanned, SyntaxShape, Value};
+
+pub struct QueryJaq;
+
+impl SimplePluginCommand for QueryJaq {
+    type Plugin = Query;
+
+    fn name(&self) -> &str {
+        "query jaq"
+    }
+
+    fn description(&self) -> &str {
+        "execute a jq-style filter directly on a nu value, without round-tripping through json"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Supports the common core of jq syntax: identity (.), field access (.foo.bar), \
+         optional fields (.foo?), the `[]`/`[N]`/`[N:M]` iterate/index/slice forms, pipes (|), \
+         and `select(.field OP literal)`. It is not a full jq or jaq implementation."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("filter", SyntaxShape::String, "jq-style filter expression")
+            .category(Category::Filters)
+    }
+
+    fn run(
+        &self,
+        _plugin: &Query,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let filter: Spanned<String> = call.req(0)?;
+        let stages = parse(&filter.item)
+            .map_err(|msg| LabeledError::new(msg).with_label("invalid jq filter", filter.span))?;
+
+        let mut current = vec![input.clone()];
+        for stage in &stages {
+            let mut next = Vec::new();
+            for value in current {
+                next.extend(apply(&value, stage));
+            }
+            current = next;
+        }
+
+        Ok(match current.len() {
+            1 => current.remove(0),
+            _ => Value::list(current, call.head),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Identity,
+    Field(String, bool),
+    Iterate,
+    Index(i64),
+    Slice(Option<i64>, Option<i64>),
+}
+
+#[derive(Debug, Clone)]
+struct Stage {
+    steps: Vec<Step>,
+    select: Option<(String, CmpOp, Literal)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CmpOp {
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+}
+
+#[derive(Debug, Clone)]
+enum Literal {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+}
+
+fn parse(filter: &str) -> Result<Vec<Stage>, String> {
+    split_top_level(filter, '|')
+        .iter()
+        .map(|stage| parse_stage(stage.trim()))
+        .collect()
+}
+
+fn split_top_level(text: &str, sep: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in text.chars() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            c if c == sep && depth == 0 => {
+                parts.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    parts.push(current);
+    parts
+}
+
+fn parse_stage(stage: &str) -> Result<Stage, String> {
+    if let Some(inner) = stage
+        .strip_prefix("select(")
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        let (field, op, literal) = parse_predicate(inner.trim())?;
+        return Ok(Stage {
+            steps: vec![],
+            select: Some((field, op, literal)),
+        });
+    }
+
+    Ok(Stage {
+        steps: parse_steps(stage)?,
+        select: None,
+    })
+}
+
+fn parse_steps(stage: &str) -> Result<Vec<Step>, String> {
+    let mut chars = stage.chars().peekable();
+    let mut steps = Vec::new();
+
+    if chars.peek() != Some(&'.') {
+        return Err(format!("jq filter must start with '.', got '{stage}'"));
+    }
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '.' => {
+                chars.next();
+                let mut name = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c == '.' || c == '[' || c == '?' {
+                        break;
+                    }
+                    name.push(c);
+                    chars.next();
+                }
+                let optional = chars.peek() == Some(&'?');
+                if optional {
+                    chars.next();
+                }
+                if !name.is_empty() {
+                    steps.push(Step::Field(name, optional));
+                } else if steps.is_empty() {
+                    steps.push(Step::Identity);
+                }
+            }
+            '[' => {
+                chars.next();
+                let mut inner = String::new();
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    inner.push(c);
+                }
+                steps.push(parse_bracket(inner.trim())?);
+            }
+            _ => return Err(format!("unexpected character '{c}' in jq filter")),
+        }
+    }
+
+    Ok(steps)
+}
+
+fn parse_bracket(inner: &str) -> Result<Step, String> {
+    if inner.is_empty() {
+        return Ok(Step::Iterate);
+    }
+    if let Some((start, end)) = inner.split_once(':') {
+        let start = parse_opt_i64(start)?;
+        let end = parse_opt_i64(end)?;
+        return Ok(Step::Slice(start, end));
+    }
+    inner
+        .parse::<i64>()
+        .map(Step::Index)
+        .map_err(|_| format!("invalid jq index '[{inner}]'"))
+}
+
+fn parse_opt_i64(text: &str) -> Result<Option<i64>, String> {
+    if text.is_empty() {
+        Ok(None)
+    } else {
+        text.parse::<i64>()
+            .map(Some)
+            .map_err(|_| format!("invalid slice bound '{text}'"))
+    }
+}
+
+fn parse_predicate(expr: &str) -> Result<(String, CmpOp, Literal), String> {
+    let ops: &[(&str, CmpOp)] = &[
+        ("==", CmpOp::Eq),
+        ("!=", CmpOp::Ne),
+        (">=", CmpOp::Ge),
+        ("<=", CmpOp::Le),
+        (">", CmpOp::Gt),
+        ("<", CmpOp::Lt),
+    ];
+
+    for (token, op) in ops {
+        if let Some((lhs, rhs)) = expr.split_once(token) {
+            let field = lhs
+                .trim()
+                .strip_prefix('.')
+                .ok_or_else(|| format!("select() field must start with '.', got '{lhs}'"))?
+                .to_string();
+            let literal = parse_literal(rhs.trim())?;
+            return Ok((field, *op, literal));
+        }
+    }
+
+    Err(format!("select() requires a comparison, got '{expr}'"))
+}
+
+fn parse_literal(text: &str) -> Result<Literal, String> {
+    if let Some(s) = text.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        return Ok(Literal::Str(s.to_string()));
+    }
+    if text == "true" {
+        return Ok(Literal::Bool(true));
+    }
+    if text == "false" {
+        return Ok(Literal::Bool(false));
+    }
+    if let Ok(i) = text.parse::<i64>() {
+        return Ok(Literal::Int(i));
+    }
+    if let Ok(f) = text.parse::<f64>() {
+        return Ok(Literal::Float(f));
+    }
+    Err(format!("invalid select() literal '{text}'"))
+}
+
+fn apply(value: &Value, stage: &Stage) -> Vec<Value> {
+    if let Some((field, op, literal)) = &stage.select {
+        return if matches_predicate(value, field, *op, literal) {
+            vec![value.clone()]
+        } else {
+            vec![]
+        };
+    }
+
+    let mut current = vec![value.clone()];
+    for step in &stage.steps {
+        let mut next = Vec::new();
+        for value in current {
+            next.extend(apply_step(&value, step));
+        }
+        current = next;
+    }
+    current
+}
+
+fn apply_step(value: &Value, step: &Step) -> Vec<Value> {
+    match step {
+        Step::Identity => vec![value.clone()],
+        Step::Field(name, _optional) => match value {
+            Value::Record { val, .. } => val.get(name).cloned().into_iter().collect(),
+            _ => vec![],
+        },
+        Step::Iterate => match value {
+            Value::List { vals, .. } => vals.clone(),
+            Value::Record { val, .. } => val.clone().into_values().collect(),
+            _ => vec![],
+        },
+        Step::Index(i) => match value {
+            Value::List { vals, .. } => index_of(vals, *i).cloned().into_iter().collect(),
+            _ => vec![],
+        },
+        Step::Slice(start, end) => match value {
+            Value::List { vals, .. } => slice_of(vals.clone(), *start, *end),
+            _ => vec![],
+        },
+    }
+}
+
+fn index_of(vals: &[Value], i: i64) -> Option<&Value> {
+    let len = vals.len() as i64;
+    let idx = if i < 0 { len + i } else { i };
+    usize::try_from(idx).ok().and_then(|idx| vals.get(idx))
+}
+
+fn slice_of(vals: Vec<Value>, start: Option<i64>, end: Option<i64>) -> Vec<Value> {
+    let len = vals.len() as i64;
+    let normalize = |i: i64| -> i64 {
+        if i < 0 {
+            (len + i).max(0)
+        } else {
+            i.min(len)
+        }
+    };
+    let start = normalize(start.unwrap_or(0));
+    let end = normalize(end.unwrap_or(len));
+    if start >= end {
+        return vec![];
+    }
+    vals.into_iter()
+        .skip(start as usize)
+        .take((end - start) as usize)
+        .collect()
+}
+
+fn matches_predicate(value: &Value, field: &str, op: CmpOp, literal: &Literal) -> bool {
+    let Value::Record { val, .. } = value else {
+        return false;
+    };
+    let Some(field_value) = val.get(field) else {
+        return false;
+    };
+
+    match (field_value, literal) {
+        (Value::Int { val, .. }, Literal::Int(lit)) => compare(*val, *lit, op),
+        (Value::Int { val, .. }, Literal::Float(lit)) => compare(*val as f64, *lit, op),
+        (Value::Float { val, .. }, Literal::Float(lit)) => compare(*val, *lit, op),
+        (Value::Float { val, .. }, Literal::Int(lit)) => compare(*val, *lit as f64, op),
+        (Value::String { val, .. }, Literal::Str(lit)) => compare(val.as_str(), lit.as_str(), op),
+        (Value::Bool { val, .. }, Literal::Bool(lit)) => compare(*val, *lit, op),
+        _ => false,
+    }
+}
+
+fn compare<T: PartialOrd>(lhs: T, rhs: T, op: CmpOp) -> bool {
+    match op {
+        CmpOp::Eq => lhs == rhs,
+        CmpOp::Ne => lhs != rhs,
+        CmpOp::Gt => lhs > rhs,
+        CmpOp::Lt => lhs < rhs,
+        CmpOp::Ge => lhs >= rhs,
+        CmpOp::Le => lhs <= rhs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_access() {
+        let record = Record::from_iter([("name".to_string(), Value::test_string("Tom"))]);
+        let value = Value::test_record(record);
+        let stages = parse(".name").unwrap();
+        let mut current = vec![value];
+        for stage in &stages {
+            let mut next = Vec::new();
+            for value in current {
+                next.extend(apply(&value, stage));
+            }
+            current = next;
+        }
+        assert_eq!(current, vec![Value::test_string("Tom")]);
+    }
+
+    #[test]
+    fn select_filters_records() {
+        let a = Value::test_record(Record::from_iter([(
+            "price".to_string(),
+            Value::test_int(10),
+        )]));
+        let b = Value::test_record(Record::from_iter([(
+            "price".to_string(),
+            Value::test_int(20),
+        )]));
+        let stages = parse("select(.price > 15)").unwrap();
+        let mut current = vec![a, b];
+        for stage in &stages {
+            let mut next = Vec::new();
+            for value in current {
+                next.extend(apply(&value, stage));
+            }
+            current = next;
+        }
+        assert_eq!(current.len(), 1);
+    }
+}
@@ -4,6 +4,7 @@ use nu_protocol::{
     record, Category, LabeledError, Record, Signature, Span, Spanned, SyntaxShape, Value,
 };
 use sxd_document::parser;
+use sxd_xpath::nodeset::Node;
 use sxd_xpath::{Context, Factory};
 
 pub struct QueryXml;
@@ -19,9 +20,21 @@ impl SimplePluginCommand for QueryXml {
         "execute xpath query on xml"
     }
 
+    fn extra_description(&self) -> &str {
+        "Namespace prefixes used in the query can be registered with --namespace, as a record \
+         mapping each prefix to its URI. Matched attribute nodes are returned as {name, value} \
+         records instead of plain strings."
+    }
+
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .required("query", SyntaxShape::String, "xpath query")
+            .named(
+                "namespace",
+                SyntaxShape::Record(vec![]),
+                "a record mapping namespace prefixes to their URI, for use in the query",
+                Some('n'),
+            )
             .category(Category::Filters)
     }
 
@@ -33,8 +46,9 @@ impl SimplePluginCommand for QueryXml {
         input: &Value,
     ) -> Result<Value, LabeledError> {
         let query: Option<Spanned<String>> = call.opt(0)?;
+        let namespaces: Option<Record> = call.get_flag("namespace")?;
 
-        execute_xpath_query(call, input, query)
+        execute_xpath_query(call, input, query, namespaces)
     }
 }
 
@@ -42,6 +56,7 @@ pub fn execute_xpath_query(
     call: &EvaluatedCall,
     input: &Value,
     query: Option<Spanned<String>>,
+    namespaces: Option<Record>,
 ) -> Result<Value, LabeledError> {
     let (query_string, span) = match &query {
         Some(v) => (&v.item, v.span),
@@ -65,11 +80,17 @@ pub fn execute_xpath_query(
     let package = package.expect("invalid xml document");
 
     let document = package.as_document();
-    let context = Context::new();
+    let mut context = Context::new();
+
+    for (prefix, uri) in namespaces.into_iter().flatten() {
+        let uri = uri
+            .coerce_into_string()
+            .map_err(|err| LabeledError::new("invalid namespace uri").with_inner(err))?;
+        context.set_namespace(&prefix, &uri);
+    }
 
     // leaving this here for augmentation at some point
     // build_variables(&arguments, &mut context);
-    // build_namespaces(&arguments, &mut context);
     let res = xpath.evaluate(&context, document.root());
 
     // Some xpath statements can be long, so let's truncate it with ellipsis
@@ -89,7 +110,7 @@ pub fn execute_xpath_query(
             match r {
                 sxd_xpath::Value::Nodeset(ns) => {
                     for n in ns.document_order() {
-                        record.push(key.clone(), Value::string(n.string_value(), call.head));
+                        record.push(key.clone(), node_to_value(n, call.head));
                     }
                 }
                 sxd_xpath::Value::Boolean(b) => {
@@ -117,6 +138,21 @@ pub fn execute_xpath_query(
     }
 }
 
+/// Renders a matched node as a nu value. Attribute nodes become a `{name, value}` record,
+/// since flattening them to their value alone would lose the attribute's name.
+fn node_to_value(node: Node, span: Span) -> Value {
+    match node {
+        Node::Attribute(attr) => Value::record(
+            record! {
+                "name" => Value::string(attr.name().local_part(), span),
+                "value" => Value::string(attr.value(), span),
+            },
+            span,
+        ),
+        other => Value::string(other.string_value(), span),
+    }
+}
+
 fn build_xpath(xpath_str: &str, span: Span) -> Result<sxd_xpath::XPath, LabeledError> {
     let factory = Factory::new();
 
@@ -132,7 +168,7 @@ fn build_xpath(xpath_str: &str, span: Span) -> Result<sxd_xpath::XPath, LabeledE
 mod tests {
     use super::execute_xpath_query as query;
     use nu_plugin::EvaluatedCall;
-    use nu_protocol::{record, Span, Spanned, Value};
+    use nu_protocol::{record, Record, Span, Spanned, Value};
 
     #[test]
     fn position_function_in_predicate() {
@@ -152,7 +188,7 @@ mod tests {
             span: Span::test_data(),
         };
 
-        let actual = query(&call, &text, Some(spanned_str)).expect("test should not fail");
+        let actual = query(&call, &text, Some(spanned_str), None).expect("test should not fail");
         let expected = Value::list(
             vec![Value::test_record(record! {
                 "count(//a/*[posit..." => Value::test_float(1.0),
@@ -181,7 +217,7 @@ mod tests {
             span: Span::test_data(),
         };
 
-        let actual = query(&call, &text, Some(spanned_str)).expect("test should not fail");
+        let actual = query(&call, &text, Some(spanned_str), None).expect("test should not fail");
         let expected = Value::list(
             vec![Value::test_record(record! {
                 "count(//*[contain..." => Value::test_float(1.0),
@@ -191,4 +227,69 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn namespace_prefix_resolves_in_query() {
+        let call = EvaluatedCall {
+            head: Span::test_data(),
+            positional: vec![],
+            named: vec![],
+        };
+
+        let text = Value::string(
+            r#"<?xml version="1.0" encoding="UTF-8"?><a xmlns:foo="urn:foo"><foo:b/></a>"#,
+            Span::test_data(),
+        );
+
+        let spanned_str: Spanned<String> = Spanned {
+            item: "count(//foo:b)".to_string(),
+            span: Span::test_data(),
+        };
+
+        let mut namespaces = Record::new();
+        namespaces.push("foo", Value::test_string("urn:foo"));
+
+        let actual =
+            query(&call, &text, Some(spanned_str), Some(namespaces)).expect("test should not fail");
+        let expected = Value::list(
+            vec![Value::test_record(record! {
+                "count(//foo:b)" => Value::test_float(1.0),
+            })],
+            Span::test_data(),
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn attribute_axis_returns_name_value_record() {
+        let call = EvaluatedCall {
+            head: Span::test_data(),
+            positional: vec![],
+            named: vec![],
+        };
+
+        let text = Value::string(
+            r#"<?xml version="1.0" encoding="UTF-8"?><a id="1"/>"#,
+            Span::test_data(),
+        );
+
+        let spanned_str: Spanned<String> = Spanned {
+            item: "//a/@id".to_string(),
+            span: Span::test_data(),
+        };
+
+        let actual = query(&call, &text, Some(spanned_str), None).expect("test should not fail");
+        let expected = Value::list(
+            vec![Value::test_record(record! {
+                "//a/@id" => Value::test_record(record! {
+                    "name" => Value::test_string("id"),
+                    "value" => Value::test_string("1"),
+                }),
+            })],
+            Span::test_data(),
+        );
+
+        assert_eq!(actual, expected);
+    }
 }
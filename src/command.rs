@@ -95,6 +95,7 @@ pub(crate) fn parse_commandline_args(
             let no_config_file = call.get_named_arg("no-config-file");
             let no_history = call.get_named_arg("no-history");
             let no_std_lib = call.get_named_arg("no-std-lib");
+            let no_ansi = call.has_flag(engine_state, &mut stack, "no-ansi")?;
             let config_file = call.get_flag_expr("config");
             let env_file = call.get_flag_expr("env-config");
             let log_level = call.get_flag_expr("log-level");
@@ -107,6 +108,7 @@ pub(crate) fn parse_commandline_args(
             let error_style: Option<Value> =
                 call.get_flag(engine_state, &mut stack, "error-style")?;
             let no_newline = call.get_named_arg("no-newline");
+            let json = call.get_named_arg("json");
 
             // ide flags
             let lsp = call.has_flag(engine_state, &mut stack, "lsp")?;
@@ -234,6 +236,7 @@ pub(crate) fn parse_commandline_args(
                 no_config_file,
                 no_history,
                 no_std_lib,
+                no_ansi,
                 config_file,
                 env_file,
                 log_level,
@@ -251,6 +254,7 @@ pub(crate) fn parse_commandline_args(
                 table_mode,
                 error_style,
                 no_newline,
+                json,
             });
         }
     }
@@ -275,6 +279,7 @@ pub(crate) struct NushellCliArgs {
     pub(crate) no_config_file: Option<Spanned<String>>,
     pub(crate) no_history: Option<Spanned<String>>,
     pub(crate) no_std_lib: Option<Spanned<String>>,
+    pub(crate) no_ansi: bool,
     pub(crate) config_file: Option<Spanned<String>>,
     pub(crate) env_file: Option<Spanned<String>>,
     pub(crate) log_level: Option<Spanned<String>>,
@@ -285,6 +290,7 @@ pub(crate) struct NushellCliArgs {
     pub(crate) table_mode: Option<Value>,
     pub(crate) error_style: Option<Value>,
     pub(crate) no_newline: Option<Spanned<String>>,
+    pub(crate) json: Option<Spanned<String>>,
     pub(crate) include_path: Option<Spanned<String>>,
     pub(crate) lsp: bool,
     pub(crate) ide_goto_def: Option<Value>,
@@ -334,10 +340,15 @@ impl Command for Nu {
             .named(
                 "error-style",
                 SyntaxShape::String,
-                "the error style to use (fancy or plain). default: fancy",
+                "the error style to use (fancy, plain, or json). default: fancy",
                 None,
             )
             .switch("no-newline", "print the result for --commands(-c) without a newline", None)
+            .switch(
+                "json",
+                "print the result for --commands(-c) as JSON instead of a table",
+                None,
+            )
             .switch(
                 "no-config-file",
                 "start with no config file and no env file",
@@ -349,6 +360,11 @@ impl Command for Nu {
                 None,
             )
             .switch("no-std-lib", "start with no standard library", None)
+            .switch(
+                "no-ansi",
+                "disable ANSI escape sequences in all output (tables, errors, prompts)",
+                None,
+            )
             .named(
                 "threads",
                 SyntaxShape::Int,
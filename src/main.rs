@@ -213,6 +213,12 @@ fn main() -> Result<()> {
 
     engine_state.history_enabled = parsed_nu_cli_args.no_history.is_none();
 
+    if parsed_nu_cli_args.no_ansi {
+        let mut config = (*engine_state.get_config()).clone();
+        config.use_ansi_coloring = nu_protocol::UseAnsiColoring::False;
+        engine_state.set_config(config);
+    }
+
     let use_color = engine_state
         .get_config()
         .use_ansi_coloring
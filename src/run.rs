@@ -95,6 +95,7 @@ pub(crate) fn run_commands(
             table_mode: parsed_nu_cli_args.table_mode,
             error_style: parsed_nu_cli_args.error_style,
             no_newline: parsed_nu_cli_args.no_newline.is_some(),
+            json: parsed_nu_cli_args.json.is_some(),
         },
     );
     perf!("evaluate_commands", start_time, use_color);